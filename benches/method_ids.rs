@@ -0,0 +1,56 @@
+//! Measures what caching class and method IDs saves on a tight loop of calls.
+//!
+//! Run with `cargo bench --bench method_ids`. The bound method resolves its `jclass` and `jmethodID` once and then
+//! reuses them, while the uncached variant looks both up on every call, like naive JNI code would.
+
+use std::time::{Duration, Instant};
+
+use duchess::java::lang::StringExt;
+use duchess::plumbing::{find_class, find_method};
+use duchess::{java, prelude::*, Jvm};
+
+const CALLS: u32 = 100_000;
+
+fn main() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let string = "duchess"
+            .to_java::<java::lang::String>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+
+        // Warm up, which also fills the caches.
+        for _ in 0..CALLS {
+            string.length().execute_with(jvm)?;
+        }
+
+        let cached = time(|| {
+            for _ in 0..CALLS {
+                string.length().execute_with(jvm)?;
+            }
+            Ok(())
+        })?;
+
+        let uncached = time(|| {
+            for _ in 0..CALLS {
+                let class = find_class(jvm, c"java/lang/String")?;
+                find_method(jvm, &class, c"length", c"()I", false)?;
+                string.length().execute_with(jvm)?;
+            }
+            Ok(())
+        })?;
+
+        println!("cached IDs:   {:>6.0} ns/call", per_call(cached));
+        println!("uncached IDs: {:>6.0} ns/call", per_call(uncached));
+        Ok(())
+    })
+}
+
+fn time<'jvm>(f: impl FnOnce() -> duchess::Result<'jvm, ()>) -> duchess::Result<'jvm, Duration> {
+    let start = Instant::now();
+    f()?;
+    Ok(start.elapsed())
+}
+
+fn per_call(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / CALLS as f64
+}
@@ -1,5 +1,4 @@
 use duchess::java::lang::ThrowableExt;
-use duchess::java::util::{HashMap as JavaHashMap, MapExt};
 use duchess::{java, prelude::*, Global, Jvm, Local, ToRust};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -24,24 +23,6 @@ duchess::java_package! {
     class AuthorizationExceptionDenied { * }
 }
 
-// XX: can be removed when we automatically look through extends/implements
-unsafe impl duchess::plumbing::Upcast<java::lang::Throwable>
-    for auth::AuthenticationExceptionUnauthenticated
-{
-}
-unsafe impl duchess::plumbing::Upcast<java::lang::Throwable>
-    for auth::AuthenticationExceptionInvalidSecurityToken
-{
-}
-unsafe impl duchess::plumbing::Upcast<java::lang::Throwable>
-    for auth::AuthenticationExceptionInvalidSignature
-{
-}
-unsafe impl duchess::plumbing::Upcast<java::lang::Throwable>
-    for auth::AuthorizationExceptionDenied
-{
-}
-
 pub struct HttpAuth(Global<auth::HttpAuth>);
 
 #[derive(Debug, duchess::ToJava)]
@@ -119,16 +100,15 @@ impl HttpAuth {
 
 impl ToRust<AuthenticateError> for duchess::java::lang::Throwable {
     fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> duchess::Result<'jvm, AuthenticateError> {
-        // XX: why can't we infer the <Throwable, ?
-        if let Ok(x) = self
+        if let Ok(message) = self
             .try_downcast::<auth::AuthenticationExceptionUnauthenticated>()
+            .map_java(|x, jvm| {
+                x.user_message()
+                    .to_rust_string_or_default()
+                    .execute_with(jvm)
+            })
             .execute_with(jvm)?
         {
-            let message = x
-                .user_message()
-                .assert_not_null()
-                .to_rust()
-                .execute_with(jvm)?;
             Ok(AuthenticateError::InternalError(message))
         // XX: should we add a .is_instance() alias for try_downcast().is_ok()?
         } else if self
@@ -146,8 +126,7 @@ impl ToRust<AuthenticateError> for duchess::java::lang::Throwable {
         } else {
             let message = self
                 .get_message()
-                .assert_not_null()
-                .to_rust()
+                .to_rust_string_or_default()
                 .execute_with(jvm)?;
             Ok(AuthenticateError::InternalError(message))
         }
@@ -156,21 +135,20 @@ impl ToRust<AuthenticateError> for duchess::java::lang::Throwable {
 
 impl ToRust<AuthorizeError> for duchess::java::lang::Throwable {
     fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> duchess::Result<'jvm, AuthorizeError> {
-        if let Ok(x) = self
+        if let Ok(message) = self
             .try_downcast::<auth::AuthorizationExceptionDenied>()
+            .map_java(|x, jvm| {
+                x.user_message()
+                    .to_rust_string_or_default()
+                    .execute_with(jvm)
+            })
             .execute_with(jvm)?
         {
-            let message = x
-                .user_message()
-                .assert_not_null()
-                .to_rust()
-                .execute_with(jvm)?;
             Ok(AuthorizeError::Denied(message))
         } else {
             let message = self
                 .get_message()
-                .assert_not_null()
-                .to_rust()
+                .to_rust_string_or_default()
                 .execute_with(jvm)?;
             Ok(AuthorizeError::InternalError(message))
         }
@@ -181,12 +159,11 @@ impl JvmOp for &AuthorizeRequest {
     type Output<'jvm> = Local<'jvm, auth::AuthorizeRequest>;
 
     fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> duchess::Result<'jvm, Self::Output<'jvm>> {
-        let java_context = JavaHashMap::new().execute_with(jvm)?;
-        for (key, value) in &self.context {
-            java_context
-                .put(key.as_str(), value.as_str())
-                .execute_with(jvm)?;
-        }
+        let java_context = self
+            .context
+            .to_java::<java::util::Map<java::lang::String, java::lang::String>>()
+            .assert_not_null()
+            .execute_with(jvm)?;
 
         auth::AuthorizeRequest::new(self.resource.as_str(), self.action.as_str(), &java_context)
             .execute_with(jvm)
@@ -0,0 +1,57 @@
+//! Processes Java data that is far larger than what fits in memory, converting it to Rust a piece at a time:
+//!
+//! ```text
+//! cargo run --release --example large_payloads
+//! ```
+//!
+//! The collection is 4 GiB of text, generated lazily by a Java iterator, and the JVM's heap is capped at 512 MiB, so
+//! neither side can hold it all at once. Converting it with `to_rust()` into a `Vec<String>` would need all 4 GiB.
+
+use duchess::{prelude::*, GlobalIter, Jvm, StringChunks};
+
+duchess::java_package! {
+    package payloads;
+
+    class Payloads { * }
+}
+
+/// Number of records in the collection.
+const RECORDS: i64 = 4096;
+
+/// Length of each record, in characters (and bytes, since they are ASCII).
+const RECORD_LEN: i32 = 1024 * 1024;
+
+fn main() -> duchess::GlobalResult<()> {
+    Jvm::builder().custom("-Xmx512m").try_launch()?;
+
+    // A collection, element by element: only one record is in Rust memory at a time
+    let records: GlobalIter<String> = payloads::Payloads::records(RECORDS, RECORD_LEN)
+        .assert_not_null()
+        .to_global_iter()
+        .execute()?;
+    let mut bytes = 0_u64;
+    let mut vowels = 0_u64;
+    for record in records {
+        let record = record?;
+        bytes += record.len() as u64;
+        vowels += record.bytes().filter(|b| b"aeiou".contains(b)).count() as u64;
+    }
+    println!(
+        "processed {} records, {:.1} GiB, {vowels} vowels",
+        RECORDS,
+        bytes as f64 / (1024.0 * 1024.0 * 1024.0)
+    );
+
+    // A large string, 64 KiB at a time
+    let chunks: StringChunks = payloads::Payloads::text(1_000_000)
+        .to_rust_chunks(64 * 1024)
+        .execute()?;
+    let total = chunks.remaining_len();
+    let mut ducks = 0;
+    for chunk in chunks {
+        ducks += chunk?.matches('🦆').count();
+    }
+    println!("read a string of {total} UTF-16 code units with {ducks} ducks");
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+package arrays;
+
+public class Grid {
+    public static int[][] identity(int n) {
+        int[][] grid = new int[n][n];
+        for (int i = 0; i < n; i++) {
+            grid[i][i] = 1;
+        }
+        return grid;
+    }
+
+    public static int sum(int[][] grid) {
+        int total = 0;
+        for (int[] row : grid) {
+            for (int cell : row) {
+                total += cell;
+            }
+        }
+        return total;
+    }
+
+    public static String[] withNull() {
+        return new String[] { "a", null };
+    }
+
+    public static String join(String[] parts) {
+        StringBuilder joined = new StringBuilder();
+        for (String part : parts) {
+            if (joined.length() > 0) {
+                joined.append(',');
+            }
+            joined.append(part);
+        }
+        return joined.toString();
+    }
+
+    public static int checksum(byte[] bytes) {
+        int total = 0;
+        for (byte b : bytes) {
+            total += b & 0xff;
+        }
+        return total;
+    }
+
+    public static long total(long[] values) {
+        long total = 0;
+        for (long value : values) {
+            total += value;
+        }
+        return total;
+    }
+}
@@ -0,0 +1,22 @@
+package blocking;
+
+public class Sleeper {
+    private static volatile boolean interrupted;
+
+    public static void sleep(long millis) throws InterruptedException {
+        try {
+            Thread.sleep(millis);
+        } catch (InterruptedException e) {
+            interrupted = true;
+            throw e;
+        }
+    }
+
+    public static boolean wasInterrupted() {
+        return interrupted;
+    }
+
+    public static int answer() {
+        return 42;
+    }
+}
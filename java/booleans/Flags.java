@@ -0,0 +1,46 @@
+package booleans;
+
+import java.lang.reflect.Field;
+import sun.misc.Unsafe;
+
+public class Flags {
+    public static boolean flag;
+
+    public static boolean isEven(int n) {
+        return n % 2 == 0;
+    }
+
+    public static boolean not(boolean b) {
+        return !b;
+    }
+
+    public static int count(boolean[] values) {
+        int count = 0;
+        for (boolean value : values) {
+            if (value) {
+                count++;
+            }
+        }
+        return count;
+    }
+
+    // Stores `value` as the byte backing `flag`, bypassing Java's normalization of booleans to 0 or 1.
+    public static void setFlagByte(byte value) throws Exception {
+        Unsafe unsafe = unsafe();
+        Field field = Flags.class.getField("flag");
+        unsafe.putByte(unsafe.staticFieldBase(field), unsafe.staticFieldOffset(field), value);
+    }
+
+    // Returns `{false, <value>}` where the second element is stored as the byte `value`.
+    public static boolean[] withByte(byte value) throws Exception {
+        boolean[] values = new boolean[2];
+        unsafe().putByte(values, (long) Unsafe.ARRAY_BOOLEAN_BASE_OFFSET + Unsafe.ARRAY_BOOLEAN_INDEX_SCALE, value);
+        return values;
+    }
+
+    private static Unsafe unsafe() throws Exception {
+        Field field = Unsafe.class.getDeclaredField("theUnsafe");
+        field.setAccessible(true);
+        return (Unsafe) field.get(null);
+    }
+}
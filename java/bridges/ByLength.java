@@ -0,0 +1,9 @@
+package bridges;
+
+import java.util.Comparator;
+
+public class ByLength implements Comparator<String> {
+    public int compare(String a, String b) {
+        return Integer.compare(a.length(), b.length());
+    }
+}
@@ -0,0 +1,18 @@
+package bridges;
+
+/** Erased, the constructor takes and `get` returns a `Number`. */
+public class Holder<T extends Number> {
+    private final T value;
+
+    public Holder(T value) {
+        this.value = value;
+    }
+
+    public T get() {
+        return value;
+    }
+
+    public <U extends T> boolean holds(U candidate) {
+        return value.equals(candidate);
+    }
+}
@@ -0,0 +1,7 @@
+package bridges;
+
+public class LengthScorer implements Scorer<String> {
+    public int score(String value) {
+        return value.length();
+    }
+}
@@ -0,0 +1,6 @@
+package bridges;
+
+/** Erased, `score` takes a `CharSequence`, so implementations for a subtype have a bridge method. */
+public interface Scorer<T extends CharSequence> {
+    int score(T value);
+}
@@ -0,0 +1,28 @@
+package closeables;
+
+public class Resource implements AutoCloseable {
+    private final String name;
+    private final boolean failOnClose;
+    private boolean closed;
+
+    public Resource(String name, boolean failOnClose) {
+        this.name = name;
+        this.failOnClose = failOnClose;
+    }
+
+    public String name() {
+        return name;
+    }
+
+    public boolean isClosed() {
+        return closed;
+    }
+
+    @Override
+    public void close() throws Exception {
+        closed = true;
+        if (failOnClose) {
+            throw new IllegalStateException(name + " failed to close");
+        }
+    }
+}
@@ -0,0 +1,22 @@
+package compare;
+
+import java.util.ArrayList;
+import java.util.List;
+
+public class Erased {
+    // Deliberately loses the type of the objects `comparable` can be compared to.
+    @SuppressWarnings("unchecked")
+    public static Comparable<Object> erase(Comparable<?> comparable) {
+        return (Comparable<Object>) comparable;
+    }
+
+    // Deliberately puts an object that isn't a `String` into a list of strings.
+    @SuppressWarnings({"unchecked", "rawtypes"})
+    public static List<String> polluted() {
+        List list = new ArrayList();
+        list.add("pear");
+        list.add(1);
+        list.add("apple");
+        return list;
+    }
+}
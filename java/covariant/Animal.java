@@ -0,0 +1,11 @@
+package covariant;
+
+public class Animal {
+    public Animal itself() {
+        return this;
+    }
+
+    public Object name() {
+        return "animal";
+    }
+}
@@ -0,0 +1,29 @@
+package covariant;
+
+public class Dog extends Animal implements Comparable<Dog> {
+    private final int age;
+
+    public Dog(int age) {
+        this.age = age;
+    }
+
+    @Override
+    public Dog itself() {
+        return this;
+    }
+
+    @Override
+    @Deprecated
+    public String name() {
+        return "dog";
+    }
+
+    public int age() {
+        return age;
+    }
+
+    @Override
+    public int compareTo(Dog other) {
+        return Integer.compare(age, other.age);
+    }
+}
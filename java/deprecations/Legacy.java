@@ -0,0 +1,20 @@
+package deprecations;
+
+public class Legacy {
+    public Legacy() {
+    }
+
+    @Deprecated(since = "2.0")
+    public int oldValue() {
+        return 1;
+    }
+
+    public int newValue() {
+        return 2;
+    }
+
+    @Deprecated(since = "2.0", forRemoval = true)
+    public static Legacy create() {
+        return new Legacy();
+    }
+}
@@ -0,0 +1,7 @@
+package exceptions;
+
+public class CheckedFailure extends Exception {
+    public CheckedFailure(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,31 @@
+package exceptions;
+
+public class Thrower {
+    public static String succeed() {
+        return "ok";
+    }
+
+    public static String throwChecked() throws CheckedFailure {
+        throw new CheckedFailure("checked");
+    }
+
+    public static String throwUnchecked() {
+        throw new UncheckedFailure("unchecked");
+    }
+
+    public static String throwWrapped() {
+        throw new IllegalStateException("wrapped", new UncheckedFailure("root cause"));
+    }
+
+    public static String throwCyclic() {
+        UncheckedFailure outer = new UncheckedFailure("outer");
+        UncheckedFailure inner = new UncheckedFailure("inner");
+        outer.initCause(inner);
+        inner.initCause(outer);
+        throw outer;
+    }
+
+    public static String throwWithoutMessage() {
+        throw new UnsupportedOperationException();
+    }
+}
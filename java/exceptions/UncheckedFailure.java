@@ -0,0 +1,7 @@
+package exceptions;
+
+public class UncheckedFailure extends RuntimeException {
+    public UncheckedFailure(String message) {
+        super(message);
+    }
+}
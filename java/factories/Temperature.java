@@ -0,0 +1,28 @@
+package factories;
+
+public class Temperature {
+    private final double celsius;
+
+    private Temperature(double celsius) {
+        this.celsius = celsius;
+    }
+
+    public static Temperature ofCelsius(double celsius) {
+        return new Temperature(celsius);
+    }
+
+    public static Temperature parse(String text) {
+        if (!text.endsWith("C")) {
+            throw new IllegalArgumentException("not a temperature: " + text);
+        }
+        return new Temperature(Double.parseDouble(text.substring(0, text.length() - 1)));
+    }
+
+    public static Temperature find(String name) {
+        return name.equals("freezing") ? new Temperature(0) : null;
+    }
+
+    public double celsius() {
+        return celsius;
+    }
+}
@@ -0,0 +1,9 @@
+package fields;
+
+public class Holder<T> {
+    public T value;
+
+    public Holder(T value) {
+        this.value = value;
+    }
+}
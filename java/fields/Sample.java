@@ -0,0 +1,22 @@
+package fields;
+
+public class Sample {
+    public static final int LIMIT = 10;
+
+    public int count;
+    public String label;
+    public double ratio = 0.5;
+
+    public Sample(int count, String label) {
+        this.count = count;
+        this.label = label;
+    }
+
+    public int getCount() {
+        return count;
+    }
+
+    public void increment() {
+        count++;
+    }
+}
@@ -0,0 +1,16 @@
+package functional;
+
+// Calls the functional interfaces it is passed, to check that Rust closures implement them.
+public class Callers {
+    public static String greet(Transformer transformer, String name) {
+        return "Hello, " + transformer.apply(name);
+    }
+
+    public static String join(Concat concat, String a, String b, String c) {
+        return concat.combine(concat.combine(a, b), c);
+    }
+
+    public static int reduce(IntOperator operator, int a, int b, int c) {
+        return operator.applyAsInt(operator.applyAsInt(a, b), c);
+    }
+}
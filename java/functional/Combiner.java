@@ -0,0 +1,5 @@
+package functional;
+
+public interface Combiner<T> {
+    T combine(T left, T right);
+}
@@ -0,0 +1,5 @@
+package functional;
+
+// Inherits its abstract method with `T = String`.
+public interface Concat extends Combiner<String> {
+}
@@ -0,0 +1,8 @@
+package functional;
+
+// Implements the abstract method it inherits, so it has none.
+public interface Echo extends Transformer {
+    default String apply(String input) {
+        return input;
+    }
+}
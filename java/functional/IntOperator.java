@@ -0,0 +1,5 @@
+package functional;
+
+public interface IntOperator {
+    int applyAsInt(int left, int right);
+}
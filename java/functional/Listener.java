@@ -0,0 +1,7 @@
+package functional;
+
+public interface Listener {
+    void started();
+
+    void stopped();
+}
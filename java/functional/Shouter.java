@@ -0,0 +1,8 @@
+package functional;
+
+// Only inherits its abstract method.
+public interface Shouter extends Transformer {
+    default String shout(String input) {
+        return apply(input).toUpperCase();
+    }
+}
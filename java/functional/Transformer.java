@@ -0,0 +1,16 @@
+package functional;
+
+// Not annotated `@FunctionalInterface`, but still has a single abstract method.
+public interface Transformer {
+    String apply(String input);
+
+    boolean equals(Object other);
+
+    default Transformer twice() {
+        return input -> apply(apply(input));
+    }
+
+    static Transformer identity() {
+        return input -> input;
+    }
+}
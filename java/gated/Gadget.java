@@ -0,0 +1,14 @@
+package gated;
+
+public class Gadget {
+    public Gadget() {
+    }
+
+    public int size() {
+        return 3;
+    }
+
+    public Widget widget() {
+        return new Widget();
+    }
+}
@@ -0,0 +1,10 @@
+package gated;
+
+public class Widget {
+    public Widget() {
+    }
+
+    public String name() {
+        return "widget";
+    }
+}
@@ -0,0 +1,30 @@
+package generics;
+
+public class Holder<T> {
+    private T value;
+
+    public Holder(T value) {
+        this.value = value;
+    }
+
+    public T get() {
+        return value;
+    }
+
+    public void set(T value) {
+        this.value = value;
+    }
+
+    public static <U> Holder<U> of(U value) {
+        return new Holder<>(value);
+    }
+
+    public static <U> java.util.List<U> twice(U value) {
+        return java.util.Arrays.asList(value, value);
+    }
+
+    @SuppressWarnings({"rawtypes", "unchecked"})
+    public static Holder raw(Object value) {
+        return new Holder(value);
+    }
+}
@@ -0,0 +1,8 @@
+package hidden;
+
+// Only on the class path of the isolated class loader created by `loaders.Isolated`.
+public class Secret {
+    public static String reveal() {
+        return "found";
+    }
+}
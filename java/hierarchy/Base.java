@@ -0,0 +1,8 @@
+package hierarchy;
+
+/** Implements `Named` only through `Labeled`. */
+public abstract class Base implements Labeled {
+    public String name() {
+        return "base";
+    }
+}
@@ -0,0 +1,13 @@
+package hierarchy;
+
+public class Cell<T> implements Source<T> {
+    private final T value;
+
+    public Cell(T value) {
+        this.value = value;
+    }
+
+    public T get() {
+        return value;
+    }
+}
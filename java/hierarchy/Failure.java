@@ -0,0 +1,8 @@
+package hierarchy;
+
+/** A `Throwable` through `IllegalStateException`, which isn't bound. */
+public class Failure extends IllegalStateException {
+    public Failure(String message) {
+        super(message);
+    }
+}
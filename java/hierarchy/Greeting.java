@@ -0,0 +1,8 @@
+package hierarchy;
+
+/** A `Source<String>` through `Cell<String>`. */
+public class Greeting extends Cell<String> {
+    public Greeting() {
+        super("hello");
+    }
+}
@@ -0,0 +1,5 @@
+package hierarchy;
+
+public interface Labeled extends Named {
+    String label();
+}
@@ -0,0 +1,12 @@
+package hierarchy;
+
+/** Inherits `Labeled` and `Named` through `Base`, and overrides `name()`. */
+public class Leaf extends Base {
+    public String name() {
+        return "leaf";
+    }
+
+    public String label() {
+        return "a " + name();
+    }
+}
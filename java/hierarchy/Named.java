@@ -0,0 +1,5 @@
+package hierarchy;
+
+public interface Named {
+    String name();
+}
@@ -0,0 +1,44 @@
+package inner;
+
+import java.util.List;
+
+public class Outer {
+    private final String name;
+
+    public Outer(String name) {
+        this.name = name;
+    }
+
+    public class Counter {
+        private final int start;
+
+        public Counter(int start) {
+            this.start = start;
+        }
+
+        public String describe() {
+            return name + ":" + start;
+        }
+    }
+
+    public class Tally {
+        private final List<String> items;
+
+        public Tally(List<String> items) {
+            this.items = items;
+        }
+
+        public String describe() {
+            return name + ":" + items.size();
+        }
+    }
+
+    public static class Nested {
+        public Nested() {
+        }
+
+        public String describe() {
+            return "nested";
+        }
+    }
+}
@@ -0,0 +1,7 @@
+package keywords.mod.except;
+
+public class Filter {
+    public static String describe() {
+        return "filter";
+    }
+}
@@ -0,0 +1,14 @@
+package keywords.move;
+
+public class Dispatcher {
+    public Dispatcher() {
+    }
+
+    public String match(String input) {
+        return "matched " + input;
+    }
+
+    public static int type() {
+        return 42;
+    }
+}
@@ -0,0 +1,31 @@
+package lists;
+
+import java.util.AbstractList;
+import java.util.Arrays;
+import java.util.List;
+
+public class Lists {
+    public static List<String> words() {
+        return Arrays.asList("alpha", "beta", "gamma");
+    }
+
+    public static List<String> withNull() {
+        return Arrays.asList("alpha", null, "gamma");
+    }
+
+    /** A list whose second element can't be read. */
+    public static List<String> failing() {
+        return new AbstractList<String>() {
+            public String get(int index) {
+                if (index == 1) {
+                    throw new IllegalStateException("element 1 is unavailable");
+                }
+                return "alpha";
+            }
+
+            public int size() {
+                return 3;
+            }
+        };
+    }
+}
@@ -0,0 +1,13 @@
+package loaders;
+
+import java.io.File;
+import java.net.MalformedURLException;
+import java.net.URL;
+import java.net.URLClassLoader;
+
+// Packaged alone in `loaders.jar`, so that a JVM can be launched with it as the only class path entry.
+public class Isolated {
+    public static ClassLoader over(String dir) throws MalformedURLException {
+        return new URLClassLoader(new URL[] { new File(dir).toURI().toURL() });
+    }
+}
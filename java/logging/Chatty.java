@@ -0,0 +1,30 @@
+package logging;
+
+import java.util.logging.Level;
+import java.util.logging.Logger;
+
+public class Chatty {
+    private static final Logger LOGGER = Logger.getLogger("logging.Chatty");
+
+    static {
+        LOGGER.setLevel(Level.ALL);
+    }
+
+    public static void everyLevel() {
+        LOGGER.severe("severe");
+        LOGGER.warning("warning");
+        LOGGER.info("info");
+        LOGGER.config("config");
+        LOGGER.fine("fine");
+        LOGGER.finer("finer");
+        LOGGER.finest("finest");
+    }
+
+    public static void greet(String name) {
+        LOGGER.log(Level.INFO, "hello {0}", name);
+    }
+
+    public static void fail() {
+        LOGGER.log(Level.WARNING, "failed", new IllegalStateException("boom"));
+    }
+}
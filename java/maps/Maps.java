@@ -0,0 +1,47 @@
+package maps;
+
+import java.util.HashMap;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.Properties;
+
+public class Maps {
+    public static Map<String, String> colors() {
+        Map<String, String> map = new HashMap<>();
+        map.put("red", "#ff0000");
+        map.put("green", "#00ff00");
+        return map;
+    }
+
+    public static Map<String, String> withNullValue() {
+        Map<String, String> map = new HashMap<>();
+        map.put("present", "yes");
+        map.put("missing", null);
+        return map;
+    }
+
+    public static Map<String, Integer> scores() {
+        Map<String, Integer> map = new LinkedHashMap<>();
+        map.put("carol", 7);
+        map.put("alice", 12);
+        map.put("bob", 3);
+        return map;
+    }
+
+    public static Map<Integer, String> numbered(int count) {
+        Map<Integer, String> map = new HashMap<>();
+        for (int i = 0; i < count; i++) {
+            map.put(i, Integer.toString(i));
+        }
+        return map;
+    }
+
+    public static Properties withDefaults() {
+        Properties defaults = new Properties();
+        defaults.setProperty("timeout", "30");
+        Properties properties = new Properties(defaults);
+        properties.setProperty("host", "localhost");
+        properties.put("port", 8080);
+        return properties;
+    }
+}
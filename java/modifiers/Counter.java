@@ -0,0 +1,39 @@
+package modifiers;
+
+public class Counter {
+    private int count;
+
+    public synchronized int increment() {
+        return ++count;
+    }
+
+    public final synchronized int get() {
+        return count;
+    }
+
+    public static synchronized Counter create() {
+        return new Counter();
+    }
+
+    public static final synchronized int limit() {
+        return 10;
+    }
+
+    public strictfp double half() {
+        return count / 2.0;
+    }
+
+    public static strictfp double scale(double value) {
+        return value * 1.5;
+    }
+
+    public final synchronized strictfp double average(int other) {
+        return (count + other) / 2.0;
+    }
+
+    public native int doubled();
+
+    public static native int tripled(int value);
+
+    public final synchronized native int negated();
+}
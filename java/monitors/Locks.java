@@ -0,0 +1,21 @@
+package monitors;
+
+public class Locks {
+    private static int count;
+
+    public Locks() {
+    }
+
+    public static boolean holds(Object object) {
+        return Thread.holdsLock(object);
+    }
+
+    public static boolean holdsClassLock() {
+        return Thread.holdsLock(Locks.class);
+    }
+
+    public static synchronized int increment() {
+        count += 1;
+        return count;
+    }
+}
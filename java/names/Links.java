@@ -0,0 +1,17 @@
+package names;
+
+public class Links {
+    public static final String URL = "static";
+
+    public String getHTTPURL() {
+        return "http";
+    }
+
+    public String getURL() {
+        return "upper";
+    }
+
+    public String getUrl() {
+        return "camel";
+    }
+}
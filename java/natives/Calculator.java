@@ -0,0 +1,39 @@
+package natives;
+
+public class Calculator {
+    private final String owner;
+
+    public Calculator(String owner) {
+        this.owner = owner;
+    }
+
+    public String owner() {
+        return owner;
+    }
+
+    public static native int add(int a, int b);
+
+    public native String greet(String name);
+
+    public String greetNobody() {
+        return greet(null);
+    }
+
+    public static native boolean check(String input);
+
+    public static int parse(String input) {
+        return Integer.parseInt(input);
+    }
+
+    public static int addTwice(int a, int b) {
+        return add(add(a, b), b);
+    }
+
+    public static String checkOrMessage(String input) {
+        try {
+            return String.valueOf(check(input));
+        } catch (RuntimeException e) {
+            return e.getMessage();
+        }
+    }
+}
@@ -0,0 +1,18 @@
+package offline;
+
+/** Only packaged in `offline.jar`, which isn't on the classpath when the tests are built. */
+public class Gadget {
+    private final String name;
+
+    public Gadget(String name) {
+        this.name = name;
+    }
+
+    public String name() {
+        return name;
+    }
+
+    public static int version() {
+        return 3;
+    }
+}
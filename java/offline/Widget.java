@@ -0,0 +1,18 @@
+package offline;
+
+public class Widget extends Gadget {
+    private final int size;
+
+    public Widget(String name, int size) {
+        super(name);
+        this.size = size;
+    }
+
+    public int size() {
+        return size;
+    }
+
+    public String describe() {
+        return name() + " of size " + size;
+    }
+}
@@ -0,0 +1,18 @@
+package params;
+
+/** Compiled with `javac -parameters`, so the names of the constructor's arguments are in the class file. */
+public class Contact {
+    private final String firstName;
+    private final String lastName;
+    private final int age;
+
+    public Contact(String firstName, String lastName, int age) {
+        this.firstName = firstName;
+        this.lastName = lastName;
+        this.age = age;
+    }
+
+    public String describe() {
+        return firstName + " " + lastName + " (" + age + ")";
+    }
+}
@@ -0,0 +1,48 @@
+package payloads;
+
+import java.util.Iterator;
+import java.util.NoSuchElementException;
+
+public class Payloads {
+    /** Returns a string of `repeats` copies of a line that mixes ASCII with characters outside the BMP. */
+    public static String text(int repeats) {
+        String line = "duchess \uD83E\uDD86 quacks \uD83D\uDE00\n";
+        StringBuilder builder = new StringBuilder(line.length() * repeats);
+        for (int i = 0; i < repeats; i++) {
+            builder.append(line);
+        }
+        return builder.toString();
+    }
+
+    /** Returns a null string. */
+    public static String missing() {
+        return null;
+    }
+
+    /** Returns a string that ends with an unpaired high surrogate. */
+    public static String broken() {
+        return "ok\uD83E";
+    }
+
+    /**
+     * Returns an iterator over `count` strings of `length` characters each. The strings are only created when they
+     * are requested, so the whole sequence is never in memory at once.
+     */
+    public static Iterator<String> records(long count, int length) {
+        return new Iterator<String>() {
+            private long next = 0;
+
+            public boolean hasNext() {
+                return next < count;
+            }
+
+            public String next() {
+                if (next >= count) {
+                    throw new NoSuchElementException();
+                }
+                char c = (char) ('a' + (next++ % 26));
+                return String.valueOf(c).repeat(length);
+            }
+        };
+    }
+}
@@ -0,0 +1,15 @@
+package pinned;
+
+// Compiled into `java/pinned.jar` only, so that it isn't on the classpath when the bindings are generated.
+public class Version {
+    public Version() {
+    }
+
+    public String describe() {
+        return "pinned 1.2";
+    }
+
+    public static int major() {
+        return 1;
+    }
+}
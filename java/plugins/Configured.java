@@ -0,0 +1,13 @@
+package plugins;
+
+public class Configured implements Plugin {
+    private final String name;
+
+    public Configured(String name) {
+        this.name = name;
+    }
+
+    public String name() {
+        return name;
+    }
+}
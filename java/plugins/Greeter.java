@@ -0,0 +1,7 @@
+package plugins;
+
+public class Greeter implements Plugin {
+    public String name() {
+        return "greeter";
+    }
+}
@@ -0,0 +1,5 @@
+package plugins;
+
+public interface Plugin {
+    String name();
+}
@@ -0,0 +1,17 @@
+package printing;
+
+public class Printer {
+    public static void greet(String name) {
+        System.out.println("Hello, " + name + "! ✓");
+    }
+
+    public static void warn(String message) {
+        System.err.print("warning: " + message);
+    }
+
+    // Prints `line` and reports whether `System.out` recorded an error
+    public static boolean printAndCheck(String line) {
+        System.out.println(line);
+        return System.out.checkError();
+    }
+}
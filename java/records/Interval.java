@@ -0,0 +1,8 @@
+package records;
+
+public record Interval(int start, int end) {
+    /** An empty interval at `start`. */
+    public Interval(int start) {
+        this(start, start);
+    }
+}
@@ -0,0 +1,7 @@
+package records;
+
+public record Point(int x, int y) {
+    public Point scale(int factor) {
+        return new Point(x * factor, y * factor);
+    }
+}
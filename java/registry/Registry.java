@@ -0,0 +1,17 @@
+package registry;
+
+import java.util.HashMap;
+import java.util.Map;
+
+public class Registry {
+    private final Map<String, Object> values = new HashMap<>();
+
+    public Registry() {
+        values.put("name", "duchess");
+        values.put("count", 3);
+    }
+
+    public Object lookup(String key) {
+        return values.get(key);
+    }
+}
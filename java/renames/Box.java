@@ -0,0 +1,11 @@
+package renames;
+
+public class Box extends Result {
+    public Box(int value) {
+        super(value);
+    }
+
+    public Result unbox() {
+        return new Result(value());
+    }
+}
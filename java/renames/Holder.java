@@ -0,0 +1,13 @@
+package renames;
+
+public class Holder {
+    private final Result result;
+
+    public Holder(Result result) {
+        this.result = result;
+    }
+
+    public Result get() {
+        return result;
+    }
+}
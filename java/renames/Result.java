@@ -0,0 +1,21 @@
+package renames;
+
+public class Result {
+    private final int value;
+
+    public Result(int value) {
+        this.value = value;
+    }
+
+    public int value() {
+        return value;
+    }
+
+    public Result plus(Result other) {
+        return new Result(value + other.value);
+    }
+
+    public static Result zero() {
+        return new Result(0);
+    }
+}
@@ -0,0 +1,20 @@
+package secrets;
+
+import java.util.Arrays;
+
+/** Takes its password as a `char[]`, like `KeyStore` and `PBEKeySpec` do, so that callers can zero it. */
+public class Vault {
+    private final char[] password;
+
+    public Vault(char[] password) {
+        this.password = password.clone();
+    }
+
+    public boolean unlock(char[] attempt) {
+        return Arrays.equals(password, attempt);
+    }
+
+    public char[] password() {
+        return password.clone();
+    }
+}
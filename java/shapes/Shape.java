@@ -0,0 +1,12 @@
+package shapes;
+
+public abstract class Shape {
+    public Shape() {
+    }
+
+    public abstract double area();
+
+    public String describe() {
+        return getClass().getSimpleName() + " with area " + area();
+    }
+}
@@ -0,0 +1,14 @@
+package shapes;
+
+public class Square extends Shape {
+    private final double side;
+
+    public Square(double side) {
+        this.side = side;
+    }
+
+    @Override
+    public double area() {
+        return side * side;
+    }
+}
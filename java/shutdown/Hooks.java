@@ -0,0 +1,20 @@
+package shutdown;
+
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+
+public class Hooks {
+    /** Writes `done` to `path` when the JVM shuts down. */
+    public static void touchOnShutdown(String path) {
+        Path file = Paths.get(path);
+        Runtime.getRuntime().addShutdownHook(new Thread(() -> {
+            try {
+                Files.write(file, "done".getBytes());
+            } catch (IOException e) {
+                throw new RuntimeException(e);
+            }
+        }));
+    }
+}
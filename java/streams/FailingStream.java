@@ -0,0 +1,19 @@
+package streams;
+
+import java.io.IOException;
+import java.io.InputStream;
+
+public class FailingStream extends InputStream {
+    public FailingStream() {
+    }
+
+    @Override
+    public int read() throws IOException {
+        throw new IOException("disk on fire");
+    }
+
+    @Override
+    public void close() throws IOException {
+        throw new IOException("stuck open");
+    }
+}
@@ -0,0 +1,14 @@
+package supercall;
+
+public class Animal {
+    public Animal() {
+    }
+
+    public String speak() {
+        return "...";
+    }
+
+    public int legs() {
+        return 4;
+    }
+}
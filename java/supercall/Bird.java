@@ -0,0 +1,21 @@
+package supercall;
+
+public class Bird extends Animal {
+    public Bird() {
+    }
+
+    @Override
+    public String speak() {
+        return "Tweet";
+    }
+
+    @Override
+    public int legs() {
+        return 2;
+    }
+
+    @Override
+    public String toString() {
+        return "a bird";
+    }
+}
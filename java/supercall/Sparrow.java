@@ -0,0 +1,11 @@
+package supercall;
+
+public class Sparrow extends Bird {
+    public Sparrow() {
+    }
+
+    @Override
+    public String speak() {
+        return "Chirp";
+    }
+}
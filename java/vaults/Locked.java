@@ -0,0 +1,7 @@
+package vaults;
+
+public class Locked extends Exception {
+    public Locked() {
+        super("locked");
+    }
+}
@@ -0,0 +1,7 @@
+package vaults;
+
+public class Missing extends Exception {
+    public Missing(String name) {
+        super("no vault named " + name);
+    }
+}
@@ -0,0 +1,46 @@
+package vaults;
+
+public class Vault {
+    private final String code;
+    private int attempts;
+
+    public Vault(String code) {
+        this.code = code;
+    }
+
+    public String open(String code) throws WrongCode, Locked {
+        if (code.isEmpty()) {
+            throw new IllegalArgumentException("empty code");
+        }
+        if (attempts >= 2) {
+            throw new Locked();
+        }
+        if (!code.equals(this.code)) {
+            attempts += 1;
+            throw new WrongCode(attempts);
+        }
+        return "treasure";
+    }
+
+    public void resetPIN(String code) throws Locked, vaults.alarm.Locked {
+        if (!code.equals(this.code)) {
+            throw new vaults.alarm.Locked();
+        }
+        if (attempts >= 2) {
+            throw new Locked();
+        }
+    }
+
+    public void resetPin(String code) throws vaults.alarm.Locked {
+        if (!code.equals(this.code)) {
+            throw new vaults.alarm.Locked();
+        }
+    }
+
+    public static Vault named(String name) throws Missing {
+        if (!name.equals("main")) {
+            throw new Missing(name);
+        }
+        return new Vault("1234");
+    }
+}
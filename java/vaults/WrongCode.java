@@ -0,0 +1,14 @@
+package vaults;
+
+public class WrongCode extends Exception {
+    private final int attempts;
+
+    public WrongCode(int attempts) {
+        super("wrong code");
+        this.attempts = attempts;
+    }
+
+    public int attempts() {
+        return attempts;
+    }
+}
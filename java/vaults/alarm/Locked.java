@@ -0,0 +1,7 @@
+package vaults.alarm;
+
+public class Locked extends Exception {
+    public Locked() {
+        super("alarm tripped");
+    }
+}
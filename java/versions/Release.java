@@ -0,0 +1,14 @@
+package versions;
+
+public class Release {
+    public Release() {
+    }
+
+    public String name() {
+        return "stable";
+    }
+
+    public static int count() {
+        return 1;
+    }
+}
@@ -0,0 +1,29 @@
+package voids;
+
+public class Tally {
+    private static int created;
+    private int count;
+
+    public Tally() {
+        created++;
+    }
+
+    public static void resetCreated() {
+        created = 0;
+    }
+
+    public static int created() {
+        return created;
+    }
+
+    public void add(int amount) {
+        if (amount < 0) {
+            throw new IllegalArgumentException("negative amount: " + amount);
+        }
+        count += amount;
+    }
+
+    public int count() {
+        return count;
+    }
+}
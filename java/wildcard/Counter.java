@@ -0,0 +1,12 @@
+package wildcard;
+
+public class Counter {
+    private int count;
+
+    public Counter() {
+    }
+
+    public int increment() {
+        return ++count;
+    }
+}
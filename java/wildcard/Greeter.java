@@ -0,0 +1,10 @@
+package wildcard;
+
+public class Greeter {
+    public Greeter() {
+    }
+
+    public String greet(String name) {
+        return Helper.prefix() + name;
+    }
+}
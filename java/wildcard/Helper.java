@@ -0,0 +1,7 @@
+package wildcard;
+
+class Helper {
+    static String prefix() {
+        return "Hello, ";
+    }
+}
@@ -9,4 +9,9 @@ fn main() {
     // See issue: https://github.com/duchess-rs/duchess/issues/7
     println!("cargo:rerun-if-env-changed=CLASSPATH");
     println!("cargo:rerun-if-env-changed=DUCHESS_DEBUG");
+    println!("cargo:rerun-if-env-changed=DUCHESS_DUMP");
+    println!("cargo:rerun-if-env-changed=DUCHESS_JDK_VERSION");
+    println!("cargo:rerun-if-env-changed=DUCHESS_JAVAP");
+    println!("cargo:rerun-if-env-changed=DUCHESS_REFLECT_STATS");
+    println!("cargo:rerun-if-env-changed=DUCHESS_DUMP_DIR");
 }
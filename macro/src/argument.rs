@@ -1,4 +1,4 @@
-use proc_macro2::Span;
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
 
 use crate::{
     class_info::{ClassDecl, ClassInfo, DotId, Id},
@@ -7,13 +7,49 @@ use crate::{
 };
 
 pub struct DuchessDeclaration {
+    /// Module that the packages are generated into, if the user wrote `mod foo;`.
+    pub module: Option<Ident>,
+
+    /// Visibility of the module, if the user wrote e.g. `pub(crate) mod foo;`. Defaults to `pub`.
+    pub module_visibility: Option<syn::Visibility>,
+
     pub packages: Vec<JavaPackage>,
 }
 
 impl Parse for DuchessDeclaration {
     fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
-        let packages = JavaPackage::parse_many(p)?;
-        Ok(Some(DuchessDeclaration { packages }))
+        // A visibility at the start belongs to `mod` if there is one, and to the first package otherwise.
+        let mut visibility = parse_visibility(p)?;
+
+        let mut module_visibility = None;
+        let module = match p.eat_contextual_keyword("mod") {
+            Some(()) => {
+                let Some(module) = Ident::parse(p)? else {
+                    return Err(p.error("expected module name after `mod`"));
+                };
+                let Some(_) = p.eat_punct(';') else {
+                    return Err(p.error("expected `;` after module name"));
+                };
+                module_visibility = visibility.take();
+                Some(module)
+            }
+            None => None,
+        };
+
+        let mut packages = vec![];
+        if visibility.is_some() {
+            match JavaPackage::parse_with_visibility(p, visibility)? {
+                Some(package) => packages.push(package),
+                None => return Err(p.error("expected `mod` or `package` after visibility")),
+            }
+        }
+        packages.extend(JavaPackage::parse_many(p)?);
+
+        Ok(Some(DuchessDeclaration {
+            module,
+            module_visibility,
+            packages,
+        }))
     }
 
     fn description() -> String {
@@ -21,6 +57,26 @@ impl Parse for DuchessDeclaration {
     }
 }
 
+/// Parses a Rust visibility like `pub` or `pub(crate)`, which the user can put in front of `mod` and `package` to
+/// control the visibility of the generated module. `pub(self)` makes it private.
+fn parse_visibility(p: &mut Parser) -> Result<Option<syn::Visibility>, SpanError> {
+    let Some(pub_token) = p.eat_token_if(|t| matches!(t, TokenTree::Ident(i) if i == "pub")) else {
+        return Ok(None);
+    };
+    let mut tokens = TokenStream::from(pub_token);
+    if let Some(restriction) = p.eat_token_if(
+        |t| matches!(t, TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis),
+    ) {
+        tokens.extend([restriction]);
+    }
+
+    let span = p.last_span().unwrap();
+    syn::parse2(tokens).map(Some).map_err(|err| SpanError {
+        span,
+        message: format!("invalid visibility: {err}"),
+    })
+}
+
 /// There are various points where the user must select
 /// a method. In these cases, we permit them to either write
 /// just a class name (in which case we search for (hopefully) at most one
@@ -36,7 +92,7 @@ pub enum MethodSelector {
 
     /// User wrote `class Foo { ... }` with full details.
     /// This class should have at most one member.
-    ClassInfo(ClassInfo),
+    ClassInfo(Box<ClassInfo>),
 }
 
 impl MethodSelector {
@@ -68,6 +124,10 @@ impl Parse for MethodSelector {
                     span: r.span,
                     message: format!("expected a class with a single member, not `*`"),
                 }),
+                ClassDecl::Specified(c) if !c.cfgs.is_empty() => Err(SpanError {
+                    span: c.span,
+                    message: "`#[cfg]` is not supported on method selectors".to_string(),
+                }),
                 ClassDecl::Specified(c) => {
                     let members = c.constructors.len() + c.fields.len() + c.methods.len();
                     if members != 1 {
@@ -79,7 +139,7 @@ impl Parse for MethodSelector {
                             ),
                         })
                     } else {
-                        Ok(Some(MethodSelector::ClassInfo(c)))
+                        Ok(Some(MethodSelector::ClassInfo(Box::new(c))))
                     }
                 }
             };
@@ -110,32 +170,191 @@ impl Parse for MethodSelector {
     }
 }
 
+/// The selector in the `#[java(...)]` attribute of a derived `ToJava` or `ToRust` impl. A class bound under another
+/// name with `class Foo as JFoo` is selected the same way, e.g. `my.pkg.Foo as JFoo` or `my.pkg.Foo as JFoo::of`.
+pub struct DeriveSelector {
+    pub method: MethodSelector,
+
+    /// The name of the Rust struct of the class, if it was renamed.
+    pub rust_name: Option<Ident>,
+}
+
+impl Parse for DeriveSelector {
+    fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
+        let Some(method) = MethodSelector::parse(p)? else {
+            return Ok(None);
+        };
+        let Some(span) = p.eat_map(|t| match t {
+            TokenTree::Ident(i) if i == "as" => Some(i.span()),
+            _ => None,
+        }) else {
+            return Ok(Some(DeriveSelector {
+                method,
+                rust_name: None,
+            }));
+        };
+
+        let MethodSelector::ClassName(path) = method else {
+            return Err(SpanError {
+                span,
+                message: "`as` goes right after the class name, e.g. `my.pkg.Foo as JFoo::new`"
+                    .to_string(),
+            });
+        };
+        let Some(rust_name) = Ident::parse(p)? else {
+            return Err(SpanError {
+                span,
+                message: "expected the name of the Rust struct after `as`".to_string(),
+            });
+        };
+        if p.eat_punct(':').is_none() {
+            return Ok(Some(DeriveSelector {
+                method: MethodSelector::ClassName(path),
+                rust_name: Some(rust_name),
+            }));
+        }
+        match (p.eat_punct(':'), Ident::parse(p)?) {
+            (Some(_), Some(ident)) => Ok(Some(DeriveSelector {
+                method: MethodSelector::MethodName(path, ident),
+                rust_name: Some(rust_name),
+            })),
+            _ => Err(SpanError {
+                span: p.peek_span().unwrap_or(Span::call_site()),
+                message: "expected method name after `::`".to_string(),
+            }),
+        }
+    }
+
+    fn description() -> String {
+        MethodSelector::description()
+    }
+}
+
+/// Selects a field by its class and name, e.g. `foo.bar.Baz::field`. Unlike methods, fields can't be overloaded, so
+/// there is no need for a full declaration to disambiguate.
+pub struct FieldSelector {
+    pub class_name: JavaPath,
+    pub field_name: Ident,
+}
+
+impl Parse for FieldSelector {
+    fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
+        let Some(class_name) = JavaPath::parse(p)? else {
+            return Ok(None);
+        };
+
+        if p.eat_punct(':').is_some() && p.eat_punct(':').is_some() {
+            if let Some(field_name) = Ident::parse(p)? {
+                return Ok(Some(FieldSelector {
+                    class_name,
+                    field_name,
+                }));
+            }
+        }
+        Err(SpanError {
+            span: p.peek_span().unwrap_or(Span::call_site()),
+            message: "expected `::` and a field name after the class name".to_string(),
+        })
+    }
+
+    fn description() -> String {
+        "field selector, e.g. `java.package.Class::field`".to_string()
+    }
+}
+
 pub struct JavaPackage {
     pub package_name: JavaPath,
+
+    /// Span of the `*` if the user wrote `package foo.bar.*;` (or `import foo.bar.*;`), which binds every public class
+    /// in the package.
+    pub wildcard: Option<Span>,
+
+    /// True if the user wrote `import foo.bar.*;` rather than `package foo.bar.*;`.
+    pub is_import: bool,
+
+    /// Classes left out of a wildcard package with `except Foo, Bar;`.
+    pub excluded: Vec<Ident>,
+
+    /// Visibility of the outermost module of the package (e.g. `foo` for `foo.bar`), if the user wrote e.g.
+    /// `pub(crate) package foo.bar;`. Defaults to `pub`.
+    pub visibility: Option<syn::Visibility>,
+
     pub classes: Vec<ClassDecl>,
 }
 
-impl Parse for JavaPackage {
-    fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
-        let Some(()) = p.eat_keyword("package") else {
+impl JavaPackage {
+    /// Parses the rest of a package after its `visibility`, if any, has been parsed.
+    fn parse_with_visibility(
+        p: &mut Parser,
+        visibility: Option<syn::Visibility>,
+    ) -> Result<Option<Self>, SpanError> {
+        let is_import = if let Some(()) = p.eat_keyword("package") {
+            false
+        } else if let Some(()) = p.eat_contextual_keyword("import") {
+            true
+        } else if visibility.is_some() {
+            return Err(p.error("expected `package` after visibility"));
+        } else {
             return Ok(None);
         };
 
-        let Some(package_name) = JavaPath::parse(p)? else {
+        let Some((package_name, wildcard)) = JavaPath::parse_with_wildcard(p)? else {
             return Err(p.error("expected package name"));
         };
 
+        if is_import && wildcard.is_none() {
+            return Err(SpanError {
+                span: package_name.span,
+                message: format!("`import` binds a whole package and must end in `.*`, e.g. `import {package_name}.*;`"),
+            });
+        }
+
         let Some(_) = p.eat_punct(';') else {
             return Err(p.error("expected `;` after package name"));
         };
 
+        // `except Foo, Bar;` leaves classes out of a wildcard package
+        let mut excluded = vec![];
+        while let Some(()) = p.eat_contextual_keyword("except") {
+            let except_span = p.last_span().unwrap();
+            if wildcard.is_none() {
+                return Err(SpanError {
+                    span: except_span,
+                    message: format!("`except` is only allowed after a wildcard package, e.g. `package {package_name}.*;`"),
+                });
+            }
+            loop {
+                let Some(class) = Ident::parse(p)? else {
+                    return Err(p.error("expected a class name after `except`"));
+                };
+                excluded.push(class);
+                if p.eat_punct(',').is_none() {
+                    break;
+                }
+            }
+            let Some(_) = p.eat_punct(';') else {
+                return Err(p.error("expected `;` after the excluded classes"));
+            };
+        }
+
         let classes = ClassDecl::parse_many(p)?;
 
         Ok(Some(JavaPackage {
             package_name,
+            wildcard,
+            is_import,
+            excluded,
+            visibility,
             classes,
         }))
     }
+}
+
+impl Parse for JavaPackage {
+    fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
+        let visibility = parse_visibility(p)?;
+        Self::parse_with_visibility(p, visibility)
+    }
 
     fn description() -> String {
         format!("java package to reflect (e.g., `package foo; ...`)")
@@ -148,13 +367,26 @@ pub struct JavaPath {
 }
 
 impl JavaPath {
+    /// Creates a path for `dot_id` whose identifiers all have the given span.
+    pub fn from_dot_id(dot_id: &DotId, span: Span) -> Self {
+        let (package, class) = dot_id.split();
+        let ids = package
+            .iter()
+            .chain(std::iter::once(class))
+            .map(|id| Ident {
+                text: id.to_string(),
+                span,
+            })
+            .collect();
+        JavaPath { ids, span }
+    }
+
     pub fn to_dot_id(&self) -> DotId {
         self.ids.iter().map(|ident| ident.to_id()).collect()
     }
-}
 
-impl Parse for JavaPath {
-    fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
+    /// Parses a path that may end in `.*`, returning the span of the `*` if present.
+    fn parse_with_wildcard(p: &mut Parser) -> Result<Option<(Self, Option<Span>)>, SpanError> {
         let Some(text) = Ident::parse(p)? else {
             return Ok(None);
         };
@@ -163,14 +395,33 @@ impl Parse for JavaPath {
         let mut ids = vec![text];
 
         while let Some(_) = p.eat_punct('.') {
+            if let Some(star) = p.eat_punct('*') {
+                return Ok(Some((JavaPath { ids, span }, Some(star))));
+            }
             let Some(next) = Ident::parse(p)? else {
-                return Err(SpanError { span: p.last_span().unwrap(), message: format!("expected identifier after `.`") });
+                return Err(SpanError {
+                    span: p.last_span().unwrap(),
+                    message: format!("expected identifier or `*` after `.`"),
+                });
             };
             span = span.join(next.span).unwrap_or(span);
             ids.push(next);
         }
 
-        Ok(Some(JavaPath { ids, span }))
+        Ok(Some((JavaPath { ids, span }, None)))
+    }
+}
+
+impl Parse for JavaPath {
+    fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
+        match Self::parse_with_wildcard(p)? {
+            None => Ok(None),
+            Some((path, None)) => Ok(Some(path)),
+            Some((_, Some(star))) => Err(SpanError {
+                span: star,
+                message: "`*` is only allowed at the end of a package name".to_string(),
+            }),
+        }
     }
 
     fn description() -> String {
@@ -205,10 +456,15 @@ impl Ident {
 
 impl Parse for Ident {
     fn parse(p: &mut Parser) -> Result<Option<Self>, SpanError> {
-        let Some(text) = p.eat_ident() else {
+        let Some(mut text) = p.eat_ident() else {
             return Ok(None);
         };
 
+        // Accept Rust's raw identifier syntax for names that are keywords in Rust, e.g. `package foo.r#type;`
+        if let Some(raw) = text.strip_prefix("r#") {
+            text = raw.to_string();
+        }
+
         Ok(Some(Ident {
             text,
             span: p.last_span().unwrap(),
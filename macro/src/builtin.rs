@@ -0,0 +1,166 @@
+//! The classes bound in `duchess::java`. Other invocations of `java_package!` can't see their declarations in
+//! `src/java.rs`, but need to know which supertypes have Rust bindings to generate upcasts to. A test keeps the list
+//! in sync with `src/java.rs`.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use proc_macro2::Span;
+
+use crate::class_info::{ClassInfo, DotId};
+
+/// The classes of `duchess::java` that are bound whichever cargo features of duchess are enabled, with their generic
+/// parameters, as declared in `src/java.rs`.
+const CLASSES: &[&str] = &[
+    "public class java.lang.Object",
+    "public class java.lang.Throwable",
+    "public final class java.lang.StackTraceElement",
+    "public class java.lang.Exception",
+    "public class java.lang.RuntimeException",
+    "public final class java.lang.Class",
+    "public abstract class java.lang.ClassLoader",
+    "public interface java.lang.AutoCloseable",
+    "public interface java.lang.Comparable<T>",
+    "public interface java.lang.CharSequence",
+    "public final class java.lang.String",
+    "public final class java.lang.System",
+    "public class java.lang.Thread",
+    "public abstract class java.lang.Record",
+    "public abstract class java.lang.Number",
+    "public final class java.lang.Integer",
+    "public final class java.lang.Long",
+    "public final class java.lang.Double",
+    "public final class java.lang.Boolean",
+    "public final class java.lang.StringBuilder",
+    "public interface java.util.List<E>",
+    "public class java.util.ArrayList<E>",
+    "public interface java.util.Iterator<E>",
+    "public interface java.util.Set<E>",
+    "public class java.util.HashSet<E>",
+    "public interface java.util.Comparator<T>",
+    "public interface java.util.Map<K, V>",
+    "public interface java.util.Map$Entry<K, V>",
+    "public class java.util.HashMap<K, V>",
+    "public class java.util.Collections",
+    "public class java.util.Properties",
+    "public class java.util.Date",
+    "public abstract class java.util.logging.Handler",
+    "public class java.util.logging.LogManager",
+    "public class java.util.logging.Logger",
+    "public class java.io.IOException",
+    "public interface java.io.Closeable",
+    "public abstract class java.io.InputStream",
+    "public abstract class java.io.OutputStream",
+    "public class java.io.FilterOutputStream",
+    "public class java.io.PrintStream",
+    "public class java.io.ByteArrayInputStream",
+    "public class java.io.ByteArrayOutputStream",
+];
+
+/// Parses [`CLASSES`].
+pub fn classes() -> BTreeMap<DotId, Arc<ClassInfo>> {
+    CLASSES
+        .iter()
+        .map(|header| {
+            let info = ClassInfo::parse(&format!("{header} {{ }}"), Span::call_site())
+                .unwrap_or_else(|e| panic!("invalid builtin class `{header}`: {}", e.message));
+            (info.name.clone(), Arc::new(info))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::BTreeMap, path::Path};
+
+    use proc_macro2::{TokenStream, TokenTree};
+
+    use crate::{
+        argument::DuchessDeclaration,
+        class_info::{ClassDecl, ClassInfo, DotId},
+        parse::Parser,
+    };
+
+    /// The classes of `duchess::java` that are only bound with some cargo features of duchess, which the macro can't
+    /// see; they are left out of [`CLASSES`](super::CLASSES).
+    const FEATURE_GATED: &[&str] = &[
+        "java.util.UUID",
+        "java.time.Instant",
+        "java.time.Duration",
+        "java.math.BigInteger",
+        "java.math.BigDecimal",
+    ];
+
+    /// Collects the classes declared by the `java_package!` invocations in `tokens`, and whether they are gated by a
+    /// `#[cfg]`, either their own or one of an enclosing module.
+    fn declared_classes(
+        tokens: TokenStream,
+        gated: bool,
+        classes: &mut BTreeMap<DotId, (ClassInfo, bool)>,
+    ) {
+        let mut cfg = false;
+        let mut tokens = tokens.into_iter();
+        while let Some(token) = tokens.next() {
+            match token {
+                TokenTree::Punct(p) if p.as_char() == '#' => {
+                    if let Some(TokenTree::Group(attr)) = tokens.next() {
+                        cfg |= matches!(attr.stream().into_iter().next(), Some(TokenTree::Ident(i)) if i == "cfg");
+                    }
+                }
+                TokenTree::Punct(p) if p.as_char() == ';' => cfg = false,
+                TokenTree::Ident(i) if i == "java_package" => {
+                    let body = tokens
+                        .find_map(|t| match t {
+                            TokenTree::Group(g) => Some(g.stream()),
+                            _ => None,
+                        })
+                        .unwrap();
+                    let decl = Parser::from(body).parse::<DuchessDeclaration>().unwrap();
+                    for class in decl.packages.into_iter().flat_map(|p| p.classes) {
+                        let ClassDecl::Specified(info) = class else {
+                            panic!("`src/java.rs` should only specify classes");
+                        };
+                        let class_gated = gated || !info.cfgs.is_empty();
+                        classes.insert(info.name.clone(), (info, class_gated));
+                    }
+                }
+                TokenTree::Group(g) => {
+                    declared_classes(g.stream(), gated || cfg, classes);
+                    cfg = false;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn classes_match_src_java() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/java.rs");
+        let source = std::fs::read_to_string(&path).unwrap();
+        let mut declared = BTreeMap::new();
+        declared_classes(source.parse().unwrap(), false, &mut declared);
+
+        let builtin = super::classes();
+        for (name, (info, gated)) in &declared {
+            let name_str = name.to_string();
+            assert_eq!(
+                *gated,
+                FEATURE_GATED.contains(&&*name_str),
+                "`{name}` should be in `FEATURE_GATED` if and only if it has a `#[cfg]`"
+            );
+            if *gated {
+                assert!(!builtin.contains_key(name), "`{name}` is feature gated");
+            } else {
+                let Some(b) = builtin.get(name) else {
+                    panic!("`{name}` is missing from `CLASSES`");
+                };
+                assert_eq!(b.generics, info.generics, "generics of `{name}`");
+            }
+        }
+        for name in builtin.keys() {
+            assert!(
+                declared.contains_key(name),
+                "`{name}` isn't declared in `src/java.rs`"
+            );
+        }
+    }
+}
@@ -1,5 +1,10 @@
+use proc_macro2::Span;
+
 use crate::{
-    class_info::{ClassInfo, ClassRef, Constructor, Method, RefType, RootMap, Type},
+    argument::{FieldSelector, Ident, JavaPath},
+    class_info::{
+        ClassInfo, ClassRef, Constructor, Field, Method, Privacy, RefType, RootMap, Type,
+    },
     reflect::Reflector,
     span_error::SpanError,
 };
@@ -29,11 +34,13 @@ impl ClassInfo {
         reflector: &mut Reflector,
         push_error: &mut impl FnMut(SpanError),
     ) -> Result<(), SpanError> {
-        let info = reflector.reflect(&self.name, self.span)?;
+        // A specified class that isn't on the classpath is taken as declared.
+        let info: &ClassInfo = &reflector.reflect_actual(&self.name, self.span)?.clone();
 
-        let mut push_error_message = |m: String| {
+        // Errors about a member point at the member, other errors at the class name.
+        let mut push_error_message = |span: Span, m: String| {
             push_error(SpanError {
-                span: self.span,
+                span,
                 message: format!("error in class `{}`: {m}", self.name),
             });
         };
@@ -46,53 +53,70 @@ impl ClassInfo {
         if !self.generics.is_empty() {
             // But if there *are* generics, they must match exactly.
             if self.generics != info.generics {
-                push_error_message(format!(
-                    "class `{}` should have generic parameters `<{}>`",
-                    self.name,
-                    info.generics
-                        .iter()
-                        .map(|g| g.to_string())
-                        .collect::<Vec<_>>()
-                        .join(", "),
-                ));
+                push_error_message(
+                    self.span,
+                    format!(
+                        "class `{}` should have generic parameters `<{}>`",
+                        self.name,
+                        info.generics
+                            .iter()
+                            .map(|g| g.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                );
             }
         }
 
+        // Supertypes can be declared even if they are only inherited indirectly. Only walk the hierarchy if needed.
+        let mut supertypes = None;
+        let mut is_supertype = |cref: &ClassRef| -> Result<bool, SpanError> {
+            if info.extends.contains(cref) || info.implements.contains(cref) {
+                return Ok(true);
+            }
+            if supertypes.is_none() {
+                supertypes = Some(reflector.supertypes(&self.name, self.span)?);
+            }
+            Ok(supertypes.as_ref().unwrap().contains(cref))
+        };
+
         for cref in &self.extends {
-            if !info.extends.iter().any(|c| c == cref) {
-                let extends_list: String = info
-                    .extends
-                    .iter()
-                    .map(|c| format!("`{}`", c))
-                    .collect::<Vec<String>>()
-                    .join(", ");
-                push_error_message(format!(
-                    "declared interface `{cref}` not found in the reflected superclasses ({})",
-                    extends_list
-                ));
+            if !is_supertype(cref)? {
+                push_error_message(
+                    self.span,
+                    format!(
+                        "declared superclass `{cref}` is not a supertype of `{}` (direct superclasses: {})",
+                        self.name,
+                        class_list(&info.extends),
+                    ),
+                );
             }
 
             cref.check(root_map, &mut |m| {
-                push_error_message(format!("{m}, but is extended by `{}`", self.name))
+                push_error_message(
+                    self.span,
+                    format!("{m}, but is extended by `{}`", self.name),
+                )
             });
         }
 
         for cref in &self.implements {
-            if !info.implements.iter().any(|c| c == cref) {
-                let implements_list: String = info
-                    .implements
-                    .iter()
-                    .map(|c| format!("`{}`", c))
-                    .collect::<Vec<String>>()
-                    .join(", ");
-                push_error_message(format!(
-                    "declared interface `{cref}` not found in the reflected interfaces (`{}`)",
-                    implements_list
-                ));
+            if !is_supertype(cref)? {
+                push_error_message(
+                    self.span,
+                    format!(
+                        "declared interface `{cref}` is not a supertype of `{}` (direct interfaces: {})",
+                        self.name,
+                        class_list(&info.implements),
+                    ),
+                );
             }
 
             cref.check(root_map, &mut |m| {
-                push_error_message(format!("{m}, but is implemented by `{}`", self.name));
+                push_error_message(
+                    self.span,
+                    format!("{m}, but is implemented by `{}`", self.name),
+                );
             });
         }
 
@@ -100,10 +124,10 @@ impl ClassInfo {
             let c_method_sig = c.to_method_sig(self);
 
             c.check(root_map, &mut |m| {
-                push_error_message(format!(
-                    "{m}, which appears in constructor {}",
-                    c_method_sig,
-                ));
+                push_error_message(
+                    c.span,
+                    format!("{m}, which appears in constructor {}", c_method_sig,),
+                );
             });
 
             if !info
@@ -111,10 +135,13 @@ impl ClassInfo {
                 .iter()
                 .any(|info_c| info_c.to_method_sig(info) == c_method_sig)
             {
-                push_error_message(format!(
-                    "constructor {} does not match any constructors in the reflected class",
-                    c_method_sig,
-                ));
+                push_error_message(
+                    c.span,
+                    format!(
+                        "constructor {} does not match any constructors in the reflected class",
+                        c_method_sig,
+                    ),
+                );
             }
         }
 
@@ -122,10 +149,10 @@ impl ClassInfo {
             let c_method_sig = c.to_method_sig();
 
             c.check(root_map, &mut |m| {
-                push_error_message(format!(
-                    "{m}, which appears in method `{}`",
-                    c.to_method_sig()
-                ));
+                push_error_message(
+                    c.span,
+                    format!("{m}, which appears in method `{}`", c.to_method_sig()),
+                );
             });
 
             if !info
@@ -141,24 +168,89 @@ impl ClassInfo {
                     .map(|info_c| info_c.to_string())
                     .collect();
                 if same_names.is_empty() {
-                    push_error_message(format!(
-                        "no method named `{}` in the reflected class",
-                        c_method_sig,
-                    ));
+                    push_error_message(
+                        c.span,
+                        format!("no method named `{}` in the reflected class", c_method_sig,),
+                    );
                 } else {
-                    push_error_message(format!(
+                    push_error_message(
+                        c.span,
+                        format!(
                         "method `{}` does not match any of the methods in the reflected class: {}",
                         c_method_sig,
                         same_names.join(", "),
-                    ));
+                    ),
+                    );
                 }
             }
         }
 
+        for f in &self.fields {
+            f.check(root_map, &mut |m| {
+                push_error_message(f.span, format!("{m}, which appears in field `{}`", f.name));
+            });
+
+            // Only public members are reflected, so there is nothing to compare other fields with.
+            if f.flags.privacy != Privacy::Public {
+                continue;
+            }
+
+            let selector = FieldSelector {
+                class_name: JavaPath::from_dot_id(&self.name, self.span),
+                field_name: Ident {
+                    text: f.name.to_string(),
+                    span: f.span,
+                },
+            };
+            let reflected = match reflector.reflect_field(&selector) {
+                Ok(reflected) => reflected,
+                Err(e) => {
+                    push_error_message(e.span, e.message);
+                    continue;
+                }
+            };
+            if reflected.ty() != &f.ty || reflected.is_static() != f.flags.is_static {
+                let is_static = |s: bool| if s { "static " } else { "" };
+                push_error_message(
+                    f.span,
+                    format!(
+                        "field `{}{} {}` does not match the field `{}{} {}` in the reflected class `{}`",
+                        is_static(f.flags.is_static),
+                        f.ty,
+                        f.name,
+                        is_static(reflected.is_static()),
+                        reflected.ty(),
+                        reflected.name(),
+                        reflected.class.name,
+                    ),
+                );
+            } else if f.flags.is_final && reflected.is_mutable() {
+                push_error_message(
+                    f.span,
+                    format!(
+                        "field `{}` is declared `final`, but is not final in the reflected class",
+                        f.name
+                    ),
+                );
+            }
+        }
+
         Ok(())
     }
 }
 
+/// Formats `crefs` for an error message.
+fn class_list(crefs: &[ClassRef]) -> String {
+    if crefs.is_empty() {
+        return "none".to_string();
+    }
+    crefs
+        .iter()
+        .map(|c| format!("`{c}`"))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 impl ClassRef {
     fn check(&self, root_map: &RootMap, push_error: &mut impl FnMut(String)) {
         let (package_name, class_id) = self.name.split();
@@ -181,6 +273,12 @@ impl Constructor {
     }
 }
 
+impl Field {
+    fn check(&self, root_map: &RootMap, mut push_error: impl FnMut(String)) {
+        self.ty.check(root_map, &mut push_error);
+    }
+}
+
 impl Method {
     fn check(&self, root_map: &RootMap, mut push_error: impl FnMut(String)) {
         for ty in &self.argument_tys {
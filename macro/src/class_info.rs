@@ -1,19 +1,38 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    sync::Arc,
+};
 
-use proc_macro2::{Delimiter, Ident, Span, TokenStream, TokenTree};
+use inflector::Inflector;
+use proc_macro2::{Delimiter, Ident, Literal, Span, TokenStream, TokenTree};
 use quote::quote_spanned;
 
 use crate::{
-    parse::{Parse, TextAccum},
+    parse::{Parse, SpanMap, TextAccum},
+    reflect::Reflector,
     span_error::SpanError,
 };
 
 /// Stores all the data about the classes/packages to be translated
 /// as well as whatever we have learned from reflection.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct RootMap {
     pub subpackages: BTreeMap<Id, SpannedPackageInfo>,
     pub classes: BTreeMap<DotId, Arc<ClassInfo>>,
+
+    /// The Rust names of the classes declared with `class Foo as JFoo`, see [`ClassInfo::rust_name`].
+    pub renamed_classes: BTreeMap<DotId, Id>,
+
+    /// The classes of `duchess::java`, see [`builtin`](crate::builtin).
+    pub builtin_classes: BTreeMap<DotId, Arc<ClassInfo>>,
+
+    /// The supertypes each class is upcast to, see [`RootMap::bound_supertypes`].
+    pub upcasts: BTreeMap<DotId, Vec<ClassRef>>,
+
+    /// The abstract method of each functional interface, in terms of the interface's generic parameters, with its Rust
+    /// name, see [`Reflector::functional_method`](crate::reflect::Reflector::functional_method).
+    pub functional_methods: BTreeMap<DotId, (Method, Id)>,
 }
 
 impl RootMap {
@@ -22,6 +41,61 @@ impl RootMap {
         self.classes.get(cn)
     }
 
+    /// Finds the class with the given name among the classes that have Rust bindings: those bound by this invocation
+    /// and those of `duchess::java`.
+    pub fn find_bound_class(&self, cn: &DotId) -> Option<&Arc<ClassInfo>> {
+        self.classes
+            .get(cn)
+            .or_else(|| self.builtin_classes.get(cn))
+    }
+
+    /// The supertypes that `info` is upcast to in Rust, see [`RootMap::bound_supertypes`].
+    pub fn upcasts(&self, info: &ClassInfo) -> &[ClassRef] {
+        self.upcasts.get(&info.name).map_or(&[], |u| &u[..])
+    }
+
+    /// Returns the supertypes that `info` can be upcast to in Rust: the ones it declares, and all of its supertypes on
+    /// the classpath, direct and indirect, that have Rust bindings, see [`RootMap::find_bound_class`]. Upcasts aren't
+    /// transitive in Rust, so this is what makes e.g. the methods of an interface implemented by a superclass callable
+    /// on the subclass.
+    pub fn bound_supertypes(
+        &self,
+        info: &ClassInfo,
+        reflector: &mut Reflector,
+    ) -> Result<Vec<ClassRef>, SpanError> {
+        let mut supertypes: Vec<ClassRef> = info
+            .extends
+            .iter()
+            .chain(&info.implements)
+            .cloned()
+            .collect();
+
+        // The generic arguments of the supertypes are in terms of the generic parameters of the class, which don't
+        // exist in Rust if the class is bound erased, like `java.lang.Class`.
+        let erased = info.generics.len()
+            != reflector
+                .reflect_actual(&info.name, info.span)?
+                .generics
+                .len();
+        for cref in reflector.supertypes(&info.name, info.span)? {
+            let Some(bound) = self.find_bound_class(&cref.name) else {
+                continue;
+            };
+            let cref = if erased || bound.generics.len() != cref.generics.len() {
+                ClassRef {
+                    name: cref.name,
+                    generics: vec![],
+                }
+            } else {
+                cref
+            };
+            if !supertypes.contains(&cref) {
+                supertypes.push(cref);
+            }
+        }
+        Ok(supertypes)
+    }
+
     /// Finds the package with the given name (if present).
     pub fn find_package(&self, ids: &[Id]) -> Option<&SpannedPackageInfo> {
         let (p0, ps) = ids.split_first().unwrap();
@@ -42,6 +116,9 @@ impl RootMap {
 pub struct SpannedPackageInfo {
     pub name: Id,
     pub span: Span,
+
+    /// Visibility of the generated module, which can only be set for outermost packages; `pub` if `None`.
+    pub visibility: Option<TokenStream>,
     pub subpackages: BTreeMap<Id, SpannedPackageInfo>,
     pub classes: Vec<DotId>,
 }
@@ -64,7 +141,7 @@ impl SpannedPackageInfo {
 
 #[derive(Debug)]
 pub enum ClassDecl {
-    /// User wrote `class Foo { * }`
+    /// User wrote `class Foo { * }`, or `optional class Foo { * }` to skip the class if it's not on the classpath.
     Reflected(ReflectedClassInfo),
 
     /// User wrote `class Foo { ... }` with full details.
@@ -73,9 +150,30 @@ pub enum ClassDecl {
 
 impl Parse for ClassDecl {
     fn parse(p: &mut crate::parse::Parser) -> Result<Option<Self>, SpanError> {
+        // `#[cfg(...)]` attributes gate the bindings of the class, see `ClassInfo::cfgs`, `#[builder]` asks for a
+        // builder for its constructor, see `ClassInfo::builder`, and `#[annotations]` for the annotations of a
+        // reflected class, see `ReflectedClassInfo::annotations`.
+        let ClassAttributes {
+            cfgs,
+            builder,
+            annotations,
+        } = parse_class_attributes(p)?;
+        let no_class = || match cfgs
+            .last()
+            .map(|(span, _)| *span)
+            .or(builder)
+            .or(annotations)
+        {
+            Some(span) => Err(SpanError {
+                span,
+                message: "expected a class after an attribute".to_string(),
+            }),
+            None => Ok(None),
+        };
+
         // Look for a keyword that could start a class definition.
         let Some(t0) = p.peek_token() else {
-            return Ok(None);
+            return no_class();
         };
         match t0 {
             TokenTree::Ident(i) => {
@@ -87,31 +185,148 @@ impl Parse for ClassDecl {
                     "interface",
                     "enum",
                     "record",
+                    "optional",
                 ];
                 let s = i.to_string();
                 if !START_KEYWORDS.contains(&s.as_str()) {
-                    return Ok(None);
+                    return no_class();
                 }
             }
-            _ => return Ok(None),
+            _ => return no_class(),
         }
 
-        // Accumulate tokens until we see a braced block `{}` that is the class body.
+        // `optional class Foo { * }` binds `Foo` only if it can be found on the classpath.
+        let optional = match p.peek_token() {
+            Some(TokenTree::Ident(i)) if *i == "optional" => {
+                let span = p.eat_token().unwrap().span();
+                if p.peek_token().is_none() {
+                    return Err(SpanError {
+                        span,
+                        message: "expected a class after `optional`".to_string(),
+                    });
+                }
+                Some(span)
+            }
+            _ => None,
+        };
+
+        // Accumulate tokens until we see a braced block `{}` that is the class body. The `from "..."` naming the
+        // artifact to reflect the class from comes right before it, and so does the `as Name` giving the generated
+        // struct another name. Both are kept out of the text that LALRPOP parses.
         let t0 = p.eat_token().unwrap();
         let mut accum = TextAccum::new(p, t0);
-        while let Some(t1) = accum.accum() {
-            match t1 {
-                TokenTree::Group(d) if d.delimiter() == Delimiter::Brace => {
-                    break;
+        let mut artifact = None;
+        let mut rust_name = None;
+        loop {
+            let rename = accum.parser().eat_map(|t| match t {
+                TokenTree::Ident(i) if i == "as" => Some(i.span()),
+                _ => None,
+            });
+            if let Some(span) = rename {
+                let Some(TokenTree::Ident(name)) = accum.parser().eat_token() else {
+                    return Err(SpanError {
+                        span,
+                        message: "expected the name of the Rust struct after `as`".to_string(),
+                    });
+                };
+                if rust_name.is_some() {
+                    return Err(SpanError {
+                        span,
+                        message: "a class can only be renamed once".to_string(),
+                    });
                 }
-                _ => {}
+                // `to_ident` escapes keywords again
+                let name = name.to_string();
+                rust_name = Some(Id::from(name.strip_prefix("r#").unwrap_or(&name)));
+                continue;
+            }
+
+            // `from` only names an artifact when a string literal follows it; elsewhere, e.g. in
+            // `extends com.from.Base`, it is part of a name.
+            let from = accum
+                .parser()
+                .eat_token_if(|t| matches!(t, TokenTree::Ident(i) if i == "from"));
+            if let Some(from) = from {
+                let Some(TokenTree::Literal(literal)) = accum
+                    .parser()
+                    .eat_token_if(|t| matches!(t, TokenTree::Literal(_)))
+                else {
+                    accum.accum_token(&from);
+                    continue;
+                };
+                let span = from.span();
+                let before_body = match accum.parser().peek_token() {
+                    Some(TokenTree::Group(d)) => d.delimiter() == Delimiter::Brace,
+                    Some(TokenTree::Ident(i)) => i == "as",
+                    _ => false,
+                };
+                if !before_body {
+                    return Err(SpanError {
+                        span,
+                        message: "`from \"...\"` must come right before the class body".to_string(),
+                    });
+                }
+                if artifact.is_some() {
+                    return Err(SpanError {
+                        span,
+                        message: "a class can only be reflected from one artifact".to_string(),
+                    });
+                }
+                artifact = Some(Artifact::from_literal(&literal)?);
+                continue;
+            }
+            match accum.accum() {
+                Some(TokenTree::Group(d)) if d.delimiter() == Delimiter::Brace => break,
+                Some(_) => {}
+                None => break,
             }
         }
 
         // Parse the text with LALRPOP.
-        let (text, span) = accum.into_accumulated_result();
-        let r = javap::parse_class_decl(span, &text)?;
-        Ok(Some(r))
+        let (text, spans) = accum.into_accumulated_result();
+        let mut r = javap::parse_class_decl(&spans, &text)?;
+        let cfgs: Vec<TokenStream> = cfgs.into_iter().map(|(_, cfg)| cfg).collect();
+        match &mut r {
+            ClassDecl::Reflected(r) => {
+                r.cfgs = cfgs;
+                r.builder = builder.is_some();
+                r.annotations = annotations.is_some();
+                r.artifact = artifact;
+                r.rust_name = rust_name;
+            }
+            ClassDecl::Specified(c) => {
+                if let Some(span) = annotations {
+                    return Err(SpanError {
+                        span,
+                        message: format!(
+                            "`#[annotations]` only applies to reflected classes (`class {} {{ * }}`), the \
+                             annotations of a class specified by hand are ignored",
+                            c.name
+                        ),
+                    });
+                }
+                c.cfgs = cfgs;
+                c.builder = builder.is_some();
+                c.artifact = artifact;
+                c.rust_name = rust_name;
+            }
+        }
+        match (r, optional) {
+            (ClassDecl::Reflected(r), Some(_)) => {
+                Ok(Some(ClassDecl::Reflected(ReflectedClassInfo {
+                    optional: true,
+                    ..r
+                })))
+            }
+            (ClassDecl::Specified(c), Some(span)) => Err(SpanError {
+                span,
+                message: format!(
+                    "only reflected classes (`class {} {{ * }}`) can be `optional`",
+                    c.name
+                ),
+            }),
+            (r, None) => Ok(Some(r)),
+        }
     }
 
     fn description() -> String {
@@ -119,12 +334,100 @@ impl Parse for ClassDecl {
     }
 }
 
+/// The attributes written before a class declaration.
+struct ClassAttributes {
+    /// The span and predicate of each `#[cfg(...)]` attribute.
+    cfgs: Vec<(Span, TokenStream)>,
+
+    /// The span of the `#[builder]` attribute, if any.
+    builder: Option<Span>,
+
+    /// The span of the `#[annotations]` attribute, if any.
+    annotations: Option<Span>,
+}
+
+/// Parses any number of `#[cfg(...)]` attributes and optional `#[builder]` and `#[annotations]` attributes.
+fn parse_class_attributes(p: &mut crate::parse::Parser) -> Result<ClassAttributes, SpanError> {
+    let mut cfgs = vec![];
+    let mut builder = None;
+    let mut annotations = None;
+    while let Some(span) = p.eat_punct('#') {
+        let unsupported = || {
+            SpanError {
+            span,
+            message:
+                "only `#[cfg(...)]`, `#[builder]` and `#[annotations]` attributes are supported on classes"
+                    .to_string(),
+        }
+        };
+        let Some(TokenTree::Group(attr)) = p.eat_token() else {
+            return Err(unsupported());
+        };
+        if attr.delimiter() != Delimiter::Bracket {
+            return Err(unsupported());
+        }
+        let mut tokens = attr.stream().into_iter();
+        match (tokens.next(), tokens.next(), tokens.next()) {
+            (Some(TokenTree::Ident(name)), Some(TokenTree::Group(predicate)), None)
+                if name == "cfg" && predicate.delimiter() == Delimiter::Parenthesis =>
+            {
+                cfgs.push((attr.span(), predicate.stream()));
+            }
+            (Some(TokenTree::Ident(name)), None, None) if name == "builder" => {
+                if builder.is_some() {
+                    return Err(SpanError {
+                        span: attr.span(),
+                        message: "duplicate `#[builder]` attribute".to_string(),
+                    });
+                }
+                builder = Some(attr.span());
+            }
+            (Some(TokenTree::Ident(name)), None, None) if name == "annotations" => {
+                if annotations.is_some() {
+                    return Err(SpanError {
+                        span: attr.span(),
+                        message: "duplicate `#[annotations]` attribute".to_string(),
+                    });
+                }
+                annotations = Some(attr.span());
+            }
+            _ => return Err(unsupported()),
+        }
+    }
+    Ok(ClassAttributes {
+        cfgs,
+        builder,
+        annotations,
+    })
+}
+
 #[derive(Clone, Debug)]
 pub struct ReflectedClassInfo {
     pub span: Span,
     pub flags: Flags,
     pub name: DotId,
     pub kind: ClassKind,
+
+    /// If true, the class is skipped when it can't be found rather than reported as an error.
+    pub optional: bool,
+
+    /// The predicates of the `#[cfg(...)]` attributes on the class.
+    pub cfgs: Vec<TokenStream>,
+
+    /// True if the class has a `#[builder]` attribute, see [`ClassInfo::builder`].
+    pub builder: bool,
+
+    /// True if the class has an `#[annotations]` attribute, which reads the annotations of the class and its members
+    /// and the names of their arguments, see [`Reflector::reflect_annotations`](crate::reflect::Reflector::reflect_annotations).
+    /// These are only printed by `javap -v`, which is much slower, so they are only read when asked for, or for a
+    /// `#[builder]`, which is named after the arguments.
+    pub annotations: bool,
+
+    /// The file the class is reflected from, see [`ClassInfo::artifact`].
+    pub artifact: Option<Artifact>,
+
+    /// The name of the generated Rust struct, see [`ClassInfo::rust_name`].
+    pub rust_name: Option<Id>,
 }
 
 #[derive(Clone, Debug)]
@@ -139,11 +442,287 @@ pub struct ClassInfo {
     pub constructors: Vec<Constructor>,
     pub fields: Vec<Field>,
     pub methods: Vec<Method>,
+
+    /// The runtime-visible annotations on the class, see [`Reflector::reflect_annotations`](crate::reflect::Reflector::reflect_annotations).
+    pub annotations: Vec<Annotation>,
+
+    /// The predicates of the `#[cfg(...)]` attributes on the class. The bindings of the class are only compiled if
+    /// they all hold, and so are the members of other classes that mention it.
+    pub cfgs: Vec<TokenStream>,
+
+    /// True if the class has a `#[builder]` attribute, which generates a builder for its constructor with a method
+    /// for each argument, named after the argument if the class file records its name.
+    pub builder: bool,
+
+    /// The `.jar` or `.jmod` file named by `class Foo from "path/to/foo.jar" { ... }`, which the class is reflected
+    /// from instead of the classpath.
+    pub artifact: Option<Artifact>,
+
+    /// The name given by `class Foo as JFoo { ... }` to the generated Rust struct, which is named after the Java
+    /// class otherwise. Its extension trait is renamed to match (`JFooExt`).
+    pub rust_name: Option<Id>,
+}
+
+/// A `.jar` or `.jmod` file that a class is reflected from, written `from "libs/foo-1.2.jar"` after the class header.
+/// Relative paths are relative to the directory of the crate's `Cargo.toml`, like `CARGO_MANIFEST_DIR`.
+#[derive(Clone, Debug)]
+pub struct Artifact {
+    pub span: Span,
+    pub path: PathBuf,
+}
+
+impl Artifact {
+    /// Parses the string literal `literal` following `from`.
+    fn from_literal(literal: &Literal) -> Result<Artifact, SpanError> {
+        let span = literal.span();
+        let path = match litrs::StringLit::try_from(literal) {
+            Ok(path) => PathBuf::from(path.value()),
+            Err(_) => {
+                return Err(SpanError {
+                    span,
+                    message: "expected the path of a `.jar` or `.jmod` file as a string"
+                        .to_string(),
+                })
+            }
+        };
+        let path = match std::env::var_os("CARGO_MANIFEST_DIR") {
+            Some(dir) if path.is_relative() => PathBuf::from(dir).join(path),
+            _ => path,
+        };
+        Ok(Artifact { span, path })
+    }
+
+    /// True for `.jmod` files, which keep their classes under `classes/` rather than at the root.
+    pub fn is_jmod(&self) -> bool {
+        self.path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("jmod"))
+    }
 }
 
 impl ClassInfo {
     pub fn parse(text: &str, span: Span) -> Result<ClassInfo, SpanError> {
-        javap::parse_class_info(span, &text)
+        javap::parse_class_info(&SpanMap::uniform(span), &text)
+    }
+
+    /// The number of constructors, methods and fields, which is roughly proportional to the code generated for the
+    /// class.
+    pub fn member_count(&self) -> usize {
+        self.constructors.len() + self.methods.len() + self.fields.len()
+    }
+
+    /// True if two methods share a name and a number of arguments, which is the case for the bridge methods that
+    /// [`drop_bridge_methods`] can't recognize without the flags printed by `javap -v`.
+    pub fn may_have_bridges(&self) -> bool {
+        self.methods.iter().enumerate().any(|(i, m)| {
+            self.methods[..i]
+                .iter()
+                .any(|n| n.name == m.name && n.argument_tys.len() == m.argument_tys.len())
+        })
+    }
+
+    /// Removes the members marked `#[since(N)]` with `N` greater than `jdk_version`, which aren't available in that
+    /// JDK, and returns a description of each of them.
+    pub fn drop_newer_members(&mut self, jdk_version: u32) -> Vec<String> {
+        let is_newer = |since: Option<u32>| since.is_some_and(|since| since > jdk_version);
+
+        let mut dropped = vec![];
+        let class = self.clone();
+        self.constructors.retain(|c| {
+            if !is_newer(c.since) {
+                return true;
+            }
+            let sig = c.to_method_sig(&class);
+            dropped.push(format!("constructor `{sig}` (JDK {})", c.since.unwrap()));
+            false
+        });
+        self.methods.retain(|m| {
+            if !is_newer(m.since) {
+                return true;
+            }
+            let sig = m.to_method_sig();
+            dropped.push(format!("method `{sig}` (JDK {})", m.since.unwrap()));
+            false
+        });
+        self.fields.retain(|f| {
+            if !is_newer(f.since) {
+                return true;
+            }
+            dropped.push(format!("field `{}` (JDK {})", f.name, f.since.unwrap()));
+            false
+        });
+        dropped
+    }
+
+    /// True if instances of this class can be created directly, i.e., it is neither abstract nor an interface.
+    pub fn is_instantiable(&self) -> bool {
+        !self.flags.is_abstract && self.kind == ClassKind::Class
+    }
+
+    /// If this is a functional (single-abstract-method) interface, returns its abstract method and the interface that
+    /// declares it. `supertypes` are the superinterfaces, direct and indirect, with the generic arguments they are
+    /// inherited with, see [`Reflector::supertypes`](crate::reflect::Reflector::supertypes); their abstract methods
+    /// count too. As in the Java language, the interface need not be annotated `@FunctionalInterface`, and abstract
+    /// redeclarations of the public methods of `java.lang.Object` (like `Comparator.equals`) don't count.
+    ///
+    /// Methods are told apart by their name and the erasure of their argument types in terms of this interface, so a
+    /// redeclaration of an inherited method, e.g. with a more precise return type, is the same method. A method that
+    /// any of the interfaces implements with a `default` method isn't abstract.
+    pub fn functional_method<'a>(
+        &'a self,
+        supertypes: &[(&'a ClassInfo, &ClassRef)],
+    ) -> Option<(&'a ClassInfo, &'a Method)> {
+        if self.kind != ClassKind::Interface {
+            return None;
+        }
+
+        let mut abstract_methods: BTreeMap<(Id, Vec<String>), (&ClassInfo, &Method)> =
+            BTreeMap::new();
+        let mut implemented: BTreeSet<(Id, Vec<String>)> = BTreeSet::new();
+        // The generic arguments are in terms of this interface's generic parameters
+        let interfaces = std::iter::once((self, vec![])).chain(
+            supertypes
+                .iter()
+                .map(|&(info, cref)| (info, info.generic_args(cref))),
+        );
+        for (info, args) in interfaces {
+            for m in info
+                .methods
+                .iter()
+                .filter(|m| !m.flags.is_static && !m.is_object_method())
+            {
+                let generics: Vec<&Generic> = m
+                    .generics
+                    .iter()
+                    .chain(&self.generics)
+                    .chain(&info.generics)
+                    .collect();
+                let key = (
+                    m.name.clone(),
+                    m.argument_tys
+                        .iter()
+                        .map(|t| t.substitute(&args).erased_descriptor(&generics))
+                        .collect(),
+                );
+                if m.flags.is_abstract {
+                    abstract_methods.entry(key).or_insert((info, m));
+                } else {
+                    implemented.insert(key);
+                }
+            }
+        }
+
+        let mut abstract_methods = abstract_methods
+            .into_iter()
+            .filter(|(key, _)| !implemented.contains(key))
+            .map(|(_, method)| method);
+        let method = abstract_methods.next()?;
+        match abstract_methods.next() {
+            Some(_) => None,
+            None => Some(method),
+        }
+    }
+
+    /// The generic arguments of `cref`, a reference to this class, paired with the generic parameters they are given
+    /// for. If `cref` is raw, the parameters are given their erasure, i.e. their first bound without its generic
+    /// arguments, as in Java.
+    pub fn generic_args(&self, cref: &ClassRef) -> Vec<(Id, RefType)> {
+        if self.generics.len() != cref.generics.len() {
+            return self
+                .generics
+                .iter()
+                .map(|g| {
+                    let name = match g.extends.first() {
+                        Some(bound) => bound.name.clone(),
+                        None => DotId::parse("java.lang.Object"),
+                    };
+                    let erasure = RefType::Class(ClassRef {
+                        name,
+                        generics: vec![],
+                    });
+                    (g.id.clone(), erasure)
+                })
+                .collect();
+        }
+        self.generics
+            .iter()
+            .map(|g| g.id.clone())
+            .zip(cref.generics.iter().cloned())
+            .collect()
+    }
+
+    /// The Rust names of this class's methods and field getters, see [`RustNames`].
+    pub fn rust_names(&self) -> RustNames {
+        let mut names = RustNames::default();
+        for method in &self.methods {
+            if !names.methods.contains_key(&method.name) {
+                let name = names.claim(method.name.to_snake_case());
+                names.methods.insert(method.name.clone(), name);
+            }
+        }
+        for field in self.fields.iter().filter(|f| f.flags.is_static) {
+            let (name, _) = names.claim(format!("get_{}", field.name.to_snake_case()));
+            names.static_field_getters.insert(field.name.clone(), name);
+        }
+        for field in self.fields.iter().filter(|f| !f.flags.is_static) {
+            let name = names.claim(format!("get_{}", field.name.to_snake_case()));
+            names.field_getters.insert(field.name.clone(), name);
+        }
+        names
+    }
+}
+
+/// The Rust names of the methods and field getters of a class, keyed by their Java names. Java names are
+/// converted to snake case with [`Id::to_snake_case`]. If several Java names convert to the same Rust name (like
+/// `getURL` and `getUrl`), the first one declared keeps it and the others get a numeric suffix (`get_url_2`).
+/// Overloads share a Java name, so they share a Rust name too.
+#[derive(Default, Debug)]
+pub struct RustNames {
+    methods: BTreeMap<Id, (Id, Id)>,
+    static_field_getters: BTreeMap<Id, Id>,
+    field_getters: BTreeMap<Id, (Id, Id)>,
+    taken: BTreeSet<String>,
+    taken_types: BTreeSet<String>,
+}
+
+impl RustNames {
+    /// The name of the Rust method for the Java method `java_name`.
+    pub fn method(&self, java_name: &Id) -> &Id {
+        &self.methods[java_name].0
+    }
+
+    /// The name of the struct (and associated type) for the Java method `java_name`, its name in camel case.
+    pub fn method_type(&self, java_name: &Id) -> &Id {
+        &self.methods[java_name].1
+    }
+
+    pub fn static_field_getter(&self, java_name: &Id) -> &Id {
+        &self.static_field_getters[java_name]
+    }
+
+    /// The name of the Rust method that reads the instance field `java_name`.
+    pub fn field_getter(&self, java_name: &Id) -> &Id {
+        &self.field_getters[java_name].0
+    }
+
+    /// The name of the struct (and associated type) for the getter of the instance field `java_name`.
+    pub fn field_getter_type(&self, java_name: &Id) -> &Id {
+        &self.field_getters[java_name].1
+    }
+
+    /// Claims `name`, or the first free `name_2`, `name_3`, ..., returning it with its camel-case type name.
+    fn claim(&mut self, name: String) -> (Id, Id) {
+        let type_name = name.to_camel_case();
+        let (mut candidate, mut candidate_type) = (name.clone(), type_name.clone());
+        let mut suffix = 1;
+        while self.taken.contains(&candidate) || self.taken_types.contains(&candidate_type) {
+            suffix += 1;
+            candidate = format!("{name}_{suffix}");
+            candidate_type = format!("{type_name}_{suffix}");
+        }
+        self.taken.insert(candidate.clone());
+        self.taken_types.insert(candidate_type.clone());
+        (Id::from(candidate), Id::from(candidate_type))
     }
 }
 
@@ -182,11 +761,13 @@ pub enum ClassKind {
 pub struct Flags {
     pub privacy: Privacy,
     pub is_final: bool,
-    pub is_synchronized: bool,
-    pub is_native: bool,
     pub is_abstract: bool,
     pub is_static: bool,
     pub is_default: bool,
+
+    /// True for the bridge and synthetic methods the compiler generates, e.g. to override a method with a covariant
+    /// return type. Only known when the `javap -v` flags were given, see [`drop_bridge_methods`].
+    pub is_bridge: bool,
 }
 
 impl Flags {
@@ -194,15 +775,69 @@ impl Flags {
         Flags {
             privacy: p,
             is_final: false,
-            is_synchronized: false,
-            is_native: false,
             is_abstract: false,
             is_static: false,
             is_default: false,
+            is_bridge: false,
+        }
+    }
+}
+
+/// True if the access flags `flags` mark a member that was generated by the compiler (`ACC_BRIDGE` or
+/// `ACC_SYNTHETIC`) rather than declared in the source.
+pub fn is_generated_member(flags: u16) -> bool {
+    const ACC_BRIDGE: u16 = 0x0040;
+    const ACC_SYNTHETIC: u16 = 0x1000;
+    flags & (ACC_BRIDGE | ACC_SYNTHETIC) != 0
+}
+
+/// Sets the [`jni_descriptor`](Method::jni_descriptor) of the members that `javap -s` didn't print it for, i.e. of
+/// classes specified by hand, from the erasure of their types. This needs the `generics` of the class, since a
+/// type parameter `T extends Bound` is erased to `Bound`, which is why it can't be done for each member on its own.
+pub fn fill_descriptors(
+    generics: &[Generic],
+    constructors: &mut [Constructor],
+    methods: &mut [Method],
+    fields: &mut [Field],
+) {
+    for c in constructors {
+        if c.jni_descriptor.is_none() {
+            c.jni_descriptor = Some(c.erased_descriptor(generics));
+        }
+    }
+    for m in methods {
+        if m.jni_descriptor.is_none() {
+            m.jni_descriptor = Some(m.erased_descriptor(generics));
+        }
+    }
+    let generics: Vec<&Generic> = generics.iter().collect();
+    for f in fields {
+        if f.jni_descriptor.is_none() {
+            f.jni_descriptor = Some(f.ty.erased_descriptor(&generics));
         }
     }
 }
 
+/// Removes the bridge methods the compiler generates next to the methods they bridge to. A class that overrides
+/// `Animal self()` with `Dog self()` has both in its class file, and `javap` prints both, which would otherwise look
+/// like two overloads that can't be told apart.
+///
+/// Bridges marked by their flags are removed. Plain `javap` output doesn't print flags, but Java doesn't allow two
+/// methods whose argument types are the same, so of those the first is kept: `javac` puts bridges after the methods
+/// declared in the source.
+pub fn drop_bridge_methods(methods: &mut Vec<Method>) {
+    methods.retain(|m| !m.flags.is_bridge);
+    let mut seen: Vec<(Id, Vec<Type>)> = vec![];
+    methods.retain(|m| {
+        let key = (m.name.clone(), m.argument_tys.clone());
+        if seen.contains(&key) {
+            return false;
+        }
+        seen.push(key);
+        true
+    });
+}
+
 #[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug)]
 pub enum Privacy {
     Public,
@@ -210,18 +845,99 @@ pub enum Privacy {
     Package,
 }
 
-#[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug)]
+/// A runtime-visible annotation on a reflected class or member, e.g. `@java.lang.Deprecated(since = "9")`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Annotation {
+    pub name: DotId,
+
+    /// The elements whose values are strings or primitives, in the order `javap` prints them. Elements with other
+    /// values (arrays, enums, classes and nested annotations) are left out.
+    pub elements: Vec<(Id, AnnotationValue)>,
+}
+
+impl Annotation {
+    pub fn is_deprecated(&self) -> bool {
+        self.name == DotId::parse("java.lang.Deprecated")
+    }
+
+    pub fn element(&self, name: &str) -> Option<&AnnotationValue> {
+        self.elements
+            .iter()
+            .find(|(id, _)| &id[..] == name)
+            .map(|(_, value)| value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnnotationValue {
+    String(String),
+
+    /// A primitive value as `javap` prints it, e.g. `true`, `5l` for a `long` or `'q'` for a `char`.
+    Primitive(String),
+}
+
+/// A `#[...]` attribute on a member of a specified class.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MemberHint {
+    /// `#[factory]`, see [`Method::factory`].
+    Factory,
+
+    /// `#[checked]`, see [`Method::checked`].
+    Checked,
+
+    /// `#[since(N)]`, see [`Method::since`].
+    Since(u32),
+}
+
+impl MemberHint {
+    /// The version given by the `#[since(N)]` among `hints`, if any.
+    pub fn since(hints: &[MemberHint]) -> Option<u32> {
+        hints.iter().find_map(|h| match h {
+            MemberHint::Since(version) => Some(*version),
+            _ => None,
+        })
+    }
+
+    /// Like [`since`](Self::since), for a constructor or field, which can't be `#[factory]` or `#[checked]`.
+    pub fn only_since<L, T>(
+        hints: &[MemberHint],
+    ) -> Result<Option<u32>, lalrpop_util::ParseError<L, T, &'static str>> {
+        if hints.iter().any(|h| !matches!(h, MemberHint::Since(_))) {
+            return Err(lalrpop_util::ParseError::User {
+                error: "only methods can be `#[factory]` or `#[checked]`",
+            });
+        }
+        Ok(Self::since(hints))
+    }
+}
+
+#[derive(Clone, Debug)]
 pub enum MemberFunction {
     Constructor(Constructor),
     Method(Method),
 }
 
-#[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct Constructor {
+    /// Span of the constructor's name, or of the class if it was reflected.
+    pub span: Span,
     pub flags: Flags,
     pub generics: Vec<Generic>,
     pub argument_tys: Vec<Type>,
     pub throws: Vec<ClassRef>,
+    pub annotations: Vec<Annotation>,
+
+    /// The names of the arguments, if the class file records them in a `MethodParameters` attribute (when compiled
+    /// with `javac -parameters`, and always for the canonical constructor of a record).
+    pub parameter_names: Option<Vec<Id>>,
+
+    /// The descriptor the JVM knows this constructor by, as printed by `javap -s` for reflected classes, or else computed
+    /// from the erasure of its types when the class is parsed, see [`fill_descriptors`].
+    pub jni_descriptor: Option<String>,
+
+    /// The JDK version the constructor was added in, given by `#[since(N)]` in a specified class. The constructor is left out
+    /// of the bindings when building against an older JDK, see [`ClassInfo::drop_newer_members`].
+    pub since: Option<u32>,
 }
 
 impl Constructor {
@@ -234,31 +950,113 @@ impl Constructor {
     }
 
     pub fn descriptor(&self) -> String {
+        match &self.jni_descriptor {
+            Some(d) => d.clone(),
+            None => self.erased_descriptor(&[]),
+        }
+    }
+
+    /// Computes the descriptor from the erasure of the argument types, given the generic parameters of the class.
+    fn erased_descriptor(&self, class_generics: &[Generic]) -> String {
+        let generics: Vec<&Generic> = self.generics.iter().chain(class_generics).collect();
         format!(
             "({})V",
             self.argument_tys
                 .iter()
-                .map(|a| a.descriptor())
+                .map(|a| a.erased_descriptor(&generics))
                 .collect::<String>()
         )
     }
 }
 
-#[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug)]
+/// `javap` prints constructors of inner (non-static nested) classes from their generic signature when they have one,
+/// which leaves out the enclosing instance that the constructor receives as its first argument, e.g.
+/// `Outer$Inner(java.util.List<java.lang.String>)` for the descriptor `(LOuter;Ljava/util/List;)V`. Given the
+/// `descriptor` of a constructor printed with `declared` arguments, returns the type of that missing argument.
+pub fn implicit_outer_argument(descriptor: &str, declared: usize) -> Option<Type> {
+    let arguments = descriptor.strip_prefix('(')?.split_once(')')?.0;
+
+    let mut count = 0;
+    let mut chars = arguments.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '[' => continue,
+            'L' => {
+                chars.find(|&c| c == ';')?;
+            }
+            _ => {}
+        }
+        count += 1;
+    }
+    if count != declared + 1 {
+        return None;
+    }
+
+    let outer = arguments.strip_prefix('L')?.split_once(';')?.0;
+    Some(Type::Ref(RefType::Class(ClassRef {
+        name: DotId::parse(outer.replace('/', ".")),
+        generics: vec![],
+    })))
+}
+
+#[derive(Clone, Debug)]
 pub struct Field {
+    /// Span of the field's name, or of the class if it was reflected.
+    pub span: Span,
     pub flags: Flags,
     pub name: Id,
     pub ty: Type,
+
+    /// The descriptor the JVM knows this field by, as printed by `javap -s` for reflected classes, or else computed
+    /// from the erasure of its types when the class is parsed, see [`fill_descriptors`].
+    pub jni_descriptor: Option<String>,
+
+    /// The JDK version the field was added in, given by `#[since(N)]` in a specified class. The field is left out
+    /// of the bindings when building against an older JDK, see [`ClassInfo::drop_newer_members`].
+    pub since: Option<u32>,
 }
 
-#[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug)]
+impl Field {
+    pub fn descriptor(&self) -> String {
+        match &self.jni_descriptor {
+            Some(d) => d.clone(),
+            None => self.ty.descriptor(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct Method {
+    /// Span of the method's name, or of the class if it was reflected.
+    pub span: Span,
     pub flags: Flags,
     pub name: Id,
     pub generics: Vec<Generic>,
     pub argument_tys: Vec<Type>,
     pub return_ty: Option<Type>,
     pub throws: Vec<ClassRef>,
+    pub annotations: Vec<Annotation>,
+
+    /// The names of the arguments, if the class file records them in a `MethodParameters` attribute (when compiled
+    /// with `javac -parameters`, and always for the canonical constructor of a record).
+    pub parameter_names: Option<Vec<Id>>,
+
+    /// The descriptor the JVM knows this method by, as printed by `javap -s` for reflected classes, or else computed
+    /// from the erasure of its types when the class is parsed, see [`fill_descriptors`].
+    pub jni_descriptor: Option<String>,
+
+    /// True if the method was marked `#[factory]` in a specified class. A factory is a static method that returns an
+    /// instance of its class, like `Integer.valueOf`, and is bound like a constructor, producing a non-null `Local`.
+    pub factory: bool,
+
+    /// True if the method was marked `#[checked]` in a specified class. Besides the usual binding, the method gets a
+    /// `_checked` variant that returns the exceptions of its `throws` clause as a generated enum, see
+    /// `duchess::CatchChecked`.
+    pub checked: bool,
+
+    /// The JDK version the method was added in, given by `#[since(N)]` in a specified class. The method is left out
+    /// of the bindings when building against an older JDK, see [`ClassInfo::drop_newer_members`].
+    pub since: Option<u32>,
 }
 
 impl Method {
@@ -271,18 +1069,40 @@ impl Method {
     }
 
     pub fn descriptor(&self) -> String {
+        match &self.jni_descriptor {
+            Some(d) => d.clone(),
+            None => self.erased_descriptor(&[]),
+        }
+    }
+
+    /// Computes the descriptor from the erasure of the argument and return types, given the generic parameters of the
+    /// class.
+    fn erased_descriptor(&self, class_generics: &[Generic]) -> String {
+        // The method's own generic parameters shadow those of the class
+        let generics: Vec<&Generic> = self.generics.iter().chain(class_generics).collect();
         format!(
             "({}){}",
             self.argument_tys
                 .iter()
-                .map(|a| a.descriptor())
+                .map(|a| a.erased_descriptor(&generics))
                 .collect::<String>(),
             self.return_ty
                 .as_ref()
-                .map(|r| r.descriptor())
+                .map(|r| r.erased_descriptor(&generics))
                 .unwrap_or_else(|| format!("V")),
         )
     }
+
+    /// True if this method has the signature of one of the public methods of `java.lang.Object` that an interface
+    /// may redeclare.
+    fn is_object_method(&self) -> bool {
+        matches!(
+            (&self.name[..], &self.descriptor()[..]),
+            ("equals", "(Ljava/lang/Object;)Z")
+                | ("hashCode", "()I")
+                | ("toString", "()Ljava/lang/String;")
+        )
+    }
 }
 
 /// Signature of a single method in a class;
@@ -322,6 +1142,33 @@ pub struct ClassRef {
     pub generics: Vec<RefType>,
 }
 
+impl ClassRef {
+    /// `java.lang.Record`, the superclass of all records.
+    pub fn record() -> Self {
+        ClassRef {
+            name: DotId::parse("java.lang.Record"),
+            generics: vec![],
+        }
+    }
+
+    /// Replaces the type parameters in the generic arguments with the types given for them in `args`, e.g.
+    /// `java.util.Collection<E>` becomes `java.util.Collection<java.lang.String>` for `E = java.lang.String`.
+    pub fn substitute(&self, args: &[(Id, RefType)]) -> ClassRef {
+        ClassRef {
+            name: self.name.clone(),
+            generics: self.generics.iter().map(|g| g.substitute(args)).collect(),
+        }
+    }
+
+    /// Pushes the names of this class and of the classes in its generic arguments onto `names`.
+    pub fn class_names<'a>(&'a self, names: &mut Vec<&'a DotId>) {
+        names.push(&self.name);
+        for g in &self.generics {
+            g.class_names(names);
+        }
+    }
+}
+
 impl std::fmt::Display for ClassRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.name)?;
@@ -367,6 +1214,40 @@ impl Type {
     pub fn descriptor(&self) -> String {
         self.to_non_repeating().descriptor()
     }
+
+    /// The descriptor of the erasure of this type, where a type parameter among `generics` is replaced by its first
+    /// bound, e.g. `T` by `java.lang.Number` for `<T extends java.lang.Number>`, and other type parameters by
+    /// `java.lang.Object`.
+    pub fn erased_descriptor(&self, generics: &[&Generic]) -> String {
+        self.to_non_repeating().erased_descriptor(generics)
+    }
+
+    /// True if the type parameter `id` appears anywhere in this type, e.g. `T` in `java.util.List<? extends T>`.
+    pub fn mentions(&self, id: &Id) -> bool {
+        match self {
+            Type::Ref(t) => t.mentions(id),
+            Type::Scalar(_) => false,
+            Type::Repeat(t) => t.mentions(id),
+        }
+    }
+
+    /// See [`ClassRef::substitute`].
+    pub fn substitute(&self, args: &[(Id, RefType)]) -> Type {
+        match self {
+            Type::Ref(t) => Type::Ref(t.substitute(args)),
+            Type::Scalar(_) => self.clone(),
+            Type::Repeat(t) => Type::Repeat(Arc::new(t.substitute(args))),
+        }
+    }
+
+    /// Pushes the names of the classes that appear anywhere in this type onto `names`.
+    pub fn class_names<'a>(&'a self, names: &mut Vec<&'a DotId>) {
+        match self {
+            Type::Ref(t) => t.class_names(names),
+            Type::Scalar(_) => {}
+            Type::Repeat(t) => t.class_names(names),
+        }
+    }
 }
 
 /// A variant of type
@@ -377,14 +1258,47 @@ pub enum NonRepeatingType {
 }
 
 impl NonRepeatingType {
+    /// See [`Type::erased_descriptor`].
+    pub fn erased_descriptor(&self, generics: &[&Generic]) -> String {
+        match self {
+            NonRepeatingType::Ref(RefType::Array(r)) => {
+                format!("[{}", r.erased_descriptor(generics))
+            }
+            NonRepeatingType::Ref(RefType::TypeParameter(id)) => {
+                let Some(index) = generics.iter().position(|g| g.id == *id) else {
+                    return self.descriptor();
+                };
+                let Some(bound) = generics[index].extends.first() else {
+                    return self.descriptor();
+                };
+                // A bound can be another type parameter, as in `<T, U extends T>`, which is erased in turn. Leaving
+                // out `T` itself ensures this terminates.
+                let others: Vec<&Generic> = generics
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| i != index)
+                    .map(|(_, g)| *g)
+                    .collect();
+                match &bound.name.ids[..] {
+                    [bound_id] if others.iter().any(|g| g.id == *bound_id) => {
+                        NonRepeatingType::Ref(RefType::TypeParameter(bound_id.clone()))
+                            .erased_descriptor(&others)
+                    }
+                    _ => format!("L{};", bound.name.to_jni_name()),
+                }
+            }
+            _ => self.descriptor(),
+        }
+    }
+
     pub fn descriptor(&self) -> String {
         match self {
             NonRepeatingType::Ref(r) => match r {
                 RefType::Class(c) => format!("L{};", c.name.to_jni_name()),
                 RefType::Array(r) => format!("[{}", r.descriptor()),
 
-                // FIXME(#42): The descriptor actually depends on how the type
-                // parameter was declared!
+                // Without the declarations of the type parameters, they can only be erased to `Object`, see
+                // `erased_descriptor`.
                 RefType::TypeParameter(_)
                 | RefType::Extends(_)
                 | RefType::Super(_)
@@ -414,6 +1328,44 @@ pub enum RefType {
     Wildcard,
 }
 
+impl RefType {
+    /// True if the type parameter `id` appears anywhere in this type.
+    pub fn mentions(&self, id: &Id) -> bool {
+        match self {
+            RefType::Class(c) => c.generics.iter().any(|g| g.mentions(id)),
+            RefType::Array(t) => t.mentions(id),
+            RefType::TypeParameter(t) => t == id,
+            RefType::Extends(t) | RefType::Super(t) => t.mentions(id),
+            RefType::Wildcard => false,
+        }
+    }
+
+    /// See [`ClassRef::substitute`].
+    pub fn substitute(&self, args: &[(Id, RefType)]) -> RefType {
+        match self {
+            RefType::Class(c) => RefType::Class(c.substitute(args)),
+            RefType::Array(t) => RefType::Array(Arc::new(t.substitute(args))),
+            RefType::TypeParameter(t) => match args.iter().find(|(id, _)| id == t) {
+                Some((_, arg)) => arg.clone(),
+                None => self.clone(),
+            },
+            RefType::Extends(t) => RefType::Extends(Arc::new(t.substitute(args))),
+            RefType::Super(t) => RefType::Super(Arc::new(t.substitute(args))),
+            RefType::Wildcard => RefType::Wildcard,
+        }
+    }
+
+    /// Pushes the names of the classes that appear anywhere in this type onto `names`.
+    pub fn class_names<'a>(&'a self, names: &mut Vec<&'a DotId>) {
+        match self {
+            RefType::Class(c) => c.class_names(names),
+            RefType::Array(t) => t.class_names(names),
+            RefType::TypeParameter(_) | RefType::Wildcard => {}
+            RefType::Extends(t) | RefType::Super(t) => t.class_names(names),
+        }
+    }
+}
+
 impl std::fmt::Display for RefType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -487,12 +1439,113 @@ impl Id {
         DotId::from(self).dot(s)
     }
 
+    /// Converts to a Rust identifier, escaping Rust keywords (e.g., a Java package named `type`) as raw identifiers.
     pub fn to_ident(&self, span: Span) -> Ident {
         let data = self.data.replace("$", "__");
-        Ident::new(&data, span)
+        if RUST_KEYWORDS.contains(&&data[..]) {
+            Ident::new_raw(&data, span)
+        } else {
+            Ident::new(&data, span)
+        }
+    }
+}
+
+impl Id {
+    /// Converts a Java name in camelCase, PascalCase or SCREAMING_SNAKE_CASE to snake_case, e.g. `getMessage` to
+    /// `get_message` and `MAX_VALUE` to `max_value`.
+    ///
+    /// A run of capitals is an acronym, which ends before a capital that starts a new word (`HTTPServer` is
+    /// `http_server`) and may be plural (`getIDs` is `get_ids`). Acronyms that are made up of several
+    /// [`KNOWN_ACRONYMS`] are split into them, so `getHTTPURL` is `get_http_url`. Digits belong to the word before
+    /// them (`toUTF8String` is `to_utf8_string`).
+    pub fn to_snake_case(&self) -> String {
+        let chars: Vec<char> = self.data.chars().collect();
+        let mut words: Vec<String> = vec![];
+        let mut word = String::new();
+        for (i, &c) in chars.iter().enumerate() {
+            if c == '_' {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+                continue;
+            }
+
+            if c.is_uppercase() && !word.is_empty() {
+                let after_lowercase = !chars[i - 1].is_uppercase();
+                // ...except for a plural like the `s` of `getIDs`
+                let plural = chars.get(i + 1) == Some(&'s')
+                    && !chars.get(i + 2).is_some_and(|c| c.is_lowercase());
+                let starts_word = chars.get(i + 1).is_some_and(|c| c.is_lowercase()) && !plural;
+                if after_lowercase || starts_word {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            word.push(c);
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+
+        let words: Vec<String> = words
+            .iter()
+            .flat_map(|word| split_acronyms(word))
+            .map(|word| word.to_lowercase())
+            .collect();
+        words.join("_")
+    }
+}
+
+/// Acronyms that are split apart when they appear next to each other in a name, see [`Id::to_snake_case`].
+const KNOWN_ACRONYMS: &[&str] = &[
+    "API", "ASCII", "CPU", "CSS", "CSV", "DNS", "FTP", "GUI", "HTML", "HTTP", "HTTPS", "ID", "IO",
+    "IP", "JDBC", "JMX", "JNI", "JSON", "JVM", "LDAP", "NIO", "OS", "RPC", "SQL", "SSL", "TCP",
+    "TLS", "UDP", "UI", "URI", "URL", "UTF", "UUID", "XML",
+];
+
+/// Splits a word like `HTTPURL` (or `HTTPURL2`) into the known acronyms it is made up of. Other words, including
+/// acronyms that don't split exactly, are returned as is.
+fn split_acronyms(word: &str) -> Vec<String> {
+    fn split(capitals: &str) -> Option<Vec<&str>> {
+        if capitals.is_empty() {
+            return Some(vec![]);
+        }
+        // prefer the longest acronym, so that e.g. `HTTPS` isn't split as `HTTP` and a dangling `S`
+        let mut acronyms: Vec<&str> = KNOWN_ACRONYMS
+            .iter()
+            .copied()
+            .filter(|a| capitals.starts_with(a))
+            .collect();
+        acronyms.sort_by_key(|a| std::cmp::Reverse(a.len()));
+        acronyms.into_iter().find_map(|acronym| {
+            let mut parts = split(&capitals[acronym.len()..])?;
+            parts.insert(0, acronym);
+            Some(parts)
+        })
+    }
+
+    let end = word
+        .find(|c: char| !c.is_ascii_uppercase())
+        .unwrap_or(word.len());
+    let (capitals, rest) = word.split_at(end);
+    match split(capitals) {
+        Some(mut parts) if parts.len() > 1 => {
+            let last = parts.pop().unwrap();
+            let mut words: Vec<String> = parts.into_iter().map(String::from).collect();
+            words.push(format!("{last}{rest}"));
+            words
+        }
+        _ => vec![word.to_string()],
     }
 }
 
+/// Rust keywords that are valid Java identifiers and can be escaped as raw identifiers. `self`, `Self`, `super` and
+/// `crate` can't be raw identifiers, but `super` is a keyword in Java too and the others are unlikely in Java code.
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "become", "box", "dyn", "extern", "fn", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "struct", "trait",
+    "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "yield", "gen", "try",
+];
+
 impl std::fmt::Display for Id {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.data)
@@ -551,6 +1604,30 @@ impl DotId {
         self
     }
 
+    /// The name of the class `s` nested in this one, e.g. `java.util.Map$Entry` for `java.util.Map` and `Entry`.
+    pub fn nested(mut self, s: &str) -> DotId {
+        let outer = self.ids.pop().unwrap();
+        self.ids.push(Id::from(format!("{}${}", &outer[..], s)));
+        self
+    }
+
+    /// The same class with the last component replaced by `rust_name`, whose module name is the path of a class
+    /// bound as `class Foo as JFoo`.
+    pub fn renamed(&self, rust_name: &Id) -> DotId {
+        let (package, _) = self.split();
+        DotId::new(package, rust_name)
+    }
+
+    /// The path of the class in the hidden module of its package that names every class bound in it after the Java
+    /// class, so that it can be found without knowing whether it was bound as `class Foo as JFoo`, see
+    /// [`JAVA_NAMES_MODULE`].
+    pub fn in_java_names(&self) -> DotId {
+        let (package, class) = self.split();
+        let mut package = package.to_vec();
+        package.push(Id::from(JAVA_NAMES_MODULE));
+        DotId::new(&package, class)
+    }
+
     pub fn is_class(&self, s: &Id) -> bool {
         self.split().1 == s
     }
@@ -591,6 +1668,10 @@ impl DotId {
     }
 }
 
+/// The hidden module generated in each package that re-exports its structs and extension traits under the names of
+/// the Java classes, e.g. `JString as String` for `class String as JString`. The derives refer to classes through it.
+pub const JAVA_NAMES_MODULE: &str = "__java_names";
+
 impl std::ops::Deref for DotId {
     type Target = [Id];
 
@@ -611,3 +1692,553 @@ impl std::fmt::Display for DotId {
 }
 
 mod javap;
+
+#[cfg(test)]
+mod test {
+    use expect_test::expect;
+    use proc_macro2::Span;
+
+    use super::{ClassDecl, ClassInfo, Flags, Id, Privacy};
+
+    #[test]
+    fn keywords_are_raw_identifiers() {
+        let ident = |s: &str| Id::from(s).to_ident(Span::call_site()).to_string();
+        assert_eq!(ident("type"), "r#type");
+        assert_eq!(ident("match"), "r#match");
+        assert_eq!(ident("typed"), "typed");
+        assert_eq!(ident("Outer$Inner"), "Outer__Inner");
+    }
+
+    #[test]
+    fn snake_case() {
+        let snake = |s: &str| Id::from(s).to_snake_case();
+        assert_eq!(snake("getMessage"), "get_message");
+        assert_eq!(snake("toString"), "to_string");
+        assert_eq!(snake("MAX_VALUE"), "max_value");
+        assert_eq!(snake("get"), "get");
+    }
+
+    #[test]
+    fn snake_case_acronyms() {
+        let snake = |s: &str| Id::from(s).to_snake_case();
+        assert_eq!(snake("getURL"), "get_url");
+        assert_eq!(snake("getHTTPURL"), "get_http_url");
+        assert_eq!(snake("HTTPServer"), "http_server");
+        assert_eq!(snake("parseXMLHttpRequest"), "parse_xml_http_request");
+        assert_eq!(snake("isHTTPS"), "is_https");
+        assert_eq!(snake("toUTF8String"), "to_utf8_string");
+        assert_eq!(snake("getIDs"), "get_ids");
+        assert_eq!(snake("getURLIDs"), "get_url_ids");
+        assert_eq!(snake("useABCD"), "use_abcd");
+        assert_eq!(snake("get2ndItem"), "get2nd_item");
+        assert_eq!(snake("getIOStream"), "get_io_stream");
+    }
+
+    #[test]
+    fn colliding_rust_names() {
+        let info = ClassInfo::parse(
+            r#"
+            public class names.Links {
+                public static final int URL;
+                public java.lang.String getURL();
+                public java.lang.String getUrl();
+                public java.lang.String getUrl(int);
+                public java.lang.String get_url();
+                public java.lang.String getUrl2();
+            }
+            "#,
+            Span::call_site(),
+        )
+        .unwrap();
+
+        let names = info.rust_names();
+        let method = |s: &str| names.method(&Id::from(s)).to_string();
+        assert_eq!(method("getURL"), "get_url");
+        assert_eq!(method("getUrl"), "get_url_2");
+        assert_eq!(method("get_url"), "get_url_3");
+        assert_eq!(method("getUrl2"), "get_url2");
+        let method_type = |s: &str| names.method_type(&Id::from(s)).to_string();
+        assert_eq!(method_type("getUrl"), "getUrl_2");
+        assert_eq!(method_type("getUrl2"), "getUrl2");
+        assert_eq!(
+            names.static_field_getter(&Id::from("URL")).to_string(),
+            "get_url_4"
+        );
+    }
+
+    fn functional_method(text: &str) -> Option<String> {
+        let info = ClassInfo::parse(text, Span::call_site()).unwrap();
+        info.functional_method(&[]).map(|(_, m)| m.name.to_string())
+    }
+
+    #[test]
+    fn functional_interfaces() {
+        assert_eq!(
+            functional_method(
+                "public interface java.lang.Runnable { public abstract void run(); }"
+            ),
+            Some("run".to_string())
+        );
+
+        // `equals` redeclares a method of `Object`; default and static methods don't count either
+        assert_eq!(
+            functional_method(
+                r#"
+                public interface java.util.Comparator<T> {
+                    public abstract int compare(T, T);
+                    public abstract boolean equals(java.lang.Object);
+                    public default java.util.Comparator<T> reversed();
+                    public static <T> java.util.Comparator<T> nullsFirst(java.util.Comparator<? super T>);
+                }
+                "#
+            ),
+            Some("compare".to_string())
+        );
+
+        assert_eq!(
+            functional_method(
+                r#"
+                public interface collections.Sized<T> {
+                    public abstract java.util.Iterator<T> iterator();
+                    public abstract int size();
+                }
+                "#
+            ),
+            None
+        );
+        assert_eq!(
+            functional_method(
+                "public abstract class shapes.Shape { public shapes.Shape(); public abstract double area(); }"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn functional_interfaces_inherit_abstract_methods() {
+        let parse = |text: &str| ClassInfo::parse(text, Span::call_site()).unwrap();
+        let function = parse(
+            r#"
+            public interface java.util.function.Function<T, R> {
+                public abstract R apply(T);
+                public default <V> java.util.function.Function<T, V> andThen(java.util.function.Function<? super R, ? extends V>);
+            }
+            "#,
+        );
+        let extends_function = |text: &str| {
+            let info = parse(text);
+            let cref = info.extends[0].clone();
+            info.functional_method(&[(&function, &cref)])
+                .map(|(declaring, m)| format!("{}.{}", declaring.name, m.name))
+        };
+
+        // Inherited from the superinterface, and redeclared with the type arguments of the subinterface
+        assert_eq!(
+            extends_function(
+                "public interface ops.Negate extends java.util.function.Function<java.lang.Integer, java.lang.Integer> { }"
+            ),
+            Some("java.util.function.Function.apply".to_string())
+        );
+        assert_eq!(
+            extends_function(
+                r#"
+                public interface ops.Negate extends java.util.function.Function<java.lang.Integer, java.lang.Integer> {
+                    public abstract java.lang.Integer apply(java.lang.Integer);
+                }
+                "#
+            ),
+            Some("ops.Negate.apply".to_string())
+        );
+
+        // Implemented by a default method, or joined by another abstract method
+        assert_eq!(
+            extends_function(
+                r#"
+                public interface ops.Identity<T> extends java.util.function.Function<T, T> {
+                    public default T apply(T);
+                }
+                "#
+            ),
+            None
+        );
+        assert_eq!(
+            extends_function(
+                r#"
+                public interface ops.Named<T> extends java.util.function.Function<T, T> {
+                    public abstract java.lang.String name();
+                }
+                "#
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn array_descriptors() {
+        let info = ClassInfo::parse(
+            r#"
+            public class arrays.Grid {
+                public arrays.Grid(int[][]);
+                public static int[][] identity(int);
+                public static int sum(int[][]);
+                public java.lang.String[] split(java.lang.String);
+                public java.lang.String[][][] cube(java.lang.Object...);
+                public byte[] bytes();
+            }
+            "#,
+            Span::call_site(),
+        )
+        .unwrap();
+
+        let descriptors: Vec<String> = info
+            .constructors
+            .iter()
+            .map(|c| c.descriptor())
+            .chain(
+                info.methods
+                    .iter()
+                    .map(|m| format!("{}{}", m.name, m.descriptor())),
+            )
+            .collect();
+        expect![[r#"
+            [
+                "([[I)V",
+                "identity(I)[[I",
+                "sum([[I)I",
+                "split(Ljava/lang/String;)[Ljava/lang/String;",
+                "cube([Ljava/lang/Object;)[[[Ljava/lang/String;",
+                "bytes()[B",
+            ]
+        "#]]
+        .assert_debug_eq(&descriptors);
+    }
+
+    #[test]
+    fn erased_descriptors() {
+        let info = ClassInfo::parse(
+            r#"
+            public class generics.Holder<T extends java.lang.Number, V> {
+                public V value;
+                public generics.Holder(T, V);
+                public T get();
+                public <U extends T> boolean holds(U);
+                public <T extends java.lang.CharSequence> T shadow(T[]);
+                public java.util.List<T> all();
+            }
+            "#,
+            Span::call_site(),
+        )
+        .unwrap();
+
+        let descriptors: Vec<String> = info
+            .constructors
+            .iter()
+            .map(|c| c.descriptor())
+            .chain(
+                info.methods
+                    .iter()
+                    .map(|m| format!("{}{}", m.name, m.descriptor())),
+            )
+            .chain(
+                info.fields
+                    .iter()
+                    .map(|f| format!("{}:{}", f.name, f.descriptor())),
+            )
+            .collect();
+        expect![[r#"
+            [
+                "(Ljava/lang/Number;Ljava/lang/Object;)V",
+                "get()Ljava/lang/Number;",
+                "holds(Ljava/lang/Number;)Z",
+                "shadow([Ljava/lang/CharSequence;)Ljava/lang/CharSequence;",
+                "all()Ljava/util/List;",
+                "value:Ljava/lang/Object;",
+            ]
+        "#]]
+        .assert_debug_eq(&descriptors);
+    }
+
+    #[test]
+    fn inner_class_constructors() {
+        // `javap -s` output: the generic constructor is printed without the enclosing instance
+        let info = ClassInfo::parse(
+            r#"
+            Compiled from "Outer.java"
+            public class inner.Outer$Tally {
+              public inner.Outer$Tally(java.util.List<java.lang.String>);
+                descriptor: (Linner/Outer;Ljava/util/List;)V
+
+              public inner.Outer$Tally(inner.Outer, int[]);
+                descriptor: (Linner/Outer;[I)V
+
+              public java.lang.String describe();
+                descriptor: ()Ljava/lang/String;
+            }
+            "#,
+            Span::call_site(),
+        )
+        .unwrap();
+
+        let descriptors: Vec<String> = info.constructors.iter().map(|c| c.descriptor()).collect();
+        expect![[r#"
+            [
+                "(Linner/Outer;Ljava/util/List;)V",
+                "(Linner/Outer;[I)V",
+            ]
+        "#]]
+        .assert_debug_eq(&descriptors);
+    }
+
+    #[test]
+    fn bridge_methods_are_dropped() {
+        // `javap -s` output of a class overriding `Animal itself()` and `Object name()` with covariant return types,
+        // which also implements `Comparable<Dog>`; the bridge for the latter is only known from its flags.
+        let methods = |text: &str| -> Vec<String> {
+            let info = ClassInfo::parse(text, Span::call_site()).unwrap();
+            info.methods
+                .iter()
+                .map(|m| format!("{}{}", m.name, m.descriptor()))
+                .collect()
+        };
+        let plain = r#"
+            public class covariant.Dog extends covariant.Animal {
+              public covariant.Dog itself();
+                descriptor: ()Lcovariant/Dog;
+
+              public java.lang.String name();
+                descriptor: ()Ljava/lang/String;
+
+              public int compareTo(covariant.Dog);
+                descriptor: (Lcovariant/Dog;)I
+
+              public java.lang.Object name();
+                descriptor: ()Ljava/lang/Object;
+
+              public covariant.Animal itself();
+                descriptor: ()Lcovariant/Animal;
+            }
+            "#;
+        expect![[r#"
+            [
+                "itself()Lcovariant/Dog;",
+                "name()Ljava/lang/String;",
+                "compareTo(Lcovariant/Dog;)I",
+            ]
+        "#]]
+        .assert_debug_eq(&methods(plain));
+
+        let with_flags = r#"
+            public class covariant.Dog extends covariant.Animal {
+              public int compareTo(covariant.Dog);
+                descriptor: (Lcovariant/Dog;)I
+                flags: (0x0001) ACC_PUBLIC
+
+              public int compareTo(java.lang.Object);
+                descriptor: (Ljava/lang/Object;)I
+                flags: (0x1041) ACC_PUBLIC, ACC_BRIDGE, ACC_SYNTHETIC
+            }
+            "#;
+        expect![[r#"
+            [
+                "compareTo(Lcovariant/Dog;)I",
+            ]
+        "#]]
+        .assert_debug_eq(&methods(with_flags));
+    }
+
+    #[test]
+    fn parse_errors_name_unsupported_constructs() {
+        // Returns the unsupported construct named by the error, and the offending line
+        let error = |text: &str| {
+            let message = ClassInfo::parse(text, Span::call_site())
+                .unwrap_err()
+                .message;
+            let construct = message
+                .split_once(" isn't supported")
+                .map(|(construct, _)| construct.to_string());
+            let (_, line) = message.rsplit_once("\n  ").unwrap();
+            (construct, line.to_string())
+        };
+
+        assert_eq!(
+            error(
+                r#"
+                public abstract sealed class shapes.Shape permits shapes.Circle {
+                }
+                "#
+            ),
+            (
+                Some("sealed class syntax".to_string()),
+                "in line 2: `public abstract sealed class shapes.Shape permits shapes.Circle {`"
+                    .to_string()
+            )
+        );
+
+        assert_eq!(
+            error(
+                r#"
+                public class limits.Limits {
+                  public static final int MAX = 5;
+                }
+                "#
+            ),
+            (
+                Some("constant value".to_string()),
+                "in line 3: `public static final int MAX = 5;`".to_string()
+            )
+        );
+
+        assert_eq!(
+            error(
+                r#"
+                public class numbers.Numbers {
+                  public <T super java.lang.Integer> void add(T);
+                }
+                "#
+            ),
+            (
+                Some("type variable syntax".to_string()),
+                "in line 3: `public <T super java.lang.Integer> void add(T);`".to_string()
+            )
+        );
+
+        assert_eq!(
+            error(
+                r#"
+                public class names.Names {
+                  public java.lang.@Nullable String name();
+                }
+                "#
+            ),
+            (
+                Some("annotation on type use".to_string()),
+                "in line 3: `public java.lang.@Nullable String name();`".to_string()
+            )
+        );
+
+        // Other errors still report the offending line
+        assert_eq!(
+            error(
+                r#"
+                public class names.Names {
+                  public java.lang.String name()
+                }
+                "#
+            ),
+            (None, "in line 4: `}`".to_string())
+        );
+    }
+
+    #[test]
+    fn from_only_names_an_artifact_before_the_body() {
+        use crate::parse::{Parse, Parser};
+
+        let parse = |text: &str| {
+            let mut p = Parser::from(text.parse::<proc_macro2::TokenStream>().unwrap());
+            ClassDecl::parse_many(&mut p)
+        };
+
+        let decls = parse(
+            r#"
+            class Widget extends com.from.Base { }
+            class Version from "java/pinned.jar" { * }
+            "#,
+        )
+        .unwrap();
+        let [ClassDecl::Specified(widget), ClassDecl::Reflected(version)] = &decls[..] else {
+            panic!("expected a specified and a reflected class, got {decls:?}");
+        };
+        assert_eq!(widget.extends[0].name.to_string(), "com.from.Base");
+        assert!(version
+            .artifact
+            .as_ref()
+            .is_some_and(|a| a.path.ends_with("java/pinned.jar")));
+
+        let error =
+            parse(r#"class Version from "java/pinned.jar" extends Base { * }"#).unwrap_err();
+        assert_eq!(
+            error.message,
+            "`from \"...\"` must come right before the class body"
+        );
+    }
+
+    #[test]
+    fn method_modifiers() {
+        // `javap` output, then the same methods with their modifiers in another order, as Java allows
+        let canonical = r#"
+            public class modifiers.Counter {
+              public synchronized int increment();
+              public final synchronized int get();
+              public static synchronized modifiers.Counter create();
+              public static final synchronized int limit();
+              public strictfp double half();
+              public static strictfp double scale(double);
+              public final synchronized strictfp double average(int);
+              public native int doubled();
+              public static native int tripled(int);
+              public final synchronized native int negated();
+              protected abstract synchronized void reset();
+            }
+        "#;
+        let reordered = r#"
+            public class modifiers.Counter {
+              synchronized public int increment();
+              synchronized final public int get();
+              synchronized static public modifiers.Counter create();
+              final synchronized static public int limit();
+              strictfp public double half();
+              static strictfp public double scale(double);
+              strictfp synchronized final public double average(int);
+              native public int doubled();
+              native static public int tripled(int);
+              native synchronized final public int negated();
+              synchronized abstract protected void reset();
+            }
+        "#;
+
+        let flags = |text: &str| -> Vec<(String, Flags)> {
+            let info = ClassInfo::parse(text, Span::call_site()).unwrap();
+            info.methods
+                .iter()
+                .map(|m| (m.name.to_string(), m.flags.clone()))
+                .collect()
+        };
+        let canonical = flags(canonical);
+        assert_eq!(canonical, flags(reordered));
+
+        let flag = |name: &str| &canonical.iter().find(|(n, _)| n == name).unwrap().1;
+        let public = Flags::new(Privacy::Public);
+        assert_eq!(flag("increment"), &public);
+        assert_eq!(flag("doubled"), &public);
+        assert_eq!(flag("half"), &public);
+        assert_eq!(
+            flag("limit"),
+            &Flags {
+                is_static: true,
+                is_final: true,
+                ..public.clone()
+            }
+        );
+        assert_eq!(
+            flag("tripled"),
+            &Flags {
+                is_static: true,
+                ..public.clone()
+            }
+        );
+        assert_eq!(
+            flag("negated"),
+            &Flags {
+                is_final: true,
+                ..public.clone()
+            }
+        );
+        assert_eq!(
+            flag("reset"),
+            &Flags {
+                is_abstract: true,
+                ..Flags::new(Privacy::Protected)
+            }
+        );
+    }
+}
@@ -1,29 +1,27 @@
 use std::fmt::Display;
 
+use crate::{parse::SpanMap, span_error::SpanError};
 use lalrpop_util::{lalrpop_mod, lexer::Token};
-use proc_macro2::Span;
-
-use crate::span_error::SpanError;
 
 use super::{ClassDecl, ClassInfo};
 
 lalrpop_mod!(pub javap_parser, "/class_info/javap_parser.rs"); // synthesized by LALRPOP
 
-pub(super) fn parse_class_decl(span: Span, input: &str) -> Result<ClassDecl, SpanError> {
-    match javap_parser::ClassDeclParser::new().parse(span, input) {
+pub(super) fn parse_class_decl(spans: &SpanMap, input: &str) -> Result<ClassDecl, SpanError> {
+    match javap_parser::ClassDeclParser::new().parse(spans, input) {
         Ok(v) => Ok(v),
         Err(error) => Err(SpanError {
-            span,
+            span: spans.span(),
             message: format_lalrpop_error(input, error),
         }),
     }
 }
 
-pub(super) fn parse_class_info(span: Span, input: &str) -> Result<ClassInfo, SpanError> {
-    match javap_parser::ClassInfoParser::new().parse(span, input) {
+pub(super) fn parse_class_info(spans: &SpanMap, input: &str) -> Result<ClassInfo, SpanError> {
+    match javap_parser::ClassInfoParser::new().parse(spans, input) {
         Ok(v) => Ok(v),
         Err(error) => Err(SpanError {
-            span,
+            span: spans.span(),
             message: format_lalrpop_error(input, error),
         }),
     }
@@ -33,33 +31,92 @@ fn format_lalrpop_error(
     input: &str,
     error: lalrpop_util::ParseError<usize, Token<'_>, impl Display>,
 ) -> String {
-    match error {
-        lalrpop_util::ParseError::ExtraToken { token } => {
-            format!("extra token at end of input (`{}`)", token.1)
-        }
+    let (message, location) = match error {
+        lalrpop_util::ParseError::ExtraToken {
+            token: (start, token, _),
+        } => (
+            format!("extra token at end of input (`{}`)", token.1),
+            Some(start),
+        ),
         lalrpop_util::ParseError::UnrecognizedEOF {
             location: _,
             expected,
-        } => {
-            format!("unexpected end of input, expected one of `{:?}`", expected)
-        }
+        } => (
+            format!("unexpected end of input, expected one of `{:?}`", expected),
+            None,
+        ),
         lalrpop_util::ParseError::UnrecognizedToken {
             token: (start, _, end),
             expected,
         } => {
             let window_string = window_string(input, start, end);
 
-            format!(
-                "unexpected token `{}` at offset {}, expected one of `{:?}`",
-                window_string, start, expected
+            (
+                format!(
+                    "unexpected token `{}` at offset {}, expected one of `{:?}`",
+                    window_string, start, expected
+                ),
+                Some(start),
             )
         }
         lalrpop_util::ParseError::InvalidToken { location } => {
             let ch_len = input[location..].chars().next().unwrap().len_utf8();
             let window_string = window_string(input, location, location + ch_len);
-            format!("invalid token `{}` at offset {}", window_string, ch_len,)
+            (
+                format!("invalid token `{}` at offset {}", window_string, location),
+                Some(location),
+            )
         }
-        lalrpop_util::ParseError::User { error } => format!("{}", error),
+        lalrpop_util::ParseError::User { error } => (format!("{}", error), None),
+    };
+
+    let Some(location) = location else {
+        return message;
+    };
+    let (line_number, line, column) = line_at(input, location);
+    let mut message = match unsupported_construct(line, column) {
+        Some(construct) => format!("{construct} isn't supported: {message}"),
+        None => message,
+    };
+    message.push_str(&format!("\n  in line {line_number}: `{}`", line.trim()));
+    message
+}
+
+/// Returns the 1-based number and the text of the line of `input` containing the byte offset `location`, and the
+/// offset within that line.
+fn line_at(input: &str, location: usize) -> (usize, &str, usize) {
+    let start = input[..location].rfind('\n').map_or(0, |i| i + 1);
+    let end = input[location..]
+        .find('\n')
+        .map_or(input.len(), |i| location + i);
+    let number = input[..location].matches('\n').count() + 1;
+    (number, &input[start..end], location - start)
+}
+
+/// Guesses which Java construct the parser doesn't handle from the `line` where parsing failed at offset `column`, so
+/// that the error can be reported as a gap in the parser.
+fn unsupported_construct(line: &str, column: usize) -> Option<&'static str> {
+    let (before, rest) = line.split_at(column);
+    let token: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '-')
+        .collect();
+    let in_generics = before.matches('<').count() > before.matches('>').count();
+
+    if line.contains('@') {
+        Some("annotation on type use")
+    } else if matches!(token.as_str(), "sealed" | "permits") || line.contains("non-sealed") {
+        Some("sealed class syntax")
+    } else if token == "enum" {
+        Some("`enum` declaration")
+    } else if matches!(token.as_str(), "module" | "requires" | "exports" | "opens") {
+        Some("module declaration")
+    } else if rest.starts_with('=') {
+        Some("constant value")
+    } else if in_generics {
+        Some("type variable syntax")
+    } else {
+        None
     }
 }
 
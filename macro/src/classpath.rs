@@ -0,0 +1,300 @@
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use crate::class_info::{DotId, Id};
+
+/// Finds the names of the top-level classes in `package` on the classpath `classpath`. Both directory and jar
+/// entries are searched. Nested classes (`Outer$Inner`) and `package-info`/`module-info` are skipped.
+///
+/// Entries that can't be read are skipped too: this is used to discover classes, and `javap` reports the real errors
+/// when they are reflected.
+pub fn classes_in_package(classpath: &str, package: &[Id]) -> Vec<DotId> {
+    let package_path: String = package.iter().map(|id| format!("{id}/")).collect();
+
+    let mut class_names = vec![];
+    for entry in entries(classpath) {
+        let file_names = if entry.is_dir() {
+            dir_class_files(&entry.join(&package_path)).unwrap_or_default()
+        } else if entry.is_file() {
+            jar_class_files(&entry, &package_path).unwrap_or_default()
+        } else {
+            vec![]
+        };
+
+        for file_name in file_names {
+            let Some(class_name) = file_name.strip_suffix(".class") else {
+                continue;
+            };
+            if class_name.contains('$') || class_name.contains('-') {
+                continue;
+            }
+            let class_id = DotId::new(package, &Id::from(class_name));
+            if !class_names.contains(&class_id) {
+                class_names.push(class_id);
+            }
+        }
+    }
+    class_names
+}
+
+/// The separator between classpath entries on this platform, like Java's `File.pathSeparatorChar`.
+const SEPARATOR: char = if cfg!(windows) { ';' } else { ':' };
+
+/// Splits `classpath` into its entries, interpreting them the way the JVM does:
+///
+/// * an empty entry is the current directory;
+/// * an entry whose last component is `*` (e.g. `lib/*`) stands for every `.jar` file directly inside that
+///   directory, in sorted order (the JVM leaves the order unspecified);
+/// * any other entry is a directory or jar file, used as written even if it doesn't exist.
+pub fn entries(classpath: &str) -> Vec<PathBuf> {
+    entries_with_separator(classpath, SEPARATOR)
+}
+
+fn entries_with_separator(classpath: &str, separator: char) -> Vec<PathBuf> {
+    let mut entries = vec![];
+    for entry in classpath.split(separator) {
+        if entry.is_empty() {
+            entries.push(PathBuf::from("."));
+        } else if let Some(dir) = wildcard_dir(entry, separator) {
+            entries.extend(jars_in(&dir).unwrap_or_default());
+        } else {
+            entries.push(PathBuf::from(entry));
+        }
+    }
+    entries
+}
+
+/// If `entry` is a wildcard like `lib/*`, returns the directory it expands in. Windows classpaths (separated by `;`)
+/// may also write `lib\*`.
+fn wildcard_dir(entry: &str, separator: char) -> Option<PathBuf> {
+    let dir = entry.strip_suffix('*')?;
+    if dir.is_empty() {
+        return Some(PathBuf::from("."));
+    }
+    let dir = dir
+        .strip_suffix('/')
+        .or_else(|| dir.strip_suffix('\\').filter(|_| separator == ';'))?;
+    Some(PathBuf::from(if dir.is_empty() { "/" } else { dir }))
+}
+
+/// Lists the `.jar` files directly inside `dir`, matching the extension case-insensitively as the JVM does.
+fn jars_in(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut jars = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let is_jar = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("jar"));
+        if is_jar && entry.file_type()?.is_file() {
+            jars.push(path);
+        }
+    }
+    jars.sort();
+    Ok(jars)
+}
+
+/// Lists the names of the files directly inside `dir`.
+fn dir_class_files(dir: &Path) -> io::Result<Vec<String>> {
+    let mut names = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            if let Some(name) = entry.file_name().to_str() {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Lists the names of the files directly inside the directory `prefix` (which ends in `/`) of the jar at `path`,
+/// by reading the jar's central directory.
+fn jar_class_files(path: &Path, prefix: &str) -> io::Result<Vec<String>> {
+    const END_OF_CENTRAL_DIRECTORY: u32 = 0x06054b50;
+    const CENTRAL_DIRECTORY_HEADER: u32 = 0x02014b50;
+    const END_RECORD_LEN: u64 = 22;
+    const MAX_COMMENT_LEN: u64 = u16::MAX as u64;
+
+    fn u16_at(bytes: &[u8], offset: usize) -> Option<u16> {
+        Some(u16::from_le_bytes(
+            bytes.get(offset..offset + 2)?.try_into().ok()?,
+        ))
+    }
+
+    fn u32_at(bytes: &[u8], offset: usize) -> Option<u32> {
+        Some(u32::from_le_bytes(
+            bytes.get(offset..offset + 4)?.try_into().ok()?,
+        ))
+    }
+
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "not a jar file");
+
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+
+    // The end of central directory record is at the very end of the file, followed only by a variable-length comment.
+    let tail_len = len.min(END_RECORD_LEN + MAX_COMMENT_LEN);
+    file.seek(SeekFrom::Start(len - tail_len))?;
+    let mut tail = vec![0; tail_len as usize];
+    file.read_exact(&mut tail)?;
+    let end_record = (0..tail.len().saturating_sub(END_RECORD_LEN as usize - 1))
+        .rev()
+        .find(|&i| u32_at(&tail, i) == Some(END_OF_CENTRAL_DIRECTORY))
+        .ok_or_else(invalid)?;
+    let entries = u16_at(&tail, end_record + 10).ok_or_else(invalid)?;
+    let directory_len = u32_at(&tail, end_record + 12).ok_or_else(invalid)?;
+    let directory_offset = u32_at(&tail, end_record + 16).ok_or_else(invalid)?;
+
+    file.seek(SeekFrom::Start(u64::from(directory_offset)))?;
+    let mut directory = vec![0; directory_len as usize];
+    file.read_exact(&mut directory)?;
+
+    let mut names = vec![];
+    let mut offset = 0;
+    for _ in 0..entries {
+        if u32_at(&directory, offset) != Some(CENTRAL_DIRECTORY_HEADER) {
+            return Err(invalid());
+        }
+        let name_len = usize::from(u16_at(&directory, offset + 28).ok_or_else(invalid)?);
+        let extra_len = usize::from(u16_at(&directory, offset + 30).ok_or_else(invalid)?);
+        let comment_len = usize::from(u16_at(&directory, offset + 32).ok_or_else(invalid)?);
+        let name = directory
+            .get(offset + 46..offset + 46 + name_len)
+            .ok_or_else(invalid)?;
+        offset += 46 + name_len + extra_len + comment_len;
+
+        let Ok(name) = std::str::from_utf8(name) else {
+            continue;
+        };
+        if let Some(file_name) = name.strip_prefix(prefix) {
+            if !file_name.is_empty() && !file_name.contains('/') {
+                names.push(file_name.to_string());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::PathBuf, process::Command};
+
+    use crate::class_info::{DotId, Id};
+
+    use super::{classes_in_package, entries_with_separator, wildcard_dir};
+
+    fn package(name: &str) -> Vec<Id> {
+        name.split('.').map(Id::from).collect()
+    }
+
+    /// The fixtures under `java/` in the repository root.
+    fn fixtures() -> String {
+        format!("{}/../java", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn classes_in_directory() {
+        let classes = classes_in_package(&fixtures(), &package("shapes"));
+        assert_eq!(
+            classes,
+            vec![DotId::parse("shapes.Shape"), DotId::parse("shapes.Square")]
+        );
+    }
+
+    #[test]
+    fn classes_in_jar() {
+        let dir = std::env::temp_dir().join(format!("duchess-classpath-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let jar = dir.join("shapes.jar");
+        let status = Command::new("jar")
+            .arg("cf")
+            .arg(&jar)
+            .arg("-C")
+            .arg(fixtures())
+            .arg("shapes")
+            .status()
+            .expect("failed to run `jar`");
+        assert!(status.success());
+
+        let classes = classes_in_package(jar.to_str().unwrap(), &package("shapes"));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            classes,
+            vec![DotId::parse("shapes.Shape"), DotId::parse("shapes.Square")]
+        );
+    }
+
+    #[test]
+    fn missing_entries_are_skipped() {
+        let classes = classes_in_package("/does/not/exist", &package("shapes"));
+        assert!(classes.is_empty());
+    }
+
+    /// A fresh temporary directory containing `lib/{a.jar,B.JAR,notes.txt}`.
+    fn lib_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("duchess-{name}-{}", std::process::id()));
+        let lib = dir.join("lib");
+        std::fs::create_dir_all(&lib).unwrap();
+        for file in ["a.jar", "B.JAR", "notes.txt"] {
+            std::fs::write(lib.join(file), "").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn unix_entries() {
+        let dir = lib_dir("unix-classpath");
+        let root = dir.to_str().unwrap();
+        let entries =
+            entries_with_separator(&format!("classes::{root}/lib/*:{root}/lib/*.jar"), ':');
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                PathBuf::from("classes"),
+                PathBuf::from("."),
+                dir.join("lib").join("B.JAR"),
+                dir.join("lib").join("a.jar"),
+                PathBuf::from(format!("{root}/lib/*.jar")),
+            ]
+        );
+    }
+
+    #[test]
+    fn windows_entries() {
+        let dir = lib_dir("windows-classpath");
+        let root = dir.to_str().unwrap();
+        let entries = entries_with_separator(&format!("C:\\classes;{root}/lib/*;"), ';');
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                PathBuf::from("C:\\classes"),
+                dir.join("lib").join("B.JAR"),
+                dir.join("lib").join("a.jar"),
+                PathBuf::from("."),
+            ]
+        );
+    }
+
+    #[test]
+    fn backslash_wildcards_only_on_windows() {
+        assert_eq!(wildcard_dir("lib\\*", ';'), Some(PathBuf::from("lib")));
+        assert_eq!(wildcard_dir("lib\\*", ':'), None);
+        assert_eq!(wildcard_dir("lib/*", ':'), Some(PathBuf::from("lib")));
+        assert_eq!(wildcard_dir("*", ':'), Some(PathBuf::from(".")));
+        assert_eq!(wildcard_dir("lib*", ':'), None);
+    }
+
+    #[test]
+    fn wildcard_of_missing_directory_is_empty() {
+        let entries = entries_with_separator("/does/not/exist/*:jar.jar", ':');
+        assert_eq!(entries, vec![PathBuf::from("jar.jar")]);
+    }
+}
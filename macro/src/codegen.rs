@@ -1,24 +1,53 @@
 use crate::{
     argument::DuchessDeclaration,
     class_info::{
-        ClassInfo, ClassRef, Constructor, DotId, Field, Id, Method, NonRepeatingType, RootMap,
-        SpannedPackageInfo, Type,
+        Annotation, AnnotationValue, ClassInfo, ClassRef, Constructor, DotId, Field, Generic, Id,
+        Method, NonRepeatingType, RefType, RootMap, RustNames, ScalarType, SpannedPackageInfo,
+        Type, JAVA_NAMES_MODULE,
     },
     reflect::Reflector,
     signature::Signature,
     span_error::SpanError,
 };
 use inflector::Inflector;
-use once_cell::sync::OnceCell;
 use proc_macro2::{Ident, Literal, Span, TokenStream};
-use quote::quote_spanned;
+use quote::{quote_spanned, ToTokens};
 
 impl DuchessDeclaration {
     pub fn to_tokens(&self) -> Result<TokenStream, SpanError> {
         let reflector = &mut Reflector::default();
         let root_map = self.to_root_map(reflector)?;
         let () = root_map.check(reflector)?;
-        root_map.to_tokens(reflector)
+        let tokens = root_map.to_tokens(reflector)?;
+
+        let packages: Vec<String> = self
+            .packages
+            .iter()
+            .map(|p| p.package_name.to_string())
+            .collect();
+        reflector.report_stats(packages.join(", "));
+
+        match &self.module {
+            None => Ok(tokens),
+            Some(module) => {
+                // Classes refer to each other by paths relative to the root of the packages, which the package
+                // modules import with `use super::*`; importing the enclosing scope here keeps the user's names
+                // visible to them too.
+                let name = module.to_id().to_ident(module.span);
+                let visibility = match &self.module_visibility {
+                    Some(v) => v.to_token_stream(),
+                    None => quote_spanned!(module.span => pub),
+                };
+                Ok(quote_spanned!(module.span =>
+                    #[allow(unused_imports)]
+                    #visibility mod #name {
+                        use super::*;
+
+                        #tokens
+                    }
+                ))
+            }
+        }
     }
 }
 
@@ -66,7 +95,7 @@ impl SpannedPackageInfo {
         let class_tokens: TokenStream = self
             .classes
             .iter()
-            .map(|class_id| root_map.classes[class_id].to_tokens())
+            .map(|class_id| root_map.classes[class_id].to_tokens(root_map))
             .collect::<Result<_, _>>()?;
 
         let supers: Vec<TokenStream> = package_id
@@ -74,9 +103,35 @@ impl SpannedPackageInfo {
             .map(|_| quote_spanned!(self.span => super))
             .collect();
 
+        // The derives only know the Java names of the classes, see `JAVA_NAMES_MODULE`.
+        let java_names_module = Id::from(JAVA_NAMES_MODULE).to_ident(self.span);
+        let java_names: Vec<TokenStream> = self
+            .classes
+            .iter()
+            .map(|class_id| {
+                let info = &root_map.classes[class_id];
+                let cfgs = &info.cfgs;
+                let struct_name = info.struct_name();
+                let ext_trait_name = info.ext_trait_name();
+                let java_name = info.name.class_name();
+                let java_struct_name = java_name.to_ident(self.span);
+                let java_ext_trait_name =
+                    Id::from(format!("{}Ext", &java_name[..])).to_ident(self.span);
+                quote_spanned!(self.span =>
+                    #(#[cfg(#cfgs)])*
+                    pub use super::{#struct_name as #java_struct_name, #ext_trait_name as #java_ext_trait_name};
+                )
+            })
+            .collect();
+
+        let visibility = match &self.visibility {
+            Some(v) => v.clone(),
+            None => quote_spanned!(self.span => pub),
+        };
+
         Ok(quote_spanned!(self.span =>
             #[allow(unused_imports)]
-            pub mod #name {
+            #visibility mod #name {
                 // Import the contents of the parent module that we are created inside
                 use #(#supers ::)* *;
 
@@ -85,33 +140,113 @@ impl SpannedPackageInfo {
 
                 #subpackage_tokens
                 #class_tokens
+
+                #[doc(hidden)]
+                pub mod #java_names_module {
+                    #(#java_names)*
+                }
             }
         ))
     }
 }
 
 impl ClassInfo {
-    pub fn to_tokens(&self) -> Result<TokenStream, SpanError> {
+    pub fn to_tokens(&self, root_map: &RootMap) -> Result<TokenStream, SpanError> {
         let struct_name = self.struct_name();
         let ext_trait_name = self.ext_trait_name();
         let cached_class = self.cached_class();
         let this_ty = self.this_type();
         let java_class_generics_with_defaults = self.class_generic_names_with_defaults();
         let java_class_generics = self.class_generic_names();
+        let names = self.rust_names();
+
+        // Convert constructors. Abstract classes and interfaces can't be instantiated, so they don't get any; their
+        // methods are still usable on instances of concrete subclasses.
+        let constructors: Vec<_> = if self.is_instantiable() {
+            self.constructors
+                .iter()
+                .map(|c| {
+                    let cfg = self.member_cfg(root_map, &c.generics, &c.argument_tys, None);
+                    let deprecated = deprecated_attr(&c.annotations, self.span);
+                    let constructor = self.constructor(c, root_map)?;
+                    Ok(quote_spanned!(self.span => #cfg #deprecated #constructor))
+                })
+                .collect::<Result<_, _>>()?
+        } else {
+            vec![]
+        };
 
-        // Convert constructors
-        let constructors: Vec<_> = self
-            .constructors
-            .iter()
-            .map(|c| self.constructor(c))
-            .collect::<Result<_, _>>()?;
+        // `#[builder]` generates a builder for the constructor, see `ClassInfo::builder`.
+        let builder = if self.builder {
+            let constructor = match &self.constructors[..] {
+                [c] if self.is_instantiable() => c,
+                _ => {
+                    return Err(SpanError {
+                        span: self.span,
+                        message: format!(
+                        "`#[builder]` needs a class with exactly one constructor, but `{}` has {}",
+                        self.name,
+                        if self.is_instantiable() {
+                            self.constructors.len()
+                        } else {
+                            0
+                        },
+                    ),
+                    })
+                }
+            };
+            let cfg = self.member_cfg(
+                root_map,
+                &constructor.generics,
+                &constructor.argument_tys,
+                None,
+            );
+            let deprecated = deprecated_attr(&constructor.annotations, self.span);
+            let builder = self.builder(
+                constructor,
+                root_map,
+                &quote_spanned!(self.span => #cfg #deprecated),
+            )?;
+            quote_spanned!(self.span => #cfg #builder)
+        } else {
+            TokenStream::new()
+        };
+
+        // A no-argument constructor also implements `JavaDefault`, which just calls `new()`.
+        let java_default_impl = match self.constructors.iter().find(|c| c.argument_tys.is_empty()) {
+            Some(c) if self.is_instantiable() => {
+                let cfg = self.member_cfg(root_map, &c.generics, &c.argument_tys, None);
+                quote_spanned!(self.span =>
+                    #cfg
+                    impl<#(#java_class_generics,)*> duchess::JavaDefault for #this_ty
+                    where
+                        #(#java_class_generics: duchess::JavaObject,)*
+                    {
+                        #[allow(deprecated)]
+                        fn java_default() -> impl IntoLocal<Self> {
+                            Self::new()
+                        }
+                    }
+                )
+            }
+            _ => TokenStream::new(),
+        };
 
         // Convert static methods (not instance methods, those are different)
         let static_methods: Vec<_> = self
             .methods
             .iter()
             .filter(|m| m.flags.is_static)
-            .map(|m| self.static_method(m))
+            .map(|m| {
+                let cfg = self.method_cfg(root_map, m);
+                let deprecated = deprecated_attr(&m.annotations, self.span);
+                self.static_method(
+                    m,
+                    &names,
+                    root_map,
+                    &quote_spanned!(self.span => #cfg #deprecated),
+                )
+            })
             .collect::<Result<_, _>>()?;
 
         // Convert instance methods (not static methods, those are different)
@@ -119,15 +254,52 @@ impl ClassInfo {
             .methods
             .iter()
             .filter(|m| !m.flags.is_static)
-            .map(|m| self.object_method(m))
+            .map(|m| {
+                let cfg = self.method_cfg(root_map, m);
+                self.object_method(m, &names, root_map, &cfg)
+            })
+            .collect::<Result<_, _>>()?;
+
+        // The enums of the exceptions declared by `#[checked]` methods
+        let checked_errors: Vec<_> = self
+            .methods
+            .iter()
+            .filter(|m| m.checked)
+            .map(|m| {
+                // Unlike the members, the enums are outside of the items gated by the class's own `#[cfg]`s
+                let class_cfgs = &self.cfgs;
+                let cfg = self.method_cfg(root_map, m);
+                self.checked_error(
+                    m,
+                    &names,
+                    root_map,
+                    &quote_spanned!(self.span => #(#[cfg(#class_cfgs)])* #cfg),
+                )
+            })
             .collect::<Result<_, _>>()?;
 
+        // Instance field getters are generated like instance methods without arguments
+        let field_getters: Vec<_> = self
+            .fields
+            .iter()
+            .filter(|f| !f.flags.is_static)
+            .map(|f| {
+                let cfg = self.member_cfg(root_map, &[], std::slice::from_ref(&f.ty), None);
+                self.object_field_getter(f, &names, root_map, &cfg)
+            })
+            .collect::<Result<_, _>>()?;
+        let object_methods: Vec<_> = object_methods.into_iter().chain(field_getters).collect();
+
         // Generate static field getters
         let static_field_getters: Vec<_> = self
             .fields
             .iter()
             .filter(|f| f.flags.is_static)
-            .map(|f| self.static_field_getter(f))
+            .map(|f| {
+                let cfg = self.member_cfg(root_map, &[], std::slice::from_ref(&f.ty), None);
+                let getter = self.static_field_getter(f, &names, root_map)?;
+                Ok(quote_spanned!(self.span => #cfg #getter))
+            })
             .collect::<Result<_, _>>()?;
 
         let method_structs: Vec<_> = object_methods.iter().map(|m| &m.method_struct).collect();
@@ -137,16 +309,38 @@ impl ClassInfo {
             .map(|m| &m.trait_impl_method)
             .collect();
         let jvm_op_impls: Vec<_> = object_methods.iter().map(|m| &m.jvm_op_impl).collect();
-        let upcast_impls = self.upcast_impls()?;
+        let upcast_impls = self.upcast_impls(root_map)?;
+        let method_descriptors: Vec<_> = self
+            .methods
+            .iter()
+            .map(|m| {
+                let cfg = self.method_cfg(root_map, m);
+                let descriptor = self.method_descriptor(m, names.method(&m.name));
+                quote_spanned!(self.span => #cfg #descriptor)
+            })
+            .collect();
+        let from_fn = self.lambda_constructor(root_map);
+        let functional_method = match root_map.functional_methods.get(&self.name) {
+            Some((m, rust_name)) => {
+                let descriptor = self.method_descriptor(m, rust_name);
+                quote_spanned!(self.span => Some(#descriptor))
+            }
+            None => quote_spanned!(self.span => None),
+        };
+
+        let cfgs = &self.cfgs;
+        let class_cfg = quote_spanned!(self.span => #(#[cfg(#cfgs)])*);
 
         let output = quote_spanned! {
             self.span =>
 
+            #class_cfg
             #[allow(non_camel_case_types)]
             pub struct #struct_name<#(#java_class_generics_with_defaults,)*> {
                 _dummy: std::marker::PhantomData<(#(#java_class_generics,)*)>
             }
 
+            #class_cfg
             #[allow(non_camel_case_types)]
             pub trait #ext_trait_name<#(#java_class_generics,)*> : duchess::JvmOp
             where
@@ -155,7 +349,10 @@ impl ClassInfo {
                 #(#trait_methods)*
             }
 
+            #(#checked_errors)*
+
             // Hide other generated items
+            #class_cfg
             #[allow(unused_imports)]
             const _: () = {
                 use duchess::{
@@ -214,6 +411,24 @@ impl ClassInfo {
                 {
                     #(#constructors)*
 
+                    #from_fn
+                }
+
+                #java_default_impl
+
+                #builder
+
+                // Members that don't depend on the class's generic parameters are bound with them defaulted to
+                // `Object`, so that e.g. `List::METHODS` doesn't need a type annotation.
+                impl #struct_name {
+                    /// The methods bound for this class, in declaration order.
+                    pub const METHODS: &'static [duchess::MethodDescriptor] = &[
+                        #(#method_descriptors,)*
+                    ];
+
+                    /// For functional (single-abstract-method) interfaces, the abstract method; `None` otherwise.
+                    pub const FUNCTIONAL_METHOD: Option<duchess::MethodDescriptor> = #functional_method;
+
                     #(#static_methods)*
 
                     #(#static_field_getters)*
@@ -240,16 +455,170 @@ impl ClassInfo {
         Ok(output)
     }
 
-    fn upcast_impls(&self) -> Result<TokenStream, SpanError> {
+    /// For a functional interface, generates `from_fn`, which implements the interface with a Rust closure, see
+    /// `duchess::Lambda`. The closure takes the arguments of the abstract method as `Option<Local>`s, or as scalars, and
+    /// returns its result in the same way. Nothing is generated if the method has generic parameters of its own, or
+    /// types the closure can't take: classes without Rust bindings, wildcards, or scalars other than `int`, `long`,
+    /// `double` and `boolean`, whose boxed classes aren't bound in `duchess::java`.
+    fn lambda_constructor(&self, root_map: &RootMap) -> TokenStream {
+        let Some((method, _)) = root_map.functional_methods.get(&self.name) else {
+            return TokenStream::new();
+        };
+        if !method.generics.is_empty() {
+            return TokenStream::new();
+        }
+        let mut class_names = vec![];
+        for ty in method.argument_tys.iter().chain(&method.return_ty) {
+            ty.class_names(&mut class_names);
+        }
+        if class_names
+            .iter()
+            .any(|name| root_map.find_bound_class(name).is_none())
+        {
+            return TokenStream::new();
+        }
+
+        let mut sig = Signature::new(&Id::from("from_fn"), self.span, &self.generics)
+            .with_renamed_classes(&root_map.renamed_classes);
+        let closure_tys = sig.forbid_capture(|sig| {
+            let mut closure_ty = |ty: &Type| match ty.to_non_repeating() {
+                NonRepeatingType::Ref(_) => {
+                    let ty = sig.java_ty(ty).ok()?;
+                    Some(quote_spanned!(self.span => Option<duchess::Local<'jvm, #ty>>))
+                }
+                NonRepeatingType::Scalar(
+                    ScalarType::Int | ScalarType::Long | ScalarType::F64 | ScalarType::Boolean,
+                ) => sig.java_ty(ty).ok(),
+                NonRepeatingType::Scalar(_) => None,
+            };
+            let argument_tys: Option<Vec<_>> =
+                method.argument_tys.iter().map(&mut closure_ty).collect();
+            let return_ty = match &method.return_ty {
+                Some(ty) => closure_ty(ty)?,
+                None => quote_spanned!(self.span => ()),
+            };
+            Some((argument_tys?, return_ty))
+        });
+        let Some((argument_tys, return_ty)) = closure_tys else {
+            return TokenStream::new();
+        };
+
+        let input_names: Vec<_> = (0..argument_tys.len())
+            .map(|i| Ident::new(&format!("a{i}"), self.span))
+            .collect();
+        // The arguments come as a `Vec`, taken apart in order
+        let args = if input_names.is_empty() {
+            quote_spanned!(self.span => _args)
+        } else {
+            quote_spanned!(self.span => args)
+        };
+        let take_args = (!input_names.is_empty())
+            .then(|| quote_spanned!(self.span => let mut args = args.into_iter();));
+        let cfg = self.method_cfg(root_map, method);
+        let doc = Literal::string(&format!(
+            " Implements `{}` with a Rust closure, which Java calls for `{}`, see [`duchess::Lambda`].",
+            self.name, method.name
+        ));
+        quote_spanned!(self.span =>
+            #cfg
+            #[doc = #doc]
+            pub fn from_fn<F>(f: F) -> duchess::Lambda<Self>
+            where
+                F: for<'jvm> Fn(&mut duchess::Jvm<'jvm>, #(#argument_tys),*) -> duchess::Result<'jvm, #return_ty>
+                    + Send
+                    + Sync
+                    + 'static,
+            {
+                duchess::Lambda::new(move |jvm, #args| {
+                    #take_args
+                    #(
+                        // SAFETY: Java passes arguments of the method's types
+                        let #input_names = unsafe {
+                            duchess::plumbing::LambdaArg::from_lambda_arg(jvm, args.next().flatten())?
+                        };
+                    )*
+                    let result = f(jvm, #(#input_names),*)?;
+                    duchess::plumbing::LambdaResult::into_lambda_result(result, jvm)
+                })
+            }
+        )
+    }
+
+    fn method_descriptor(&self, method: &Method, rust_name: &Id) -> TokenStream {
+        let name = Literal::string(&method.name);
+        let rust_name = Literal::string(rust_name);
+        let descriptor = Literal::string(&method.descriptor());
+        let is_static = method.flags.is_static;
+        quote_spanned!(self.span =>
+            duchess::MethodDescriptor {
+                name: #name,
+                rust_name: #rust_name,
+                descriptor: #descriptor,
+                is_static: #is_static,
+            }
+        )
+    }
+
+    /// The `#[cfg]` attributes for a member of this class that mentions the classes in `generics`, `tys` and `extra`.
+    /// A member that mentions a class gated by `#[cfg]` is gated the same way, so that it is only compiled if the
+    /// class is. Predicates that already gate this class are left out.
+    fn member_cfg(
+        &self,
+        root_map: &RootMap,
+        generics: &[Generic],
+        tys: &[Type],
+        extra: Option<&Type>,
+    ) -> TokenStream {
+        let mut class_names = vec![];
+        for g in generics {
+            for bound in &g.extends {
+                bound.class_names(&mut class_names);
+            }
+        }
+        for ty in tys.iter().chain(extra) {
+            ty.class_names(&mut class_names);
+        }
+
+        let mut predicates: Vec<String> = self.cfgs.iter().map(|c| c.to_string()).collect();
+        let mut cfgs = vec![];
+        for name in class_names {
+            let Some(class) = root_map.find_class(name) else {
+                continue;
+            };
+            for cfg in &class.cfgs {
+                let predicate = cfg.to_string();
+                if !predicates.contains(&predicate) {
+                    predicates.push(predicate);
+                    cfgs.push(cfg);
+                }
+            }
+        }
+        quote_spanned!(self.span => #(#[cfg(#cfgs)])*)
+    }
+
+    fn method_cfg(&self, root_map: &RootMap, method: &Method) -> TokenStream {
+        self.member_cfg(
+            root_map,
+            &method.generics,
+            &method.argument_tys,
+            method.return_ty.as_ref(),
+        )
+    }
+
+    fn upcast_impls(&self, root_map: &RootMap) -> Result<TokenStream, SpanError> {
         let struct_name = self.struct_name();
         let java_class_generics = self.class_generic_names();
         self
-            .resolve_upcasts()
+            .resolve_upcasts(root_map)
+            .iter()
             .map(|r| {
+                let cfg = self.member_cfg(root_map, &[], &[Type::Ref(RefType::Class(r.clone()))], None);
                 let mut sig = Signature::new(&Id::from("supertrait"), self.span, &[])
+                .with_renamed_classes(&root_map.renamed_classes)
                 .with_internal_generics(&self.generics)?;
                 let tokens = sig.forbid_capture(|sig| sig.class_ref_ty(r)).unwrap();
                 Ok(quote_spanned!(self.span =>
+                    #cfg
                     unsafe impl<#(#java_class_generics,)*> plumbing::Upcast<#tokens> for #struct_name<#(#java_class_generics,)*>
                     where
                         #(#java_class_generics: duchess::JavaObject,)*
@@ -259,19 +628,19 @@ impl ClassInfo {
             .collect()
     }
 
-    // XX: Clearly, we'll need more sophisticated resolution of what types we descend from, but for now we can at least
-    // inject the "everything is an Object" root.
-    fn resolve_upcasts(&self) -> impl Iterator<Item = &'_ ClassRef> {
-        static OBJECT: OnceCell<ClassRef> = OnceCell::new();
-        let object = OBJECT.get_or_init(|| ClassRef {
+    /// The supertypes to generate upcasts to, see [`RootMap::bound_supertypes`], plus the "everything is an Object"
+    /// root.
+    fn resolve_upcasts(&self, root_map: &RootMap) -> Vec<ClassRef> {
+        let object = ClassRef {
             name: DotId::parse("java.lang.Object"),
             generics: vec![],
-        });
+        };
 
-        self.extends
-            .iter()
-            .chain(&self.implements)
-            .chain(Some(object).filter(|obj| obj.name != self.name).into_iter())
+        let mut upcasts = root_map.upcasts(self).to_vec();
+        if self.name != object.name && !upcasts.contains(&object) {
+            upcasts.push(object);
+        }
+        upcasts
     }
 
     fn cached_class(&self) -> TokenStream {
@@ -290,8 +659,13 @@ impl ClassInfo {
         }
     }
 
-    fn constructor(&self, constructor: &Constructor) -> Result<TokenStream, SpanError> {
-        let mut sig = Signature::new(self.name.class_name(), self.span, &self.generics);
+    fn constructor(
+        &self,
+        constructor: &Constructor,
+        root_map: &RootMap,
+    ) -> Result<TokenStream, SpanError> {
+        let mut sig = Signature::new(self.name.class_name(), constructor.span, &self.generics)
+            .with_renamed_classes(&root_map.renamed_classes);
 
         let input_traits: Vec<_> = constructor
             .argument_tys
@@ -317,7 +691,9 @@ impl ClassInfo {
         let name = Literal::string(&self.name.to_string());
         let descriptor = Literal::string(&constructor.descriptor());
 
+        let must_use = must_use_attr(self.span);
         let output = quote_spanned!(self.span =>
+            #must_use
             pub fn new(
                 #(#input_names : impl #input_traits,)*
             ) -> impl #output_trait {
@@ -401,15 +777,179 @@ impl ClassInfo {
         Ok(output)
     }
 
+    /// Generates `Class::builder()` and the builder it returns, which has a method to set each argument of
+    /// `constructor` and a `build()` method that creates the object once all arguments are set. Each argument is a
+    /// type parameter of the builder, which is `()` while the argument isn't set, so calling `build()` too early or
+    /// setting an argument twice doesn't compile.
+    ///
+    /// The methods are named after the arguments if the class file records their names, and `arg0`, `arg1`, ...
+    /// otherwise. `attrs` are put on `builder()`.
+    fn builder(
+        &self,
+        constructor: &Constructor,
+        root_map: &RootMap,
+        attrs: &TokenStream,
+    ) -> Result<TokenStream, SpanError> {
+        if !constructor.generics.is_empty() {
+            return Err(SpanError {
+                span: self.span,
+                message: format!(
+                    "`#[builder]` doesn't support generic constructors, like that of `{}`",
+                    self.name
+                ),
+            });
+        }
+
+        let mut sig = Signature::new(self.name.class_name(), constructor.span, &self.generics)
+            .with_renamed_classes(&root_map.renamed_classes);
+        let input_traits: Vec<_> = sig.forbid_capture(|sig| {
+            constructor
+                .argument_tys
+                .iter()
+                .map(|ty| sig.input_trait(ty))
+                .collect::<Result<_, _>>()
+        })?;
+
+        let input_names: Vec<_> = (0..input_traits.len())
+            .map(|i| Ident::new(&format!("a{i}"), self.span))
+            .collect();
+        let setter_names = builder_method_names(constructor, self.span);
+
+        let ty = self.this_type();
+        let java_class_generics = self.class_generic_names();
+        let builder_name = Ident::new(&format!("{}Builder", self.struct_name()), self.span);
+        // The builder is generated next to the class, so it can't share its name with a class of the same package
+        let (package, _) = self.name.split();
+        if let Some(class) = root_map
+            .classes
+            .values()
+            .find(|c| c.name.split().0 == package && builder_name == c.struct_id()[..])
+        {
+            return Err(SpanError {
+                span: self.span,
+                message: format!(
+                    "the builder of `{}` would be named `{builder_name}`, like the binding of `{}`; bind one of them \
+                     under another name with `class ... as ...`",
+                    self.name, class.name
+                ),
+            });
+        }
+        let unset: Vec<_> = input_names
+            .iter()
+            .map(|_| quote_spanned!(self.span => ()))
+            .collect();
+
+        let must_use = must_use_attr(self.span);
+        let setters: Vec<_> = (0..input_names.len())
+            .map(|i| {
+                let setter_name = &setter_names[i];
+                let input_trait = &input_traits[i];
+                let other_names: Vec<_> = input_names
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, name)| name)
+                    .collect();
+                let before = input_names.iter().enumerate().map(|(j, name)| {
+                    if i == j {
+                        quote_spanned!(self.span => ())
+                    } else {
+                        quote_spanned!(self.span => #name)
+                    }
+                });
+                let after = input_names.iter().enumerate().map(|(j, name)| {
+                    if i == j {
+                        quote_spanned!(self.span => Value)
+                    } else {
+                        quote_spanned!(self.span => #name)
+                    }
+                });
+                let fields = input_names.iter().enumerate().map(|(j, name)| {
+                    if i == j {
+                        quote_spanned!(self.span => #name: value)
+                    } else {
+                        quote_spanned!(self.span => #name: self.#name)
+                    }
+                });
+                quote_spanned!(self.span =>
+                    #[allow(non_camel_case_types)]
+                    impl<#(#java_class_generics,)* #(#other_names,)*> #builder_name<#(#java_class_generics,)* #(#before,)*>
+                    where
+                        #(#java_class_generics: duchess::JavaObject,)*
+                    {
+                        #[must_use]
+                        pub fn #setter_name<Value: #input_trait>(self, value: Value) -> #builder_name<#(#java_class_generics,)* #(#after,)*> {
+                            #builder_name {
+                                #(#fields,)*
+                                phantom: self.phantom,
+                            }
+                        }
+                    }
+                )
+            })
+            .collect();
+
+        Ok(quote_spanned!(self.span =>
+            impl<#(#java_class_generics,)*> #ty
+            where
+                #(#java_class_generics: duchess::JavaObject,)*
+            {
+                #attrs
+                pub fn builder() -> #builder_name<#(#java_class_generics,)* #(#unset,)*> {
+                    #builder_name {
+                        #(#input_names: (),)*
+                        phantom: std::marker::PhantomData,
+                    }
+                }
+            }
+
+            #[allow(non_camel_case_types)]
+            pub struct #builder_name<#(#java_class_generics,)* #(#input_names,)*> {
+                #(#input_names: #input_names,)*
+                phantom: std::marker::PhantomData<(#(#java_class_generics,)*)>,
+            }
+
+            #(#setters)*
+
+            #[allow(non_camel_case_types)]
+            impl<#(#java_class_generics,)* #(#input_names,)*> #builder_name<#(#java_class_generics,)* #(#input_names,)*>
+            where
+                #(#java_class_generics: duchess::JavaObject,)*
+                #(#input_names: #input_traits,)*
+            {
+                /// Creates the object with the arguments that were set.
+                #[allow(deprecated)]
+                #must_use
+                pub fn build(self) -> impl IntoLocal<#ty> {
+                    <#ty>::new(#(self.#input_names,)*)
+                }
+            }
+        ))
+    }
+
     /// Generates code for instance methods.
     ///
     ///
     /// NB. This function (particularly the JvmOp impl) has significant overlap with `static_method`
     /// and `static_field_getter`, so if you make changes here, you may well need changes there.
-    fn object_method(&self, method: &Method) -> Result<MethodOutput, SpanError> {
+    fn object_method(
+        &self,
+        method: &Method,
+        names: &RustNames,
+        root_map: &RootMap,
+        cfg: &TokenStream,
+    ) -> Result<MethodOutput, SpanError> {
         assert!(!method.flags.is_static);
 
-        let mut sig = Signature::new(&method.name, self.span, &self.generics)
+        if method.factory {
+            return Err(SpanError {
+                span: method.span,
+                message: format!("`#[factory]` method `{}` must be static", method.name),
+            });
+        }
+
+        let mut sig = Signature::new(&method.name, method.span, &self.generics)
+            .with_renamed_classes(&root_map.renamed_classes)
             .with_internal_generics(&method.generics)?;
 
         let this_ty = self.this_type();
@@ -427,6 +967,7 @@ impl ClassInfo {
         let output_ty = sig.output_type(&method.return_ty)?;
         let output_trait = sig.method_trait(&method.return_ty)?;
         let jni_call_fn = sig.jni_call_fn(&method.return_ty)?;
+        let jni_nonvirtual_call_fn = sig.jni_nonvirtual_call_fn(&method.return_ty)?;
 
         let jni_descriptor = jni_c_str(&method.descriptor(), self.span);
 
@@ -435,8 +976,8 @@ impl ClassInfo {
 
         let jni_method = jni_c_str(&*method.name, self.span);
 
-        let rust_method_name = Id::from(method.name.to_snake_case()).to_ident(self.span);
-        let rust_method_type_name = Id::from(method.name.to_camel_case()).to_ident(self.span);
+        let rust_method_name = names.method(&method.name).to_ident(self.span);
+        let rust_method_type_name = names.method_type(&method.name).to_ident(self.span);
 
         // The generic parameters declared on the Java method.
         let java_class_generics: Vec<_> = self.class_generic_names();
@@ -458,6 +999,7 @@ impl ClassInfo {
         // For each method `m` in the Java type, we create a struct (named `m`)
         // that will implement the `JvmOp`.
         let method_struct = quote_spanned!(self.span =>
+            #cfg
             #[derive(Clone)]
             #[allow(non_camel_case_types)]
             pub struct #rust_method_type_name<
@@ -473,15 +1015,21 @@ impl ClassInfo {
         );
 
         let sig_where_clauses = &sig.where_clauses;
+        let deprecated = deprecated_attr(&method.annotations, self.span);
+        let must_use = must_use_attr(self.span);
 
         // The method signature for the extension trait.
         let trait_method = quote_spanned!(self.span =>
+            #cfg
             type #rust_method_type_name<#(#rust_method_generics),*>: #output_trait
             where
                 #(#input_names: #input_traits,)*
                 #(#sig_where_clauses,)*
                 ;
 
+            #cfg
+            #deprecated
+            #must_use
             fn #rust_method_name<#(#rust_method_generics),*>(
                 self,
                 #(#input_names: #input_names),*
@@ -494,6 +1042,7 @@ impl ClassInfo {
 
         // The method signature for the extension trait.
         let trait_impl_method = quote_spanned!(self.span =>
+            #cfg
             type #rust_method_type_name<#(#rust_method_generics),*> =
                 #rust_method_type_name<Self, #(#method_struct_generics),*>
             where
@@ -501,6 +1050,7 @@ impl ClassInfo {
                 #(#sig_where_clauses,)*
                 ;
 
+            #cfg
             fn #rust_method_name<#(#rust_method_generics),*>(
                 self,
                 #(#input_names: #input_names),*
@@ -517,9 +1067,53 @@ impl ClassInfo {
             }
         );
 
+        // `#[checked]` methods also get a variant returning the exceptions they declare as an enum.
+        let (trait_method, trait_impl_method) = if method.checked {
+            let checked_name = self.checked_method_name(method, names);
+            let error_name = self.checked_error_name(method, names);
+            let checked_ty = quote_spanned!(self.span =>
+                duchess::CatchChecked<Self::#rust_method_type_name<#(#rust_method_generics),*>, #error_name>
+            );
+            (
+                quote_spanned!(self.span =>
+                    #trait_method
+
+                    #cfg
+                    #deprecated
+                    #must_use
+                    fn #checked_name<#(#rust_method_generics),*>(
+                        self,
+                        #(#input_names: #input_names),*
+                    ) -> #checked_ty
+                    where
+                        #(#input_names: #input_traits,)*
+                        #(#sig_where_clauses,)*
+                        ;
+                ),
+                quote_spanned!(self.span =>
+                    #trait_impl_method
+
+                    #cfg
+                    fn #checked_name<#(#rust_method_generics),*>(
+                        self,
+                        #(#input_names: #input_names),*
+                    ) -> #checked_ty
+                    where
+                        #(#input_names: #input_traits,)*
+                        #(#sig_where_clauses,)*
+                    {
+                        duchess::CatchChecked::new(self.#rust_method_name(#(#input_names),*))
+                    }
+                ),
+            )
+        } else {
+            (trait_method, trait_impl_method)
+        };
+
         // Implementation of `JvmOp` for `m` -- when executed, call the method
         // via JNI, after converting its arguments appropriately.
         let impl_output = quote_spanned!(self.span =>
+            #cfg
             #[allow(non_camel_case_types)]
             impl<This, #(#method_struct_generics),*> JvmOp
             for #rust_method_type_name<This, #(#method_struct_generics),*>
@@ -537,6 +1131,8 @@ impl ClassInfo {
                     jvm: &mut Jvm<'jvm>,
                 ) -> duchess::Result<'jvm, Self::Output<'jvm>> {
                     let this = self.this.execute_with(jvm)?;
+                    // `call_super::<S>()` requests the implementation in `S` rather than the most derived override
+                    let super_class = this.super_class();
                     let this: & #this_ty = this.as_jref()?;
                     let this = this.as_raw();
 
@@ -551,15 +1147,33 @@ impl ClassInfo {
                         find_method(jvm, &class, #jni_method, #jni_descriptor, false)
                     })?;
 
-                    let output = unsafe {
-                        jvm.env().invoke(|env| env.#jni_call_fn, |env, f| f(
-                            env,
-                            this.as_ptr(),
-                            method.as_ptr(),
-                            [
-                                #(#input_names.into_jni_value(),)*
-                            ].as_ptr(),
-                        ))
+                    let output = if let Some(super_class) = super_class {
+                        // The method ID picks the implementation that is called, so it is looked up in `S`, which
+                        // finds `S`'s own override or else the one it inherits.
+                        let class = super_class(jvm)?;
+                        let method = find_method(jvm, &class, #jni_method, #jni_descriptor, false)?;
+                        unsafe {
+                            jvm.env().invoke(|env| env.#jni_nonvirtual_call_fn, |env, f| f(
+                                env,
+                                this.as_ptr(),
+                                class.as_raw().as_ptr(),
+                                method.as_ptr(),
+                                [
+                                    #(#input_names.into_jni_value(),)*
+                                ].as_ptr(),
+                            ))
+                        }
+                    } else {
+                        unsafe {
+                            jvm.env().invoke(|env| env.#jni_call_fn, |env, f| f(
+                                env,
+                                this.as_ptr(),
+                                method.as_ptr(),
+                                [
+                                    #(#input_names.into_jni_value(),)*
+                                ].as_ptr(),
+                            ))
+                        }
                     };
                     check_exception(jvm)?;
 
@@ -586,10 +1200,19 @@ impl ClassInfo {
     ///
     /// NB. This function (particularly the JvmOp impl) has significant overlap with `object_method`
     /// and `static_field_getter`, so if you make changes here, you may well need changes there.
-    fn static_method(&self, method: &Method) -> Result<TokenStream, SpanError> {
+    fn static_method(
+        &self,
+        method: &Method,
+        names: &RustNames,
+        root_map: &RootMap,
+        attrs: &TokenStream,
+    ) -> Result<TokenStream, SpanError> {
         assert!(method.flags.is_static);
 
-        let mut sig = Signature::new(&method.name, self.span, &self.generics)
+        // Static members can't refer to the class's generic parameters, so they are bound on the class with its
+        // parameters defaulted (i.e., erased) and can be called without naming them, e.g. `Holder::raw(...)`.
+        let mut sig = Signature::new(&method.name, method.span, &[])
+            .with_renamed_classes(&root_map.renamed_classes)
             .with_internal_generics(&method.generics)?;
 
         let input_traits: Vec<_> = method
@@ -602,8 +1225,22 @@ impl ClassInfo {
             .map(|i| Ident::new(&format!("a{i}"), self.span))
             .collect();
 
-        let output_ty = sig.output_type(&method.return_ty)?;
-        let output_trait = sig.method_trait(&method.return_ty)?;
+        let nullable_output_ty = sig.output_type(&method.return_ty)?;
+        let (output_ty, output_trait, output) = if method.factory {
+            // Like a constructor, a factory produces an instance of the class, so `null` is an error
+            let ty = self.factory_type(method, &mut sig)?;
+            (
+                quote_spanned!(self.span => Local<'jvm, #ty>),
+                quote_spanned!(self.span => IntoLocal<#ty>),
+                quote_spanned!(self.span => output.ok_or(duchess::Error::NullDeref)),
+            )
+        } else {
+            (
+                nullable_output_ty.clone(),
+                sig.method_trait(&method.return_ty)?,
+                quote_spanned!(self.span => Ok(output)),
+            )
+        };
         let jni_call_fn = sig.jni_static_call_fn(&method.return_ty)?;
 
         let jni_descriptor = jni_c_str(&method.descriptor(), self.span);
@@ -613,53 +1250,45 @@ impl ClassInfo {
 
         let jni_method = jni_c_str(&*method.name, self.span);
 
-        let rust_method_name = Id::from(method.name.to_snake_case()).to_ident(self.span);
-        let rust_method_type_name = Id::from(method.name.to_camel_case()).to_ident(self.span);
-
-        // The generic parameters declared on the Java method.
-        let java_class_generics: Vec<_> = self.class_generic_names();
+        let rust_method_name = names.method(&method.name).to_ident(self.span);
+        let rust_method_type_name = names.method_type(&method.name).to_ident(self.span);
 
         // The generic parameters we need on the Rust method, these include:
         //
         // * a type parameter `a0` for each input
         // * a type parameter for each java generic
         // * any fresh generics we created to capture wildcards
+        //
+        // The *method struct* (which will implement the `JvmOp`) needs the same ones.
         let rust_method_generics: Vec<_> = input_names.iter().chain(&sig.rust_generics).collect();
 
-        // The generic parameters we need on the *method struct* (which will implement the `JvmOp`).
-        // These include the class generics plus all the generics from the method.
-        let method_struct_generics: Vec<_> = java_class_generics
-            .iter()
-            .chain(rust_method_generics.iter().copied())
-            .collect();
-
         // For each method `m` in the Java type, we create a struct (named `m`)
         // that will implement the `JvmOp`.
         let method_struct = quote_spanned!(self.span =>
             #[derive(Clone)]
             #[allow(non_camel_case_types)]
             pub struct #rust_method_type_name<
-                #(#method_struct_generics,)*
+                #(#rust_method_generics,)*
             > {
                 #(#input_names : #input_names,)*
                 phantom: std::marker::PhantomData<(
-                    #(#method_struct_generics,)*
+                    #(#rust_method_generics,)*
                 )>,
             }
         );
 
         let sig_where_clauses = &sig.where_clauses;
+        let must_use = must_use_attr(self.span);
 
         // Implementation of `JvmOp` for `m` -- when executed, call the method
         // via JNI, after converting its arguments appropriately.
-        let this_ty = self.this_type();
+        let this_ty = self.struct_name();
         let jvmop_impl = quote_spanned!(self.span =>
             #[allow(non_camel_case_types)]
-            impl<#(#method_struct_generics),*> JvmOp
-            for #rust_method_type_name<#(#method_struct_generics),*>
+            impl<#(#rust_method_generics),*> JvmOp
+            for #rust_method_type_name<#(#rust_method_generics),*>
             where
                 #(#input_names: #input_traits,)*
-                #(#java_class_generics: duchess::JavaObject,)*
                 #(#sig_where_clauses,)*
             {
                 type Output<'jvm> = #output_ty;
@@ -692,33 +1321,347 @@ impl ClassInfo {
                     };
                     check_exception(jvm)?;
 
-                    let output: #output_ty = unsafe { FromJniValue::from_jni_value(jvm, output) };
-                    Ok(output)
+                    let output: #nullable_output_ty = unsafe { FromJniValue::from_jni_value(jvm, output) };
+                    #output
                 }
             }
         );
 
+        // The inputs are `impl Trait` arguments, so that the Java generics are the only type parameters of the Rust
+        // method, and can be given with a turbofish when they can't be inferred, e.g.
+        // `Collections::empty_list::<java::lang::String>()`.
+        let java_generics = &sig.rust_generics;
+        let input_placeholders = input_names.iter().map(|_| quote_spanned!(self.span => _));
         let inherent_method = quote_spanned!(self.span =>
+            #attrs
             #[allow(non_camel_case_types)]
-            pub fn #rust_method_name<#(#rust_method_generics),*>(
-                #(#input_names: #input_names),*
+            #must_use
+            pub fn #rust_method_name<#(#java_generics),*>(
+                #(#input_names: impl #input_traits),*
             ) -> impl #output_trait
             where
-                #(#input_names: #input_traits,)*
                 #(#sig_where_clauses,)*
             {
                 #method_struct
 
                 #jvmop_impl
 
-                #rust_method_type_name {
+                #rust_method_type_name::<#(#input_placeholders,)* #(#java_generics),*> {
                     #(#input_names: #input_names,)*
                     phantom: Default::default(),
                 }
             }
         );
 
-        Ok(inherent_method)
+        // `#[checked]` methods also get a variant returning the exceptions they declare as an enum.
+        if !method.checked {
+            return Ok(inherent_method);
+        }
+        let checked_name = self.checked_method_name(method, names);
+        let error_name = self.checked_error_name(method, names);
+        Ok(quote_spanned!(self.span =>
+            #inherent_method
+
+            #attrs
+            #[allow(non_camel_case_types)]
+            #must_use
+            pub fn #checked_name<#(#java_generics),*>(
+                #(#input_names: impl #input_traits),*
+            ) -> duchess::CatchChecked<impl #output_trait, #error_name>
+            where
+                #(#sig_where_clauses,)*
+            {
+                duchess::CatchChecked::new(Self::#rust_method_name::<#(#java_generics),*>(#(#input_names),*))
+            }
+        ))
+    }
+
+    /// The name of the `_checked` variant of the `#[checked]` method `method`, see `Method::checked`.
+    fn checked_method_name(&self, method: &Method, names: &RustNames) -> Ident {
+        Ident::new(
+            &format!("{}_checked", names.method(&method.name)),
+            self.span,
+        )
+    }
+
+    /// The name of the enum of the exceptions declared by the `#[checked]` method `method`, e.g.
+    /// `HttpAuthAuthenticateError` for `HttpAuth.authenticate`. Like the `_checked` method, it is named after the
+    /// Rust name of the method, so that methods whose names only differ in case (`getURL` and `getUrl`) get
+    /// different enums.
+    fn checked_error_name(&self, method: &Method, names: &RustNames) -> Ident {
+        Ident::new(
+            &format!(
+                "{}{}Error",
+                self.struct_name(),
+                names.method(&method.name).to_pascal_case()
+            ),
+            self.span,
+        )
+    }
+
+    /// Generates the enum of the exceptions in the `throws` clause of the `#[checked]` method `method`, with a
+    /// variant named after each exception class, and its `CheckedException` impl, which picks the variant of the
+    /// first class in the clause that a thrown exception is an instance of. The enum also implements `Debug`,
+    /// `Display` and `std::error::Error`, so that it can be propagated with `?`.
+    fn checked_error(
+        &self,
+        method: &Method,
+        names: &RustNames,
+        root_map: &RootMap,
+        attrs: &TokenStream,
+    ) -> Result<TokenStream, SpanError> {
+        if method.throws.is_empty() {
+            return Err(SpanError {
+                span: method.span,
+                message: format!(
+                    "`#[checked]` method `{}` doesn't declare any exceptions",
+                    method.name
+                ),
+            });
+        }
+
+        let mut sig = Signature::new(&method.name, method.span, &self.generics)
+            .with_renamed_classes(&root_map.renamed_classes);
+        // Exceptions with the same class name in different packages are told apart by their packages, e.g.
+        // `AIOException` and `BIOException` for `a.IOException` and `b.IOException`.
+        let simple_names: Vec<&Id> = method
+            .throws
+            .iter()
+            .map(|exception| {
+                let name = root_map.renamed_classes.get(&exception.name);
+                name.unwrap_or(exception.name.class_name())
+            })
+            .collect();
+        let variants: Vec<Ident> = method
+            .throws
+            .iter()
+            .zip(&simple_names)
+            .map(|(exception, &name)| {
+                if simple_names.iter().filter(|&&n| n == name).count() == 1 {
+                    return name.to_ident(self.span);
+                }
+                let (package, _) = exception.name.split();
+                let package: String = package.iter().map(|id| id.to_pascal_case()).collect();
+                Id::from(format!("{package}{name}")).to_ident(self.span)
+            })
+            .collect();
+        let tys: Vec<TokenStream> = method
+            .throws
+            .iter()
+            .map(|exception| {
+                let ty = Type::Ref(RefType::Class(exception.clone()));
+                sig.forbid_capture(|sig| sig.java_ty(&ty))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let debug_names: Vec<Literal> = method
+            .throws
+            .iter()
+            .zip(&variants)
+            .map(|(exception, variant)| Literal::string(&format!("{variant}({})", exception.name)))
+            .collect();
+
+        let error_name = self.checked_error_name(method, names);
+        let doc = Literal::string(&format!(
+            " The exceptions declared by `{}.{}`, returned by its `_checked` variant.",
+            self.name, method.name
+        ));
+        Ok(quote_spanned!(self.span =>
+            #attrs
+            #[doc = #doc]
+            #[allow(non_camel_case_types)]
+            pub enum #error_name {
+                #(#variants(duchess::Global<#tys>),)*
+            }
+
+            // Only names the exception class, since calling `toString()` would need the JVM
+            #attrs
+            impl std::fmt::Debug for #error_name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        #(Self::#variants(_) => f.write_str(#debug_names),)*
+                    }
+                }
+            }
+
+            #attrs
+            impl std::fmt::Display for #error_name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        #(Self::#variants(exception) => f.write_str(&duchess::plumbing::try_extract_message(exception)),)*
+                    }
+                }
+            }
+
+            #attrs
+            impl std::error::Error for #error_name {}
+
+            #attrs
+            impl duchess::CheckedException for #error_name {
+                fn from_thrown<'jvm>(
+                    exception: &duchess::java::lang::Throwable,
+                    jvm: &mut duchess::Jvm<'jvm>,
+                ) -> duchess::Result<'jvm, Option<Self>> {
+                    #(
+                        if let Some(exception) = duchess::plumbing::global_if_instance::<#tys>(exception, jvm)? {
+                            return Ok(Some(Self::#variants(exception)));
+                        }
+                    )*
+                    Ok(None)
+                }
+            }
+        ))
+    }
+
+    /// The Rust type of the instances returned by the `#[factory]` method `method`, which must return its class, with
+    /// any type arguments, e.g. `java.util.List<E>` for `static <E> java.util.List<E> of(E, E)`.
+    fn factory_type(&self, method: &Method, sig: &mut Signature) -> Result<TokenStream, SpanError> {
+        match &method.return_ty {
+            Some(ty @ Type::Ref(RefType::Class(class))) if class.name == self.name => {
+                sig.forbid_capture(|sig| sig.java_ty(ty))
+            }
+            _ => Err(SpanError {
+                span: method.span,
+                message: format!(
+                    "`#[factory]` method `{}` must return an instance of `{}`",
+                    method.name, self.name
+                ),
+            }),
+        }
+    }
+
+    /// Generates a getter for an instance field, which is part of the extension trait like an instance method.
+    ///
+    /// NB. This function (particularly the JvmOp impl) has significant overlap with `object_method`
+    /// and `static_field_getter`, so if you make changes here, you may well need changes there.
+    fn object_field_getter(
+        &self,
+        field: &Field,
+        names: &RustNames,
+        root_map: &RootMap,
+        cfg: &TokenStream,
+    ) -> Result<MethodOutput, SpanError> {
+        assert!(!field.flags.is_static);
+
+        let mut sig = Signature::new(&field.name, field.span, &self.generics)
+            .with_renamed_classes(&root_map.renamed_classes);
+
+        let this_ty = self.this_type();
+
+        let output_ty = sig.non_void_output_type(&field.ty)?;
+        let output_trait = sig.field_trait(&field.ty)?;
+        let jni_field_fn = sig.jni_field_get_fn(&field.ty)?;
+
+        let jni_field = jni_c_str(&*field.name, self.span);
+        let jni_descriptor = jni_c_str(field.descriptor(), self.span);
+
+        let rust_field_name = names.field_getter(&field.name).to_ident(self.span);
+        let rust_field_type_name = names.field_getter_type(&field.name).to_ident(self.span);
+
+        let java_class_generics: Vec<_> = self.class_generic_names();
+
+        let field_struct = quote_spanned!(self.span =>
+            #cfg
+            #[derive(Clone)]
+            #[allow(non_camel_case_types)]
+            pub struct #rust_field_type_name<
+                This,
+                #(#java_class_generics,)*
+            > {
+                this: This,
+                phantom: std::marker::PhantomData<(
+                    #(#java_class_generics,)*
+                )>,
+            }
+        );
+
+        let sig_where_clauses = &sig.where_clauses;
+        let must_use = must_use_attr(self.span);
+
+        let trait_method = quote_spanned!(self.span =>
+            #cfg
+            type #rust_field_type_name: #output_trait
+            where
+                #(#sig_where_clauses,)*
+                ;
+
+            #cfg
+            #must_use
+            fn #rust_field_name(self) -> Self::#rust_field_type_name
+            where
+                #(#sig_where_clauses,)*
+                ;
+        );
+
+        let trait_impl_method = quote_spanned!(self.span =>
+            #cfg
+            type #rust_field_type_name =
+                #rust_field_type_name<Self, #(#java_class_generics),*>
+            where
+                #(#sig_where_clauses,)*
+                ;
+
+            #cfg
+            fn #rust_field_name(self) -> Self::#rust_field_type_name
+            where
+                #(#sig_where_clauses,)*
+            {
+                #rust_field_type_name {
+                    this: self,
+                    phantom: Default::default(),
+                }
+            }
+        );
+
+        let impl_output = quote_spanned!(self.span =>
+            #cfg
+            #[allow(non_camel_case_types)]
+            impl<This, #(#java_class_generics),*> JvmOp
+            for #rust_field_type_name<This, #(#java_class_generics),*>
+            where
+                This: JvmOp,
+                for<'jvm> This::Output<'jvm>: duchess::AsJRef<#this_ty>,
+                #(#java_class_generics: duchess::JavaObject,)*
+                #(#sig_where_clauses,)*
+            {
+                type Output<'jvm> = #output_ty;
+
+                fn execute_with<'jvm>(
+                    self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> duchess::Result<'jvm, Self::Output<'jvm>> {
+                    let this = self.this.execute_with(jvm)?;
+                    let this: & #this_ty = this.as_jref()?;
+                    let this = this.as_raw();
+
+                    // Cache the field id for this field, shared by all generic monomorphizations as for methods.
+                    static FIELD: OnceCell<FieldPtr> = OnceCell::new();
+                    let field = FIELD.get_or_try_init(|| {
+                        let class = <#this_ty>::class(jvm)?;
+                        find_field(jvm, &class, #jni_field, #jni_descriptor, false)
+                    })?;
+
+                    let output = unsafe {
+                        jvm.env().invoke(|env| env.#jni_field_fn, |env, f| f(
+                            env,
+                            this.as_ptr(),
+                            field.as_ptr(),
+                        ))
+                    };
+                    check_exception(jvm)?;
+
+                    let output: #output_ty = unsafe { FromJniValue::from_jni_value(jvm, output) };
+                    Ok(output)
+                }
+            }
+        );
+
+        Ok(MethodOutput {
+            method_struct: field_struct,
+            trait_method,
+            trait_impl_method,
+            jvm_op_impl: impl_output,
+        })
     }
 
     /// Generates a static field getter that should be part of the inherent methods
@@ -726,55 +1669,47 @@ impl ClassInfo {
     ///
     /// NB. This function (particularly the JvmOp impl) has significant overlap with `object_method`
     /// and `static_method`, so if you make changes here, you may well need changes there.
-    fn static_field_getter(&self, field: &Field) -> Result<TokenStream, SpanError> {
+    fn static_field_getter(
+        &self,
+        field: &Field,
+        names: &RustNames,
+        root_map: &RootMap,
+    ) -> Result<TokenStream, SpanError> {
         assert!(field.flags.is_static);
 
-        let mut sig = Signature::new(&field.name, self.span, &self.generics);
+        // Like static methods, static fields can't refer to the class's generic parameters.
+        let mut sig = Signature::new(&field.name, field.span, &[])
+            .with_renamed_classes(&root_map.renamed_classes);
 
         let output_ty = sig.non_void_output_type(&field.ty)?;
         let output_trait = sig.field_trait(&field.ty)?;
         let jni_field_fn = sig.jni_static_field_get_fn(&field.ty)?;
 
         let jni_field = jni_c_str(&*field.name, self.span);
-        let jni_descriptor = jni_c_str(&field.ty.descriptor(), self.span);
+        let jni_descriptor = jni_c_str(field.descriptor(), self.span);
 
-        let rust_field_name =
-            Id::from(format!("get_{}", field.name.to_snake_case())).to_ident(self.span);
+        let rust_field_name = names.static_field_getter(&field.name).to_ident(self.span);
         let rust_field_type_name =
             Id::from(format!("{}Getter", field.name.to_camel_case())).to_ident(self.span);
 
-        // The generic parameters declared on the Java method.
-        let java_class_generics: Vec<_> = self.class_generic_names();
-
-        // The generic parameters we need on the *method struct* (which will implement the `JvmOp`).
-        // These include the class generics plus all the generics from the method.
-        let field_struct_generics: Vec<_> = java_class_generics.clone(); // XX: Unnecessary clone
-
         // For each field `f` in the Java type, we create a struct (named `<f>Getter`)
         // that will implement the `JvmOp`.
         let field_struct = quote_spanned!(self.span =>
             #[derive(Clone)]
             #[allow(non_camel_case_types)]
-            pub struct #rust_field_type_name<
-                #(#field_struct_generics,)*
-            > {
-                phantom: std::marker::PhantomData<(
-                    #(#field_struct_generics,)*
-                )>,
-            }
+            pub struct #rust_field_type_name {}
         );
 
         let sig_where_clauses = &sig.where_clauses;
+        let must_use = must_use_attr(self.span);
 
         // Implementation of `JvmOp` for `f` -- when executed, call the method
         // via JNI, after converting its arguments appropriately.
-        let this_ty = self.this_type();
+        let this_ty = self.struct_name();
         let jvmop_impl = quote_spanned!(self.span =>
             #[allow(non_camel_case_types)]
-            impl<#(#field_struct_generics),*> JvmOp
-            for #rust_field_type_name<#(#field_struct_generics),*>
+            impl JvmOp for #rust_field_type_name
             where
-                #(#java_class_generics: duchess::JavaObject,)*
                 #(#sig_where_clauses,)*
             {
                 type Output<'jvm> = #output_ty;
@@ -811,6 +1746,7 @@ impl ClassInfo {
 
         let inherent_method = quote_spanned!(self.span =>
             #[allow(non_camel_case_types)]
+            #must_use
             pub fn #rust_field_name() -> impl #output_trait
             where
                 #(#sig_where_clauses,)*
@@ -819,9 +1755,7 @@ impl ClassInfo {
 
                 #jvmop_impl
 
-                #rust_field_type_name {
-                    phantom: Default::default(),
-                }
+                #rust_field_type_name {}
             }
         );
 
@@ -832,11 +1766,16 @@ impl ClassInfo {
     }
 
     fn struct_name(&self) -> Ident {
-        self.name.class_name().to_ident(self.span)
+        self.struct_id().to_ident(self.span)
+    }
+
+    /// The name of the generated struct, see [`ClassInfo::rust_name`].
+    fn struct_id(&self) -> &Id {
+        self.rust_name.as_ref().unwrap_or(self.name.class_name())
     }
 
     fn ext_trait_name(&self) -> Ident {
-        let mut id = self.name.class_name().clone();
+        let mut id = self.struct_id().clone();
         id.data.push_str("Ext");
         id.to_ident(self.span)
     }
@@ -891,6 +1830,61 @@ trait GenericExt {
     fn to_where_clause(&self, span: Span) -> TokenStream;
 }
 
+/// The names of the methods of the builder generated for `constructor`, see [`ClassInfo::builder`]. These are the
+/// names of the arguments in snake case if the class file records them, they are all valid Rust identifiers and none
+/// of them is `build`, and `arg0`, `arg1`, ... otherwise.
+fn builder_method_names(constructor: &Constructor, span: Span) -> Vec<Ident> {
+    let positional = || {
+        (0..constructor.argument_tys.len())
+            .map(|i| Ident::new(&format!("arg{i}"), span))
+            .collect()
+    };
+    let Some(names) = &constructor.parameter_names else {
+        return positional();
+    };
+
+    let names: Vec<String> = names.iter().map(|n| n.to_snake_case()).collect();
+    let is_identifier = |name: &str| {
+        name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+            && !["build", "_", "self", "super", "crate"].contains(&name)
+    };
+    let unique = names
+        .iter()
+        .enumerate()
+        .all(|(i, n)| !names[..i].contains(n));
+    if !unique || !names.iter().all(|n| is_identifier(n)) {
+        return positional();
+    }
+    names
+        .iter()
+        .map(|n| Id::from(&n[..]).to_ident(span))
+        .collect()
+}
+
+/// `#[deprecated]` for a member annotated with `@java.lang.Deprecated`, noting its `since` and `forRemoval` elements.
+fn deprecated_attr(annotations: &[Annotation], span: Span) -> TokenStream {
+    let Some(deprecated) = annotations.iter().find(|a| a.is_deprecated()) else {
+        return TokenStream::new();
+    };
+
+    let mut note = "deprecated in Java".to_string();
+    if let Some(AnnotationValue::String(since)) = deprecated.element("since") {
+        note.push_str(&format!(" since {since}"));
+    }
+    if deprecated.element("forRemoval") == Some(&AnnotationValue::Primitive("true".to_string())) {
+        note.push_str(", and marked for removal");
+    }
+
+    let note = Literal::string(&note);
+    quote_spanned!(span => #[deprecated(note = #note)])
+}
+
+/// Generated methods only build a `JvmOp`, so calling one without executing the result does nothing.
+fn must_use_attr(span: Span) -> TokenStream {
+    quote_spanned!(span => #[must_use = "this is a lazy JvmOp; call .execute() to run it"])
+}
+
 fn jni_c_str(contents: impl Into<String>, span: Span) -> TokenStream {
     let mut contents = contents.into().into_bytes();
     // \0 isn't valid UTF-8, so don't need to check that contents doesn't contain interior nul bytes.
@@ -899,3 +1893,62 @@ fn jni_c_str(contents: impl Into<String>, span: Span) -> TokenStream {
     let byte_string = Literal::byte_string(&contents);
     quote_spanned!(span => unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(#byte_string) })
 }
+
+#[cfg(test)]
+mod test {
+    use proc_macro2::Span;
+
+    use crate::class_info::{ClassInfo, Id, RootMap};
+
+    fn generates_constructor(text: &str) -> bool {
+        let info = ClassInfo::parse(text, Span::call_site()).unwrap();
+        let tokens = info.to_tokens(&RootMap::default()).unwrap().to_string();
+        tokens.contains("fn new")
+    }
+
+    #[test]
+    fn abstract_classes_have_no_constructors() {
+        assert!(generates_constructor(
+            "public class shapes.Circle { public shapes.Circle(double); }"
+        ));
+        assert!(!generates_constructor(
+            "public abstract class shapes.Shape { public shapes.Shape(); public abstract double area(); }"
+        ));
+    }
+
+    #[test]
+    fn void_methods_use_void_calls() {
+        let info = ClassInfo::parse(
+            "public class voids.Tally { public void add(int); public static void resetCreated(); }",
+            Span::call_site(),
+        )
+        .unwrap();
+        let tokens = info.to_tokens(&RootMap::default()).unwrap().to_string();
+        assert!(tokens.contains("CallVoidMethodA"));
+        assert!(tokens.contains("CallStaticVoidMethodA"));
+        assert!(!tokens.contains("CallObjectMethodA"));
+    }
+
+    #[test]
+    fn builder_method_names_fall_back_to_positions() {
+        let mut info = ClassInfo::parse(
+            "public class inner.Outer$Counter { public inner.Outer$Counter(inner.Outer, int); }",
+            Span::call_site(),
+        )
+        .unwrap();
+        let names = |info: &ClassInfo| -> Vec<String> {
+            super::builder_method_names(&info.constructors[0], Span::call_site())
+                .iter()
+                .map(|i| i.to_string())
+                .collect()
+        };
+        assert_eq!(names(&info), ["arg0", "arg1"]);
+
+        info.constructors[0].parameter_names = Some(vec![Id::from("this$0"), Id::from("start")]);
+        assert_eq!(names(&info), ["arg0", "arg1"]);
+
+        info.constructors[0].parameter_names =
+            Some(vec![Id::from("outer"), Id::from("startValue")]);
+        assert_eq!(names(&info), ["outer", "start_value"]);
+    }
+}
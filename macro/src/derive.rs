@@ -1,20 +1,30 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, sync::Arc};
 
 use proc_macro2::Span;
 use quote::quote_spanned;
 use syn::{spanned::Spanned, Attribute};
-use synstructure::VariantInfo;
+use synstructure::{BindingInfo, VariantInfo};
 
-use crate::{argument::MethodSelector, parse::Parser, reflect::Reflector, signature::Signature};
+use crate::{
+    argument::{DeriveSelector, Ident, JavaPath, MethodSelector},
+    class_info::{ClassInfo, DotId, Id, Type},
+    parse::Parser,
+    reflect::Reflector,
+    signature::Signature,
+};
 
 pub fn derive_to_rust(s: synstructure::Structure) -> proc_macro2::TokenStream {
     let mut driver = Driver {
         input: &s,
         reflector: &mut Reflector::default(),
     };
-    match driver.try_derive_to_rust() {
+    let result = driver.try_derive_to_rust();
+    driver
+        .reflector
+        .report_stats(format_args!("{}.to_rust", s.ast().ident));
+    match result {
         Ok(t) => {
-            crate::debug_tokens(&s.ast().ident, &t);
+            crate::debug_tokens(format!("{}.to_rust", s.ast().ident), &t);
             t
         }
         Err(e) => e.into_compile_error(),
@@ -26,9 +36,13 @@ pub fn derive_to_java(s: synstructure::Structure) -> proc_macro2::TokenStream {
         input: &s,
         reflector: &mut Reflector::default(),
     };
-    match driver.try_derive_to_java() {
+    let result = driver.try_derive_to_java();
+    driver
+        .reflector
+        .report_stats(format_args!("{}.to_java", s.ast().ident));
+    match result {
         Ok(t) => {
-            crate::debug_tokens(&s.ast().ident, &t);
+            crate::debug_tokens(format!("{}.to_java", s.ast().ident), &t);
             t
         }
         Err(e) => e.into_compile_error(),
@@ -84,17 +98,12 @@ impl Driver<'_> {
 
         let to_rust_body = self.variant_to_rust(variant)?;
 
-        let method_selector = self.find_method_selector(variant_span, variant.ast().attrs)?;
-        let reflected_method = self.reflector.reflect_method(&method_selector)?;
-        let class_name = reflected_method
-            .class()
-            .name
-            .to_module_name(method_selector.class_span());
-
-        let ext_trait_name = reflected_method
-            .class()
-            .name
-            .to_ext_trait_name(method_selector.class_span());
+        let (method_selector, rust_name) =
+            self.find_method_selector(variant_span, variant.ast().attrs)?;
+        let class = self.reflect_class(&method_selector)?;
+        let rust_path = rust_path(&class.name, rust_name.as_ref());
+        let class_name = rust_path.to_module_name(method_selector.class_span());
+        let ext_trait_name = rust_path.to_ext_trait_name(method_selector.class_span());
 
         let self_ty = &self.input.ast().ident;
         Ok(quote_spanned!(self.span() =>
@@ -122,12 +131,11 @@ impl Driver<'_> {
 
         let to_java_body = self.variant_to_java(variant)?;
 
-        let method_selector = self.find_method_selector(variant_span, variant.ast().attrs)?;
-        let reflected_method = self.reflector.reflect_method(&method_selector)?;
-        let class_name = reflected_method
-            .class()
-            .name
-            .to_module_name(method_selector.class_span());
+        let (method_selector, rust_name) =
+            self.find_method_selector(variant_span, variant.ast().attrs)?;
+        let class = self.reflect_class(&method_selector)?;
+        let class_name =
+            rust_path(&class.name, rust_name.as_ref()).to_module_name(method_selector.class_span());
 
         let self_ty = &self.input.ast().ident;
         Ok(quote_spanned!(self.span() =>
@@ -155,6 +163,19 @@ impl Driver<'_> {
         &mut self,
         variant: &VariantInfo,
     ) -> Result<proc_macro2::TokenStream, syn::Error> {
+        // Accessors returning scalars (e.g. the `int` components of a record) produce the Rust value directly,
+        // without a null check or `to_rust` conversion.
+        let (method_selector, _) =
+            self.find_method_selector(variant.ast().ident.span(), variant.ast().attrs)?;
+        let class = self.reflect_class(&method_selector)?;
+        let names = class.rust_names();
+        let scalar_accessors: Vec<String> = class
+            .methods
+            .iter()
+            .filter(|m| m.argument_tys.is_empty() && matches!(m.return_ty, Some(Type::Scalar(_))))
+            .map(|m| names.method(&m.name).to_string())
+            .collect();
+
         // For each field, construct an expression we will use to initialize its value.
         let mut initializers = VecDeque::new();
         for field in variant.ast().fields {
@@ -163,6 +184,12 @@ impl Driver<'_> {
                     // Special case for fields named this
                     initializers
                         .push_back(quote_spanned!(name.span() => self.global().execute_with(jvm)?));
+                } else if scalar_accessors.iter().any(|m| name == m) {
+                    initializers.push_back(quote_spanned!(name.span() =>
+                    self
+                        .#name()
+                        .execute_with(jvm)?
+                    ));
                 } else if self.is_option(&field.ty) {
                     initializers.push_back(quote_spanned!(name.span() =>
                     self
@@ -226,9 +253,28 @@ impl Driver<'_> {
         // know the constructor so we can find the expected types, since we need
         // to provide those when we call `.to_java::<J>()`.
 
-        let method_selector = self.find_method_selector(variant_span, variant.ast().attrs)?;
+        let (method_selector, rust_name) =
+            self.find_method_selector(variant_span, variant.ast().attrs)?;
+
+        // The class file may record the names of the arguments, which are used to match them with the fields. They are
+        // only printed by `javap -v`, which is much slower, so they are only read if there are fields to reorder.
+        let named_fields = variant
+            .bindings()
+            .iter()
+            .filter(|b| b.ast().ident.is_some())
+            .count();
+        if let (MethodSelector::ClassName(cn) | MethodSelector::MethodName(cn, _), 2..) =
+            (&method_selector, named_fields)
+        {
+            self.reflector
+                .reflect_annotations(&[cn.to_dot_id()], cn.span)?;
+        }
 
-        let reflected_method = self.reflector.reflect_method(&method_selector)?;
+        // Each field is passed as an argument, which tells apart overloads with different numbers of arguments.
+        let arity = variant.ast().fields.len();
+        let reflected_method = self
+            .reflector
+            .reflect_method(&method_selector, Some(arity))?;
 
         if !reflected_method.is_static() {
             return Err(syn::Error::new(
@@ -258,10 +304,15 @@ impl Driver<'_> {
         // calls.
         //
         // FIXME: We could allow users to tell us, I guess.
-        if reflected_method.generics().len() != 0 {
+        let method_generics = reflected_method.method_generics();
+        if !method_generics.is_empty() {
+            let generics: Vec<String> = method_generics.iter().map(|g| g.to_string()).collect();
             return Err(syn::Error::new(
                 method_selector.span(),
-                format!("selected method or constructor has generic parameters, not supported",),
+                format!(
+                    "selected method or constructor has generic parameters `<{}>`, not supported",
+                    generics.join(", ")
+                ),
             ));
         }
 
@@ -278,20 +329,21 @@ impl Driver<'_> {
                 .collect::<Result<_, _>>()
         })?;
 
-        let args: Vec<_> = variant
-            .bindings()
-            .iter()
+        let bindings = bindings_in_argument_order(variant, reflected_method.parameter_names());
+        let args: Vec<_> = bindings
+            .into_iter()
             .zip(&java_types)
-            .map(|(binding, java_type)| {
-                quote_spanned!(binding.span() =>
+            .zip(method_arguments)
+            .map(|((binding, java_type), argument_ty)| match argument_ty {
+                // scalars are passed by value
+                Type::Scalar(_) => quote_spanned!(binding.span() => *#binding),
+                _ => quote_spanned!(binding.span() =>
                     duchess::ToJava::to_java::<#java_type>(#binding)
-                )
+                ),
             })
             .collect();
 
-        let class_name = reflected_method
-            .class()
-            .name
+        let class_name = rust_path(&reflected_method.class().name, rust_name.as_ref())
             .to_module_name(method_selector.class_span());
         let method_name = reflected_method.name().to_ident(method_selector.span());
 
@@ -303,17 +355,38 @@ impl Driver<'_> {
         ))
     }
 
+    /// Reflects the class named by `method_selector`, whose constructors and methods don't matter.
+    fn reflect_class(
+        &mut self,
+        method_selector: &MethodSelector,
+    ) -> Result<Arc<ClassInfo>, syn::Error> {
+        match method_selector {
+            MethodSelector::ClassName(cn) | MethodSelector::MethodName(cn, _) => {
+                Ok(self.reflector.reflect(&cn.to_dot_id(), cn.span)?.clone())
+            }
+            MethodSelector::ClassInfo(ci) => Err(syn::Error::new(
+                ci.span,
+                "class declarations are not supported in `#[java(...)]`",
+            )),
+        }
+    }
+
     fn find_method_selector(
         &mut self,
         span: Span,
         attrs: &[Attribute],
-    ) -> Result<MethodSelector, syn::Error> {
+    ) -> Result<(MethodSelector, Option<Id>), syn::Error> {
         for attr in attrs {
             let path = attr.meta.path();
             if path.is_ident("java") {
                 let list = attr.meta.require_list()?;
                 if let syn::MacroDelimiter::Paren(_) = list.delimiter {
-                    return Ok(Parser::from(list.tokens.clone()).parse()?);
+                    if is_named_selector(&list.tokens) {
+                        return Ok((named_method_selector(attr)?, None));
+                    }
+                    let selector: DeriveSelector = Parser::from(list.tokens.clone()).parse()?;
+                    let rust_name = selector.rust_name.map(|name| name.to_id());
+                    return Ok((selector.method, rust_name));
                 };
                 return Err(syn::Error::new(
                     attr.span(),
@@ -334,3 +407,143 @@ impl Driver<'_> {
         }
     }
 }
+
+/// The path to the Rust struct of the class `name`, which is named `rust_name` if the selector names it. Otherwise the
+/// struct is reached through its Java name, which works whether or not the class was bound as `class Foo as JFoo`.
+fn rust_path(name: &DotId, rust_name: Option<&Id>) -> DotId {
+    match rust_name {
+        Some(rust_name) => name.renamed(rust_name),
+        None => name.in_java_names(),
+    }
+}
+
+/// The bindings of `variant` in the order of the arguments they are passed as. If the names of the arguments are
+/// known and each field has the snake-case name of one of them (e.g. `first_name` for `firstName`), the fields are
+/// matched by name, so they can be declared in any order. Otherwise they are passed in the order they are declared.
+fn bindings_in_argument_order<'v, 'a>(
+    variant: &'v VariantInfo<'a>,
+    parameter_names: Option<&[Id]>,
+) -> Vec<&'v BindingInfo<'a>> {
+    let positional = || variant.bindings().iter().collect();
+    let Some(parameter_names) = parameter_names else {
+        return positional();
+    };
+    let bindings = variant.bindings();
+    let indices: Option<Vec<usize>> = parameter_names
+        .iter()
+        .map(|name| {
+            let name = name.to_snake_case();
+            bindings
+                .iter()
+                .position(|b| b.ast().ident.as_ref().is_some_and(|i| *i == name))
+        })
+        .collect();
+    match indices {
+        Some(indices)
+            if indices.len() == bindings.len()
+                && (0..bindings.len()).all(|index| indices.contains(&index)) =>
+        {
+            indices.into_iter().map(|index| &bindings[index]).collect()
+        }
+        _ => positional(),
+    }
+}
+
+/// True if the attribute is written as `#[java(class = "...", ...)]` rather than `#[java(package.Class)]`.
+fn is_named_selector(tokens: &proc_macro2::TokenStream) -> bool {
+    let mut tokens = tokens.clone().into_iter();
+    matches!(
+        (tokens.next(), tokens.next()),
+        (Some(proc_macro2::TokenTree::Ident(_)), Some(proc_macro2::TokenTree::Punct(p))) if p.as_char() == '='
+    )
+}
+
+/// Parses `#[java(class = "Class", package = "some.pkg")]` into a selector for `some.pkg.Class`.
+/// As in `java_package!`, the class may also be written with its package, which then has to agree with `package`.
+fn named_method_selector(attr: &Attribute) -> Result<MethodSelector, syn::Error> {
+    let mut class: Option<syn::LitStr> = None;
+    let mut package: Option<syn::LitStr> = None;
+    attr.parse_nested_meta(|meta| {
+        let slot = if meta.path.is_ident("class") {
+            &mut class
+        } else if meta.path.is_ident("package") {
+            &mut package
+        } else {
+            return Err(meta.error("expected `class` or `package`"));
+        };
+        if slot.is_some() {
+            return Err(meta.error("duplicate key"));
+        }
+        *slot = Some(meta.value()?.parse()?);
+        Ok(())
+    })?;
+
+    let Some(class) = class else {
+        return Err(syn::Error::new(
+            attr.span(),
+            r#"expected `class = "..."` to name the java class"#,
+        ));
+    };
+    let span = class.span();
+    let mut class_ids = dotted_idents(&class)?;
+
+    if let Some(package) = package {
+        let package_ids = dotted_idents(&package)?;
+        if class_ids.len() == 1 {
+            // If the user just wrote (e.g.) `class = "HttpRequest"`, add the package ourselves.
+            class_ids = package_ids.into_iter().chain(class_ids).collect();
+        } else if class_ids[..class_ids.len() - 1]
+            .iter()
+            .map(|id| &id.text)
+            .ne(package_ids.iter().map(|id| &id.text))
+        {
+            // Otherwise, check that the package the user wrote matches.
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "class `{}` expected to be in package `{}`",
+                    class.value(),
+                    package.value()
+                ),
+            ));
+        }
+    } else if class_ids.len() == 1 {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "class `{}` has no package, supply `package = \"...\"` or a fully qualified name",
+                class.value()
+            ),
+        ));
+    }
+
+    Ok(MethodSelector::ClassName(JavaPath {
+        ids: class_ids,
+        span,
+    }))
+}
+
+/// Splits a string literal like `"java.lang.Object"` into identifiers, all spanned at the literal.
+fn dotted_idents(lit: &syn::LitStr) -> Result<Vec<Ident>, syn::Error> {
+    let value = lit.value();
+    value
+        .split('.')
+        .map(|text| {
+            let mut chars = text.chars();
+            let valid = chars
+                .next()
+                .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+                && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+            if !valid {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("`{value}` is not a valid java name"),
+                ));
+            }
+            Ok(Ident {
+                text: text.to_string(),
+                span: lit.span(),
+            })
+        })
+        .collect()
+}
@@ -4,8 +4,10 @@ use proc_macro::TokenStream;
 use rust_format::Formatter;
 
 mod argument;
+mod builtin;
 mod check;
 mod class_info;
+mod classpath;
 mod codegen;
 mod derive;
 mod parse;
@@ -43,16 +45,57 @@ synstructure::decl_derive!([ToRust, attributes(java)] => derive::derive_to_rust)
 
 synstructure::decl_derive!([ToJava, attributes(java)] => derive::derive_to_java);
 
+/// Dumps the code generated for `name` if requested through the `DUCHESS_DUMP` environment variable
+/// (`DUCHESS_DEBUG` is accepted as an alias). The variable holds `1` or `*` to dump everything, or a
+/// comma-separated list of prefixes, e.g. `DUCHESS_DUMP=auth.HttpAuth,auth.HttpRequest`.
+///
+/// The code is printed to stderr, or written to `<name>.rs` inside `DUCHESS_DUMP_DIR` if that is set.
 fn debug_tokens(name: impl std::fmt::Display, token_stream: &proc_macro2::TokenStream) {
-    let Ok(f) = std::env::var("DUCHESS_DEBUG") else { return };
-    if f == "*" || f == "1" || name.to_string().starts_with(&f) {
-        match rust_format::RustFmt::default().format_tokens(token_stream.clone()) {
-            Ok(v) => {
-                eprintln!("{v}");
-            }
-            Err(_) => {
-                eprintln!("{token_stream:?}");
+    let Ok(filter) = std::env::var("DUCHESS_DUMP").or_else(|_| std::env::var("DUCHESS_DEBUG"))
+    else {
+        return;
+    };
+    let name = name.to_string();
+    if !dump_requested(&filter, &name) {
+        return;
+    }
+
+    let code = match rust_format::PrettyPlease::default().format_tokens(token_stream.clone()) {
+        Ok(v) => v,
+        Err(_) => token_stream.to_string(),
+    };
+
+    match std::env::var_os("DUCHESS_DUMP_DIR") {
+        Some(dir) => {
+            let path = std::path::Path::new(&dir).join(format!("{name}.rs"));
+            if let Err(err) =
+                std::fs::create_dir_all(&dir).and_then(|()| std::fs::write(&path, code))
+            {
+                eprintln!("duchess: unable to write `{}`: {err}", path.display());
             }
         }
+        None => eprintln!("// duchess: generated code for `{name}`\n{code}"),
+    }
+}
+
+fn dump_requested(filter: &str, name: &str) -> bool {
+    filter
+        .split(',')
+        .map(str::trim)
+        .any(|f| f == "*" || f == "1" || (!f.is_empty() && name.starts_with(f)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::dump_requested;
+
+    #[test]
+    fn dump_filter() {
+        assert!(dump_requested("1", "auth.HttpAuth"));
+        assert!(dump_requested("*", "auth.HttpAuth"));
+        assert!(dump_requested("auth", "auth.HttpAuth"));
+        assert!(dump_requested("log.Event, auth.HttpAuth", "auth.HttpAuth"));
+        assert!(!dump_requested("auth.HttpRequest", "auth.HttpAuth"));
+        assert!(!dump_requested("", "auth.HttpAuth"));
     }
 }
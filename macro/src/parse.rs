@@ -1,6 +1,6 @@
 use std::iter::Peekable;
 
-use proc_macro2::{Spacing, Span, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream, TokenTree};
 
 use crate::span_error::SpanError;
 
@@ -94,6 +94,12 @@ impl Parser {
 
     pub fn eat_keyword(&mut self, kw: &str) -> Option<()> {
         assert!(KEYWORDS.contains(&kw));
+        self.eat_contextual_keyword(kw)
+    }
+
+    /// Eats the identifier `kw`, which is only a keyword where the declaration expects it, like `import` at the start
+    /// of a package. Elsewhere it is an ordinary identifier, so that e.g. `package com.example.import;` still parses.
+    pub fn eat_contextual_keyword(&mut self, kw: &str) -> Option<()> {
         self.eat_map(|t| match t {
             TokenTree::Ident(i) => {
                 let s = i.to_string();
@@ -129,14 +135,14 @@ impl Parser {
     }
 }
 
-/// Utility class for accumulating tokens into a string, keeping a combined span
-/// that contains (in theory) all the tokens accumulated. This class is a hack
-/// used to bridge our LALRPOP parser, which operates on strings, with the user's
-/// code. What we should really do is modify the LALRPOP parser to operate on tokens
-/// directly, but that's for later.
+/// Utility class for accumulating tokens into a string, remembering the span of
+/// each token so that positions in the string can be mapped back to the user's code.
+/// This class is a hack used to bridge our LALRPOP parser, which operates on strings,
+/// with the user's code. What we should really do is modify the LALRPOP parser to
+/// operate on tokens directly, but that's for later.
 pub struct TextAccum<'p> {
     text: String,
-    span: Span,
+    spans: SpanMap,
     parser: &'p mut Parser,
 }
 
@@ -144,13 +150,18 @@ impl<'p> TextAccum<'p> {
     pub fn new(parser: &'p mut Parser, t0: TokenTree) -> Self {
         let mut s = Self {
             text: String::new(),
-            span: t0.span(),
+            spans: SpanMap::uniform(t0.span()),
             parser,
         };
         s.accum_token(&t0);
         s
     }
 
+    /// The parser the tokens are taken from, to consume tokens without accumulating them.
+    pub fn parser(&mut self) -> &mut Parser {
+        self.parser
+    }
+
     /// Accumulate next token into the internal buffer and return it.
     pub fn accum(&mut self) -> Option<TokenTree> {
         self.accum_if(|_| true)
@@ -163,8 +174,36 @@ impl<'p> TextAccum<'p> {
         Some(t1)
     }
 
-    fn accum_token(&mut self, token: &TokenTree) {
-        self.text.push_str(&token.to_string());
+    /// Accumulates `token`, which was already consumed from the parser, e.g. to look at the token after it.
+    pub fn accum_token(&mut self, token: &TokenTree) {
+        // Groups are flattened so that the tokens inside of them keep their own spans.
+        if let TokenTree::Group(group) = token {
+            let (open, close) = match group.delimiter() {
+                Delimiter::Parenthesis => ("(", ")"),
+                Delimiter::Brace => ("{", "}"),
+                Delimiter::Bracket => ("[", "]"),
+                Delimiter::None => ("", ""),
+            };
+            self.push(open, group.span_open());
+            for t in group.stream() {
+                self.accum_token(&t);
+            }
+            self.push(close, group.span_close());
+            return;
+        }
+
+        // `$` separates the names of nested classes (`Outer$Inner`), so it is glued to the identifiers around it.
+        if let TokenTree::Punct(p) = token {
+            if p.as_char() == '$' {
+                if self.text.ends_with(' ') {
+                    self.text.pop();
+                }
+                self.push("$", p.span());
+                return;
+            }
+        }
+
+        self.push(&token.to_string(), token.span());
 
         // insert whitespace if this is a token that needs to be separated from following tokens
         match token {
@@ -176,13 +215,53 @@ impl<'p> TextAccum<'p> {
             },
             TokenTree::Literal(_) => (),
         }
+    }
 
-        self.span = self.span.join(token.span()).unwrap_or(self.span);
+    fn push(&mut self, text: &str, span: Span) {
+        if text.is_empty() {
+            return;
+        }
+        self.spans.tokens.push((self.text.len(), span));
+        self.text.push_str(text);
     }
 
-    /// Return the string we accumulated.
-    pub fn into_accumulated_result(self) -> (String, Span) {
-        (self.text, self.span)
+    /// Return the string we accumulated along with the spans of its tokens.
+    pub fn into_accumulated_result(self) -> (String, SpanMap) {
+        (self.text, self.spans)
+    }
+}
+
+/// Maps offsets in text accumulated by a [`TextAccum`] back to the spans of the tokens they came from.
+/// Text that didn't come from tokens (e.g. `javap` output) maps every offset to the same span.
+#[derive(Debug)]
+pub struct SpanMap {
+    span: Span,
+
+    /// Start offset and span of each token, sorted by offset.
+    tokens: Vec<(usize, Span)>,
+}
+
+impl SpanMap {
+    /// A map that assigns `span` to all of the text.
+    pub fn uniform(span: Span) -> Self {
+        SpanMap {
+            span,
+            tokens: vec![],
+        }
+    }
+
+    /// The span for the text as a whole, used when there is nothing more precise to point at.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// The span of the token containing the text at `offset`.
+    pub fn at(&self, offset: usize) -> Span {
+        let index = self.tokens.partition_point(|&(start, _)| start <= offset);
+        match index.checked_sub(1) {
+            Some(i) => self.tokens[i].1,
+            None => self.span,
+        }
     }
 }
 
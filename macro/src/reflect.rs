@@ -1,18 +1,42 @@
-use std::{collections::BTreeMap, env, process::Command, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    ffi::OsString,
+    path::Path,
+    process::{Command, Output},
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use proc_macro2::Span;
+use quote::ToTokens;
 
 use crate::{
-    argument::{DuchessDeclaration, Ident, JavaPackage, MethodSelector},
+    argument::{DuchessDeclaration, FieldSelector, Ident, JavaPackage, JavaPath, MethodSelector},
+    builtin,
     class_info::{
-        ClassDecl, ClassInfo, Constructor, DotId, Generic, Id, Method, RootMap, SpannedPackageInfo,
-        Type,
+        is_generated_member, Annotation, AnnotationValue, Artifact, ClassDecl, ClassInfo,
+        ClassKind, ClassRef, Constructor, DotId, Field, Generic, Id, Method, Privacy, RefType,
+        RootMap, SpannedPackageInfo, Type,
     },
+    classpath,
     span_error::SpanError,
 };
 
 impl DuchessDeclaration {
     pub fn to_root_map(&self, reflector: &mut Reflector) -> Result<RootMap, SpanError> {
+        // Classes reflected from an artifact have to be registered before anything is reflected, since reflecting
+        // other classes can reflect them too, e.g. as supertypes. Likewise, the specified classes are known before
+        // anything refers to them, in any of the packages.
+        let mut specified = vec![];
+        for package in &self.packages {
+            package.use_artifacts(reflector)?;
+            specified.extend(package.specified_classes(reflector)?);
+        }
+        for warning in reflector.seed_specified(specified, Span::call_site())? {
+            eprintln!("duchess: warning: {warning}");
+        }
+
         let mut subpackages = BTreeMap::new();
         let mut classes = BTreeMap::new();
         for package in &self.packages {
@@ -22,11 +46,64 @@ impl DuchessDeclaration {
                 &mut subpackages,
                 &mut classes,
             )?;
+
+            // The visibility applies to the outermost module, e.g. `foo` for `package foo.bar;`, which other
+            // packages may share. A package without one is `pub`, which conflicts with any other visibility too.
+            let first = &package.package_name.ids[0];
+            let (span, visibility) = match &package.visibility {
+                Some(visibility) => (
+                    syn::spanned::Spanned::span(visibility),
+                    visibility.to_token_stream(),
+                ),
+                None => (first.span, quote::quote!(pub)),
+            };
+            let root = subpackages.get_mut(&first.to_id()).unwrap();
+            match &root.visibility {
+                Some(v) if v.to_string() != visibility.to_string() => {
+                    return Err(SpanError {
+                        span,
+                        message: format!(
+                            "conflicting visibility for module `{first}`, which was declared `{v}` before"
+                        ),
+                    });
+                }
+                _ => root.visibility = Some(visibility),
+            }
         }
-        Ok(RootMap {
+
+        // The Rust names of renamed classes replace their Java names in the paths to them.
+        let renamed_classes = classes
+            .iter()
+            .filter_map(|(name, class)| Some((name.clone(), class.rust_name.clone()?)))
+            .collect();
+
+        let mut root_map = RootMap {
             subpackages,
             classes,
-        })
+            renamed_classes,
+            builtin_classes: builtin::classes(),
+            upcasts: BTreeMap::new(),
+            functional_methods: BTreeMap::new(),
+        };
+        reflector.reflect_supertypes(root_map.classes.keys(), Span::call_site())?;
+        root_map.upcasts = root_map
+            .classes
+            .values()
+            .map(|info| {
+                Ok((
+                    info.name.clone(),
+                    root_map.bound_supertypes(info, reflector)?,
+                ))
+            })
+            .collect::<Result<_, SpanError>>()?;
+        for name in root_map.classes.keys() {
+            if let Some(functional_method) = reflector.functional_method(name, Span::call_site())? {
+                root_map
+                    .functional_methods
+                    .insert(name.clone(), functional_method);
+            }
+        }
+        Ok(root_map)
     }
 }
 
@@ -43,6 +120,7 @@ impl JavaPackage {
         let package_info = || SpannedPackageInfo {
             name: first.to_id(),
             span: first.span,
+            visibility: None,
             subpackages: Default::default(),
             classes: Default::default(),
         };
@@ -60,37 +138,225 @@ impl JavaPackage {
         }
     }
 
+    /// Registers the classes to reflect from an artifact, see [`Reflector::use_artifact`].
+    fn use_artifacts(&self, reflector: &mut Reflector) -> Result<(), SpanError> {
+        for c in &self.classes {
+            let (span, name, artifact) = match c {
+                ClassDecl::Reflected(c) => (c.span, &c.name, &c.artifact),
+                ClassDecl::Specified(c) => (c.span, &c.name, &c.artifact),
+            };
+            if let Some(artifact) = artifact {
+                let dot_id = self.make_absolute_dot_id(span, name)?;
+                reflector.use_artifact(&dot_id, artifact)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The classes whose members are given in the declaration, with their fully qualified names. Members marked
+    /// `#[since(N)]` that are newer than the JDK are left out.
+    fn specified_classes(
+        &self,
+        reflector: &mut Reflector,
+    ) -> Result<Vec<Arc<ClassInfo>>, SpanError> {
+        let mut specified = vec![];
+        for c in &self.classes {
+            if let ClassDecl::Specified(c) = c {
+                let dot_id = self.make_absolute_dot_id(c.span, &c.name)?;
+                let mut info = ClassInfo {
+                    name: dot_id,
+                    ..c.clone()
+                };
+
+                let has_since = info.constructors.iter().any(|c| c.since.is_some())
+                    || info.methods.iter().any(|m| m.since.is_some())
+                    || info.fields.iter().any(|f| f.since.is_some());
+                if has_since {
+                    let jdk_version = reflector.jdk_version(c.span)?;
+                    for member in info.drop_newer_members(jdk_version.feature) {
+                        // A pinned version leaves members out on purpose
+                        if !jdk_version.pinned {
+                            eprintln!(
+                                "duchess: warning: skipping {member} of `{}`, which is not available in JDK {}",
+                                info.name, jdk_version.feature
+                            );
+                        }
+                    }
+                }
+                specified.push(Arc::new(info));
+            }
+        }
+        Ok(specified)
+    }
+
     fn insert_classes_into_root_map(
         &self,
         reflector: &mut Reflector,
         package: &mut SpannedPackageInfo,
         classes: &mut BTreeMap<DotId, Arc<ClassInfo>>,
     ) -> Result<(), SpanError> {
+        if let Some(span) = self.wildcard {
+            self.insert_all_classes_into_root_map(span, reflector, package, classes)?;
+        }
+
+        // The members of `class Foo { * }` come from reflection, and so do their annotations if they are asked for.
+        let mut annotated = vec![];
+        let mut reflected = vec![];
+        for c in &self.classes {
+            if let ClassDecl::Reflected(c) = c {
+                let dot_id = self.make_absolute_dot_id(c.span, &c.name)?;
+                if c.optional && !reflector.probe(&dot_id, c.span)? {
+                    continue;
+                }
+                if c.annotations || c.builder {
+                    annotated.push(dot_id);
+                } else {
+                    reflected.push(dot_id);
+                }
+            }
+        }
+        reflector.reflect_annotations(&annotated, self.package_name.span)?;
+        reflector.reflect_bridges(&reflected, self.package_name.span)?;
+
         for c in &self.classes {
             let (dot_id, info) = match c {
                 ClassDecl::Reflected(c) => {
                     let dot_id = self.make_absolute_dot_id(c.span, &c.name)?;
+                    if c.optional && !reflector.probe(&dot_id, c.span)? {
+                        continue;
+                    }
                     let info = reflector.reflect(&dot_id, c.span)?;
-                    (dot_id, info.clone())
+                    let mut info = info.clone();
+                    if !c.cfgs.is_empty() {
+                        Arc::make_mut(&mut info).cfgs = c.cfgs.clone();
+                    }
+                    if c.builder {
+                        Arc::make_mut(&mut info).builder = true;
+                    }
+                    if c.rust_name.is_some() {
+                        Arc::make_mut(&mut info).rust_name = c.rust_name.clone();
+                    }
+                    (dot_id, info)
                 }
                 ClassDecl::Specified(c) => {
+                    // Seeded by `to_root_map`, so this is the declaration
                     let dot_id = self.make_absolute_dot_id(c.span, &c.name)?;
-                    (
-                        dot_id.clone(),
-                        Arc::new(ClassInfo {
-                            name: dot_id,
-                            ..c.clone()
-                        }),
-                    )
+                    let info = reflector.reflect(&dot_id, c.span)?.clone();
+                    (dot_id, info)
                 }
             };
 
+            // A class bound as `class Foo as JFoo` can't take the Rust name of another class in the package.
+            let rust_name = info.rust_name.as_ref().unwrap_or(dot_id.class_name());
+            let collision = package.classes.iter().find(|other| {
+                let other_name = classes[*other].rust_name.as_ref();
+                **other != dot_id && other_name.unwrap_or(other.class_name()) == rust_name
+            });
+            if let Some(other) = collision {
+                let span = match c {
+                    ClassDecl::Reflected(c) => c.span,
+                    ClassDecl::Specified(c) => c.span,
+                };
+                return Err(SpanError {
+                    span,
+                    message: format!(
+                        "`{dot_id}` and `{other}` would both be bound as `{rust_name}`, rename one of them with `as`"
+                    ),
+                });
+            }
+
             package.classes.push(dot_id.clone());
             classes.insert(dot_id, info);
         }
         Ok(())
     }
 
+    /// Handles `package foo.bar.*;` by binding every public class in the package that isn't declared explicitly.
+    fn insert_all_classes_into_root_map(
+        &self,
+        span: Span,
+        reflector: &mut Reflector,
+        package: &mut SpannedPackageInfo,
+        classes: &mut BTreeMap<DotId, Arc<ClassInfo>>,
+    ) -> Result<(), SpanError> {
+        let package_ids: Vec<Id> = self.package_name.ids.iter().map(|n| n.to_id()).collect();
+        let declared: Vec<DotId> = self
+            .classes
+            .iter()
+            .map(|c| match c {
+                ClassDecl::Reflected(c) => self.make_absolute_dot_id(c.span, &c.name),
+                ClassDecl::Specified(c) => self.make_absolute_dot_id(c.span, &c.name),
+            })
+            .collect::<Result<_, _>>()?;
+
+        let found = classpath::classes_in_package(&classpath(), &package_ids);
+        if found.is_empty() {
+            return Err(SpanError {
+                span,
+                message: format!("no classes found in package `{}`", self.package_name),
+            });
+        }
+
+        // Check the exclusions against the classpath, so that a typo doesn't silently bind the class anyway
+        let mut excluded = vec![];
+        for class in &self.excluded {
+            let dot_id = DotId::new(&package_ids, &class.to_id());
+            if !found.contains(&dot_id) {
+                return Err(SpanError {
+                    span: class.span,
+                    message: format!("no class `{}` in package `{}`", class, self.package_name),
+                });
+            }
+            excluded.push(dot_id);
+        }
+
+        // Excluded classes aren't reflected at all, so they don't cause warnings if they fail to parse
+        let found: Vec<DotId> = found
+            .into_iter()
+            .filter(|dot_id| !excluded.contains(dot_id))
+            .collect();
+        for warning in reflector.reflect_all(&found, span)? {
+            eprintln!("duchess: warning: {warning}");
+        }
+
+        let bound: Vec<DotId> = found
+            .into_iter()
+            .filter(|dot_id| !declared.contains(dot_id))
+            .filter(|dot_id| {
+                reflector
+                    .cached(dot_id)
+                    .is_some_and(|info| info.flags.privacy == Privacy::Public)
+            })
+            .collect();
+        reflector.reflect_bridges(&bound, span)?;
+
+        let members: usize = bound
+            .iter()
+            .map(|dot_id| reflector.cached(dot_id).unwrap().member_count())
+            .sum();
+        if let Some(warning) =
+            large_package_warning(self.keyword(), &self.package_name, bound.len(), members)
+        {
+            eprintln!("duchess: warning: {warning}");
+        }
+
+        for dot_id in bound {
+            let info = reflector.cached(&dot_id).unwrap();
+            package.classes.push(dot_id.clone());
+            classes.insert(dot_id, info.clone());
+        }
+        Ok(())
+    }
+
+    /// The keyword the package was declared with, `package` or `import`.
+    fn keyword(&self) -> &'static str {
+        if self.is_import {
+            "import"
+        } else {
+            "package"
+        }
+    }
+
     /// The users give classnames that may not include java package information.
     fn make_absolute_dot_id(&self, span: Span, class_dot_id: &DotId) -> Result<DotId, SpanError> {
         let package_ids: Vec<Id> = self.package_name.ids.iter().map(|n| n.to_id()).collect();
@@ -117,39 +383,168 @@ impl JavaPackage {
     }
 }
 
+/// Binding more members than this with a wildcard package prints a warning, since it generates a lot of code that
+/// slows down the build.
+const LARGE_PACKAGE_MEMBERS: usize = 2000;
+
+fn large_package_warning(
+    keyword: &str,
+    package: &JavaPath,
+    classes: usize,
+    members: usize,
+) -> Option<String> {
+    (members > LARGE_PACKAGE_MEMBERS).then(|| {
+        format!(
+            "`{keyword} {package}.*;` binds {classes} classes with {members} members, which generates a lot of code; \
+             consider listing the classes that are used, or leaving some out with `except`"
+        )
+    })
+}
+
 /// Reflection cache. Given fully qualified java class names,
 /// look up info about their interfaces.
 #[derive(Default)]
 pub struct Reflector {
     classes: BTreeMap<DotId, Arc<ClassInfo>>,
+    /// Classes whose members were given in the declaration, see [`Reflector::seed_specified`].
+    specified: BTreeMap<DotId, Arc<ClassInfo>>,
+    missing: BTreeSet<DotId>,
+    annotated: BTreeSet<DotId>,
+    /// Classes whose bridge methods were removed without adding their annotations, see [`Reflector::reflect_bridges`].
+    bridges_dropped: BTreeSet<DotId>,
+    /// Classes to reflect from a specific artifact rather than the classpath, see [`Reflector::use_artifact`].
+    artifacts: BTreeMap<DotId, Artifact>,
+    /// The JDK version, once it is known, see [`Reflector::jdk_version`].
+    jdk_version: Option<JdkVersion>,
+    stats: ReflectorStats,
+}
+
+/// The JDK version that members marked `#[since(N)]` are compared against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JdkVersion {
+    /// The feature version, e.g. 8 for `1.8.0_392` or 17 for `17.0.8`.
+    pub feature: u32,
+
+    /// True if the version was pinned with `DUCHESS_JDK_VERSION` rather than detected.
+    pub pinned: bool,
+}
+
+/// Counters describing the work a [`Reflector`] did, for diagnosing slow builds.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReflectorStats {
+    /// Number of classes whose info was obtained from `javap`.
+    pub classes_reflected: usize,
+
+    /// Number of requested classes that were already cached.
+    pub cache_hits: usize,
+
+    /// Number of requested classes that were not cached and had to be passed to `javap`.
+    pub cache_misses: usize,
+
+    /// Number of times `javap` was run.
+    pub javap_invocations: usize,
+
+    /// Total time spent waiting for `javap`.
+    pub javap_time: Duration,
+}
+
+impl std::fmt::Display for ReflectorStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} classes reflected, {} cache hits, {} cache misses, {} javap invocations taking {:.2?}",
+            self.classes_reflected,
+            self.cache_hits,
+            self.cache_misses,
+            self.javap_invocations,
+            self.javap_time,
+        )
+    }
 }
 
 impl Reflector {
-    /// Returns the (potentially cached) info about `class_name`;
+    /// Registers the classes whose members are specified in the declaration being processed, so that looking them
+    /// up resolves to the declaration rather than running `javap`, which may not even find them: a specified class
+    /// doesn't have to be on the classpath at build time.
+    ///
+    /// The classes that are on the classpath are still reflected, all with a single `javap` invocation, so that the
+    /// declarations can be [checked](Self::reflect_actual) against them. A warning is returned for each of the others,
+    /// since a class that is missing by mistake, e.g. because of a typo in its name, would otherwise go unnoticed.
+    pub fn seed_specified(
+        &mut self,
+        class_infos: impl IntoIterator<Item = Arc<ClassInfo>>,
+        span: Span,
+    ) -> Result<Vec<String>, SpanError> {
+        let mut names = vec![];
+        for info in class_infos {
+            names.push(info.name.clone());
+            self.specified.insert(info.name.clone(), info);
+        }
+        if names.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Classes that can't be found or parsed are taken as declared, with a warning of our own rather than the ones
+        // of `javap`.
+        self.reflect_all(&names, span)?;
+        let mut warnings = vec![];
+        for name in names {
+            if !self.classes.contains_key(&name) {
+                warnings.push(format!(
+                    "class `{name}` can't be reflected from the classpath, so its declaration is used without checking it"
+                ));
+                self.missing.insert(name);
+            }
+        }
+        Ok(warnings)
+    }
+
+    /// Returns the (potentially cached) info about `class_name`. For a class specified in the declaration, this is
+    /// the declaration, see [`seed_specified`](Self::seed_specified).
     pub fn reflect(
         &mut self,
         class_name: &DotId,
         span: Span,
     ) -> Result<&Arc<ClassInfo>, SpanError> {
-        // yields an error if we cannot reflect on that class.
-        if self.classes.contains_key(class_name) {
-            return Ok(&self.classes[class_name]);
+        if self.specified.contains_key(class_name) {
+            self.stats.cache_hits += 1;
+            return Ok(&self.specified[class_name]);
         }
+        self.reflect_classpath(class_name, span)
+    }
 
-        let mut command = Command::new("javap");
+    /// Returns the info about `class_name` as it is on the classpath, even if it was specified in the declaration,
+    /// falling back to the declaration only if the class isn't on the classpath. This is what declarations are checked
+    /// against.
+    pub fn reflect_actual(
+        &mut self,
+        class_name: &DotId,
+        span: Span,
+    ) -> Result<&Arc<ClassInfo>, SpanError> {
+        if self.specified.contains_key(class_name) && self.missing.contains(class_name) {
+            self.stats.cache_hits += 1;
+            return Ok(&self.specified[class_name]);
+        }
+        self.reflect_classpath(class_name, span)
+    }
 
-        let classpath = match env::var("CLASSPATH") {
-            Ok(val) => val,
-            Err(e) => panic!("duchess cannot read the CLASSPATH environment variable: {e}"),
-        };
+    /// Returns the (potentially cached) info about `class_name` from `javap`, yielding an error if it can't be
+    /// reflected.
+    fn reflect_classpath(
+        &mut self,
+        class_name: &DotId,
+        span: Span,
+    ) -> Result<&Arc<ClassInfo>, SpanError> {
+        if self.classes.contains_key(class_name) {
+            self.stats.cache_hits += 1;
+            return Ok(&self.classes[class_name]);
+        }
+        self.stats.cache_misses += 1;
 
-        command
-            .arg("-cp")
-            .arg(classpath)
-            .arg("-public")
-            .arg(format!("{}", class_name));
+        let mut command = javap_command(span)?;
+        command.arg(self.javap_class_arg(class_name));
 
-        let output_or_err = command.output();
+        let output_or_err = self.run_javap(&mut command);
 
         let output = match output_or_err {
             Ok(o) => o,
@@ -162,6 +557,19 @@ impl Reflector {
         };
 
         if !output.status.success() {
+            if is_class_not_found(&String::from_utf8_lossy(&output.stderr)) {
+                self.missing.insert(class_name.clone());
+            }
+            if let Some(artifact) = self.artifacts.get(class_name) {
+                return Err(SpanError {
+                    span,
+                    message: format!(
+                        "class `{class_name}` not found in `{}`: {}",
+                        artifact.path.display(),
+                        String::from_utf8_lossy(&output.stderr).trim()
+                    ),
+                });
+            }
             return Err(SpanError {
                 span,
                 message: format!(
@@ -181,93 +589,1909 @@ impl Reflector {
             }
         };
 
-        let mut ci = ClassInfo::parse(&s, span)?;
-
-        // reset the span for the cached data to the call site so that when others look it up,
-        // they get the same span.
-        ci.span = Span::call_site();
+        // Parse with the call site as the span of the class and its members so that when others look up the cached
+        // data, they get the same span; only errors point at the class being reflected.
+        let ci = ClassInfo::parse(&s, Span::call_site()).map_err(|e| SpanError { span, ..e })?;
+        self.stats.classes_reflected += 1;
         Ok(self
             .classes
             .entry(class_name.clone())
             .or_insert(Arc::new(ci)))
     }
 
+    /// Returns all supertypes of `class_name`, direct and indirect, following both superclasses and interfaces. The
+    /// generic arguments of each supertype are expressed in terms of the type parameters of `class_name`, so for
+    /// `java.util.ArrayList<E>` this includes `java.util.Collection<E>`. A supertype reached through a raw reference
+    /// has its own supertypes erased too.
     ///
-    pub fn reflect_method(
+    /// Like the check of declared supertypes that this is for, this walks the classes as they are on the classpath,
+    /// see [`reflect_actual`](Self::reflect_actual).
+    pub fn supertypes(
         &mut self,
-        method_selector: &MethodSelector,
-    ) -> Result<ReflectedMethod<'_>, SpanError> {
-        match method_selector {
-            MethodSelector::ClassName(cn) => {
-                let dot_id = cn.to_dot_id();
-                let class_info = self.reflect(&dot_id, cn.span)?;
-                match class_info.constructors.len() {
-                    1 => Ok(ReflectedMethod::Constructor(class_info, &class_info.constructors[0])),
-                    0 => Err(SpanError { span: cn.span, message: format!("no constructors found") }),
-                    n => Err(SpanError { span: cn.span, message: format!("{n} constructors found, use an explicit class declaration to disambiguate") }),
+        class_name: &DotId,
+        span: Span,
+    ) -> Result<Vec<ClassRef>, SpanError> {
+        let info = self.reflect_actual(class_name, span)?.clone();
+        let mut pending: Vec<ClassRef> = info
+            .extends
+            .iter()
+            .chain(&info.implements)
+            .cloned()
+            .collect();
+        let mut supertypes: Vec<ClassRef> = vec![];
+        while let Some(cref) = pending.pop() {
+            if supertypes.contains(&cref) {
+                continue;
+            }
+
+            let info = self.reflect_actual(&cref.name, span)?.clone();
+            let raw = cref.generics.len() != info.generics.len();
+            let args: Vec<(Id, RefType)> = info
+                .generics
+                .iter()
+                .map(|g| g.id.clone())
+                .zip(cref.generics.iter().cloned())
+                .collect();
+            for parent in info.extends.iter().chain(&info.implements) {
+                if raw {
+                    pending.push(ClassRef {
+                        name: parent.name.clone(),
+                        generics: vec![],
+                    });
+                } else {
+                    pending.push(parent.substitute(&args));
                 }
             }
-            MethodSelector::MethodName(cn, mn) => {
-                let dot_id = cn.to_dot_id();
-                let class_info = self.reflect(&dot_id, cn.span)?;
-                let methods: Vec<&Method> = class_info
-                    .methods
+            supertypes.push(cref);
+        }
+        Ok(supertypes)
+    }
+
+    /// If `class_name` is a functional interface, returns its abstract method, see [`ClassInfo::functional_method`],
+    /// and the name of the method in the extension trait of the interface that declares it. The types of an inherited
+    /// method are substituted with the generic arguments it is inherited with, so they are in terms of the generic
+    /// parameters of `class_name`; its descriptor stays that of the declaration. Like
+    /// [`supertypes`](Self::supertypes), this looks at the interfaces as they are on the classpath, so the methods that
+    /// aren't bound count too.
+    pub fn functional_method(
+        &mut self,
+        class_name: &DotId,
+        span: Span,
+    ) -> Result<Option<(Method, Id)>, SpanError> {
+        let info = self.reflect_actual(class_name, span)?.clone();
+        if info.kind != ClassKind::Interface {
+            return Ok(None);
+        }
+
+        let mut supertypes = vec![];
+        for cref in self.supertypes(class_name, span)? {
+            supertypes.push((self.reflect_actual(&cref.name, span)?.clone(), cref));
+        }
+        let supertypes: Vec<(&ClassInfo, &ClassRef)> = supertypes
+            .iter()
+            .map(|(info, cref)| (&**info, cref))
+            .collect();
+        Ok(info
+            .functional_method(&supertypes)
+            .map(|(declaring, method)| {
+                let rust_name = declaring.rust_names().method(&method.name).clone();
+                let args = supertypes
                     .iter()
-                    .filter(|m| &m.name[..] == &mn.text[..])
-                    .collect();
-                match methods.len() {
-                    1 => Ok(ReflectedMethod::Method(class_info, &methods[0])),
-                    0 => Err(SpanError { span: cn.span, message: format!("no methods named `{mn}` found") }),
-                    n => Err(SpanError { span: cn.span, message: format!("{n} methods named `{mn}` found, use an explicit class declaration to disambiguate") }),
+                    .find(|(info, _)| info.name == declaring.name)
+                    .map_or(vec![], |(info, cref)| info.generic_args(cref));
+                let method = Method {
+                    jni_descriptor: Some(method.descriptor()),
+                    argument_tys: method
+                        .argument_tys
+                        .iter()
+                        .map(|t| t.substitute(&args))
+                        .collect(),
+                    return_ty: method.return_ty.as_ref().map(|t| t.substitute(&args)),
+                    ..method.clone()
+                };
+                (method, rust_name)
+            }))
+    }
+
+    /// Reflects the supertypes of `class_names`, direct and indirect, with one `javap` invocation for each level of the
+    /// hierarchy rather than one for each class, so that [`supertypes`](Self::supertypes) finds them in the cache.
+    pub fn reflect_supertypes<'a>(
+        &mut self,
+        class_names: impl IntoIterator<Item = &'a DotId>,
+        span: Span,
+    ) -> Result<(), SpanError> {
+        let mut pending: Vec<DotId> = class_names.into_iter().cloned().collect();
+        let mut seen: BTreeSet<DotId> = pending.iter().cloned().collect();
+        while !pending.is_empty() {
+            // A class that can't be found is reported by `supertypes`, which knows which class it is a supertype of.
+            let on_classpath: Vec<DotId> = pending
+                .iter()
+                .filter(|c| !self.missing.contains(c))
+                .cloned()
+                .collect();
+            self.reflect_all(&on_classpath, span)?;
+
+            let mut parents = vec![];
+            for name in &pending {
+                let Some(info) = self.classes.get(name).or_else(|| self.specified.get(name)) else {
+                    continue;
+                };
+                for parent in info.extends.iter().chain(&info.implements) {
+                    if seen.insert(parent.name.clone()) {
+                        parents.push(parent.name.clone());
+                    }
                 }
             }
-            MethodSelector::ClassInfo(_) => todo!(),
+            pending = parents;
         }
+        Ok(())
     }
-}
-
-/// Reflection on something callable.
-#[derive(Copy, Clone, Debug)]
-pub enum ReflectedMethod<'i> {
-    Constructor(&'i ClassInfo, &'i Constructor),
-    Method(&'i ClassInfo, &'i Method),
-}
 
-impl ReflectedMethod<'_> {
-    /// The name of this callable thing in Rust
-    pub fn name(&self) -> Id {
-        match self {
-            ReflectedMethod::Constructor(..) => Id::from("new"),
-            ReflectedMethod::Method(_, m) => m.name.clone(),
+    /// Returns whether `class_name` can be reflected from the classpath. This is used to skip optional classes that
+    /// are not on the classpath. Only a class that `javap` reports as not found is absent; any other error, like a
+    /// class file that fails to parse, is returned.
+    ///
+    /// Both outcomes are cached: a later [`reflect`](Self::reflect) of a class that was found is a cache hit, and
+    /// probing a missing class again doesn't run `javap` again.
+    pub fn probe(&mut self, class_name: &DotId, span: Span) -> Result<bool, SpanError> {
+        if self.missing.contains(class_name) {
+            self.stats.cache_hits += 1;
+            return Ok(false);
         }
+        let Err(err) = self.reflect_classpath(class_name, span) else {
+            return Ok(true);
+        };
+        // `reflect_classpath` records the classes that `javap` can't find
+        if self.missing.contains(class_name) {
+            return Ok(false);
+        }
+        Err(err)
     }
 
-    pub fn class(&self) -> &ClassInfo {
-        match self {
-            ReflectedMethod::Constructor(c, _) => c,
-            ReflectedMethod::Method(c, _) => c,
+    /// Reflects all of `class_names` with a single `javap` invocation, populating the cache. This is much faster than
+    /// reflecting them one at a time when binding a large number of classes.
+    ///
+    /// Classes that are already cached are not reflected again. Classes that `javap` can't find or whose output fails
+    /// to parse are skipped rather than aborting the whole batch; a warning is returned for each of them.
+    pub fn reflect_all(
+        &mut self,
+        class_names: &[DotId],
+        span: Span,
+    ) -> Result<Vec<String>, SpanError> {
+        let uncached: Vec<&DotId> = class_names
+            .iter()
+            .filter(|c| !self.classes.contains_key(c))
+            .collect();
+        self.stats.cache_hits += class_names.len() - uncached.len();
+        self.stats.cache_misses += uncached.len();
+        if uncached.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut command = javap_command(span)?;
+        command.args(uncached.iter().map(|c| self.javap_class_arg(c)));
+
+        let output = match self.run_javap(&mut command) {
+            Ok(o) => o,
+            Err(err) => {
+                return Err(SpanError {
+                    span,
+                    message: format!("failed to execute `{command:?}`: {err}"),
+                });
+            }
+        };
+
+        // `javap` keeps going when some of the classes can't be found, so a failure status only means that at least
+        // one of them is missing.
+        let mut warnings: Vec<String> = String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.to_string())
+            .collect();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (infos, parse_warnings) = parse_javap_output(&stdout);
+        warnings.extend(parse_warnings);
+
+        for mut ci in infos {
+            ci.span = Span::call_site();
+            self.stats.classes_reflected += 1;
+            self.classes.entry(ci.name.clone()).or_insert(Arc::new(ci));
         }
+
+        Ok(warnings)
     }
 
-    /// Is this something that is called on a *type*?
-    pub fn is_static(&self) -> bool {
-        match self {
-            ReflectedMethod::Constructor(..) => true,
-            ReflectedMethod::Method(_, m) => m.flags.is_static,
+    /// Adds the runtime-visible annotations of `class_names` and their constructors and methods to the cached class
+    /// info, reflecting the classes first if necessary.
+    ///
+    /// Annotations are only printed by `javap -v`, whose output is much larger (it includes the bytecode), so this is
+    /// a separate step that is only done for the classes that ask for it.
+    pub fn reflect_annotations(
+        &mut self,
+        class_names: &[DotId],
+        span: Span,
+    ) -> Result<(), SpanError> {
+        self.reflect_verbose(class_names, true, span)
+    }
+
+    /// Removes the bridge methods of `class_names` that [`drop_bridge_methods`](crate::class_info::drop_bridge_methods)
+    /// can't recognize without their flags. Only the classes that [may have such bridges](ClassInfo::may_have_bridges)
+    /// are run through `javap -v`.
+    pub fn reflect_bridges(&mut self, class_names: &[DotId], span: Span) -> Result<(), SpanError> {
+        let mut candidates = vec![];
+        for class_name in class_names {
+            if self.reflect(class_name, span)?.may_have_bridges() {
+                candidates.push(class_name.clone());
+            }
         }
+        self.reflect_verbose(&candidates, false, span)
     }
 
-    pub fn generics(&self) -> &Vec<Generic> {
-        match self {
-            ReflectedMethod::Constructor(_, c) => &c.generics,
-            ReflectedMethod::Method(_, m) => &m.generics,
+    /// Applies the output of `javap -v` for `class_names`, adding the annotations only if `annotations` is set.
+    fn reflect_verbose(
+        &mut self,
+        class_names: &[DotId],
+        annotations: bool,
+        span: Span,
+    ) -> Result<(), SpanError> {
+        let mut pending = vec![];
+        for class_name in class_names {
+            let done = self.annotated.contains(class_name)
+                || (!annotations && self.bridges_dropped.contains(class_name));
+            if done || pending.contains(&class_name) {
+                continue;
+            }
+            self.reflect(class_name, span)?;
+            pending.push(class_name);
         }
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut command = javap_command(span)?;
+        command.arg("-v");
+        command.args(pending.iter().map(|c| self.javap_class_arg(c)));
+
+        let output = match self.run_javap(&mut command) {
+            Ok(o) if o.status.success() => o,
+            Ok(o) => {
+                return Err(SpanError {
+                    span,
+                    message: format!(
+                        "unsuccessful execution of `{command:?}`: {}",
+                        String::from_utf8_lossy(&o.stderr)
+                    ),
+                });
+            }
+            Err(err) => {
+                return Err(SpanError {
+                    span,
+                    message: format!("failed to execute `{command:?}`: {err}"),
+                });
+            }
+        };
+
+        for class in parse_javap_annotations(&String::from_utf8_lossy(&output.stdout)) {
+            if let Some(ci) = self.classes.get_mut(&class.name) {
+                class
+                    .apply(Arc::make_mut(ci), annotations)
+                    .map_err(|message| SpanError { span, message })?;
+            }
+        }
+        let done = if annotations {
+            &mut self.annotated
+        } else {
+            &mut self.bridges_dropped
+        };
+        done.extend(pending.into_iter().cloned());
+        Ok(())
     }
 
-    pub fn argument_tys(&self) -> &Vec<Type> {
-        match self {
-            ReflectedMethod::Constructor(_, c) => &c.argument_tys,
-            ReflectedMethod::Method(_, m) => &m.argument_tys,
+    /// Reflects `class_name` from `artifact`, a `.jar` or `.jmod` file, rather than from the classpath. The artifact
+    /// only needs to contain the class itself; its supertypes and the other classes it mentions are still reflected
+    /// from the classpath.
+    pub fn use_artifact(
+        &mut self,
+        class_name: &DotId,
+        artifact: &Artifact,
+    ) -> Result<(), SpanError> {
+        if !artifact.path.is_file() {
+            return Err(SpanError {
+                span: artifact.span,
+                message: format!("`{}` does not exist", artifact.path.display()),
+            });
         }
+        if let Some(previous) = self.artifacts.get(class_name) {
+            if previous.path != artifact.path {
+                return Err(SpanError {
+                    span: artifact.span,
+                    message: format!(
+                        "class `{class_name}` is already reflected from `{}`",
+                        previous.path.display()
+                    ),
+                });
+            }
+            return Ok(());
+        }
+
+        // Anything cached so far came from the classpath
+        self.classes.remove(class_name);
+        self.missing.remove(class_name);
+        self.annotated.remove(class_name);
+        self.bridges_dropped.remove(class_name);
+        self.artifacts.insert(class_name.clone(), artifact.clone());
+        Ok(())
+    }
+
+    /// The argument naming `class_name` on the `javap` command line: the class name itself, or a `jar:` URL of the
+    /// class file if it is reflected from an artifact, which `javap` reads without involving the classpath.
+    fn javap_class_arg(&self, class_name: &DotId) -> String {
+        let Some(artifact) = self.artifacts.get(class_name) else {
+            return class_name.to_string();
+        };
+        let prefix = if artifact.is_jmod() { "classes/" } else { "" };
+        format!(
+            "jar:file:{}!/{prefix}{}.class",
+            artifact.path.display(),
+            class_name.to_jni_name()
+        )
+    }
+
+    /// Runs `command`, a `javap` invocation, recording it in the stats.
+    fn run_javap(&mut self, command: &mut Command) -> std::io::Result<Output> {
+        let start = Instant::now();
+        let output = command.output();
+        self.stats.javap_invocations += 1;
+        self.stats.javap_time += start.elapsed();
+        output
+    }
+
+    /// Returns the counters describing the reflection done so far.
+    pub fn stats(&self) -> &ReflectorStats {
+        &self.stats
+    }
+
+    /// Prints the stats to stderr if the `DUCHESS_REFLECT_STATS` environment variable is set. Called at the end of a
+    /// macro expansion, with `what` describing it (e.g. the package or struct being generated).
+    pub fn report_stats(&self, what: impl std::fmt::Display) {
+        if env::var_os("DUCHESS_REFLECT_STATS").is_some() {
+            eprintln!("duchess: reflecting for `{what}`: {}", self.stats());
+        }
+    }
+
+    /// Returns the version of the JDK, which is the version of the `javap` that reflects the classes, unless it is
+    /// pinned with the `DUCHESS_JDK_VERSION` environment variable (e.g. `DUCHESS_JDK_VERSION=11`) so that the bindings
+    /// are the same whichever JDK builds them.
+    pub fn jdk_version(&mut self, span: Span) -> Result<JdkVersion, SpanError> {
+        if let Some(version) = self.jdk_version {
+            return Ok(version);
+        }
+
+        let version = match env::var("DUCHESS_JDK_VERSION") {
+            Ok(pinned) if !pinned.is_empty() => {
+                let Some(feature) = parse_jdk_version(&pinned) else {
+                    return Err(SpanError {
+                        span,
+                        message: format!(
+                            "`DUCHESS_JDK_VERSION` is set to `{pinned}`, which is not a JDK version"
+                        ),
+                    });
+                };
+                JdkVersion {
+                    feature,
+                    pinned: true,
+                }
+            }
+            _ => {
+                let javap = javap_program(env::var_os("DUCHESS_JAVAP"))
+                    .map_err(|message| SpanError { span, message })?;
+                let mut command = Command::new(javap);
+                command.arg("-version");
+                let output = self.run_javap(&mut command).map_err(|err| SpanError {
+                    span,
+                    message: format!("failed to execute `{command:?}`: {err}"),
+                })?;
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let Some(feature) = parse_jdk_version(&stdout) else {
+                    return Err(SpanError {
+                        span,
+                        message: format!(
+                            "unable to tell the JDK version from the output of `{command:?}`: `{}`",
+                            stdout.trim()
+                        ),
+                    });
+                };
+                JdkVersion {
+                    feature,
+                    pinned: false,
+                }
+            }
+        };
+        self.jdk_version = Some(version);
+        Ok(version)
+    }
+
+    /// Returns the cached info about `class_name`, if it has been reflected.
+    pub fn cached(&self, class_name: &DotId) -> Option<&Arc<ClassInfo>> {
+        self.classes.get(class_name)
+    }
+
+    /// Finds the constructor or method selected by `method_selector`, which must be unique. If `arity` is given, only
+    /// constructors and methods with that many arguments are considered, which is enough to tell apart most overloads
+    /// (e.g. when the caller knows how many arguments it will pass).
+    pub fn reflect_method(
+        &mut self,
+        method_selector: &MethodSelector,
+        arity: Option<usize>,
+    ) -> Result<ReflectedMethod<'_>, SpanError> {
+        let has_arity = |argument_tys: &[Type]| arity.is_none_or(|n| argument_tys.len() == n);
+        let with_arity = match arity {
+            Some(1) => " with 1 argument".to_string(),
+            Some(n) => format!(" with {n} arguments"),
+            None => String::new(),
+        };
+        match method_selector {
+            MethodSelector::ClassName(cn) => {
+                let dot_id = cn.to_dot_id();
+                let class_info = self.reflect(&dot_id, cn.span)?;
+                let constructors: Vec<&Constructor> = class_info
+                    .constructors
+                    .iter()
+                    .filter(|c| has_arity(&c.argument_tys))
+                    .collect();
+                match constructors.len() {
+                    1 => Ok(ReflectedMethod::Constructor(class_info, constructors[0])),
+                    0 if arity.is_some() && !class_info.constructors.is_empty() => Err(SpanError {
+                        span: cn.span,
+                        message: format!(
+                            "no constructors{with_arity} found, the constructors are:{}",
+                            candidate_list(class_info.constructors.iter().map(|c| constructor_decl(class_info, c))),
+                        ),
+                    }),
+                    0 => Err(SpanError { span: cn.span, message: format!("no constructors found") }),
+                    n => Err(SpanError {
+                        span: cn.span,
+                        message: format!(
+                            "{n} constructors{with_arity} found, use an explicit class declaration to disambiguate:{}",
+                            candidate_list(constructors.iter().map(|c| constructor_decl(class_info, c))),
+                        ),
+                    }),
+                }
+            }
+            MethodSelector::MethodName(cn, mn) => {
+                let dot_id = cn.to_dot_id();
+                let class_info = self.reflect(&dot_id, cn.span)?;
+                let named: Vec<&Method> = class_info
+                    .methods
+                    .iter()
+                    .filter(|m| &m.name[..] == &mn.text[..])
+                    .collect();
+                let methods: Vec<&Method> = named
+                    .iter()
+                    .copied()
+                    .filter(|m| has_arity(&m.argument_tys))
+                    .collect();
+                match methods.len() {
+                    1 => Ok(ReflectedMethod::Method(class_info, &methods[0])),
+                    0 if !named.is_empty() => Err(SpanError {
+                        span: cn.span,
+                        message: format!(
+                            "no methods named `{mn}`{with_arity} found, the methods named `{mn}` are:{}",
+                            candidate_list(named.iter().map(|m| method_decl(m))),
+                        ),
+                    }),
+                    0 => Err(SpanError { span: cn.span, message: format!("no methods named `{mn}` found") }),
+                    n => Err(SpanError {
+                        span: cn.span,
+                        message: format!(
+                            "{n} methods named `{mn}`{with_arity} found, use an explicit class declaration to disambiguate:{}",
+                            candidate_list(methods.iter().map(|m| method_decl(m))),
+                        ),
+                    }),
+                }
+            }
+            MethodSelector::ClassInfo(_) => todo!(),
+        }
+    }
+
+    /// Finds the field selected by `field_selector`. Fields can't be overloaded, so there is at most one. Declared
+    /// fields are checked with this, so it looks at the class as it is on the classpath, see
+    /// [`reflect_actual`](Self::reflect_actual).
+    pub fn reflect_field(
+        &mut self,
+        field_selector: &FieldSelector,
+    ) -> Result<ReflectedField<'_>, SpanError> {
+        let FieldSelector {
+            class_name,
+            field_name,
+        } = field_selector;
+        let dot_id = class_name.to_dot_id();
+        let class_info = self.reflect_actual(&dot_id, class_name.span)?;
+        match class_info
+            .fields
+            .iter()
+            .find(|f| f.name[..] == field_name.text[..])
+        {
+            Some(field) => Ok(ReflectedField {
+                class: class_info,
+                field,
+            }),
+            None => Err(SpanError {
+                span: field_name.span,
+                message: format!("no field named `{field_name}` found in `{class_name}`"),
+            }),
+        }
+    }
+}
+
+/// Formats the candidates of an ambiguous selection, one per line, so that one can be copied into a class declaration.
+fn candidate_list(decls: impl Iterator<Item = String>) -> String {
+    decls.map(|decl| format!("\n    {decl};")).collect()
+}
+
+/// Formats a constructor the way `javap` prints it, e.g. `public java.util.ArrayList(int)`.
+fn constructor_decl(class_info: &ClassInfo, constructor: &Constructor) -> String {
+    format!(
+        "{}{}{}({}){}",
+        privacy_prefix(&constructor.flags.privacy),
+        generics_prefix(&constructor.generics),
+        class_info.name,
+        comma_separated(&constructor.argument_tys),
+        throws_suffix(&constructor.throws),
+    )
+}
+
+/// Formats a method the way `javap` prints it, e.g. `public static java.lang.String valueOf(int)`.
+fn method_decl(method: &Method) -> String {
+    format!(
+        "{}{}{}{} {}({}){}",
+        privacy_prefix(&method.flags.privacy),
+        if method.flags.is_static {
+            "static "
+        } else {
+            ""
+        },
+        generics_prefix(&method.generics),
+        match &method.return_ty {
+            Some(ty) => ty.to_string(),
+            None => "void".to_string(),
+        },
+        method.name,
+        comma_separated(&method.argument_tys),
+        throws_suffix(&method.throws),
+    )
+}
+
+fn throws_suffix(throws: &[ClassRef]) -> String {
+    if throws.is_empty() {
+        String::new()
+    } else {
+        format!(" throws {}", comma_separated(throws))
+    }
+}
+
+fn privacy_prefix(privacy: &Privacy) -> &'static str {
+    match privacy {
+        Privacy::Public => "public ",
+        Privacy::Protected => "protected ",
+        Privacy::Package => "",
+    }
+}
+
+fn generics_prefix(generics: &[Generic]) -> String {
+    if generics.is_empty() {
+        return String::new();
+    }
+    let generics: Vec<String> = generics.iter().map(|g| g.to_string()).collect();
+    format!("<{}> ", generics.join(", "))
+}
+
+fn comma_separated(tys: &[impl std::fmt::Display]) -> String {
+    let tys: Vec<String> = tys.iter().map(|ty| ty.to_string()).collect();
+    tys.join(", ")
+}
+
+/// Whether `javap` failed because it couldn't find a class, as opposed to e.g. failing to read the classpath.
+fn is_class_not_found(stderr: &str) -> bool {
+    stderr.lines().any(|l| l.contains("class not found: "))
+}
+
+/// Creates a `javap` command that prints the public members of the classes passed as further arguments, along with
+/// their descriptors.
+fn javap_command(span: Span) -> Result<Command, SpanError> {
+    let javap = javap_program(env::var_os("DUCHESS_JAVAP"))
+        .map_err(|message| SpanError { span, message })?;
+    let mut command = Command::new(javap);
+    command.arg("-cp").arg(classpath()).arg("-public").arg("-s");
+    // The messages are matched by `is_class_not_found`, so they must not be translated
+    command.arg("-J-Duser.language=en");
+    Ok(command)
+}
+
+/// Picks the `javap` to run given the value of the `DUCHESS_JAVAP` environment variable: `javap` from the `PATH` if it
+/// is unset, otherwise the configured program. A configured path (as opposed to a bare program name, which is looked
+/// up on the `PATH`) has to exist.
+fn javap_program(configured: Option<OsString>) -> Result<OsString, String> {
+    let Some(javap) = configured.filter(|javap| !javap.is_empty()) else {
+        return Ok(OsString::from("javap"));
+    };
+
+    let path = Path::new(&javap);
+    if path.components().count() > 1 && !path.is_file() {
+        return Err(format!(
+            "`DUCHESS_JAVAP` is set to `{}`, which does not exist",
+            path.display()
+        ));
+    }
+    Ok(javap)
+}
+
+/// Parses the feature version out of a JDK version string as printed by `javap -version`, e.g. 8 for `1.8.0_392`, 17
+/// for `17.0.8` and 22 for `22-ea`.
+fn parse_jdk_version(text: &str) -> Option<u32> {
+    let leading_number = |component: &str| -> Option<u32> {
+        let digits: String = component.chars().take_while(char::is_ascii_digit).collect();
+        digits.parse().ok()
+    };
+
+    let mut components = text.trim().split('.');
+    match leading_number(components.next()?)? {
+        // The versions before 9 are written `1.N`
+        1 => components.next().and_then(leading_number),
+        0 => None,
+        feature => Some(feature),
+    }
+}
+
+fn classpath() -> String {
+    match env::var("CLASSPATH") {
+        Ok(val) => val,
+        Err(e) => panic!("duchess cannot read the CLASSPATH environment variable: {e}"),
+    }
+}
+
+/// Parses the output of `javap` for several classes, which prints each class one after the other. Each class ends with
+/// a closing `}` on a line of its own. Classes that fail to parse are skipped with a warning.
+///
+/// The output differs slightly between JDK versions: some print notes like `Warning: ...` between classes, and newer
+/// ones print `sealed` classes with their `permits` clause. Both are dropped here, since the bindings don't use them.
+fn parse_javap_output(output: &str) -> (Vec<ClassInfo>, Vec<String>) {
+    let mut infos = vec![];
+    let mut warnings = vec![];
+
+    let mut class_text = String::new();
+    for line in output.lines() {
+        let line = line.trim_end();
+        let is_header = !line.starts_with(char::is_whitespace) && line.ends_with('{');
+        if class_text.is_empty() && !is_header && !line.starts_with("Compiled from") {
+            continue;
+        }
+
+        if is_header {
+            class_text.push_str(&normalize_class_header(line));
+        } else {
+            class_text.push_str(line);
+        }
+        class_text.push('\n');
+        if line != "}" {
+            continue;
+        }
+
+        match ClassInfo::parse(&class_text, Span::call_site()) {
+            Ok(ci) => infos.push(ci),
+            Err(err) => {
+                let header = class_text
+                    .lines()
+                    .find(|l| !l.starts_with("Compiled from"))
+                    .unwrap_or_default()
+                    .trim_end_matches('{')
+                    .trim();
+                warnings.push(format!("skipping `{header}`: {}", err.message));
+            }
+        }
+        class_text.clear();
+    }
+
+    (infos, warnings)
+}
+
+/// The runtime-visible annotations of a class and its constructors and methods, see [`parse_javap_annotations`].
+#[derive(Debug)]
+struct ClassAnnotations {
+    name: DotId,
+    class: Vec<Annotation>,
+
+    /// The constructors and methods in the order `javap` prints them.
+    members: Vec<MemberAnnotations>,
+}
+
+#[derive(Debug)]
+struct MemberAnnotations {
+    /// The name of the method, or of the class for constructors.
+    name: String,
+    annotations: Vec<Annotation>,
+
+    /// True for the bridge methods generated by the compiler, see [`drop_bridge_methods`](crate::class_info::drop_bridge_methods).
+    is_bridge: bool,
+
+    /// The names from the `MethodParameters` attribute, empty if there is none.
+    parameter_names: Vec<String>,
+}
+
+impl ClassAnnotations {
+    /// Removes the bridge methods from `ci`, the class as printed without `-v`, and adds the annotations if
+    /// `annotations` is set. Fails if the members don't line up, which would otherwise attach annotations and argument
+    /// names to the wrong members.
+    fn apply(self, ci: &mut ClassInfo, annotations: bool) -> Result<(), String> {
+        if annotations {
+            ci.annotations = self.class;
+        }
+
+        // `javap` prints the members in the same order with and without `-v`, and only the public ones in both cases.
+        // Without `-v` bridge methods can only be recognized when they have the same argument types as the method they
+        // bridge to, so the others are still in the class info and are removed here. `javac` puts bridges after
+        // all other methods.
+        let class_name = ci.name.to_string();
+        let mut constructors = ci.constructors.iter_mut();
+        let mut index = 0;
+        let mut bridges = vec![];
+        let mismatch = |found: &str, expected: Option<&Id>| {
+            let expected = match expected {
+                Some(expected) => format!("where `{expected}` was expected"),
+                None => "after the last member".to_string(),
+            };
+            format!(
+                "the members of `{class_name}` printed by `javap -v` don't match those printed by `javap`: found \
+                 `{found}` {expected}"
+            )
+        };
+        for member in self.members {
+            if member.name == class_name {
+                let Some(c) = constructors.next() else {
+                    return Err(mismatch(&member.name, None));
+                };
+                if annotations {
+                    c.annotations = member.annotations;
+                    c.parameter_names = parameter_names(member.parameter_names, &c.argument_tys);
+                }
+                continue;
+            }
+            match ci.methods.get_mut(index) {
+                Some(m) if m.name[..] == member.name => {
+                    if member.is_bridge {
+                        bridges.push(index);
+                    } else if annotations {
+                        m.annotations = member.annotations;
+                        m.parameter_names =
+                            parameter_names(member.parameter_names, &m.argument_tys);
+                    }
+                    index += 1;
+                }
+                _ if member.is_bridge => {}
+                Some(m) => return Err(mismatch(&member.name, Some(&m.name))),
+                None => return Err(mismatch(&member.name, None)),
+            }
+        }
+        for index in bridges.into_iter().rev() {
+            ci.methods.remove(index);
+        }
+        Ok(())
+    }
+}
+
+/// Converts the names from a `MethodParameters` attribute, which are only used if there is one for each argument.
+/// Parameters without a name in the class file are printed as `<no name>`.
+fn parameter_names(names: Vec<String>, argument_tys: &[Type]) -> Option<Vec<Id>> {
+    if names.len() != argument_tys.len() || names.iter().any(|n| n.starts_with('<')) {
+        return None;
+    }
+    Some(names.into_iter().map(Id::from).collect())
+}
+
+/// Extracts the annotations from the output of `javap -v`. Each class starts with a `Classfile` line and a header
+/// line, followed by its members between `{` and `}` and then the attributes of the class itself. The annotations are
+/// printed as an attribute, e.g.
+///
+/// ```text
+///   public int legacy();
+///     descriptor: ()I
+///     ...
+///     RuntimeVisibleAnnotations:
+///       0: #15(#16=s#17)
+///         java.lang.Deprecated(
+///           since="2.0"
+///         )
+/// ```
+///
+/// The names of the arguments are taken from the `MethodParameters` attribute in the same way, which lists one
+/// argument per line after a heading, each followed by its flags (e.g. `final`).
+fn parse_javap_annotations(output: &str) -> Vec<ClassAnnotations> {
+    let mut classes: Vec<ClassAnnotations> = vec![];
+    let mut expect_header = false;
+    let mut in_body = false;
+
+    let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+        let indent = line.len() - line.trim_start().len();
+
+        if line.starts_with("Classfile ") {
+            expect_header = true;
+            in_body = false;
+        } else if expect_header && indent == 0 && !line.starts_with("Compiled from") {
+            expect_header = false;
+            let Some(name) = class_name_in_header(line) else {
+                continue;
+            };
+            classes.push(ClassAnnotations {
+                name,
+                class: vec![],
+                members: vec![],
+            });
+        } else if line == "{" || line == "}" {
+            in_body = line == "{";
+        } else if in_body && indent == 2 {
+            // Only constructors and methods have annotations that are reflected.
+            let Some(class) = classes.last_mut() else {
+                continue;
+            };
+            let name = match line.split_once('(') {
+                Some((before, _)) => before.rsplit(' ').next().unwrap_or_default(),
+                None => "",
+            };
+            class.members.push(MemberAnnotations {
+                name: name.to_string(),
+                annotations: vec![],
+                is_bridge: false,
+                parameter_names: vec![],
+            });
+        } else if in_body && indent == 4 && line.trim_start().starts_with("flags:") {
+            let hex = line.trim_start()["flags:".len()..].trim_start();
+            let hex = hex
+                .trim_start_matches("(0x")
+                .split(')')
+                .next()
+                .unwrap_or_default();
+            if let Some(member) = classes.last_mut().and_then(|c| c.members.last_mut()) {
+                member.is_bridge = u16::from_str_radix(hex, 16).is_ok_and(is_generated_member);
+            }
+        } else if in_body && indent == 4 && line.trim_start() == "MethodParameters:" {
+            let start = i;
+            while i < lines.len() && lines[i].len() - lines[i].trim_start().len() > indent {
+                i += 1;
+            }
+            let names = lines[start..i]
+                .iter()
+                .skip(1)
+                .map(|l| l.split_whitespace().next().unwrap_or_default().to_string())
+                .collect();
+            if let Some(member) = classes.last_mut().and_then(|c| c.members.last_mut()) {
+                member.parameter_names = names;
+            }
+        } else if line.trim_start() == "RuntimeVisibleAnnotations:" {
+            let start = i;
+            while i < lines.len() && lines[i].len() - lines[i].trim_start().len() > indent {
+                i += 1;
+            }
+            let annotations = parse_annotations(&lines[start..i]);
+            let Some(class) = classes.last_mut() else {
+                continue;
+            };
+            if !in_body {
+                class.class = annotations;
+            } else if let Some(member) = class.members.last_mut() {
+                member.annotations = annotations;
+            }
+        }
+    }
+
+    for class in &mut classes {
+        class.members.retain(|member| !member.name.is_empty());
+    }
+    classes
+}
+
+/// The name of the class declared by a header like `public final class java.lang.Integer extends java.lang.Number`.
+fn class_name_in_header(line: &str) -> Option<DotId> {
+    let mut words = line.split(' ');
+    words.find(|w| ["class", "interface", "enum", "record"].contains(w))?;
+    let name = words.next()?;
+    let name = name.split('<').next().unwrap_or(name);
+    Some(name.split('.').map(Id::from).collect())
+}
+
+/// Parses the lines of a `RuntimeVisibleAnnotations` attribute. Each annotation is preceded by a line with its
+/// constant pool indices, which is skipped, and elements with non-simple values span more lines, which are skipped too.
+fn parse_annotations(lines: &[&str]) -> Vec<Annotation> {
+    let is_index = |l: &str| {
+        let l = l.trim_start();
+        l.starts_with(|c: char| c.is_ascii_digit()) && l.contains(": #")
+    };
+    let Some(base) = lines
+        .iter()
+        .find(|l| !is_index(l))
+        .map(|l| l.len() - l.trim_start().len())
+    else {
+        return vec![];
+    };
+
+    let mut annotations: Vec<Annotation> = vec![];
+    for &line in lines {
+        let indent = line.len() - line.trim_start().len();
+        let text = line.trim_start();
+        if is_index(line) || text == ")" {
+            continue;
+        }
+        if indent == base {
+            annotations.push(Annotation {
+                name: text
+                    .trim_end_matches('(')
+                    .split('.')
+                    .map(Id::from)
+                    .collect(),
+                elements: vec![],
+            });
+        } else if indent == base + 2 {
+            let Some((name, value)) = text.split_once('=') else {
+                continue;
+            };
+            let (Some(annotation), Some(value)) = (annotations.last_mut(), annotation_value(value))
+            else {
+                continue;
+            };
+            annotation.elements.push((Id::from(name), value));
+        }
+    }
+    annotations
+}
+
+/// Parses an annotation element value if it is a string or a primitive.
+fn annotation_value(text: &str) -> Option<AnnotationValue> {
+    if let Some(s) = text.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+        return Some(AnnotationValue::String(s.to_string()));
+    }
+    let is_primitive = text == "true"
+        || text == "false"
+        || text.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '\'' || c == '(');
+    is_primitive.then(|| AnnotationValue::Primitive(text.to_string()))
+}
+
+/// Removes the `sealed`/`non-sealed` modifiers and the `permits` clause from a class header like
+/// `public abstract sealed class shapes.Shape permits shapes.Circle, shapes.Square {`.
+fn normalize_class_header(line: &str) -> String {
+    let line = match line.find(" permits ") {
+        Some(start) => format!("{} {{", &line[..start]),
+        None => line.to_string(),
+    };
+    line.split(' ')
+        .filter(|word| *word != "sealed" && *word != "non-sealed")
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Reflection on something callable.
+#[derive(Copy, Clone, Debug)]
+pub enum ReflectedMethod<'i> {
+    Constructor(&'i ClassInfo, &'i Constructor),
+    Method(&'i ClassInfo, &'i Method),
+}
+
+impl ReflectedMethod<'_> {
+    /// The name of this callable thing in Rust
+    pub fn name(&self) -> Id {
+        match self {
+            ReflectedMethod::Constructor(..) => Id::from("new"),
+            ReflectedMethod::Method(_, m) => m.name.clone(),
+        }
+    }
+
+    pub fn class(&self) -> &ClassInfo {
+        match self {
+            ReflectedMethod::Constructor(c, _) => c,
+            ReflectedMethod::Method(c, _) => c,
+        }
+    }
+
+    /// Is this something that is called on a *type*?
+    pub fn is_static(&self) -> bool {
+        match self {
+            ReflectedMethod::Constructor(..) => true,
+            ReflectedMethod::Method(_, m) => m.flags.is_static,
+        }
+    }
+
+    pub fn generics(&self) -> &Vec<Generic> {
+        match self {
+            ReflectedMethod::Constructor(_, c) => &c.generics,
+            ReflectedMethod::Method(_, m) => &m.generics,
+        }
+    }
+
+    pub fn argument_tys(&self) -> &Vec<Type> {
+        match self {
+            ReflectedMethod::Constructor(_, c) => &c.argument_tys,
+            ReflectedMethod::Method(_, m) => &m.argument_tys,
+        }
+    }
+
+    /// The names of the arguments, in the same order as [`Self::argument_tys`], or `None` if the class file doesn't
+    /// record them or they haven't been reflected (see [`Reflector::reflect_annotations`]).
+    pub fn parameter_names(&self) -> Option<&[Id]> {
+        match self {
+            ReflectedMethod::Constructor(_, c) => c.parameter_names.as_deref(),
+            ReflectedMethod::Method(_, m) => m.parameter_names.as_deref(),
+        }
+    }
+
+    /// The return type, or `None` for `void` methods and constructors.
+    pub fn return_ty(&self) -> Option<&Type> {
+        match self {
+            ReflectedMethod::Constructor(..) => None,
+            ReflectedMethod::Method(_, m) => m.return_ty.as_ref(),
+        }
+    }
+
+    /// The generic parameters declared on the method itself (not its class), with their bounds resolved. This
+    /// describes the parameters, e.g. for error messages; the generated bindings translate the declared bounds into
+    /// Rust bounds in [`Signature::with_internal_generics`](crate::signature::Signature::with_internal_generics).
+    pub fn method_generics(&self) -> Vec<MethodGeneric> {
+        self.generics()
+            .iter()
+            .map(|g| {
+                let bounds = if g.extends.is_empty() {
+                    vec![Type::Ref(RefType::Class(ClassRef {
+                        name: DotId::parse("java.lang.Object"),
+                        generics: vec![],
+                    }))]
+                } else {
+                    g.extends
+                        .iter()
+                        .map(|c| Type::Ref(RefType::Class(c.clone())))
+                        .collect()
+                };
+                let in_arguments = self.argument_tys().iter().any(|ty| ty.mentions(&g.id));
+                let in_return = self.return_ty().is_some_and(|ty| ty.mentions(&g.id));
+                MethodGeneric {
+                    id: g.id.clone(),
+                    bounds,
+                    return_only: in_return && !in_arguments,
+                }
+            })
+            .collect()
+    }
+}
+
+/// A generic parameter of a [`ReflectedMethod`], see [`ReflectedMethod::method_generics`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MethodGeneric {
+    pub id: Id,
+
+    /// The upper bounds of the parameter, e.g. `java.lang.Comparable<T>` for `T extends Comparable<T>`. Parameters
+    /// declared without bounds are bounded by `java.lang.Object`.
+    pub bounds: Vec<Type>,
+
+    /// True if the parameter appears in the return type but in none of the arguments, like `T` in `<T> T empty()`.
+    /// Such a parameter can't be inferred from the arguments, so the caller decides what it is.
+    pub return_only: bool,
+}
+
+impl std::fmt::Display for MethodGeneric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} extends {}", self.id, self.bounds[0])?;
+        for bound in &self.bounds[1..] {
+            write!(f, " & {bound}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Reflection on a field, see [`Reflector::reflect_field`].
+#[derive(Copy, Clone, Debug)]
+pub struct ReflectedField<'i> {
+    pub class: &'i ClassInfo,
+    pub field: &'i Field,
+}
+
+impl ReflectedField<'_> {
+    pub fn name(&self) -> &Id {
+        &self.field.name
+    }
+
+    pub fn ty(&self) -> &Type {
+        &self.field.ty
+    }
+
+    /// Is this a field of the class rather than of its instances?
+    pub fn is_static(&self) -> bool {
+        self.field.flags.is_static
+    }
+
+    /// Can the field be assigned, i.e. is it not `final`?
+    pub fn is_mutable(&self) -> bool {
+        !self.field.flags.is_final
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, ffi::OsString, path::PathBuf, sync::Arc};
+
+    use proc_macro2::Span;
+
+    use crate::{
+        argument::{FieldSelector, Ident, JavaPath, MethodSelector},
+        class_info::{Artifact, ClassInfo, DotId, Id},
+    };
+
+    use super::{
+        is_class_not_found, javap_program, large_package_warning, method_decl,
+        parse_javap_annotations, parse_javap_output, parse_jdk_version, Annotation,
+        ReflectedMethod, Reflector,
+    };
+
+    fn path(s: &str) -> JavaPath {
+        JavaPath {
+            ids: s
+                .split('.')
+                .map(|text| Ident {
+                    text: text.to_string(),
+                    span: Span::call_site(),
+                })
+                .collect(),
+            span: Span::call_site(),
+        }
+    }
+
+    fn reflector_with(class_text: &str) -> Reflector {
+        let mut reflector = Reflector::default();
+        let info = ClassInfo::parse(class_text, Span::call_site()).unwrap();
+        reflector.classes.insert(info.name.clone(), Arc::new(info));
+        reflector
+    }
+
+    /// Reflects `selector` against `class_text`, returning the error message.
+    fn ambiguity_error(class_text: &str, selector: MethodSelector) -> String {
+        reflector_with(class_text)
+            .reflect_method(&selector, None)
+            .unwrap_err()
+            .message
+    }
+
+    const PRINTER: &str = r#"
+        public class overloads.Printer {
+            public overloads.Printer();
+            public overloads.Printer(java.lang.String, int);
+            public overloads.Printer(java.lang.String, long);
+            public overloads.Printer(java.lang.String);
+            public void print(int);
+            public void print();
+        }
+    "#;
+
+    #[test]
+    fn arity_selects_constructor() {
+        let mut reflector = reflector_with(PRINTER);
+        let selector = MethodSelector::ClassName(path("overloads.Printer"));
+
+        let constructor = reflector.reflect_method(&selector, Some(1)).unwrap();
+        assert_eq!(constructor.argument_tys().len(), 1);
+
+        expect_test::expect![[r#"
+            2 constructors with 2 arguments found, use an explicit class declaration to disambiguate:
+                public overloads.Printer(java.lang.String, int);
+                public overloads.Printer(java.lang.String, long);"#]]
+        .assert_eq(&reflector.reflect_method(&selector, Some(2)).unwrap_err().message);
+
+        expect_test::expect![[r#"
+            no constructors with 3 arguments found, the constructors are:
+                public overloads.Printer();
+                public overloads.Printer(java.lang.String, int);
+                public overloads.Printer(java.lang.String, long);
+                public overloads.Printer(java.lang.String);"#]]
+        .assert_eq(
+            &reflector
+                .reflect_method(&selector, Some(3))
+                .unwrap_err()
+                .message,
+        );
+    }
+
+    #[test]
+    fn arity_selects_method() {
+        let mut reflector = reflector_with(PRINTER);
+        let selector = MethodSelector::MethodName(
+            path("overloads.Printer"),
+            Ident {
+                text: "print".to_string(),
+                span: Span::call_site(),
+            },
+        );
+
+        let method = reflector.reflect_method(&selector, Some(0)).unwrap();
+        assert!(method.argument_tys().is_empty());
+    }
+
+    #[test]
+    fn reflect_field_by_name() {
+        let mut reflector = reflector_with(
+            r#"
+            public class fields.Counter {
+                public static final int LIMIT;
+                public long count;
+            }
+            "#,
+        );
+        let selector = |name: &str| FieldSelector {
+            class_name: path("fields.Counter"),
+            field_name: Ident {
+                text: name.to_string(),
+                span: Span::call_site(),
+            },
+        };
+
+        let limit = reflector.reflect_field(&selector("LIMIT")).unwrap();
+        assert_eq!(limit.ty().to_string(), "int");
+        assert!(limit.is_static() && !limit.is_mutable());
+
+        let count = reflector.reflect_field(&selector("count")).unwrap();
+        assert_eq!(count.ty().to_string(), "long");
+        assert!(!count.is_static() && count.is_mutable());
+
+        expect_test::expect!["no field named `total` found in `fields.Counter`"].assert_eq(
+            &reflector
+                .reflect_field(&selector("total"))
+                .unwrap_err()
+                .message,
+        );
+    }
+
+    #[test]
+    fn supertypes_substitute_generic_arguments() {
+        let mut reflector = Reflector::default();
+        for class_text in [
+            "public class coll.Names extends coll.Bag<java.lang.String> { }",
+            "public class coll.Bag<E> extends coll.Base implements coll.Sized<E> { }",
+            "public class coll.Base implements coll.Sized { }",
+            "public interface coll.Sized<T> extends coll.Shape<T[]> { }",
+            "public interface coll.Shape<S> { }",
+        ] {
+            let info = ClassInfo::parse(class_text, Span::call_site()).unwrap();
+            reflector.classes.insert(info.name.clone(), info.into());
+        }
+
+        let supertypes: Vec<String> = reflector
+            .supertypes(&DotId::parse("coll.Names"), Span::call_site())
+            .unwrap()
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        expect_test::expect![[r#"
+            [
+                "coll.Bag<java.lang.String>",
+                "coll.Sized<java.lang.String>",
+                "coll.Shape<java.lang.String[]>",
+                "coll.Base",
+                "coll.Sized",
+                "coll.Shape",
+            ]
+        "#]]
+        .assert_debug_eq(&supertypes);
+    }
+
+    #[test]
+    fn ambiguous_methods_list_candidates() {
+        let message = ambiguity_error(
+            r#"
+            public class overloads.Printer {
+                public overloads.Printer();
+                public void print(int);
+                public static <T> T print(java.lang.String, T...);
+                public java.lang.Object print(java.util.List<? extends java.lang.Number>);
+            }
+            "#,
+            MethodSelector::MethodName(
+                path("overloads.Printer"),
+                Ident {
+                    text: "print".to_string(),
+                    span: Span::call_site(),
+                },
+            ),
+        );
+        expect_test::expect![[r#"
+            3 methods named `print` found, use an explicit class declaration to disambiguate:
+                public void print(int);
+                public static <T> T print(java.lang.String, T...);
+                public java.lang.Object print(java.util.List<? extends java.lang.Number>);"#]]
+        .assert_eq(&message);
+    }
+
+    #[test]
+    fn ambiguous_constructors_list_candidates() {
+        let message = ambiguity_error(
+            r#"
+            public class overloads.Printer {
+                public overloads.Printer();
+                public overloads.Printer(java.lang.String, int);
+            }
+            "#,
+            MethodSelector::ClassName(path("overloads.Printer")),
+        );
+        expect_test::expect![[r#"
+            2 constructors found, use an explicit class declaration to disambiguate:
+                public overloads.Printer();
+                public overloads.Printer(java.lang.String, int);"#]]
+        .assert_eq(&message);
+    }
+
+    #[test]
+    fn annotations_from_verbose_javap_output() {
+        // Abridged `javap -public -s -v` output; the constant pool and bytecode are left out.
+        let output = r#"Classfile /tmp/ann/Old.class
+  Last modified Oct 15, 2026; size 518 bytes
+  Compiled from "Old.java"
+public class ann.Old
+  minor version: 0
+  major version: 61
+Constant pool:
+   #1 = Methodref          #2.#3          // java/lang/Object."<init>":()V
+{
+  public int count;
+    descriptor: I
+    flags: (0x0001) ACC_PUBLIC
+    Deprecated: true
+    RuntimeVisibleAnnotations:
+      0: #14()
+        java.lang.Deprecated
+
+  public ann.Old();
+    descriptor: ()V
+    flags: (0x0001) ACC_PUBLIC
+    Code:
+      stack=1, locals=1, args_size=1
+         0: aload_0
+         1: invokespecial #1                  // Method java/lang/Object."<init>":()V
+         4: return
+
+  public int legacy();
+    descriptor: ()I
+    flags: (0x0001) ACC_PUBLIC
+    Deprecated: true
+    RuntimeVisibleAnnotations:
+      0: #15(#16=s#17,#18=Z#19)
+        java.lang.Deprecated(
+          since="2.0"
+          forRemoval=true
+        )
+      1: #19(#20=[s#21],#22=@#23(#24=s#25),#26=J#27)
+        ann.Marker(
+          tags=["a"]
+          nested=@ann.Inner(
+            value="i"
+          )
+          level=5l
+        )
+
+  public static <T> T fresh(T);
+    descriptor: (Ljava/lang/Object;)Ljava/lang/Object;
+    flags: (0x0009) ACC_PUBLIC, ACC_STATIC
+}
+SourceFile: "Old.java"
+RuntimeVisibleAnnotations:
+  0: #14()
+    java.lang.Deprecated
+"#;
+        let format_annotations = |annotations: &[Annotation]| -> String {
+            let annotations: Vec<String> = annotations
+                .iter()
+                .map(|a| {
+                    let elements: Vec<String> = a
+                        .elements
+                        .iter()
+                        .map(|(k, v)| format!("{k}={v:?}"))
+                        .collect();
+                    format!("@{}({})", a.name, elements.join(", "))
+                })
+                .collect();
+            format!("[{}]", annotations.join(" "))
+        };
+        let mut summary = String::new();
+        for class in parse_javap_annotations(output) {
+            summary.push_str(&format!(
+                "{}: {}\n",
+                class.name,
+                format_annotations(&class.class)
+            ));
+            for member in &class.members {
+                summary.push_str(&format!(
+                    "  {}: {}\n",
+                    member.name,
+                    format_annotations(&member.annotations)
+                ));
+            }
+        }
+        expect_test::expect![[r#"
+            ann.Old: [@java.lang.Deprecated()]
+              ann.Old: []
+              legacy: [@java.lang.Deprecated(since=String("2.0"), forRemoval=Primitive("true")) @ann.Marker(level=Primitive("5l"))]
+              fresh: []
+        "#]]
+        .assert_eq(&summary);
+    }
+
+    #[test]
+    fn parameter_names_from_method_parameters() {
+        let mut info = ClassInfo::parse(
+            r#"
+            public class params.Contact {
+                public params.Contact(java.lang.String, int);
+                public void rename(java.lang.String, java.lang.String);
+                public void touch(long);
+            }
+            "#,
+            Span::call_site(),
+        )
+        .unwrap();
+        let output = r#"Classfile /tmp/params/Contact.class
+  Compiled from "Contact.java"
+public class params.Contact
+{
+  public params.Contact(java.lang.String, int);
+    descriptor: (Ljava/lang/String;I)V
+    flags: (0x0001) ACC_PUBLIC
+    Code:
+      stack=1, locals=3, args_size=3
+         0: return
+    MethodParameters:
+      Name                           Flags
+      name
+      age                            final
+
+  public void rename(java.lang.String, java.lang.String);
+    descriptor: (Ljava/lang/String;Ljava/lang/String;)V
+    flags: (0x0001) ACC_PUBLIC
+    MethodParameters:
+      Name                           Flags
+      <no name>                      synthetic
+      last
+
+  public void touch(long);
+    descriptor: (J)V
+    flags: (0x0001) ACC_PUBLIC
+}
+SourceFile: "Contact.java"
+"#;
+        for class in parse_javap_annotations(output) {
+            class.apply(&mut info, true).unwrap();
+        }
+
+        let names = |names: &Option<Vec<Id>>| {
+            names
+                .as_ref()
+                .map(|n| n.iter().map(|n| n.to_string()).collect::<Vec<_>>())
+        };
+        assert_eq!(
+            names(&info.constructors[0].parameter_names),
+            Some(vec!["name".to_string(), "age".to_string()])
+        );
+        assert_eq!(names(&info.methods[0].parameter_names), None);
+        assert_eq!(names(&info.methods[1].parameter_names), None);
+    }
+
+    #[test]
+    fn misaligned_annotations_are_an_error() {
+        let mut info = ClassInfo::parse(
+            "public class params.Contact { public void touch(long); }",
+            Span::call_site(),
+        )
+        .unwrap();
+        let output = r#"Classfile /tmp/params/Contact.class
+public class params.Contact
+{
+  public void rename(java.lang.String, java.lang.String);
+    descriptor: (Ljava/lang/String;Ljava/lang/String;)V
+    flags: (0x0001) ACC_PUBLIC
+}
+"#;
+        let class = parse_javap_annotations(output).pop().unwrap();
+        assert_eq!(
+            class.apply(&mut info, true).unwrap_err(),
+            "the members of `params.Contact` printed by `javap -v` don't match those printed by `javap`: found \
+             `rename` where `touch` was expected"
+        );
+    }
+
+    #[test]
+    fn parse_javap_output_skips_unparseable_classes() {
+        let output = r#"Compiled from "Greeter.java"
+public class wildcard.Greeter {
+  public wildcard.Greeter();
+  public java.lang.String greet(java.lang.String);
+}
+Compiled from "Broken.java"
+public class wildcard.Broken {
+  public ??? broken();
+}
+Compiled from "Counter.java"
+public class wildcard.Counter {
+  public int increment();
+}
+"#;
+        let (infos, warnings) = parse_javap_output(output);
+        let names: Vec<&DotId> = infos.iter().map(|ci| &ci.name).collect();
+        assert_eq!(
+            names,
+            [
+                &DotId::parse("wildcard.Greeter"),
+                &DotId::parse("wildcard.Counter")
+            ]
+        );
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0].starts_with("skipping `public class wildcard.Broken`"),
+            "{}",
+            warnings[0]
+        );
+    }
+
+    #[test]
+    fn parse_javap_output_across_class_file_versions() {
+        // Captured with `javap -public -s` of JDK 17. Class files compiled with `javac --release 8` mark `strictfp`
+        // methods, and inner classes of generic classes are printed as `Outer<T>.Inner`.
+        let release8 = r#"Compiled from "Numbers.java"
+public final class jdk.Numbers {
+  public static final double PI;
+    descriptor: D
+  public static final double[] TABLE;
+    descriptor: [D
+  public jdk.Numbers();
+    descriptor: ()V
+
+  public static strictfp double toRadians(double);
+    descriptor: (D)D
+}
+Compiled from "G.java"
+public class jdk.G<T> {
+  public transient int t;
+    descriptor: I
+  public volatile int v;
+    descriptor: I
+  public jdk.G();
+    descriptor: ()V
+
+  public jdk.G<T>.In<java.lang.String> make();
+    descriptor: ()Ljdk/G$In;
+
+  public static jdk.G<java.lang.String>.In<java.lang.Integer>.Deeper deeper();
+    descriptor: ()Ljdk/G$In$Deeper;
+}
+Compiled from "G.java"
+public class jdk.G$In<U> {
+  public jdk.G$In(jdk.G);
+    descriptor: (Ljdk/G;)V
+}
+"#;
+        // With `javac --release 17`, classes can be sealed, which `javap -public` doesn't print.
+        let release17 = r#"Compiled from "Shape.java"
+public abstract class jdk.Shape {
+  public jdk.Shape();
+    descriptor: ()V
+
+  public abstract double area();
+    descriptor: ()D
+}
+Compiled from "Square.java"
+public class jdk.Square extends jdk.Shape {
+  public jdk.Square(double);
+    descriptor: (D)V
+
+  public double area();
+    descriptor: ()D
+}
+"#;
+
+        let (infos, warnings) = parse_javap_output(&format!("{release8}{release17}"));
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        let summary: Vec<String> = infos
+            .iter()
+            .map(|ci| {
+                let mut summary = format!("{} {{", ci.name);
+                for f in &ci.fields {
+                    summary.push_str(&format!(" {} {};", f.ty, f.name));
+                }
+                for m in &ci.methods {
+                    summary.push_str(&format!(" {};", method_decl(m)));
+                }
+                summary.push_str(" }");
+                summary
+            })
+            .collect();
+        expect_test::expect![[r#"
+            [
+                "jdk.Numbers { double PI; double[] TABLE; public static double toRadians(double); }",
+                "jdk.G { int t; int v; public jdk.G$In<java.lang.String> make(); public static jdk.G$In$Deeper deeper(); }",
+                "jdk.G$In { }",
+                "jdk.Shape { public double area(); }",
+                "jdk.Square { public double area(); }",
+            ]
+        "#]]
+        .assert_debug_eq(&summary);
+    }
+
+    #[test]
+    fn stats_count_cache_hits_and_javap_runs() {
+        let mut reflector = Reflector::default();
+        let square = DotId::parse("shapes.Square");
+        let shape = DotId::parse("shapes.Shape");
+
+        reflector.reflect(&square, Span::call_site()).unwrap();
+        reflector.reflect(&square, Span::call_site()).unwrap();
+        let warnings = reflector
+            .reflect_all(&[square, shape], Span::call_site())
+            .unwrap();
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        let stats = reflector.stats();
+        assert_eq!(
+            (
+                stats.classes_reflected,
+                stats.cache_hits,
+                stats.cache_misses,
+                stats.javap_invocations
+            ),
+            (2, 2, 2, 2)
+        );
+    }
+
+    #[test]
+    fn probe_caches_both_outcomes() {
+        let mut reflector = Reflector::default();
+        let square = DotId::parse("shapes.Square");
+        let missing = DotId::parse("shapes.Circle");
+
+        assert!(reflector.probe(&square, Span::call_site()).unwrap());
+        assert!(!reflector.probe(&missing, Span::call_site()).unwrap());
+        assert!(!reflector.probe(&missing, Span::call_site()).unwrap());
+        reflector.reflect(&square, Span::call_site()).unwrap();
+
+        let stats = reflector.stats();
+        assert_eq!(
+            (
+                stats.cache_hits,
+                stats.cache_misses,
+                stats.javap_invocations
+            ),
+            (2, 2, 2)
+        );
+    }
+
+    #[test]
+    fn only_missing_classes_are_absent() {
+        assert!(is_class_not_found(
+            "Error: class not found: shapes.Circle\n"
+        ));
+        assert!(!is_class_not_found(
+            "Error: error while reading constant pool for shapes.Square: unexpected tag at #5\n"
+        ));
+    }
+
+    #[test]
+    fn specified_classes_are_not_reflected() {
+        let mut reflector = Reflector::default();
+        let gadget = DotId::parse("offline.Gadget");
+        let square = DotId::parse("shapes.Square");
+        let specified = [
+            "public class offline.Gadget { public java.lang.String name(); }",
+            "public class shapes.Square { }",
+        ]
+        .map(|text| Arc::new(ClassInfo::parse(text, Span::call_site()).unwrap()));
+        let warnings = reflector
+            .seed_specified(specified, Span::call_site())
+            .unwrap();
+        assert_eq!(
+            warnings,
+            ["class `offline.Gadget` can't be reflected from the classpath, so its declaration is used without checking it"]
+        );
+
+        // Both resolve to the declaration...
+        let info = reflector.reflect(&gadget, Span::call_site()).unwrap();
+        assert_eq!(info.methods.len(), 1);
+        let info = reflector.reflect(&square, Span::call_site()).unwrap();
+        assert!(info.constructors.is_empty());
+
+        // ...but only the one that isn't on the classpath is checked against it.
+        let info = reflector
+            .reflect_actual(&square, Span::call_site())
+            .unwrap();
+        assert!(!info.constructors.is_empty());
+        let info = reflector
+            .reflect_actual(&gadget, Span::call_site())
+            .unwrap();
+        assert_eq!(info.methods.len(), 1);
+
+        assert_eq!(reflector.stats().javap_invocations, 1);
+    }
+
+    fn artifact(path: &str) -> Artifact {
+        Artifact {
+            span: Span::call_site(),
+            path: PathBuf::from(format!("{}/../java/{path}", env!("CARGO_MANIFEST_DIR"))),
+        }
+    }
+
+    #[test]
+    fn reflect_from_artifact() {
+        let mut reflector = Reflector::default();
+        let version = DotId::parse("pinned.Version");
+        assert!(!reflector.probe(&version, Span::call_site()).unwrap());
+
+        reflector
+            .use_artifact(&version, &artifact("pinned.jar"))
+            .unwrap();
+        let info = reflector.reflect(&version, Span::call_site()).unwrap();
+        assert_eq!(info.name, version);
+        assert_eq!(info.methods.len(), 2);
+    }
+
+    #[test]
+    fn class_missing_from_artifact() {
+        let mut reflector = Reflector::default();
+        let square = DotId::parse("shapes.Square");
+        reflector
+            .use_artifact(&square, &artifact("pinned.jar"))
+            .unwrap();
+        let error = reflector.reflect(&square, Span::call_site()).unwrap_err();
+        assert!(
+            error
+                .message
+                .starts_with("class `shapes.Square` not found in `"),
+            "{}",
+            error.message
+        );
+    }
+
+    #[test]
+    fn artifact_must_exist() {
+        let mut reflector = Reflector::default();
+        let error = reflector
+            .use_artifact(&DotId::parse("pinned.Version"), &artifact("missing.jar"))
+            .unwrap_err();
+        assert!(error.message.ends_with("missing.jar` does not exist"));
+    }
+
+    #[test]
+    fn jmod_classes_are_under_classes() {
+        let mut reflector = Reflector::default();
+        let artifact = artifact("pinned.jar");
+        reflector.artifacts.insert(
+            DotId::parse("pinned.Version"),
+            Artifact {
+                path: artifact.path.with_extension("jmod"),
+                ..artifact
+            },
+        );
+        let arg = reflector.javap_class_arg(&DotId::parse("pinned.Version"));
+        assert!(arg.starts_with("jar:file:"));
+        assert!(arg.ends_with("pinned.jmod!/classes/pinned/Version.class"));
+        assert_eq!(
+            reflector.javap_class_arg(&DotId::parse("shapes.Square")),
+            "shapes.Square"
+        );
+    }
+
+    #[test]
+    fn method_generics_resolve_bounds() {
+        let info = ClassInfo::parse(
+            r#"
+            public class generics.Collections {
+                public static <T extends java.lang.Comparable<T>> T max(java.util.List<T>);
+                public static <T> T empty();
+                public static <K, V extends java.lang.Number & java.lang.Comparable<V>> void put(java.util.Map<K, V>, V);
+            }
+            "#,
+            Span::call_site(),
+        )
+        .unwrap();
+
+        let generics: Vec<String> = info
+            .methods
+            .iter()
+            .flat_map(|m| {
+                ReflectedMethod::Method(&info, m)
+                    .method_generics()
+                    .into_iter()
+                    .map(|g| format!("{}: {g} (return only: {})", m.name, g.return_only))
+            })
+            .collect();
+        expect_test::expect![[r#"
+            [
+                "max: T extends java.lang.Comparable<T> (return only: false)",
+                "empty: T extends java.lang.Object (return only: true)",
+                "put: K extends java.lang.Object (return only: false)",
+                "put: V extends java.lang.Number & java.lang.Comparable<V> (return only: false)",
+            ]
+        "#]]
+        .assert_debug_eq(&generics);
+    }
+
+    #[test]
+    fn javap_program_from_env() {
+        assert_eq!(javap_program(None), Ok(OsString::from("javap")));
+        assert_eq!(
+            javap_program(Some(OsString::new())),
+            Ok(OsString::from("javap"))
+        );
+        assert_eq!(
+            javap_program(Some(OsString::from("javap17"))),
+            Ok(OsString::from("javap17"))
+        );
+
+        let javap = env::current_exe().unwrap().into_os_string();
+        assert_eq!(javap_program(Some(javap.clone())), Ok(javap));
+
+        let error = javap_program(Some(OsString::from("/does/not/exist/javap"))).unwrap_err();
+        assert_eq!(
+            error,
+            "`DUCHESS_JAVAP` is set to `/does/not/exist/javap`, which does not exist"
+        );
+    }
+
+    #[test]
+    fn jdk_versions() {
+        assert_eq!(parse_jdk_version("1.8.0_392\n"), Some(8));
+        assert_eq!(parse_jdk_version("11.0.21"), Some(11));
+        assert_eq!(parse_jdk_version("17.0.15\n"), Some(17));
+        assert_eq!(parse_jdk_version("21"), Some(21));
+        assert_eq!(parse_jdk_version("22-ea"), Some(22));
+        assert_eq!(parse_jdk_version("1.8"), Some(8));
+        assert_eq!(parse_jdk_version(""), None);
+        assert_eq!(parse_jdk_version("javap: error"), None);
+    }
+
+    #[test]
+    fn members_newer_than_the_jdk_are_dropped() {
+        let mut info = ClassInfo::parse(
+            r#"
+            public class java.lang.String {
+                public java.lang.String();
+                #[since(11)]
+                public java.lang.String strip();
+                public int length();
+                #[since(21)]
+                public int indexOf(int, int, int);
+            }
+            "#,
+            Span::call_site(),
+        )
+        .unwrap();
+
+        let dropped = info.clone().drop_newer_members(21);
+        assert!(dropped.is_empty(), "{dropped:?}");
+
+        let dropped = info.drop_newer_members(11);
+        assert_eq!(dropped, ["method `indexOf(int, int, int)` (JDK 21)"]);
+        let names: Vec<&str> = info.methods.iter().map(|m| &m.name[..]).collect();
+        assert_eq!(names, ["strip", "length"]);
+    }
+
+    #[test]
+    fn large_wildcard_packages_warn() {
+        assert_eq!(
+            large_package_warning("package", &path("small"), 10, 200),
+            None
+        );
+        assert_eq!(
+            large_package_warning("package", &path("com.big"), 150, 4000).unwrap(),
+            "`package com.big.*;` binds 150 classes with 4000 members, which generates a lot of code; consider \
+             listing the classes that are used, or leaving some out with `except`"
+        );
+        assert_eq!(
+            large_package_warning("import", &path("com.big"), 150, 4000).unwrap(),
+            "`import com.big.*;` binds 150 classes with 4000 members, which generates a lot of code; consider \
+             listing the classes that are used, or leaving some out with `except`"
+        );
     }
 }
@@ -1,5 +1,7 @@
+use std::collections::BTreeMap;
+
 use crate::{
-    class_info::{ClassRef, Generic, Id, NonRepeatingType, RefType, ScalarType, Type},
+    class_info::{ClassRef, DotId, Generic, Id, NonRepeatingType, RefType, ScalarType, Type},
     span_error::SpanError,
 };
 use proc_macro2::{Ident, Span, TokenStream};
@@ -8,7 +10,7 @@ use quote::quote_spanned;
 /// "Signature" processes Java argument/return types and
 /// converts them into Rust types. This includes translating Java
 /// generics into Rust generics.
-pub struct Signature {
+pub struct Signature<'r> {
     /// Member being translated.
     item_name: Id,
 
@@ -39,9 +41,12 @@ pub struct Signature {
     /// If false, report an error if `?` appears, because it is a context where
     /// we don't support capture.
     capture_generics: bool,
+
+    /// The Rust names of classes bound as `class Foo as JFoo`, which are used in place of their Java names.
+    renamed_classes: Option<&'r BTreeMap<DotId, Id>>,
 }
 
-impl Signature {
+impl<'r> Signature<'r> {
     /// Creates a signature attached to an item (e.g., a method) named `method_name`,
     /// declared at `span`, which inherits `external_generics` from its class.
     ///
@@ -59,6 +64,16 @@ impl Signature {
             rust_generics: vec![],
             where_clauses: vec![],
             capture_generics: true,
+            renamed_classes: None,
+        }
+    }
+
+    /// Refers to the classes in `renamed_classes` by their Rust names, see
+    /// [`ClassInfo::rust_name`](crate::class_info::ClassInfo::rust_name).
+    pub fn with_renamed_classes(self, renamed_classes: &'r BTreeMap<DotId, Id>) -> Self {
+        Signature {
+            renamed_classes: Some(renamed_classes),
+            ..self
         }
     }
 
@@ -206,6 +221,33 @@ impl Signature {
         Ok(Ident::new(f, self.span))
     }
 
+    pub fn jni_nonvirtual_call_fn(&mut self, ty: &Option<Type>) -> Result<Ident, SpanError> {
+        let f = match ty {
+            Some(Type::Ref(_)) => "CallNonvirtualObjectMethodA",
+            Some(Type::Repeat(_)) => {
+                return Err(SpanError {
+                    span: self.span,
+                    message: format!(
+                        "unsupported repeating return type in method `{}`",
+                        self.item_name
+                    ),
+                })
+            }
+            Some(Type::Scalar(scalar)) => match scalar {
+                ScalarType::Int => "CallNonvirtualIntMethodA",
+                ScalarType::Long => "CallNonvirtualLongMethodA",
+                ScalarType::Short => "CallNonvirtualShortMethodA",
+                ScalarType::Byte => "CallNonvirtualByteMethodA",
+                ScalarType::F64 => "CallNonvirtualDoubleMethodA",
+                ScalarType::F32 => "CallNonvirtualFloatMethodA",
+                ScalarType::Boolean => "CallNonvirtualBooleanMethodA",
+                ScalarType::Char => "CallNonvirtualCharMethodA",
+            },
+            None => "CallNonvirtualVoidMethodA",
+        };
+        Ok(Ident::new(f, self.span))
+    }
+
     pub fn jni_static_call_fn(&mut self, ty: &Option<Type>) -> Result<Ident, SpanError> {
         let f = match ty {
             Some(Type::Ref(_)) => "CallStaticObjectMethodA",
@@ -259,6 +301,32 @@ impl Signature {
         Ok(Ident::new(f, self.span))
     }
 
+    pub fn jni_field_get_fn(&mut self, ty: &Type) -> Result<Ident, SpanError> {
+        let f = match ty {
+            Type::Ref(_) => "GetObjectField",
+            Type::Repeat(_) => {
+                return Err(SpanError {
+                    span: self.span,
+                    message: format!(
+                        "unsupported repeating type in getter of field `{}`",
+                        self.item_name
+                    ),
+                })
+            }
+            Type::Scalar(scalar) => match scalar {
+                ScalarType::Int => "GetIntField",
+                ScalarType::Long => "GetLongField",
+                ScalarType::Short => "GetShortField",
+                ScalarType::Byte => "GetByteField",
+                ScalarType::F64 => "GetDoubleField",
+                ScalarType::F32 => "GetFloatField",
+                ScalarType::Boolean => "GetBooleanField",
+                ScalarType::Char => "GetCharField",
+            },
+        };
+        Ok(Ident::new(f, self.span))
+    }
+
     /// Returns an appropriate trait for a method that
     /// returns `ty`. Assumes objects are nullable.
     pub fn method_trait(&mut self, ty: &Option<Type>) -> Result<TokenStream, SpanError> {
@@ -334,7 +402,10 @@ impl Signature {
 
     pub fn class_ref_ty(&mut self, ty: &ClassRef) -> Result<TokenStream, SpanError> {
         let ClassRef { name, generics } = ty;
-        let rust_name = name.to_module_name(self.span);
+        let rust_name = match self.renamed_classes.and_then(|renamed| renamed.get(name)) {
+            Some(renamed) => name.renamed(renamed).to_module_name(self.span),
+            None => name.to_module_name(self.span),
+        };
         if generics.len() == 0 {
             Ok(quote_spanned!(self.span => #rust_name))
         } else {
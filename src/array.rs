@@ -7,8 +7,8 @@ use crate::{
     plumbing::JavaObjectExt,
     raw::{HasEnvPtr, ObjectPtr},
     to_java::ToJavaImpl,
-    AsJRef, Error, JDeref, JavaObject, JavaType, Jvm, JvmOp, Local, Nullable, ScalarMethod, ToRust,
-    TryJDeref,
+    AsJRef, Error, Global, JDeref, JavaObject, JavaType, Jvm, JvmOp, Local, Nullable, ScalarMethod,
+    ToRust, ToRustInto, TryJDeref,
 };
 
 pub struct JavaArray<T: JavaType> {
@@ -45,6 +45,12 @@ impl<T: JavaType> TryJDeref for JavaArray<T> {
 pub trait JavaArrayExt<T: JavaType>: JvmOp {
     type Length: ScalarMethod<jni_sys::jsize>;
     fn length(self) -> Self::Length;
+
+    /// True if the array has no elements, like `isEmpty()` on collections and strings.
+    type IsEmpty: ScalarMethod<bool>;
+    // consumes `self` like every other operation
+    #[allow(clippy::wrong_self_convention)]
+    fn is_empty(self) -> Self::IsEmpty;
 }
 
 impl<This, T> JavaArrayExt<T> for This
@@ -60,6 +66,13 @@ where
             element: PhantomData,
         }
     }
+
+    type IsEmpty = IsEmpty<Self, T>;
+    fn is_empty(self) -> Self::IsEmpty {
+        IsEmpty {
+            length: self.length(),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -88,6 +101,24 @@ where
     }
 }
 
+#[derive(Clone)]
+pub struct IsEmpty<This, T> {
+    length: Length<This, T>,
+}
+
+impl<This, T> JvmOp for IsEmpty<This, T>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<T>>,
+    T: JavaType,
+{
+    type Output<'jvm> = bool;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        Ok(self.length.execute_with(jvm)? == 0)
+    }
+}
+
 macro_rules! primivite_array {
     ($([$rust:ty]: $java_name:literal $java_ty:ident $new_fn:ident $get_fn:ident $set_fn:ident,)*) => {
         $(
@@ -124,6 +155,31 @@ macro_rules! primivite_array {
                 }
             }
 
+            impl JvmOp for &Vec<$rust> {
+                type Output<'jvm> = Local<'jvm, JavaArray<$rust>>;
+
+                fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+                    self.as_slice().execute_with(jvm)
+                }
+            }
+
+            impl JvmOp for Vec<$rust> {
+                type Output<'jvm> = Local<'jvm, JavaArray<$rust>>;
+
+                fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+                    self.as_slice().execute_with(jvm)
+                }
+            }
+
+            /// Vectors are elements of multidimensional arrays, e.g. a `&[Vec<i32>]` is passed as an `int[][]`.
+            impl ArrayElement for Vec<$rust> {
+                type Java = JavaArray<$rust>;
+
+                fn to_java_element<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Option<Local<'jvm, Self::Java>>> {
+                    Ok(Some(self.as_slice().execute_with(jvm)?))
+                }
+            }
+
             impl ToJavaImpl<java::Array<$rust>> for [$rust] {
                 fn to_java_impl<'jvm>(
                     rust: &Self,
@@ -144,8 +200,18 @@ macro_rules! primivite_array {
 
             impl ToRust<Vec<$rust>> for JavaArray<$rust> {
                 fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> $crate::Result<'jvm, Vec<$rust>> {
+                    let mut vec = Vec::new();
+                    ToRustInto::to_rust_into(self, jvm, &mut vec)?;
+                    Ok(vec)
+                }
+            }
+
+            /// Reuses the buffer of the target `Vec`.
+            impl ToRustInto<Vec<$rust>> for JavaArray<$rust> {
+                fn to_rust_into<'jvm>(&self, jvm: &mut Jvm<'jvm>, vec: &mut Vec<$rust>) -> $crate::Result<'jvm, ()> {
                     let len = self.length().execute_with(jvm)?;
-                    let mut vec = Vec::<$rust>::with_capacity(len as usize);
+                    vec.clear();
+                    vec.reserve(len as usize);
 
                     unsafe {
                         jvm.env().invoke(|env| env.$get_fn, |env, f| f(
@@ -155,17 +221,45 @@ macro_rules! primivite_array {
                             len,
                             vec.as_mut_ptr().cast::<jni_sys::$java_ty>(),
                         ));
+                        <$rust as JniElement>::normalize(vec.as_mut_ptr(), len as usize);
                         vec.set_len(len as usize);
                     }
                     check_exception(jvm)?;
 
-                    Ok(vec)
+                    Ok(())
                 }
             }
         )*
     };
 }
 
+/// Fixes up the elements of a primitive array region copied from Java, before they are read as Rust values.
+trait JniElement: Sized {
+    /// # Safety
+    ///
+    /// `buffer` must point to `len` elements written by JNI.
+    unsafe fn normalize(_buffer: *mut Self, _len: usize) {}
+}
+
+impl JniElement for i8 {}
+impl JniElement for u16 {}
+impl JniElement for i16 {}
+impl JniElement for i32 {}
+impl JniElement for i64 {}
+impl JniElement for f32 {}
+impl JniElement for f64 {}
+
+/// A `jboolean` is true for any nonzero byte, but a Rust `bool` must be 0 or 1.
+impl JniElement for bool {
+    unsafe fn normalize(buffer: *mut Self, len: usize) {
+        let bytes =
+            unsafe { std::slice::from_raw_parts_mut(buffer.cast::<jni_sys::jboolean>(), len) };
+        for byte in bytes {
+            *byte = (*byte != jni_sys::JNI_FALSE) as jni_sys::jboolean;
+        }
+    }
+}
+
 // Bool is represented as u8 in JNI
 primivite_array! {
     [bool]: "boolean" jboolean NewBooleanArray GetBooleanArrayRegion SetBooleanArrayRegion,
@@ -177,3 +271,229 @@ primivite_array! {
     [f32]: "float" jfloat NewFloatArray GetFloatArrayRegion SetFloatArrayRegion,
     [f64]: "double" jdouble NewDoubleArray GetDoubleArrayRegion SetDoubleArrayRegion,
 }
+
+/// Unsigned bytes are passed as a `byte[]` with the same bits, so e.g. `0xff` becomes `-1`.
+impl JvmOp for &[u8] {
+    type Output<'jvm> = Local<'jvm, JavaArray<i8>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        // SAFETY: i8 and u8 have the same size and alignment
+        let this: &[i8] = unsafe { std::slice::from_raw_parts(self.as_ptr().cast(), self.len()) };
+        this.execute_with(jvm)
+    }
+}
+
+impl JvmOp for &Vec<u8> {
+    type Output<'jvm> = Local<'jvm, JavaArray<i8>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        self.as_slice().execute_with(jvm)
+    }
+}
+
+impl JvmOp for Vec<u8> {
+    type Output<'jvm> = Local<'jvm, JavaArray<i8>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        self.as_slice().execute_with(jvm)
+    }
+}
+
+/// Rust values that can be the elements of a slice passed as a Java object array. A `&[E]` (or a `Vec<E>`) is a
+/// [`JvmOp`] that creates a `E::Java[]`, so it can be passed wherever a method expects that array type, e.g. a
+/// `&[&str]` for a `String[]` parameter. Passing a slice whose elements convert to another type is a compile error.
+pub trait ArrayElement {
+    /// The component type of the array.
+    type Java: JavaObject;
+
+    /// Converts the element, returning `None` for a `null` element.
+    fn to_java_element<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, Self::Java>>>;
+}
+
+impl ArrayElement for &str {
+    type Java = java::lang::String;
+
+    fn to_java_element<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, Self::Java>>> {
+        Ok(Some(self.execute_with(jvm)?))
+    }
+}
+
+impl ArrayElement for String {
+    type Java = java::lang::String;
+
+    fn to_java_element<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, Self::Java>>> {
+        Ok(Some(self.as_str().execute_with(jvm)?))
+    }
+}
+
+macro_rules! reference_array_element {
+    ($([$($param:tt)*] $t:ty,)*) => {
+        $(
+            impl<$($param)*> ArrayElement for $t {
+                type Java = R;
+
+                fn to_java_element<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Option<Local<'jvm, R>>> {
+                    Ok(self.try_jderef().ok().map(|r| jvm.local(r)))
+                }
+            }
+        )*
+    };
+}
+
+impl<R: JavaObject> ArrayElement for &R {
+    type Java = R;
+
+    fn to_java_element<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, R>>> {
+        Ok(Some(jvm.local(*self)))
+    }
+}
+
+reference_array_element! {
+    [R: JavaObject] Local<'_, R>,
+    [R: JavaObject] Global<R>,
+    [R: JavaObject] Option<Local<'_, R>>,
+    [R: JavaObject] Option<Global<R>>,
+}
+
+/// Creates an object array with each element converted by [`ArrayElement`]. The local reference to each element is
+/// deleted once it is stored, and an element that fails to convert is reported with its position in
+/// [`Error::ElementConversion`].
+impl<E: ArrayElement> JvmOp for &[E] {
+    type Output<'jvm> = Local<'jvm, JavaArray<E::Java>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let Ok(len) = self.len().try_into() else {
+            return Err(Error::SliceTooLong(self.len()));
+        };
+
+        let class = E::Java::class(jvm)?;
+        let env = jvm.env();
+        let array = unsafe {
+            env.invoke(
+                |env| env.NewObjectArray,
+                |env, f| f(env, len, class.as_raw().as_ptr(), std::ptr::null_mut()),
+            )
+        };
+        let Some(array) = ObjectPtr::new(array) else {
+            check_exception(jvm)?; // Likely threw OutOfMemoryError
+            return Err(Error::JvmInternal(format!(
+                "failed to allocate an object array of length {len}"
+            )));
+        };
+        // SAFETY: NewObjectArray returned a new local ref to an `E::Java[]`
+        let array: Local<'jvm, JavaArray<E::Java>> = unsafe { Local::from_raw(env, array) };
+
+        for (index, element) in self.iter().enumerate() {
+            let element = element
+                .to_java_element(jvm)
+                .map_err(|error| error.for_element(index))?;
+            let element_ptr = element
+                .as_ref()
+                .map_or(std::ptr::null_mut(), |e| e.as_raw().as_ptr());
+            unsafe {
+                jvm.env().invoke(
+                    |env| env.SetObjectArrayElement,
+                    |env, f| f(env, array.as_raw().as_ptr(), index as i32, element_ptr),
+                );
+            }
+            check_exception(jvm)?;
+        }
+
+        Ok(array)
+    }
+}
+
+impl<E: ArrayElement> JvmOp for &Vec<E> {
+    type Output<'jvm> = Local<'jvm, JavaArray<E::Java>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        self.as_slice().execute_with(jvm)
+    }
+}
+
+impl<E: ArrayElement> JvmOp for Vec<E> {
+    type Output<'jvm> = Local<'jvm, JavaArray<E::Java>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        self.as_slice().execute_with(jvm)
+    }
+}
+
+/// Reads a `byte[]` as unsigned bytes, reusing the buffer of the target `Vec`.
+impl ToRustInto<Vec<u8>> for JavaArray<i8> {
+    fn to_rust_into<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        vec: &mut Vec<u8>,
+    ) -> crate::Result<'jvm, ()> {
+        let len = self.length().execute_with(jvm)?;
+        vec.clear();
+        vec.reserve(len as usize);
+
+        // SAFETY: jbyte and u8 have the same size and alignment, and every byte is a valid u8
+        unsafe {
+            jvm.env().invoke(
+                |env| env.GetByteArrayRegion,
+                |env, f| {
+                    f(
+                        env,
+                        self.as_raw().as_ptr(),
+                        0,
+                        len,
+                        vec.as_mut_ptr().cast::<jni_sys::jbyte>(),
+                    )
+                },
+            );
+            vec.set_len(len as usize);
+        }
+        check_exception(jvm)?;
+
+        Ok(())
+    }
+}
+
+/// Converts each element of an object array, such as a `String[]` or `int[][]`. An element that fails to convert,
+/// including a null element (with [`Error::NullDeref`]), is reported as an [`Error::ElementConversion`] with its index.
+impl<T, R> ToRust<Vec<R>> for JavaArray<T>
+where
+    T: JavaObject + ToRust<R>,
+{
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Vec<R>> {
+        let len = self.length().execute_with(jvm)?;
+        let mut vec = Vec::with_capacity(len as usize);
+
+        for index in 0..len {
+            let env = jvm.env();
+            let element = unsafe {
+                env.invoke(
+                    |env| env.GetObjectArrayElement,
+                    |env, f| f(env, self.as_raw().as_ptr(), index),
+                )
+            };
+            check_exception(jvm)?;
+
+            let Some(element) = ObjectPtr::new(element) else {
+                return Err(Error::NullDeref.for_element(index as usize));
+            };
+            // SAFETY: elements of a `T[]` are instances of `T`, and GetObjectArrayElement returns a new local ref
+            let element: Local<'jvm, T> = unsafe { Local::from_raw(env, element) };
+            let element =
+                T::to_rust(&element, jvm).map_err(|error| error.for_element(index as usize))?;
+            vec.push(element);
+        }
+
+        Ok(vec)
+    }
+}
@@ -0,0 +1,58 @@
+//! Conversions between Rust scalars and the Java classes that box them (`java.lang.Integer` and friends).
+
+use crate::{
+    java::{
+        self,
+        lang::{BooleanExt, NumberExt},
+    },
+    ops::IntoJava,
+    to_java::ToJavaImpl,
+    Jvm, JvmOp, Local, ToRust,
+};
+
+macro_rules! boxed {
+    ($($rust:ty => $java:ident ($unbox:ident),)*) => {
+        $(
+            /// Boxes the value, so that it can be passed where Java expects an object, like Java's autoboxing.
+            impl IntoJava<java::lang::$java> for $rust {
+                type Output<'jvm> = Local<'jvm, java::lang::$java>;
+
+                fn into_java<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+                    java::lang::$java::value_of(self).assert_not_null().execute_with(jvm)
+                }
+            }
+
+            /// Boxes the value, so that it can be passed as a `java.lang.Object` argument (e.g. to
+            /// `StringBuilder.append`), like Java's autoboxing.
+            impl IntoJava<java::lang::Object> for $rust {
+                type Output<'jvm> = Local<'jvm, java::lang::$java>;
+
+                fn into_java<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+                    <$rust as IntoJava<java::lang::$java>>::into_java(self, jvm)
+                }
+            }
+
+            impl ToJavaImpl<java::lang::$java> for $rust {
+                fn to_java_impl<'jvm>(
+                    rust: &Self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::Result<'jvm, Option<Local<'jvm, java::lang::$java>>> {
+                    Ok(Some(IntoJava::<java::lang::$java>::into_java(*rust, jvm)?))
+                }
+            }
+
+            impl ToRust<$rust> for java::lang::$java {
+                fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, $rust> {
+                    self.$unbox().execute_with(jvm)
+                }
+            }
+        )*
+    };
+}
+
+boxed! {
+    i32 => Integer(int_value),
+    i64 => Long(long_value),
+    f64 => Double(double_value),
+    bool => Boolean(boolean_value),
+}
@@ -0,0 +1,103 @@
+use std::{borrow::Borrow, collections::HashMap, hash::Hash, sync::Mutex};
+
+use crate::{ops::IntoLocal, Global, JavaObject, Jvm, WeakGlobal};
+
+/// Maps Rust keys to Java objects without keeping the objects alive, e.g. to reuse interned strings or configuration
+/// objects that are expensive to create. Each entry holds a [`WeakGlobal`], so an object stays cached as long as some
+/// other reference to it exists, and is created again once it has been garbage collected:
+///
+/// ```rust,ignore
+/// static NAMES: Lazy<JavaObjectCache<String, java::lang::String>> = Lazy::new(JavaObjectCache::new);
+///
+/// let name = NAMES.get_or_insert_with(jvm, key.clone(), key.as_str().to_java())?;
+/// ```
+///
+/// The cache can be shared between threads. Entries whose objects were collected are only removed when their key is
+/// looked up again, or by [`JavaObjectCache::purge`].
+pub struct JavaObjectCache<K, T: JavaObject> {
+    entries: Mutex<HashMap<K, WeakGlobal<T>>>,
+}
+
+impl<K, T> JavaObjectCache<K, T>
+where
+    K: Eq + Hash,
+    T: JavaObject,
+{
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the object cached for `key`, if it is still alive.
+    pub fn get<Q>(&self, key: &Q) -> Option<Global<T>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let mut entries = self.entries.lock().unwrap();
+        let object = entries.get(key)?.upgrade();
+        if object.is_none() {
+            entries.remove(key);
+        }
+        object
+    }
+
+    /// Returns the object cached for `key` if it is still alive, and otherwise executes `op` to create it and caches
+    /// the result.
+    ///
+    /// The cache isn't locked while `op` runs, so `op` can use the cache itself. If two threads miss the same key at
+    /// the same time, both run their `op` and the object created last is the one that stays cached.
+    pub fn get_or_insert_with<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        key: K,
+        op: impl IntoLocal<T>,
+    ) -> crate::Result<'jvm, Global<T>> {
+        if let Some(object) = self.get(&key) {
+            return Ok(object);
+        }
+
+        let object = op.execute_with(jvm)?;
+        let object = jvm.global(&*object);
+        self.entries.lock().unwrap().insert(key, object.downgrade());
+        Ok(object)
+    }
+
+    /// Removes the entry for `key`, returning the cached object if it is still alive.
+    pub fn remove<Q>(&self, key: &Q) -> Option<Global<T>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let weak = self.entries.lock().unwrap().remove(key)?;
+        weak.upgrade()
+    }
+
+    /// Removes the entries whose objects have been garbage collected.
+    pub fn purge(&self) {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|_, weak| weak.upgrade().is_some());
+    }
+
+    /// The number of entries, including those whose objects have been garbage collected but not yet purged.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K, T> Default for JavaObjectCache<K, T>
+where
+    K: Eq + Hash,
+    T: JavaObject,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
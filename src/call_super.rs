@@ -0,0 +1,57 @@
+use std::marker::PhantomData;
+
+use crate::{refs::SuperClass, AsJRef, JavaObject, Jvm, JvmOp, Local, Nullable, TryJDeref};
+
+/// [`JvmOp`][] that produces a [`SuperRef`], see [`JvmOp::call_super`].
+#[derive(Clone)]
+pub struct CallSuper<This, S> {
+    this: This,
+    phantom: PhantomData<S>,
+}
+
+impl<This, S> CallSuper<This, S>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<S>,
+    S: JavaObject,
+{
+    pub(crate) fn new(this: This) -> Self {
+        Self {
+            this,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<This, S> JvmOp for CallSuper<This, S>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<S>,
+    S: JavaObject,
+{
+    type Output<'jvm> = SuperRef<'jvm, S>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let this = self.this.execute_with(jvm)?;
+        let this = jvm.local(this.as_jref()?);
+        Ok(SuperRef { this })
+    }
+}
+
+/// A reference to an object whose methods are invoked nonvirtually, i.e. calling the implementation declared by `S`
+/// (or inherited by it) even if the object's class overrides them. Created by [`JvmOp::call_super`].
+pub struct SuperRef<'jvm, S: JavaObject> {
+    this: Local<'jvm, S>,
+}
+
+impl<S: JavaObject> TryJDeref for SuperRef<'_, S> {
+    type Java = S;
+
+    fn try_jderef(&self) -> Nullable<&S> {
+        Ok(&self.this)
+    }
+
+    fn super_class(&self) -> Option<SuperClass> {
+        Some(S::class)
+    }
+}
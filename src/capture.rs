@@ -0,0 +1,335 @@
+//! Redirecting Java's `System.out` and `System.err` to Rust writers, see [`Jvm::capture_stdout`].
+
+use std::{
+    ffi::c_void,
+    io::{self, Write},
+    sync::{Mutex, PoisonError},
+};
+
+use once_cell::sync::OnceCell;
+
+use crate::{
+    error::check_exception,
+    find::find_constructor,
+    java::{
+        self,
+        io::{OutputStreamExt, PrintStream, PrintStreamExt},
+        lang::Throwable,
+    },
+    jvm::JavaObjectExt,
+    raw::{HasEnvPtr, MethodPtr, ObjectPtr},
+    Error, Global, Jvm, JvmOp, Local, NativeMethod,
+};
+
+/// The class file of `duchess.RustOutputStream`, compiled from `capture/RustOutputStream.java`. It isn't on the
+/// classpath, so it is defined with `DefineClass` the first time output is captured.
+const CLASS_FILE: &[u8] = include_bytes!("capture/RustOutputStream.class");
+
+/// The Rust writer a `RustOutputStream` writes to. The stream holds a pointer to it in its `sink` field.
+type Sink = Mutex<Box<dyn Write + Send>>;
+
+/// Replaces `System.out` or `System.err` with a stream that writes to a Rust [`io::Write`], until it is dropped or
+/// [restored](OutputCapture::restore). Created by [`Jvm::capture_stdout`] and [`Jvm::capture_stderr`].
+///
+/// Restoring puts back the stream that was replaced, then closes the capturing stream, which flushes the writer and
+/// drops it. Java code that kept a reference to `System.out` from before the capture keeps writing to the original
+/// stream. As with [`CloseableGuard`](crate::CloseableGuard), dropping the guard outside of [`Jvm::with`] attaches the
+/// current thread if needed, and errors are logged and discarded.
+#[must_use = "the original stream is restored as soon as the guard is dropped"]
+pub struct OutputCapture {
+    stream: StandardStream,
+    /// The stream that was replaced, which may be `null`.
+    previous: Option<Global<PrintStream>>,
+    /// `None` once the original stream has been restored.
+    capture: Option<Global<PrintStream>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum StandardStream {
+    Out,
+    Err,
+}
+
+impl StandardStream {
+    fn get<'jvm>(
+        self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, PrintStream>>> {
+        match self {
+            StandardStream::Out => java::lang::System::get_out().execute_with(jvm),
+            StandardStream::Err => java::lang::System::get_err().execute_with(jvm),
+        }
+    }
+
+    fn set<'jvm>(
+        self,
+        stream: &Option<Global<PrintStream>>,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, ()> {
+        match self {
+            StandardStream::Out => java::lang::System::set_out(stream).execute_with(jvm),
+            StandardStream::Err => java::lang::System::set_err(stream).execute_with(jvm),
+        }
+    }
+}
+
+impl<'jvm> Jvm<'jvm> {
+    /// Redirects Java's `System.out` to `writer` until the returned guard is dropped, e.g. to interleave it properly
+    /// with Rust's output, or to forward it to Rust logging:
+    ///
+    /// ```rust,ignore
+    /// let capture = jvm.capture_stdout(std::io::stdout())?;
+    /// ```
+    ///
+    /// Java text is encoded as UTF-8. `println` flushes the writer; other output is flushed when Java calls `flush()`
+    /// or the capture ends. Errors returned by the writer are thrown to the Java code as `IOException`s, which
+    /// `PrintStream` records for its `checkError()` method rather than throwing.
+    pub fn capture_stdout(
+        &mut self,
+        writer: impl Write + Send + 'static,
+    ) -> crate::Result<'jvm, OutputCapture> {
+        capture(self, StandardStream::Out, Box::new(writer))
+    }
+
+    /// Redirects Java's `System.err` to `writer` until the returned guard is dropped, see [`Jvm::capture_stdout`].
+    pub fn capture_stderr(
+        &mut self,
+        writer: impl Write + Send + 'static,
+    ) -> crate::Result<'jvm, OutputCapture> {
+        capture(self, StandardStream::Err, Box::new(writer))
+    }
+}
+
+impl OutputCapture {
+    /// Puts back the stream that was replaced, and closes the capturing stream, which flushes and drops the writer.
+    pub fn restore<'jvm>(mut self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, ()> {
+        match self.capture.take() {
+            Some(capture) => restore(self.stream, &self.previous, &capture, jvm),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for OutputCapture {
+    fn drop(&mut self) {
+        let Some(capture) = self.capture.take() else {
+            return;
+        };
+        capture.with_env("restore captured output", |env| {
+            if let Err(err) = restore(self.stream, &self.previous, &capture, &mut Jvm(env)) {
+                tracing::warn!(?err, stream = ?self.stream, "error restoring captured output when dropped");
+            }
+        });
+    }
+}
+
+fn restore<'jvm>(
+    stream: StandardStream,
+    previous: &Option<Global<PrintStream>>,
+    capture: &Global<PrintStream>,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, ()> {
+    stream.set(previous, jvm)?;
+    PrintStreamExt::close(capture).execute_with(jvm)
+}
+
+fn capture<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    stream: StandardStream,
+    writer: Box<dyn Write + Send>,
+) -> crate::Result<'jvm, OutputCapture> {
+    let output = new_output_stream(jvm, writer)?;
+    let result = PrintStream::new(&output, true, "UTF-8").execute_with(jvm);
+    let print_stream = close_on_error(jvm, &output, result)?;
+
+    let capture = Some(jvm.global(&*print_stream));
+    let result = stream.get(jvm).and_then(|previous| {
+        let previous = previous.map(|previous| jvm.global(&*previous));
+        stream.set(&capture, jvm)?;
+        Ok(previous)
+    });
+    let previous = close_on_error(jvm, print_stream.as_ref(), result)?;
+    Ok(OutputCapture {
+        stream,
+        previous,
+        capture,
+    })
+}
+
+/// Closes `output` if `result` is an error, which frees the writer of the `RustOutputStream` it writes to, and returns
+/// `result`. An error closing the stream is logged, so that the original error is returned.
+fn close_on_error<'jvm, T>(
+    jvm: &mut Jvm<'jvm>,
+    output: &java::io::OutputStream,
+    result: crate::Result<'jvm, T>,
+) -> crate::Result<'jvm, T> {
+    if result.is_err() {
+        if let Err(err) = output.close().execute_with(jvm) {
+            tracing::warn!(?err, "error closing the stream of a failed output capture");
+        }
+    }
+    result
+}
+
+/// Creates a `RustOutputStream` that owns `writer`.
+fn new_output_stream<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    writer: Box<dyn Write + Send>,
+) -> crate::Result<'jvm, Local<'jvm, java::io::OutputStream>> {
+    static CONSTRUCTOR: OnceCell<MethodPtr> = OnceCell::new();
+
+    let class = output_stream_class(jvm)?;
+    let constructor = CONSTRUCTOR.get_or_try_init(|| find_constructor(jvm, class, c"(J)V"))?;
+
+    let sink: *mut Sink = Box::into_raw(Box::new(Mutex::new(writer)));
+    let env = jvm.env();
+    let obj = unsafe {
+        env.invoke(
+            |env| env.NewObjectA,
+            |env, f| {
+                f(
+                    env,
+                    class.as_raw().as_ptr(),
+                    constructor.as_ptr(),
+                    [jni_sys::jvalue {
+                        j: sink as jni_sys::jlong,
+                    }]
+                    .as_ptr(),
+                )
+            },
+        )
+    };
+    match ObjectPtr::new(obj) {
+        // SAFETY: NewObjectA returns a new local reference to a `RustOutputStream`, which is an `OutputStream`
+        Some(obj) => Ok(unsafe { Local::from_raw(env, obj) }),
+        None => {
+            // SAFETY: the stream wasn't created, so nothing else refers to the sink
+            drop(unsafe { Box::from_raw(sink) });
+            check_exception(jvm)?;
+            Err(Error::JvmInternal(
+                "failed to create `duchess.RustOutputStream`".to_string(),
+            ))
+        }
+    }
+}
+
+/// Defines `duchess.RustOutputStream` and binds its native methods, the first time it is needed.
+fn output_stream_class<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, &'static Global<java::lang::Class>> {
+    static CLASS: OnceCell<Global<java::lang::Class>> = OnceCell::new();
+
+    CLASS.get_or_try_init(|| {
+        let env = jvm.env();
+        // Defined by the bootstrap class loader, so it is found no matter which class loader is configured. It only
+        // refers to JDK classes.
+        let class = unsafe {
+            env.invoke(
+                |env| env.DefineClass,
+                |env, f| {
+                    f(
+                        env,
+                        c"duchess/RustOutputStream".as_ptr(),
+                        std::ptr::null_mut(),
+                        CLASS_FILE.as_ptr().cast(),
+                        CLASS_FILE.len() as jni_sys::jsize,
+                    )
+                },
+            )
+        };
+        let Some(class) = ObjectPtr::new(class) else {
+            check_exception(jvm)?;
+            return Err(Error::JvmInternal(
+                "failed to define `duchess.RustOutputStream`".to_string(),
+            ));
+        };
+        // SAFETY: DefineClass returns a new local reference to the class
+        let class: Local<'jvm, java::lang::Class> = unsafe { Local::from_raw(env, class) };
+
+        // SAFETY: the functions match the descriptors
+        let methods = unsafe {
+            [
+                NativeMethod::new(c"write", c"(J[BII)V", write as *const c_void),
+                NativeMethod::new(c"flush", c"(J)V", flush as *const c_void),
+                NativeMethod::new(c"release", c"(J)V", release as *const c_void),
+            ]
+        };
+        jvm.register_natives_on(&class, &methods)?;
+        Ok(jvm.global(&*class))
+    })
+}
+
+/// Converts an error returned by the writer to an `IOException` to throw to Java.
+fn io_exception<'jvm>(jvm: &mut Jvm<'jvm>, error: io::Error) -> Error<Local<'jvm, Throwable>> {
+    match java::io::IOException::new(error.to_string().as_str()).execute_with(jvm) {
+        Ok(exception) => Error::Thrown(exception.upcast::<java::lang::Exception>().upcast()),
+        Err(error) => error,
+    }
+}
+
+/// Returns the writer that `sink` points to.
+///
+/// # Safety
+///
+/// `sink` must be the pointer held by a `RustOutputStream` that hasn't been released. The stream's methods are
+/// `synchronized`, so it can't be released while in use.
+unsafe fn sink<'a>(sink: jni_sys::jlong) -> &'a Sink {
+    unsafe { &*(sink as *const Sink) }
+}
+
+extern "system" fn write(
+    env: *mut jni_sys::JNIEnv,
+    _class: jni_sys::jclass,
+    sink_ptr: jni_sys::jlong,
+    bytes: jni_sys::jobject,
+    offset: jni_sys::jint,
+    len: jni_sys::jint,
+) {
+    unsafe {
+        Jvm::native(env, |jvm| {
+            // The Java side checked that the range is in bounds
+            let mut buffer = vec![0_u8; len as usize];
+            jvm.env().invoke(
+                |env| env.GetByteArrayRegion,
+                |env, f| f(env, bytes, offset, len, buffer.as_mut_ptr().cast()),
+            );
+            check_exception(jvm)?;
+
+            let result = sink(sink_ptr)
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .write_all(&buffer);
+            result.map_err(|error| io_exception(jvm, error))
+        })
+    }
+}
+
+extern "system" fn flush(
+    env: *mut jni_sys::JNIEnv,
+    _class: jni_sys::jclass,
+    sink_ptr: jni_sys::jlong,
+) {
+    unsafe {
+        Jvm::native(env, |jvm| {
+            let result = sink(sink_ptr)
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .flush();
+            result.map_err(|error| io_exception(jvm, error))
+        })
+    }
+}
+
+extern "system" fn release(
+    env: *mut jni_sys::JNIEnv,
+    _class: jni_sys::jclass,
+    sink_ptr: jni_sys::jlong,
+) {
+    unsafe {
+        Jvm::native(env, |_jvm| {
+            // SAFETY: `close()` clears the stream's pointer before releasing it, so this is the last use
+            drop(Box::from_raw(sink_ptr as *mut Sink));
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,62 @@
+package duchess;
+
+import java.io.IOException;
+import java.io.OutputStream;
+
+/**
+ * An {@code OutputStream} that writes to a Rust {@code std::io::Write}, which duchess defines at runtime to redirect
+ * {@code System.out} and {@code System.err}. {@code sink} points to the Rust writer, which is freed when the stream is
+ * closed.
+ */
+public final class RustOutputStream extends OutputStream {
+    private long sink;
+
+    RustOutputStream(long sink) {
+        this.sink = sink;
+    }
+
+    @Override
+    public synchronized void write(int b) throws IOException {
+        write(open(), new byte[] { (byte) b }, 0, 1);
+    }
+
+    @Override
+    public synchronized void write(byte[] b, int off, int len) throws IOException {
+        if (off < 0 || len < 0 || len > b.length - off) {
+            throw new IndexOutOfBoundsException();
+        }
+        write(open(), b, off, len);
+    }
+
+    @Override
+    public synchronized void flush() throws IOException {
+        flush(open());
+    }
+
+    @Override
+    public synchronized void close() throws IOException {
+        if (sink == 0) {
+            return;
+        }
+        long s = sink;
+        sink = 0;
+        try {
+            flush(s);
+        } finally {
+            release(s);
+        }
+    }
+
+    private long open() throws IOException {
+        if (sink == 0) {
+            throw new IOException("Stream closed");
+        }
+        return sink;
+    }
+
+    private static native void write(long sink, byte[] b, int off, int len) throws IOException;
+
+    private static native void flush(long sink) throws IOException;
+
+    private static native void release(long sink);
+}
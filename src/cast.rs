@@ -1,7 +1,11 @@
 use std::marker::PhantomData;
 
 use crate::{
-    jvm::JavaObjectExt, raw::HasEnvPtr, refs::AsJRef, JavaObject, JvmOp, Local, TryJDeref,
+    java::{self, lang::ClassExt},
+    jvm::JavaObjectExt,
+    raw::HasEnvPtr,
+    refs::AsJRef,
+    Error, JavaObject, Jvm, JvmOp, Local, TryJDeref,
 };
 
 /// A trait to represent safe upcast operations for a [`JavaObject`].
@@ -40,6 +44,24 @@ where
             _marker: PhantomData,
         }
     }
+
+    /// Passes the downcast object to `f` if the downcast succeeds, all as part of the same operation. If it fails,
+    /// the original object is returned as the error, like for `try_downcast` itself:
+    ///
+    /// ```rust,ignore
+    /// let message: Result<String, _> = exception
+    ///     .try_downcast::<auth::AuthorizationExceptionDenied>()
+    ///     .map_java(|denied, jvm| denied.user_message().assert_not_null().to_rust().execute_with(jvm))
+    ///     .execute_with(jvm)?;
+    /// ```
+    ///
+    /// This shadows [`JvmOp::map_java`], which would pass the `Result` of the downcast to `f` instead.
+    pub fn map_java<F, R>(self, f: F) -> MapDowncast<J, To, F>
+    where
+        F: for<'jvm> FnOnce(Local<'jvm, To>, &mut Jvm<'jvm>) -> crate::Result<'jvm, R>,
+    {
+        MapDowncast { downcast: self, f }
+    }
 }
 
 impl<J, To> JvmOp for TryDowncast<J, To>
@@ -65,7 +87,7 @@ where
             env.invoke(
                 |env| env.IsInstanceOf,
                 |env, f| f(env, instance_raw.as_ptr(), class_raw.as_ptr()),
-            ) == jni_sys::JNI_TRUE
+            ) != jni_sys::JNI_FALSE
         };
 
         if is_inst {
@@ -78,6 +100,218 @@ where
     }
 }
 
+/// [`JvmOp`][] that downcasts the result of an operation, throwing a `ClassCastException` if it isn't a `To`, see
+/// [`JvmOp::returning`].
+pub struct Returning<J, To> {
+    op: J,
+    _marker: PhantomData<To>,
+}
+
+impl<J: Clone, To> Clone for Returning<J, To> {
+    fn clone(&self) -> Self {
+        Self {
+            op: self.op.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<J, To> Returning<J, To>
+where
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: TryJDeref,
+    To: for<'jvm> Upcast<<J::Output<'jvm> as TryJDeref>::Java>,
+{
+    pub(crate) fn new(op: J) -> Self {
+        Self {
+            op,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<J, To> JvmOp for Returning<J, To>
+where
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: TryJDeref,
+    To: for<'jvm> Upcast<<J::Output<'jvm> as TryJDeref>::Java>,
+{
+    type Output<'jvm> = Option<Local<'jvm, To>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let instance = self.op.execute_with(jvm)?;
+        let Ok(instance) = instance.try_jderef() else {
+            return Ok(None);
+        };
+
+        if let Some(casted) = downcast_to::<_, To>(instance, jvm)? {
+            return Ok(Some(casted));
+        }
+
+        // `Class.cast` throws the same `ClassCastException` as a cast in Java would, naming both classes
+        let class = To::class(jvm)?;
+        // SAFETY: every Java object is an instance of `Object`
+        let object = unsafe { java::lang::Object::from_raw(instance.as_raw()) };
+        class.cast(object).execute_with(jvm)?;
+        Err(Error::JvmInternal(
+            "`Class.cast` accepted an object that isn't an instance of the class".to_string(),
+        ))
+    }
+}
+
+/// [`JvmOp`][] that passes the result of a successful downcast to a closure, see [`TryDowncast::map_java`].
+pub struct MapDowncast<J, To, F> {
+    downcast: TryDowncast<J, To>,
+    f: F,
+}
+
+impl<J, To, F, R> JvmOp for MapDowncast<J, To, F>
+where
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: TryJDeref,
+    To: for<'jvm> Upcast<<J::Output<'jvm> as TryJDeref>::Java>,
+    F: for<'jvm> FnOnce(Local<'jvm, To>, &mut Jvm<'jvm>) -> crate::Result<'jvm, R>,
+{
+    type Output<'jvm> = Result<R, J::Output<'jvm>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        match self.downcast.execute_with(jvm)? {
+            Ok(instance) => Ok(Ok((self.f)(instance, jvm)?)),
+            Err(instance) => Ok(Err(instance)),
+        }
+    }
+}
+
+/// Tuples of classes to try downcasting to in order, see [`JvmOp::try_downcast_one_of`].
+pub trait DowncastTargets<From: JavaObject> {
+    /// The result of the downcast, where `T` is the original object.
+    type Output<'jvm, T>;
+
+    /// Returns the first class in the tuple that `instance` is an instance of, or `instance` itself if there is none.
+    fn downcast<'jvm, T>(
+        instance: T,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Self::Output<'jvm, T>>
+    where
+        T: TryJDeref<Java = From>;
+}
+
+/// Checks whether `instance` is an instance of `To`, and if so returns a new local reference to it as a `To`.
+pub(crate) fn downcast_to<'jvm, From, To>(
+    instance: &From,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Option<Local<'jvm, To>>>
+where
+    From: JavaObject,
+    To: Upcast<From>,
+{
+    let class = To::class(jvm)?;
+    let env = jvm.env();
+    let is_inst = unsafe {
+        env.invoke(
+            |env| env.IsInstanceOf,
+            |env, f| f(env, instance.as_raw().as_ptr(), class.as_raw().as_ptr()),
+        ) != jni_sys::JNI_FALSE
+    };
+
+    if is_inst {
+        // SAFETY: just shown that jobject instanceof To::class
+        let casted = unsafe { std::mem::transmute::<&From, &To>(instance) };
+        Ok(Some(jvm.local(casted)))
+    } else {
+        Ok(None)
+    }
+}
+
+macro_rules! downcast_targets {
+    ($($enum:ident { $($ty:ident => $variant:ident,)* })*) => {
+        $(
+            /// The result of [`JvmOp::try_downcast_one_of`]: the object downcast to the first class in the tuple that
+            /// it is an instance of, or the original object if it isn't an instance of any of them.
+            pub enum $enum<'jvm, $($ty: JavaObject,)* T> {
+                $($variant(Local<'jvm, $ty>),)*
+                Other(T),
+            }
+
+            impl<From, $($ty,)*> DowncastTargets<From> for ($($ty,)*)
+            where
+                From: JavaObject,
+                $($ty: Upcast<From>,)*
+            {
+                type Output<'jvm, T> = $enum<'jvm, $($ty,)* T>;
+
+                fn downcast<'jvm, T>(instance: T, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm, T>>
+                where
+                    T: TryJDeref<Java = From>,
+                {
+                    let object = instance.try_jderef()?;
+                    $(
+                        if let Some(object) = downcast_to::<From, $ty>(object, jvm)? {
+                            return Ok($enum::$variant(object));
+                        }
+                    )*
+                    Ok($enum::Other(instance))
+                }
+            }
+        )*
+    };
+}
+
+downcast_targets! {
+    OneOf2 { A => First, B => Second, }
+    OneOf3 { A => First, B => Second, C => Third, }
+    OneOf4 { A => First, B => Second, C => Third, D => Fourth, }
+    OneOf5 { A => First, B => Second, C => Third, D => Fourth, E => Fifth, }
+    OneOf6 { A => First, B => Second, C => Third, D => Fourth, E => Fifth, F => Sixth, }
+}
+
+/// [`JvmOp`][] that downcasts an object to the first of several classes that it is an instance of, see
+/// [`JvmOp::try_downcast_one_of`].
+pub struct TryDowncastOneOf<J, Targets> {
+    op: J,
+    _marker: PhantomData<Targets>,
+}
+
+impl<J: Clone, Targets> Clone for TryDowncastOneOf<J, Targets> {
+    fn clone(&self) -> Self {
+        Self {
+            op: self.op.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<J, Targets> TryDowncastOneOf<J, Targets>
+where
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: TryJDeref,
+    Targets: for<'jvm> DowncastTargets<<J::Output<'jvm> as TryJDeref>::Java>,
+{
+    pub(crate) fn new(op: J) -> Self {
+        Self {
+            op,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<J, Targets> JvmOp for TryDowncastOneOf<J, Targets>
+where
+    J: JvmOp,
+    for<'jvm> J::Output<'jvm>: TryJDeref,
+    Targets: for<'jvm> DowncastTargets<<J::Output<'jvm> as TryJDeref>::Java>,
+{
+    type Output<'jvm> = <Targets as DowncastTargets<<J::Output<'jvm> as TryJDeref>::Java>>::Output<
+        'jvm,
+        J::Output<'jvm>,
+    >;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let instance = self.op.execute_with(jvm)?;
+        Targets::downcast(instance, jvm)
+    }
+}
+
 pub struct AsUpcast<J, To> {
     op: J,
     _marker: PhantomData<To>,
@@ -129,7 +363,7 @@ where
                 jvm.env().invoke(
                     |env| env.IsInstanceOf,
                     |env, f| f(env, instance_raw.as_ptr(), class_raw.as_ptr()),
-                ) == jni_sys::JNI_TRUE
+                ) != jni_sys::JNI_FALSE
             });
         }
 
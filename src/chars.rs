@@ -0,0 +1,191 @@
+//! Java `char[]`s, which APIs handling secrets such as passwords take instead of a `String` so that the characters
+//! can be overwritten once they are no longer needed. A Java `char` is a UTF-16 code unit, bound as `u16`, so a
+//! `char[]` is a `JavaArray<u16>`; the conversions here go between that and Rust `char`s, which may take two of them.
+
+use crate::{
+    array::{JavaArray, JavaArrayExt},
+    error::check_exception,
+    java,
+    jvm::JavaObjectExt,
+    plumbing::HasEnvPtr,
+    str::decode_utf16_into,
+    to_java::ToJavaImpl,
+    AsJRef, Error, Jvm, JvmOp, Local, ToRust, ToRustInto, VoidMethod,
+};
+
+/// Overwrites `buffer` with zeros in a way the compiler can't optimize away, even though it isn't read again.
+fn zero(buffer: &mut [u16]) {
+    for unit in buffer.iter_mut() {
+        // SAFETY: `unit` is a valid, aligned reference
+        unsafe { std::ptr::write_volatile(unit, 0) };
+    }
+    std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Like [`zero`], for the chars decoded so far when the rest of the array fails to decode.
+fn zero_chars(buffer: &mut [char]) {
+    for c in buffer.iter_mut() {
+        // SAFETY: `c` is a valid, aligned reference
+        unsafe { std::ptr::write_volatile(c, '\0') };
+    }
+    std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Encodes the characters as UTF-16, so characters outside the Basic Multilingual Plane become surrogate pairs, and
+/// copies them into a new `char[]` without going through a Java `String`. The intermediate buffer is zeroed.
+impl JvmOp for &[char] {
+    type Output<'jvm> = Local<'jvm, JavaArray<u16>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        // Sized exactly, so that `utf16` never reallocates and leaves an unzeroed copy behind
+        let mut utf16 = Vec::with_capacity(self.iter().map(|c| c.len_utf16()).sum());
+        let mut units = [0; 2];
+        for c in self {
+            utf16.extend_from_slice(c.encode_utf16(&mut units));
+        }
+        zero(&mut units);
+
+        let array = utf16.as_slice().execute_with(jvm);
+        zero(&mut utf16);
+        array
+    }
+}
+
+impl JvmOp for &Vec<char> {
+    type Output<'jvm> = Local<'jvm, JavaArray<u16>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        self.as_slice().execute_with(jvm)
+    }
+}
+
+impl JvmOp for Vec<char> {
+    type Output<'jvm> = Local<'jvm, JavaArray<u16>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        self.as_slice().execute_with(jvm)
+    }
+}
+
+impl ToJavaImpl<java::Array<u16>> for [char] {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::Array<u16>>>> {
+        Ok(Some(rust.execute_with(jvm)?))
+    }
+}
+
+impl ToJavaImpl<java::Array<u16>> for Vec<char> {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::Array<u16>>>> {
+        Ok(Some(rust.execute_with(jvm)?))
+    }
+}
+
+/// Decodes the UTF-16 code units of the array, failing on an unpaired surrogate. The intermediate buffer is zeroed.
+impl ToRust<Vec<char>> for JavaArray<u16> {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Vec<char>> {
+        let mut utf16 = Vec::new();
+        ToRustInto::<Vec<u16>>::to_rust_into(self, jvm, &mut utf16)?;
+        // There are at most as many chars as code units, so `chars` never reallocates either
+        let mut chars = Vec::with_capacity(utf16.len());
+        let mut unpaired = None;
+        for c in char::decode_utf16(utf16.iter().copied()) {
+            match c {
+                Ok(c) => chars.push(c),
+                Err(e) => {
+                    unpaired = Some(e.unpaired_surrogate());
+                    break;
+                }
+            }
+        }
+        zero(&mut utf16);
+
+        match unpaired {
+            None => Ok(chars),
+            Some(unit) => {
+                zero_chars(&mut chars);
+                Err(Error::Conversion(format!(
+                    "Java char[] contained an unpaired surrogate: {unit:#06x}"
+                )))
+            }
+        }
+    }
+}
+
+impl ToRust<String> for JavaArray<u16> {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, String> {
+        let mut string = String::new();
+        ToRustInto::<String>::to_rust_into(self, jvm, &mut string)?;
+        Ok(string)
+    }
+}
+
+/// Decodes the UTF-16 code units of the array like `new String(chars)`, reusing the buffer of the target `String`.
+/// The intermediate buffer is zeroed.
+impl ToRustInto<String> for JavaArray<u16> {
+    fn to_rust_into<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        target: &mut String,
+    ) -> crate::Result<'jvm, ()> {
+        let mut utf16 = Vec::new();
+        ToRustInto::<Vec<u16>>::to_rust_into(self, jvm, &mut utf16)?;
+        let result = decode_utf16_into("char[]", &utf16, target);
+        zero(&mut utf16);
+        result
+    }
+}
+
+/// Extension trait for `char[]`s holding secrets, see [`zero`](CharArrayExt::zero).
+pub trait CharArrayExt: JvmOp {
+    type Zero: VoidMethod;
+
+    /// Overwrites every element of the array with `'\0'`, like `Arrays.fill(chars, '\0')`, so that a password isn't
+    /// left in the heap once it has been used.
+    fn zero(self) -> Self::Zero;
+}
+
+impl<This> CharArrayExt for This
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<u16>>,
+{
+    type Zero = Zero<Self>;
+
+    fn zero(self) -> Self::Zero {
+        Zero { this: self }
+    }
+}
+
+#[derive(Clone)]
+pub struct Zero<This> {
+    this: This,
+}
+
+impl<This> JvmOp for Zero<This>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<u16>>,
+{
+    type Output<'jvm> = ();
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let this = self.this.execute_with(jvm)?;
+        let this = this.as_jref()?;
+        let len = this.length().execute_with(jvm)?;
+        let zeros = vec![0; len as usize];
+
+        // SAFETY: `zeros` has the `len` elements that are copied into the array
+        unsafe {
+            jvm.env().invoke(
+                |env| env.SetCharArrayRegion,
+                |env, f| f(env, this.as_raw().as_ptr(), 0, len, zeros.as_ptr()),
+            );
+        }
+        check_exception(jvm)
+    }
+}
@@ -0,0 +1,72 @@
+use std::marker::PhantomData;
+
+use crate::{
+    cast::{downcast_to, Upcast},
+    java::lang::Throwable,
+    Global, Jvm, JvmOp,
+};
+
+/// The exceptions declared in the `throws` clause of a method marked `#[checked]`, as an enum with a variant holding
+/// each of them. The enum is generated along with the method's bindings, see [`CatchChecked`].
+pub trait CheckedException: Sized {
+    /// Returns the variant of the first class in the `throws` clause that `exception` is an instance of, or `None` if
+    /// it isn't an instance of any of them, e.g. because it is a `RuntimeException`.
+    fn from_thrown<'jvm>(
+        exception: &Throwable,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Self>>;
+}
+
+/// Executes an operation, returning the exceptions declared by the method it calls as an `Err` of the enum `E`
+/// instead of an [`Error::Thrown`](crate::Error::Thrown). Created by the `_checked` variant that is generated for
+/// methods marked `#[checked]`, e.g. `auth.authenticate_checked(&request)`.
+///
+/// Other exceptions, like a `NullPointerException` thrown by the method, are still returned as
+/// [`Error::Thrown`](crate::Error::Thrown).
+#[derive(Clone)]
+pub struct CatchChecked<J, E> {
+    op: J,
+    phantom: PhantomData<E>,
+}
+
+impl<J, E> CatchChecked<J, E>
+where
+    J: JvmOp,
+    E: CheckedException,
+{
+    pub fn new(op: J) -> Self {
+        Self {
+            op,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<J, E> JvmOp for CatchChecked<J, E>
+where
+    J: JvmOp,
+    E: CheckedException,
+{
+    type Output<'jvm> = Result<J::Output<'jvm>, E>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        match self.op.execute_with(jvm) {
+            Ok(output) => Ok(Ok(output)),
+            Err(crate::Error::Thrown(exception)) => match E::from_thrown(&exception, jvm)? {
+                Some(error) => Ok(Err(error)),
+                None => Err(crate::Error::Thrown(exception)),
+            },
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Returns a global reference to `exception` as a `T` if it is an instance of `T`. Used by the generated
+/// [`CheckedException`] impls, which only use it with the exception classes of a `throws` clause.
+pub fn global_if_instance<'jvm, T: Upcast<Throwable>>(
+    exception: &Throwable,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Option<Global<T>>> {
+    let exception = downcast_to::<Throwable, T>(exception, jvm)?;
+    Ok(exception.map(|exception| jvm.global::<T>(&exception)))
+}
@@ -0,0 +1,84 @@
+use std::ops::Deref;
+
+use crate::{
+    cast::Upcast,
+    java::lang::{AutoCloseable, AutoCloseableExt},
+    Global, Jvm, JvmOp,
+};
+
+/// Owns a Java `AutoCloseable`, like a stream or a connection, and closes it when dropped, like a
+/// `try (var resource = ...) { ... }` block in Java. Created by [`Jvm::closeable`].
+///
+/// `close()` can throw, which `Drop` can't report, so call [`CloseableGuard::close`] to handle the error; when the
+/// guard is dropped instead, an exception thrown by `close()` is logged and discarded. The object is held as a global
+/// reference, so the guard can outlive the [`Jvm`] it was created with, and dropping it outside of [`Jvm::with`]
+/// attaches the current thread to close the object if needed.
+#[must_use = "the object is closed as soon as the guard is dropped"]
+pub struct CloseableGuard<T: Upcast<AutoCloseable>> {
+    /// `None` once the object has been closed.
+    object: Option<Global<T>>,
+}
+
+impl<'jvm> Jvm<'jvm> {
+    /// Wraps `object` in a [`CloseableGuard`] that calls its `close()` method when dropped:
+    ///
+    /// ```rust,ignore
+    /// let stream = jvm.closeable(&*stream);
+    /// let mut reader = JavaReader::new(jvm, &*stream)?;
+    /// ```
+    pub fn closeable<T>(&mut self, object: &T) -> CloseableGuard<T>
+    where
+        T: Upcast<AutoCloseable>,
+    {
+        CloseableGuard {
+            object: Some(self.global(object)),
+        }
+    }
+}
+
+impl<T: Upcast<AutoCloseable>> CloseableGuard<T> {
+    /// Closes the object now, returning the exception if `close()` throws.
+    pub fn close<'jvm>(mut self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, ()> {
+        match self.object.take() {
+            Some(object) => close(&object, jvm),
+            None => Ok(()),
+        }
+    }
+
+    /// Releases the object without closing it.
+    pub fn into_inner(mut self) -> Global<T> {
+        self.object
+            .take()
+            .expect("object is only taken when the guard is consumed")
+    }
+}
+
+fn close<'jvm, T: Upcast<AutoCloseable>>(
+    object: &Global<T>,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, ()> {
+    AutoCloseableExt::close(object).execute_with(jvm)
+}
+
+impl<T: Upcast<AutoCloseable>> Deref for CloseableGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.object
+            .as_ref()
+            .expect("object is only taken when the guard is consumed")
+    }
+}
+
+impl<T: Upcast<AutoCloseable>> Drop for CloseableGuard<T> {
+    fn drop(&mut self) {
+        let Some(object) = self.object.take() else {
+            return;
+        };
+        object.with_env("close AutoCloseable", |env| {
+            if let Err(err) = close(&object, &mut Jvm(env)) {
+                tracing::warn!(?err, "exception closing an AutoCloseable when dropped");
+            }
+        });
+    }
+}
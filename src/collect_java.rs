@@ -0,0 +1,232 @@
+//! Building Java collections from Rust iterators, see [`CollectJava::collect_java`].
+
+use std::marker::PhantomData;
+
+use once_cell::sync::OnceCell;
+
+use crate::{
+    cast::Upcast,
+    find::find_constructor,
+    java::{
+        self,
+        util::{ListExt, MapExt, SetExt},
+    },
+    jvm::JavaObjectExt,
+    plumbing::{check_exception, HasEnvPtr},
+    raw::{MethodPtr, ObjectPtr},
+    to_java::ToJavaImpl,
+    Error, JavaObject, Jvm, JvmOp, Local,
+};
+
+/// Extension trait for consuming a Rust iterator into a new Java collection.
+pub trait CollectJava: Iterator + Sized {
+    /// Returns a [`JvmOp`] that creates a Java collection `C` and adds each item of the iterator to it, converting
+    /// the items with [`ToJava`](crate::ToJava). For example, `names.iter().collect_java::<java::util::ArrayList<
+    /// java::lang::String>>()` builds a list of strings, and an iterator of `(key, value)` pairs can be collected
+    /// into a `java::util::HashMap`.
+    ///
+    /// If the iterator knows its exact length, like the iterator of a `Vec` or a `HashMap`, the collection is created
+    /// with room for all of the items, so that it doesn't have to grow or rehash while they are added.
+    ///
+    /// The local reference to each converted item is deleted once it has been added, so collecting a long iterator
+    /// doesn't exhaust the local references available to a native method. The operation stops at the first item that
+    /// fails to convert, reporting its position in the iterator with [`Error::ElementConversion`].
+    fn collect_java<C>(self) -> CollectJavaOp<Self, C>
+    where
+        C: FromRustIterator<Self::Item>;
+}
+
+impl<I: Iterator> CollectJava for I {
+    fn collect_java<C>(self) -> CollectJavaOp<Self, C>
+    where
+        C: FromRustIterator<Self::Item>,
+    {
+        CollectJavaOp {
+            iter: self,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Java collections that can be built from an iterator of Rust values with [`CollectJava::collect_java`].
+pub trait FromRustIterator<T>: JavaObject {
+    fn from_rust_iter<'jvm>(
+        iter: impl Iterator<Item = T>,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Local<'jvm, Self>>;
+}
+
+/// [`JvmOp`][] that builds a Java collection from a Rust iterator, see [`CollectJava::collect_java`].
+pub struct CollectJavaOp<I, C> {
+    iter: I,
+    phantom: PhantomData<C>,
+}
+
+impl<I, C> JvmOp for CollectJavaOp<I, C>
+where
+    I: Iterator,
+    C: FromRustIterator<I::Item>,
+{
+    type Output<'jvm> = Local<'jvm, C>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        C::from_rust_iter(self.iter, jvm)
+    }
+}
+
+impl<T, JE> FromRustIterator<T> for java::util::ArrayList<JE>
+where
+    T: ToJavaImpl<JE>,
+    JE: Upcast<java::lang::Object> + Upcast<JE>,
+{
+    fn from_rust_iter<'jvm>(
+        iter: impl Iterator<Item = T>,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Local<'jvm, Self>> {
+        let list = match exact_len(&iter) {
+            Some(len) => array_list_with_capacity(len, jvm)?,
+            None => java::util::ArrayList::new().execute_with(jvm)?,
+        };
+        for (index, item) in iter.enumerate() {
+            let element = convert(index, &item, jvm)?;
+            list.add(&element).execute_with(jvm)?;
+        }
+        Ok(list)
+    }
+}
+
+impl<T, JE> FromRustIterator<T> for java::util::HashSet<JE>
+where
+    T: ToJavaImpl<JE>,
+    JE: Upcast<java::lang::Object> + Upcast<JE>,
+{
+    fn from_rust_iter<'jvm>(
+        iter: impl Iterator<Item = T>,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Local<'jvm, Self>> {
+        let set = match exact_len(&iter) {
+            Some(len) => hash_set_with_capacity(len, jvm)?,
+            None => java::util::HashSet::new().execute_with(jvm)?,
+        };
+        for (index, item) in iter.enumerate() {
+            let element = convert(index, &item, jvm)?;
+            set.add(&element).execute_with(jvm)?;
+        }
+        Ok(set)
+    }
+}
+
+/// Puts each `(key, value)` pair, so later pairs replace earlier ones with an equal key.
+impl<K, V, JK, JV> FromRustIterator<(K, V)> for java::util::HashMap<JK, JV>
+where
+    K: ToJavaImpl<JK>,
+    V: ToJavaImpl<JV>,
+    JK: Upcast<java::lang::Object> + Upcast<JK>,
+    JV: Upcast<java::lang::Object> + Upcast<JV>,
+{
+    fn from_rust_iter<'jvm>(
+        iter: impl Iterator<Item = (K, V)>,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Local<'jvm, Self>> {
+        let map = match exact_len(&iter) {
+            Some(len) => hash_map_with_capacity(len, jvm)?,
+            None => java::util::HashMap::new().execute_with(jvm)?,
+        };
+        for (index, (key, value)) in iter.enumerate() {
+            let key = convert(index, &key, jvm)?;
+            let value = convert(index, &value, jvm)?;
+            map.put(&key, &value).execute_with(jvm)?;
+        }
+        Ok(map)
+    }
+}
+
+fn convert<'jvm, T, J>(
+    index: usize,
+    item: &T,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Option<Local<'jvm, J>>>
+where
+    T: ToJavaImpl<J>,
+    J: Upcast<java::lang::Object>,
+{
+    T::to_java_impl(item, jvm).map_err(|error| error.for_element(index))
+}
+
+/// The number of items left in `iter`, if its size hint is exact, as it is for an [`ExactSizeIterator`].
+fn exact_len(iter: &impl Iterator) -> Option<usize> {
+    match iter.size_hint() {
+        (lower, Some(upper)) if lower == upper => Some(lower),
+        _ => None,
+    }
+}
+
+/// Creates an `ArrayList` with `new ArrayList(len)`, so that adding `len` elements doesn't grow its backing array.
+pub(crate) fn array_list_with_capacity<'jvm, E: JavaObject>(
+    len: usize,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Local<'jvm, java::util::ArrayList<E>>> {
+    static CONSTRUCTOR: OnceCell<MethodPtr> = OnceCell::new();
+    new_with_capacity(&CONSTRUCTOR, len, jvm)
+}
+
+/// Creates a `HashSet` that holds `len` elements without rehashing.
+fn hash_set_with_capacity<'jvm, E: JavaObject>(
+    len: usize,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Local<'jvm, java::util::HashSet<E>>> {
+    static CONSTRUCTOR: OnceCell<MethodPtr> = OnceCell::new();
+    new_with_capacity(&CONSTRUCTOR, hash_capacity(len), jvm)
+}
+
+/// Creates a `HashMap` that holds `len` entries without rehashing.
+pub(crate) fn hash_map_with_capacity<'jvm, K: JavaObject, V: JavaObject>(
+    len: usize,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Local<'jvm, java::util::HashMap<K, V>>> {
+    static CONSTRUCTOR: OnceCell<MethodPtr> = OnceCell::new();
+    new_with_capacity(&CONSTRUCTOR, hash_capacity(len), jvm)
+}
+
+/// The initial capacity a hash table needs to hold `len` entries without exceeding the default load factor of 0.75,
+/// like `HashMap.newHashMap` computes.
+fn hash_capacity(len: usize) -> usize {
+    len.saturating_mul(4).div_ceil(3)
+}
+
+/// Creates a `C` with its `(int)` constructor, whose method ID is cached in `constructor`. Capacities beyond the range
+/// of an `int` are clamped.
+fn new_with_capacity<'jvm, C: JavaObject>(
+    constructor: &OnceCell<MethodPtr>,
+    capacity: usize,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Local<'jvm, C>> {
+    let class = C::class(jvm)?;
+    let constructor = constructor.get_or_try_init(|| find_constructor(jvm, &class, c"(I)V"))?;
+
+    let capacity = i32::try_from(capacity).unwrap_or(i32::MAX);
+    let env = jvm.env();
+    let obj = unsafe {
+        env.invoke(
+            |env| env.NewObjectA,
+            |env, f| {
+                f(
+                    env,
+                    class.as_raw().as_ptr(),
+                    constructor.as_ptr(),
+                    [jni_sys::jvalue { i: capacity }].as_ptr(),
+                )
+            },
+        )
+    };
+    match ObjectPtr::new(obj) {
+        // SAFETY: NewObjectA returns a new local reference to an instance of `C`
+        Some(obj) => Ok(unsafe { Local::from_raw(env, obj) }),
+        None => {
+            check_exception(jvm)?;
+            Err(Error::JvmInternal(
+                "failed to create a collection with an initial capacity".to_string(),
+            ))
+        }
+    }
+}
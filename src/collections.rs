@@ -0,0 +1,222 @@
+//! Conversions between Java collections and Rust collections. Conversions from Rust to Java are in `to_java`.
+
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{
+    java::{
+        self,
+        util::{IteratorExt, ListExt, MapExt, Map__EntryExt, PropertiesExt, SetExt},
+    },
+    AsJRef, JavaObject, Jvm, JvmOp, ToRust,
+};
+
+/// Converts each element of a `List`, in order. The conversion stops at the first element that fails to convert,
+/// reporting its index with [`Error::ElementConversion`]. A null element is reported in the same way, wrapping
+/// [`Error::NullDeref`].
+impl<T, R> ToRust<Vec<R>> for java::util::List<T>
+where
+    T: JavaObject + ToRust<R>,
+{
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Vec<R>> {
+        list_to_vec(self, jvm)
+    }
+}
+
+/// Converts each element of an `ArrayList`, like the conversion of a [`List`](java::util::List).
+impl<T, R> ToRust<Vec<R>> for java::util::ArrayList<T>
+where
+    T: JavaObject + ToRust<R>,
+{
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Vec<R>> {
+        list_to_vec(self.as_jref()?, jvm)
+    }
+}
+
+fn list_to_vec<'jvm, T, R>(
+    list: &java::util::List<T>,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Vec<R>>
+where
+    T: JavaObject + ToRust<R>,
+{
+    let len = list.size().execute_with(jvm)?;
+    let mut vec = Vec::with_capacity(len.max(0) as usize);
+
+    for index in 0..len {
+        // Each element is a new local ref, which is deleted when `element` is dropped at the end of the iteration.
+        let element = list
+            .get(index)
+            .assert_not_null()
+            .execute_with(jvm)
+            .and_then(|element| T::to_rust(&element, jvm))
+            .map_err(|error| error.for_element(index as usize))?;
+        vec.push(element);
+    }
+
+    Ok(vec)
+}
+
+/// Converts each key and value of a `Map`. The conversion stops at the first entry that fails to convert, reporting
+/// its index with [`Error::ElementConversion`]. A null key or value is reported in the same way, wrapping
+/// [`Error::NullDeref`]. Use a `Properties` object, or filter the map in Java, when nulls are expected.
+impl<JK, JV, K, V, S> ToRust<HashMap<K, V, S>> for java::util::Map<JK, JV>
+where
+    JK: JavaObject + ToRust<K>,
+    JV: JavaObject + ToRust<V>,
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, HashMap<K, V, S>> {
+        map_to_hash_map(self, jvm)
+    }
+}
+
+/// Converts each key and value of a `HashMap`, like the conversion of a [`Map`](java::util::Map).
+impl<JK, JV, K, V, S> ToRust<HashMap<K, V, S>> for java::util::HashMap<JK, JV>
+where
+    JK: JavaObject + ToRust<K>,
+    JV: JavaObject + ToRust<V>,
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, HashMap<K, V, S>> {
+        map_to_hash_map(self.as_jref()?, jvm)
+    }
+}
+
+/// Converts each entry of a `Map` to a `(key, value)` tuple, in the order the map iterates over them. The conversion
+/// stops at the first entry that fails to convert, reporting its index with [`Error::ElementConversion`]. A null key
+/// or value is reported in the same way, wrapping [`Error::NullDeref`].
+impl<JK, JV, K, V> ToRust<Vec<(K, V)>> for java::util::Map<JK, JV>
+where
+    JK: JavaObject + ToRust<K>,
+    JV: JavaObject + ToRust<V>,
+{
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Vec<(K, V)>> {
+        map_to_vec(self, jvm)
+    }
+}
+
+/// Converts each entry of a `HashMap`, like the conversion of a [`Map`](java::util::Map).
+impl<JK, JV, K, V> ToRust<Vec<(K, V)>> for java::util::HashMap<JK, JV>
+where
+    JK: JavaObject + ToRust<K>,
+    JV: JavaObject + ToRust<V>,
+{
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Vec<(K, V)>> {
+        map_to_vec(self.as_jref()?, jvm)
+    }
+}
+
+/// Converts the string properties, including those inherited from the default properties, like
+/// `Properties.stringPropertyNames`. Entries whose key or value isn't a `String` are skipped, so this never fails
+/// because of nulls.
+impl<S> ToRust<HashMap<String, String, S>> for java::util::Properties
+where
+    S: BuildHasher + Default,
+{
+    fn to_rust<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, HashMap<String, String, S>> {
+        let names = self
+            .string_property_names()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        let mut map = HashMap::with_capacity_and_hasher(
+            len_hint(names.size().execute_with(jvm)?),
+            S::default(),
+        );
+
+        let iterator = names.iterator().assert_not_null().execute_with(jvm)?;
+        while iterator.has_next().execute_with(jvm)? {
+            let name = iterator.next().assert_not_null().execute_with(jvm)?;
+            // `stringPropertyNames` is a snapshot, so the property may have been removed since
+            if let Some(value) = self.get_property(&name).execute_with(jvm)? {
+                map.insert(ToRust::to_rust(&name, jvm)?, ToRust::to_rust(&value, jvm)?);
+            }
+        }
+
+        Ok(map)
+    }
+}
+
+fn map_to_hash_map<'jvm, JK, JV, K, V, S>(
+    map: &java::util::Map<JK, JV>,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, HashMap<K, V, S>>
+where
+    JK: JavaObject + ToRust<K>,
+    JV: JavaObject + ToRust<V>,
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    let mut hash_map =
+        HashMap::with_capacity_and_hasher(len_hint(map.size().execute_with(jvm)?), S::default());
+    for_each_entry(map, jvm, |key, value| {
+        hash_map.insert(key, value);
+        Ok(())
+    })?;
+    Ok(hash_map)
+}
+
+fn map_to_vec<'jvm, JK, JV, K, V>(
+    map: &java::util::Map<JK, JV>,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, Vec<(K, V)>>
+where
+    JK: JavaObject + ToRust<K>,
+    JV: JavaObject + ToRust<V>,
+{
+    let mut entries = Vec::with_capacity(len_hint(map.size().execute_with(jvm)?));
+    for_each_entry(map, jvm, |key, value| {
+        entries.push((key, value));
+        Ok(())
+    })?;
+    Ok(entries)
+}
+
+/// Iterates over the `entrySet()` of `map`, converting the key and value of each entry and passing them to `f`. An
+/// entry that fails to convert is reported with its position in [`Error::ElementConversion`].
+fn for_each_entry<'jvm, JK, JV, K, V>(
+    map: &java::util::Map<JK, JV>,
+    jvm: &mut Jvm<'jvm>,
+    mut f: impl FnMut(K, V) -> crate::Result<'jvm, ()>,
+) -> crate::Result<'jvm, ()>
+where
+    JK: JavaObject + ToRust<K>,
+    JV: JavaObject + ToRust<V>,
+{
+    let entries = map.entry_set().assert_not_null().execute_with(jvm)?;
+    let iterator = entries.iterator().assert_not_null().execute_with(jvm)?;
+    let mut index = 0;
+    while iterator.has_next().execute_with(jvm)? {
+        // The entry, key and value are new local refs, which are deleted when they are dropped at the end of the
+        // iteration, so large maps don't exhaust the local ref table.
+        let entry = iterator.next().assert_not_null().execute_with(jvm)?;
+        let (key, value) = convert_entry(&entry, jvm).map_err(|error| error.for_element(index))?;
+        f(key, value)?;
+        index += 1;
+    }
+    Ok(())
+}
+
+fn convert_entry<'jvm, JK, JV, K, V>(
+    entry: &java::util::Map__Entry<JK, JV>,
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, (K, V)>
+where
+    JK: JavaObject + ToRust<K>,
+    JV: JavaObject + ToRust<V>,
+{
+    let key = entry.get_key().assert_not_null().execute_with(jvm)?;
+    let value = entry.get_value().assert_not_null().execute_with(jvm)?;
+    Ok((JK::to_rust(&key, jvm)?, JV::to_rust(&value, jvm)?))
+}
+
+fn len_hint(size: i32) -> usize {
+    size.max(0) as usize
+}
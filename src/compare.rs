@@ -0,0 +1,119 @@
+use std::{cmp::Ordering, marker::PhantomData};
+
+use crate::{
+    cast::Upcast,
+    java::{
+        lang::{Comparable, ComparableExt},
+        util::{List, ListExt},
+    },
+    AsJRef, Error, IntoJava, Jvm, JvmOp, Local,
+};
+
+/// [`JvmOp`][] that compares two objects with `compareTo`, see [`JvmOp::cmp_to`].
+#[derive(Clone)]
+pub struct CmpTo<This, Other, T> {
+    this: This,
+    other: Other,
+    phantom: PhantomData<T>,
+}
+
+impl<This, Other, T> CmpTo<This, Other, T>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<Comparable<T>>,
+    Other: IntoJava<T>,
+    T: Upcast<T>,
+{
+    pub(crate) fn new(this: This, other: Other) -> Self {
+        Self {
+            this,
+            other,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<This, Other, T> JvmOp for CmpTo<This, Other, T>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<Comparable<T>>,
+    Other: IntoJava<T>,
+    T: Upcast<T>,
+{
+    type Output<'jvm> = Ordering;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let this = self.this.execute_with(jvm)?;
+        let other = self.other.into_java(jvm)?;
+        let this: Local<'jvm, Comparable<T>> = jvm.local(this.as_jref()?);
+        let other: Local<'jvm, T> = jvm.local(other.as_jref()?);
+
+        // Only the sign of the result is meaningful
+        let result = this.compare_to(&other).execute_with(jvm)?;
+        Ok(result.cmp(&0))
+    }
+}
+
+/// [`JvmOp`][] that sorts a list by the natural ordering of its elements, see [`JvmOp::sort_natural`].
+#[derive(Clone)]
+pub struct SortNatural<This, E> {
+    this: This,
+    phantom: PhantomData<E>,
+}
+
+impl<This, E> SortNatural<This, E>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<List<E>>,
+    E: Upcast<Comparable<E>> + Upcast<E>,
+{
+    pub(crate) fn new(this: This) -> Self {
+        Self {
+            this,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<This, E> JvmOp for SortNatural<This, E>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<List<E>>,
+    E: Upcast<Comparable<E>> + Upcast<E>,
+{
+    type Output<'jvm> = ();
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let list = self.this.execute_with(jvm)?;
+        let list: Local<'jvm, List<E>> = jvm.local(list.as_jref()?);
+
+        let size = list.size().execute_with(jvm)?;
+        let mut elements = Vec::with_capacity(size as usize);
+        for index in 0..size {
+            // `compareTo` can't be called on null, like in `Collections.sort`
+            let element = list.get(index).execute_with(jvm)?.ok_or(Error::NullDeref)?;
+            elements.push(element);
+        }
+
+        // `sort_by` can't be interrupted, so once a comparison fails the remaining ones are skipped and the list is
+        // left as it was
+        let mut error = None;
+        elements.sort_by(|a, b| {
+            if error.is_some() {
+                return Ordering::Equal;
+            }
+            a.cmp_to(b).execute_with(jvm).unwrap_or_else(|e| {
+                error = Some(e);
+                Ordering::Equal
+            })
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        for (index, element) in elements.iter().enumerate() {
+            list.set(index as i32, element).execute_with(jvm)?;
+        }
+        Ok(())
+    }
+}
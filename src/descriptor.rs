@@ -0,0 +1,20 @@
+/// Describes a Java method bound by [`java_package`](crate::java_package).
+///
+/// Each generated class exposes the methods it binds as a `METHODS` associated constant, in the order they were
+/// declared. This is intended for tooling (REPLs, reflective helpers, serializers) that needs to enumerate what duchess
+/// knows about a class without re-running `javap`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MethodDescriptor {
+    /// The name of the method in Java, e.g. `toString`.
+    pub name: &'static str,
+
+    /// The name of the generated Rust method, e.g. `to_string`.
+    pub rust_name: &'static str,
+
+    /// The JVM descriptor of the method, e.g. `()Ljava/lang/String;`.
+    pub descriptor: &'static str,
+
+    /// Whether the method is static. Static methods are generated as associated functions on the class, and
+    /// instance methods are generated on its extension trait.
+    pub is_static: bool,
+}
@@ -40,15 +40,38 @@ pub enum Error<T: AsJRef<Throwable>> {
     #[error("JVM already exists")]
     JvmAlreadyExists,
 
+    /// The JVM was destroyed at the end of [`duchess::run`](crate::run), and can't be used or launched again.
+    #[error("the JVM has been destroyed")]
+    JvmDestroyed,
+
+    /// The JVM doesn't support the JNI version requested with `.jni_version(...)` on [`Jvm::builder`](crate::Jvm::builder).
+    #[error("the JVM doesn't support JNI version {}", jni_version_name(*.0))]
+    UnsupportedJniVersion(jni_sys::jint),
+
+    /// The operation didn't complete in time, see [`JvmOp::execute_with_timeout`](crate::JvmOp::execute_with_timeout).
+    #[error("Java operation timed out after {0:?}")]
+    Timeout(std::time::Duration),
+
     #[cfg(feature = "dylibjvm")]
     #[error(transparent)]
     UnableToLoadLibjvm(#[from] Box<dyn std::error::Error + Send + 'static>),
 
+    /// A value can't be converted between Java and Rust, e.g. because it doesn't fit the target type.
+    #[error("conversion failed: {0}")]
+    Conversion(String),
+
+    /// Converting an element of a collection failed; `index` is the position of the element. Exceptions thrown
+    /// while converting an element are returned as [`Error::Thrown`] instead.
+    #[error("failed to convert element {index}: {}", display_boxed(.error))]
+    ElementConversion { index: usize, error: Box<Error<T>> },
+
     #[error("{0}")]
     JvmInternal(String),
 }
 
-fn try_extract_message(exception: &impl AsJRef<Throwable>) -> String {
+/// The `toString()` of `exception`, or a placeholder if it can't be called. Used by the `Display` impls of the
+/// enums of `#[checked]` methods.
+pub fn try_extract_message(exception: &impl AsJRef<Throwable>) -> String {
     let message = Jvm::with(|jvm| {
         let exception = jvm.local(exception.as_jref()?);
         exception
@@ -60,6 +83,21 @@ fn try_extract_message(exception: &impl AsJRef<Throwable>) -> String {
     message.unwrap_or_else(|_| "<unable to get exception message>".into())
 }
 
+/// Formats a JNI version like `JNI_VERSION_1_8` as `1.8`, and like `JNI_VERSION_10` as `10`.
+fn jni_version_name(version: jni_sys::jint) -> String {
+    let (major, minor) = (version >> 16, version & 0xffff);
+    if major == 1 || minor != 0 {
+        format!("{major}.{minor}")
+    } else {
+        format!("{major}")
+    }
+}
+
+/// Formats a nested error. Interpolating the field directly would make the derived `Display` impl require itself.
+fn display_boxed<T: AsJRef<Throwable>>(error: &Error<T>) -> String {
+    error.to_string()
+}
+
 impl<T> Debug for Error<T>
 where
     T: AsJRef<Throwable>,
@@ -69,6 +107,21 @@ where
     }
 }
 
+impl<T: AsJRef<Throwable>> Error<T> {
+    /// Reports that converting the element at `index` of a collection failed with this error, wrapping it in
+    /// [`Error::ElementConversion`]. Exceptions and internal errors aren't about the element, so they are returned
+    /// as is, which also lets [`TryCatch`](crate::TryCatch) catch an exception thrown while converting an element.
+    pub(crate) fn for_element(self, index: usize) -> Self {
+        match self {
+            Error::Thrown(_) | Error::JvmInternal(_) => self,
+            error => Error::ElementConversion {
+                index,
+                error: Box::new(error),
+            },
+        }
+    }
+}
+
 impl<'jvm> Error<Local<'jvm, Throwable>> {
     pub fn into_global(self, jvm: &mut Jvm<'jvm>) -> Error<Global<Throwable>> {
         match self {
@@ -77,8 +130,16 @@ impl<'jvm> Error<Local<'jvm, Throwable>> {
             Error::NullDeref => Error::NullDeref,
             Error::NestedUsage => Error::NestedUsage,
             Error::JvmAlreadyExists => Error::JvmAlreadyExists,
+            Error::JvmDestroyed => Error::JvmDestroyed,
+            Error::UnsupportedJniVersion(v) => Error::UnsupportedJniVersion(v),
+            Error::Timeout(d) => Error::Timeout(d),
             #[cfg(feature = "dylibjvm")]
             Error::UnableToLoadLibjvm(e) => Error::UnableToLoadLibjvm(e),
+            Error::Conversion(m) => Error::Conversion(m),
+            Error::ElementConversion { index, error } => Error::ElementConversion {
+                index,
+                error: Box::new(error.into_global(jvm)),
+            },
             Error::JvmInternal(m) => Error::JvmInternal(m),
         }
     }
@@ -87,14 +148,35 @@ impl<'jvm> Error<Local<'jvm, Throwable>> {
 /// Used by codegen to check if the JVM exception flag is set, materializing an [`Error::Thrown`] if it is.
 #[doc(hidden)]
 pub fn check_exception<'jvm>(jvm: &mut Jvm<'jvm>) -> Result<'jvm, ()> {
-    let env = jvm.env();
-    // SAFETY: we don't hold on to the return env ptr
-    let thrown = unsafe { env.invoke(|env| env.ExceptionOccurred, |env, f| f(env)) };
-    if let Some(thrown) = ObjectPtr::new(thrown) {
-        unsafe { env.invoke(|env| env.ExceptionClear, |env, f| f(env)) };
+    match jvm.take_pending_exception() {
+        Some(thrown) => Err(Error::Thrown(thrown)),
+        None => Ok(()),
+    }
+}
+
+impl<'jvm> Jvm<'jvm> {
+    /// Whether a Java exception is pending on this thread, i.e. a JNI call made through [`Jvm::raw_env`] threw and the
+    /// exception hasn't been [taken](Jvm::take_pending_exception) yet. Operations executed by duchess never leave one
+    /// pending.
+    pub fn has_pending_exception(&self) -> bool {
+        let env = self.env();
+        // SAFETY: ExceptionCheck may be called while an exception is pending
+        let pending = unsafe { env.invoke_unchecked(|env| env.ExceptionCheck, |env, f| f(env)) };
+        pending != jni_sys::JNI_FALSE
+    }
+
+    /// Returns the pending Java exception, if any, and clears it, like duchess does after each JNI call it makes. Most
+    /// JNI functions must not be called while an exception is pending, so code calling JNI directly through
+    /// [`Jvm::raw_env`] must take the exception before using duchess operations again; debug builds panic otherwise.
+    ///
+    /// Clearing the exception with `ExceptionClear` directly works too, but loses it.
+    pub fn take_pending_exception(&mut self) -> Option<Local<'jvm, Throwable>> {
+        let env = self.env();
+        // SAFETY: ExceptionOccurred and ExceptionClear may be called while an exception is pending
+        let thrown = unsafe { env.invoke_unchecked(|env| env.ExceptionOccurred, |env, f| f(env)) };
+        let thrown = ObjectPtr::new(thrown)?;
+        unsafe { env.invoke_unchecked(|env| env.ExceptionClear, |env, f| f(env)) };
         // SAFETY: the ptr returned by ExceptionOccurred is already a local ref and must be an instance of Throwable
-        Err(Error::Thrown(unsafe { Local::from_raw(env, thrown) }))
-    } else {
-        Ok(())
+        Some(unsafe { Local::from_raw(env, thrown) })
     }
 }
@@ -0,0 +1,46 @@
+use crate::{java::lang::ThrowableExt, Error, Jvm, JvmOp};
+
+/// [`JvmOp`][] that runs a cleanup step after an operation, whether or not it failed, see [`JvmOp::finally_java`].
+#[derive(Clone)]
+pub struct Finally<This, F> {
+    this: This,
+    cleanup: F,
+}
+
+impl<This, F> Finally<This, F>
+where
+    This: JvmOp,
+    F: for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::Result<'jvm, ()>,
+{
+    pub(crate) fn new(this: This, cleanup: F) -> Self {
+        Self { this, cleanup }
+    }
+}
+
+impl<This, F> JvmOp for Finally<This, F>
+where
+    This: JvmOp,
+    F: for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::Result<'jvm, ()>,
+{
+    type Output<'jvm> = This::Output<'jvm>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let result = self.this.execute_with(jvm);
+        let cleanup = (self.cleanup)(jvm);
+        match (result, cleanup) {
+            (result, Ok(())) => result,
+            (Ok(_), Err(error)) => Err(error),
+            (Err(Error::Thrown(primary)), Err(Error::Thrown(secondary))) => {
+                // Like try-with-resources, the exception from the cleanup is attached to the primary one
+                if let Err(error) = primary.add_suppressed(&secondary).execute_with(jvm) {
+                    tracing::warn!(?error, "couldn't add suppressed exception from cleanup");
+                }
+                Err(Error::Thrown(primary))
+            }
+            (Err(primary), Err(error)) => {
+                tracing::warn!(?error, "cleanup failed after an earlier error");
+                Err(primary)
+            }
+        }
+    }
+}
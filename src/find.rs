@@ -1,29 +1,92 @@
 use std::ffi::CStr;
 
+use once_cell::sync::OnceCell;
+
 use crate::{
-    java,
+    java::{
+        self,
+        lang::{ClassLoaderExt, ThreadExt},
+    },
     jvm::JavaObjectExt,
     plumbing::{check_exception, HasEnvPtr},
     raw::{FieldPtr, MethodPtr, ObjectPtr},
-    Jvm, Local, Result,
+    Error, Global, Jvm, JvmOp, Local, Result,
 };
 
+/// The class loader configured with [`JvmBuilder::class_loader`](crate::jvm::JvmBuilder::class_loader).
+static CLASS_LOADER: OnceCell<Global<java::lang::ClassLoader>> = OnceCell::new();
+
+pub(crate) fn set_class_loader<'jvm>(loader: Global<java::lang::ClassLoader>) -> Result<'jvm, ()> {
+    CLASS_LOADER
+        .set(loader)
+        .map_err(|_| Error::JvmInternal("a class loader has already been configured".to_string()))
+}
+
+/// Finds the class with the JNI name `jni_name`, like `java/lang/String`. If a class loader was configured with
+/// [`JvmBuilder::class_loader`](crate::jvm::JvmBuilder::class_loader), classes outside of the JDK are loaded with it.
+///
+/// `FindClass` uses the class loader of the Java method that called into Rust, or the system class loader if there
+/// is none, as on threads started in Rust. Classes that were loaded by another class loader, like the classes of an
+/// application running in a container, aren't visible to the system class loader. If `FindClass` fails, the class is
+/// therefore looked up through the context class loader of the current thread before giving up.
 pub fn find_class<'jvm>(
     jvm: &mut Jvm<'jvm>,
     jni_name: &CStr,
 ) -> Result<'jvm, Local<'jvm, java::lang::Class>> {
+    // JDK classes are always found with `FindClass`, which also keeps `java.lang.ClassLoader` itself from being
+    // loaded through a class loader. `ClassLoader.loadClass` doesn't load array classes.
+    if let Some(loader) = CLASS_LOADER.get() {
+        let name = jni_name.to_bytes();
+        if !name.starts_with(b"java/") && !name.starts_with(b"[") {
+            let binary_name = jni_name.to_string_lossy().replace('/', ".");
+            return loader
+                .load_class(binary_name.as_str())
+                .assert_not_null()
+                .execute_with(jvm);
+        }
+    }
+
     let env = jvm.env();
     let class = unsafe { env.invoke(|env| env.FindClass, |env, f| f(env, jni_name.as_ptr())) };
     if let Some(class) = ObjectPtr::new(class) {
-        Ok(unsafe { Local::from_raw(env, class) })
-    } else {
-        check_exception(jvm)?;
+        return Ok(unsafe { Local::from_raw(env, class) });
+    }
+
+    let error = match check_exception(jvm) {
+        Err(error) => error,
         // Class not existing should've triggered NoClassDefFoundError so something strange is now happening
-        Err(crate::Error::JvmInternal(format!(
+        Ok(()) => crate::Error::JvmInternal(format!(
             "failed to find class `{}`",
             jni_name.to_string_lossy()
-        )))
+        )),
+    };
+
+    // Report the error from `FindClass` if the fallback fails too, as it names the class that couldn't be loaded.
+    match load_with_context_class_loader(jvm, jni_name) {
+        Ok(Some(class)) => Ok(class),
+        Ok(None) | Err(_) => Err(error),
+    }
+}
+
+fn load_with_context_class_loader<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    jni_name: &CStr,
+) -> Result<'jvm, Option<Local<'jvm, java::lang::Class>>> {
+    // `ClassLoader.loadClass` takes binary names like `java.util.Map$Entry`, and doesn't load array classes.
+    let jni_name = jni_name.to_string_lossy();
+    if jni_name.starts_with('[') {
+        return Ok(None);
     }
+    let binary_name = jni_name.replace('/', ".");
+
+    let Some(loader) = java::lang::Thread::current_thread()
+        .assert_not_null()
+        .get_context_class_loader()
+        .execute_with(jvm)?
+    else {
+        return Ok(None);
+    };
+    loader.load_class(binary_name.as_str()).execute_with(jvm)
 }
 
 pub fn find_method<'jvm>(
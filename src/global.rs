@@ -1,4 +1,4 @@
-use crate::{Global, JavaObject, Jvm, JvmOp, Local};
+use crate::{Global, GlobalResult, JavaObject, Jvm, JvmOp, Local};
 
 /// [`JvmOp`][] that converts a local result into a global one.
 pub struct GlobalOp<J: JvmOp> {
@@ -71,3 +71,32 @@ where
         Ok(self.map(|p| jvm.global::<T>(&p)))
     }
 }
+
+/// Conversions from a [`crate::Result`], whose values and errors may be local references tied to a `'jvm`, to a
+/// [`GlobalResult`] that can outlive it, e.g. to return it from [`Jvm::with`] or send it to another thread.
+///
+/// There are no `From` impls for these conversions since creating global references needs the `jvm`.
+pub trait LocalResultExt<'jvm, T> {
+    /// Keeps the value as is, converting a thrown exception to a global reference.
+    fn into_global_result(self, jvm: &mut Jvm<'jvm>) -> GlobalResult<T>;
+
+    /// Converts both the value and a thrown exception to global references, like [`JvmOp::global`] does for the
+    /// value of an operation.
+    fn globalize(self, jvm: &mut Jvm<'jvm>) -> GlobalResult<GlobalVersionOf<'jvm, T>>
+    where
+        T: IntoGlobal<'jvm>;
+}
+
+impl<'jvm, T> LocalResultExt<'jvm, T> for crate::Result<'jvm, T> {
+    fn into_global_result(self, jvm: &mut Jvm<'jvm>) -> GlobalResult<T> {
+        self.map_err(|e| e.into_global(jvm))
+    }
+
+    fn globalize(self, jvm: &mut Jvm<'jvm>) -> GlobalResult<GlobalVersionOf<'jvm, T>>
+    where
+        T: IntoGlobal<'jvm>,
+    {
+        self.and_then(|value| value.into_global(jvm))
+            .into_global_result(jvm)
+    }
+}
@@ -0,0 +1,197 @@
+//! Iterating over Java collections without holding on to a [`Jvm`], see [`JvmOp::to_global_iter`].
+
+use std::marker::PhantomData;
+
+use crate::{
+    java::{
+        self,
+        util::{IteratorExt, ListExt, SetExt},
+    },
+    Error, Global, GlobalResult, JavaObject, Jvm, JvmOp, Local, ToRust, TryJDeref,
+};
+
+/// Java objects whose elements can be iterated over, see [`JvmOp::to_global_iter`].
+pub trait JavaIterable: JavaObject {
+    type Item: JavaObject;
+
+    /// Returns a new `java.util.Iterator` over the elements.
+    fn java_iterator<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Local<'jvm, java::util::Iterator<Self::Item>>>;
+}
+
+/// The elements are those the iterator hasn't returned yet, and iterating advances the iterator itself.
+impl<E: JavaObject> JavaIterable for java::util::Iterator<E> {
+    type Item = E;
+
+    fn java_iterator<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Local<'jvm, java::util::Iterator<E>>> {
+        Ok(jvm.local(self))
+    }
+}
+
+macro_rules! iterable {
+    ($($ty:ident)::*) => {
+        impl<E: JavaObject> JavaIterable for $($ty)::*<E> {
+            type Item = E;
+
+            fn java_iterator<'jvm>(
+                &self,
+                jvm: &mut Jvm<'jvm>,
+            ) -> crate::Result<'jvm, Local<'jvm, java::util::Iterator<E>>> {
+                self.iterator().assert_not_null().execute_with(jvm)
+            }
+        }
+    };
+}
+
+iterable!(java::util::List);
+iterable!(java::util::ArrayList);
+iterable!(java::util::Set);
+iterable!(java::util::HashSet);
+
+/// [`JvmOp`][] that starts a [`GlobalIter`], see [`JvmOp::to_global_iter`].
+pub struct ToGlobalIter<This, R> {
+    this: This,
+    phantom: PhantomData<fn() -> R>,
+}
+
+impl<This, R> ToGlobalIter<This, R>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: TryJDeref,
+    for<'jvm> <This::Output<'jvm> as TryJDeref>::Java: JavaIterable,
+    for<'jvm> <<This::Output<'jvm> as TryJDeref>::Java as JavaIterable>::Item: ToRust<R>,
+    R: 'static,
+{
+    pub(crate) fn new(this: This) -> Self {
+        Self {
+            this,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<This, R> JvmOp for ToGlobalIter<This, R>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: TryJDeref,
+    for<'jvm> <This::Output<'jvm> as TryJDeref>::Java: JavaIterable,
+    for<'jvm> <<This::Output<'jvm> as TryJDeref>::Java as JavaIterable>::Item: ToRust<R>,
+    R: 'static,
+{
+    type Output<'jvm> = GlobalIter<R>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let this = self.this.execute_with(jvm)?;
+        let cursor = Cursor::new(this.try_jderef()?, jvm)?;
+        Ok(GlobalIter {
+            cursor: Some(Box::new(cursor)),
+            index: 0,
+        })
+    }
+}
+
+/// A Rust iterator over the elements of a Java collection that isn't tied to a [`Jvm`], so it can be stored or
+/// returned. Created by [`JvmOp::to_global_iter`].
+///
+/// The underlying `java.util.Iterator` is held as a global reference. Each call to `next` runs in [`Jvm::with`],
+/// attaching the current thread if needed, and converts the element with [`ToRust`]. The local reference to the
+/// element is deleted before `next` returns, so iterating doesn't build up local references.
+///
+/// Null elements fail to convert with [`Error::NullDeref`], and an element that fails to convert is reported with
+/// its position in [`Error::ElementConversion`]. Iteration can continue past such an error, or past an exception
+/// thrown while converting an element. If the Java iterator itself fails, e.g. `next` throws a
+/// `ConcurrentModificationException`, the error is returned and the iterator is done.
+pub struct GlobalIter<R> {
+    /// `None` once the Java iterator is exhausted.
+    cursor: Option<Box<dyn NextElement<R> + Send>>,
+    index: usize,
+}
+
+impl<R> Iterator for GlobalIter<R> {
+    type Item = GlobalResult<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cursor = self.cursor.as_mut()?;
+        let index = self.index;
+        let element = Jvm::with(|jvm| match cursor.next_element(index, jvm)? {
+            Some(Err(error)) => Ok(Some(Err(error.into_global(jvm)))),
+            Some(Ok(element)) => Ok(Some(Ok(element))),
+            None => Ok(None),
+        });
+        match element {
+            Ok(Some(element)) => {
+                self.index += 1;
+                Some(element)
+            }
+            Ok(None) => {
+                self.cursor = None;
+                None
+            }
+            Err(error) => {
+                // The Java iterator failed, so there is no telling whether `next` would ever return `None`
+                self.cursor = None;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// Advances the Java iterator of a [`GlobalIter`], hiding the Java type of the elements.
+trait NextElement<R> {
+    /// Converts the next element, which is at position `index`, or returns `None` if there are no more elements.
+    /// Errors from advancing the Java iterator are returned in the outer `Result`, and errors from converting the
+    /// element in the inner one.
+    fn next_element<'jvm>(
+        &mut self,
+        index: usize,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<crate::Result<'jvm, R>>>;
+}
+
+struct Cursor<T: JavaObject, R> {
+    iterator: Global<java::util::Iterator<T>>,
+    phantom: PhantomData<fn() -> R>,
+}
+
+impl<T, R> Cursor<T, R>
+where
+    T: JavaObject + ToRust<R>,
+{
+    fn new<'jvm, C>(collection: &C, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self>
+    where
+        C: JavaIterable<Item = T>,
+    {
+        let iterator = collection.java_iterator(jvm)?;
+        Ok(Cursor {
+            iterator: jvm.global(&iterator),
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<T, R> NextElement<R> for Cursor<T, R>
+where
+    T: JavaObject + ToRust<R>,
+{
+    fn next_element<'jvm>(
+        &mut self,
+        index: usize,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<crate::Result<'jvm, R>>> {
+        if !self.iterator.has_next().execute_with(jvm)? {
+            return Ok(None);
+        }
+
+        // The element is a new local ref, which is deleted when it is dropped at the end of this call.
+        let Some(element) = self.iterator.next().execute_with(jvm)? else {
+            return Ok(Some(Err(Error::NullDeref.for_element(index))));
+        };
+        let element = T::to_rust(&element, jvm).map_err(|error| error.for_element(index));
+        Ok(Some(element))
+    }
+}
@@ -0,0 +1,188 @@
+//! Adapters between Java's `java.io` streams and Rust's [`std::io`] traits.
+
+use std::io;
+
+use crate::{
+    error::check_exception,
+    java::{
+        self,
+        io::{InputStreamExt, OutputStreamExt},
+        lang::{Throwable, ThrowableExt},
+    },
+    plumbing::JavaObjectExt,
+    raw::HasEnvPtr,
+    AsJRef, Error, Jvm, JvmOp, Local,
+};
+
+/// Size of the `byte[]` used to move data between Rust and Java. Each `read` or `write` transfers at most this many
+/// bytes with a single JNI call.
+const BUFFER_SIZE: usize = 8 * 1024;
+
+/// Reads from a Java [`InputStream`](java::io::InputStream) as a Rust [`io::Read`].
+///
+/// Each call to `read` invokes `InputStream.read(byte[], int, int)` once, reusing the same Java buffer, so wrapping
+/// the reader in a [`std::io::BufReader`] avoids a JNI call per small read. Exceptions thrown by the stream (usually
+/// `IOException`s) are reported as [`io::Error`]s carrying the exception's message.
+///
+/// The stream is not closed when the reader is dropped.
+pub struct JavaReader<'a, 'jvm> {
+    jvm: &'a mut Jvm<'jvm>,
+    stream: Local<'jvm, java::io::InputStream>,
+    buffer: Option<Local<'jvm, java::Array<i8>>>,
+}
+
+impl<'a, 'jvm> JavaReader<'a, 'jvm> {
+    pub fn new(
+        jvm: &'a mut Jvm<'jvm>,
+        stream: &impl AsJRef<java::io::InputStream>,
+    ) -> crate::Result<'jvm, Self> {
+        let stream = jvm.local(stream.as_jref()?);
+        Ok(JavaReader {
+            jvm,
+            stream,
+            buffer: None,
+        })
+    }
+
+    fn read_java(&mut self, buf: &mut [u8]) -> crate::Result<'jvm, usize> {
+        let jvm = &mut *self.jvm;
+        let buffer = match &self.buffer {
+            Some(buffer) => buffer,
+            None => self.buffer.insert(new_buffer(jvm)?),
+        };
+
+        let len = buf.len().min(BUFFER_SIZE);
+        let read = self.stream.read(buffer, 0, len as i32).execute_with(jvm)?;
+        // `read` returns -1 at the end of the stream
+        let Ok(read) = usize::try_from(read) else {
+            return Ok(0);
+        };
+
+        unsafe {
+            jvm.env().invoke(
+                |env| env.GetByteArrayRegion,
+                |env, f| {
+                    f(
+                        env,
+                        buffer.as_raw().as_ptr(),
+                        0,
+                        read as i32,
+                        buf.as_mut_ptr().cast::<jni_sys::jbyte>(),
+                    )
+                },
+            );
+        }
+        check_exception(jvm)?;
+
+        Ok(read)
+    }
+}
+
+impl io::Read for JavaReader<'_, '_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        match self.read_java(buf) {
+            Ok(read) => Ok(read),
+            Err(e) => Err(to_io_error(self.jvm, e)),
+        }
+    }
+}
+
+/// Writes to a Java [`OutputStream`](java::io::OutputStream) as a Rust [`io::Write`].
+///
+/// Each call to `write` invokes `OutputStream.write(byte[], int, int)` once, reusing the same Java buffer, so wrapping
+/// the writer in a [`std::io::BufWriter`] avoids a JNI call per small write. Exceptions thrown by the stream (usually
+/// `IOException`s) are reported as [`io::Error`]s carrying the exception's message.
+///
+/// The stream is neither flushed nor closed when the writer is dropped.
+pub struct JavaWriter<'a, 'jvm> {
+    jvm: &'a mut Jvm<'jvm>,
+    stream: Local<'jvm, java::io::OutputStream>,
+    buffer: Option<Local<'jvm, java::Array<i8>>>,
+}
+
+impl<'a, 'jvm> JavaWriter<'a, 'jvm> {
+    pub fn new(
+        jvm: &'a mut Jvm<'jvm>,
+        stream: &impl AsJRef<java::io::OutputStream>,
+    ) -> crate::Result<'jvm, Self> {
+        let stream = jvm.local(stream.as_jref()?);
+        Ok(JavaWriter {
+            jvm,
+            stream,
+            buffer: None,
+        })
+    }
+
+    fn write_java(&mut self, buf: &[u8]) -> crate::Result<'jvm, usize> {
+        let jvm = &mut *self.jvm;
+        let buffer = match &self.buffer {
+            Some(buffer) => buffer,
+            None => self.buffer.insert(new_buffer(jvm)?),
+        };
+
+        let len = buf.len().min(BUFFER_SIZE);
+        unsafe {
+            jvm.env().invoke(
+                |env| env.SetByteArrayRegion,
+                |env, f| {
+                    f(
+                        env,
+                        buffer.as_raw().as_ptr(),
+                        0,
+                        len as i32,
+                        buf.as_ptr().cast::<jni_sys::jbyte>(),
+                    )
+                },
+            );
+        }
+        check_exception(jvm)?;
+
+        self.stream.write(buffer, 0, len as i32).execute_with(jvm)?;
+        Ok(len)
+    }
+}
+
+impl io::Write for JavaWriter<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        match self.write_java(buf) {
+            Ok(written) => Ok(written),
+            Err(e) => Err(to_io_error(self.jvm, e)),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.stream.flush().execute_with(self.jvm) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(to_io_error(self.jvm, e)),
+        }
+    }
+}
+
+fn new_buffer<'jvm>(jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, java::Array<i8>>> {
+    [0_i8; BUFFER_SIZE].as_slice().execute_with(jvm)
+}
+
+/// Converts a duchess error into an [`io::Error`]. Exceptions are converted to their message here, since formatting
+/// them later would need to re-enter the JVM.
+fn to_io_error<'jvm>(jvm: &mut Jvm<'jvm>, error: Error<Local<'jvm, Throwable>>) -> io::Error {
+    match error {
+        Error::Thrown(exception) => {
+            let message = exception
+                .to_string()
+                .assert_not_null()
+                .to_rust()
+                .execute_with(jvm)
+                .unwrap_or_else(|_| "<unable to get exception message>".into());
+            io::Error::other(format!("Java invocation threw: {message}"))
+        }
+        error => io::Error::other(error.to_string()),
+    }
+}
@@ -79,12 +79,66 @@ mod auto {
             public java.lang.Class[] getInterfaces();
             public java.lang.Class getComponentType();
             public java.lang.Class arrayType();
+            public java.lang.ClassLoader getClassLoader();
+            public java.io.InputStream getResourceAsStream(java.lang.String);
+            public java.lang.Object cast(java.lang.Object);
         }
 
-        public final class java.lang.String {
+        public abstract class java.lang.ClassLoader {
+            public java.lang.String getName();
+            public java.lang.Class loadClass(java.lang.String) throws java.lang.ClassNotFoundException;
+            public final java.lang.ClassLoader getParent();
+            public static java.lang.ClassLoader getSystemClassLoader();
+            public java.io.InputStream getResourceAsStream(java.lang.String);
+        }
+
+        public interface java.lang.AutoCloseable {
+            public abstract void close() throws java.lang.Exception;
+        }
+
+        public interface java.lang.Comparable<T> {
+            public abstract int compareTo(T);
+        }
+
+        public interface java.lang.CharSequence {
+            public abstract int length();
+            public abstract char charAt(int);
+            public default boolean isEmpty();
+            public abstract java.lang.CharSequence subSequence(int, int);
+        }
+
+        public final class java.lang.String implements java.lang.CharSequence, java.lang.Comparable<java.lang.String> {
             public java.lang.String(byte[]);
             public int length();
             public boolean isEmpty();
+            public byte[] getBytes();
+            public boolean contains(java.lang.CharSequence);
+            public java.lang.String[] split(java.lang.String);
+            public char[] toCharArray();
+            public static java.lang.String valueOf(char[]);
+        }
+
+        public final class java.lang.System {
+            public static final java.io.PrintStream out;
+            public static final java.io.PrintStream err;
+            public static void setOut(java.io.PrintStream);
+            public static void setErr(java.io.PrintStream);
+            public static native int identityHashCode(java.lang.Object);
+            public static java.util.Properties getProperties();
+            public static java.lang.String getProperty(java.lang.String);
+            public static void gc();
+        }
+
+        public class java.lang.Thread {
+            public static native java.lang.Thread currentThread();
+            public void interrupt();
+            public static boolean interrupted();
+            public boolean isInterrupted();
+            public final boolean isAlive();
+            public final java.lang.String getName();
+            public long getId();
+            public java.lang.ClassLoader getContextClassLoader();
+            public void setContextClassLoader(java.lang.ClassLoader);
         }
 
         public abstract class java.lang.Record {
@@ -93,6 +147,52 @@ mod auto {
             public abstract java.lang.String toString();
         }
 
+        public abstract class java.lang.Number { // java.io.Serializable
+            public java.lang.Number();
+            public abstract int intValue();
+            public abstract long longValue();
+            public abstract float floatValue();
+            public abstract double doubleValue();
+        }
+
+        public final class java.lang.Integer extends java.lang.Number implements java.lang.Comparable<java.lang.Integer> {
+            public static java.lang.Integer valueOf(int);
+            // public static java.lang.Integer valueOf(java.lang.String);
+        }
+
+        public final class java.lang.Long extends java.lang.Number implements java.lang.Comparable<java.lang.Long> {
+            public static java.lang.Long valueOf(long);
+            // public static java.lang.Long valueOf(java.lang.String);
+        }
+
+        public final class java.lang.Double extends java.lang.Number implements java.lang.Comparable<java.lang.Double> {
+            public static java.lang.Double valueOf(double);
+            // public static java.lang.Double valueOf(java.lang.String);
+        }
+
+        public final class java.lang.Boolean implements java.lang.Comparable<java.lang.Boolean> { // java.io.Serializable
+            public static java.lang.Boolean valueOf(boolean);
+            // public static java.lang.Boolean valueOf(java.lang.String);
+            public boolean booleanValue();
+        }
+
+        public final class java.lang.StringBuilder implements java.lang.CharSequence { // java.lang.Comparable<java.lang.StringBuilder>
+            public java.lang.StringBuilder();
+            // public java.lang.StringBuilder(int);
+            // public java.lang.StringBuilder(java.lang.String);
+            public java.lang.StringBuilder append(java.lang.Object);
+            // public java.lang.StringBuilder append(java.lang.String);
+            // public java.lang.StringBuilder append(java.lang.CharSequence);
+            // public java.lang.StringBuilder append(boolean);
+            // public java.lang.StringBuilder append(char);
+            // public java.lang.StringBuilder append(int);
+            // public java.lang.StringBuilder append(long);
+            // public java.lang.StringBuilder append(double);
+            public java.lang.StringBuilder reverse();
+            public int length();
+            public java.lang.String toString();
+        }
+
 
         package java.util;
 
@@ -100,6 +200,7 @@ mod auto {
             public abstract int size();
             public abstract boolean isEmpty();
             public abstract boolean contains(java.lang.Object);
+            public abstract java.util.Iterator<E> iterator();
             public abstract <T> T[] toArray(T[]);
             public abstract boolean add(E);
             public abstract boolean remove(java.lang.Object);
@@ -112,11 +213,8 @@ mod auto {
             public abstract int lastIndexOf(java.lang.Object);
             public abstract java.util.List<E> subList(int, int);
 
-            // FIXME: Java generics from static methods are totally
-            // disjoint from the enclosing Self type, but not in Rust.
-            // How do we bridge this gap most ergonomically?
-            //
-            // public static <E> java.util.List<E> of(E...);
+            #[factory]
+            public static <E> java.util.List<E> of(E...);
         }
 
         public class java.util.ArrayList<E> implements java.util.List<E> {
@@ -131,6 +229,7 @@ mod auto {
             public java.lang.Object clone();
             public java.lang.Object[] toArray();
             public E get(int);
+            public java.util.Iterator<E> iterator();
             public E set(int, E);
             public boolean add(E);
             public boolean equals(java.lang.Object);
@@ -140,6 +239,46 @@ mod auto {
             public java.util.List<E> subList(int, int);
         }
 
+        public interface java.util.Iterator<E> {
+            public abstract boolean hasNext();
+            public abstract E next();
+        }
+
+        public interface java.util.Set<E> {
+            public abstract int size();
+            public abstract boolean isEmpty();
+            public abstract boolean contains(java.lang.Object);
+            public abstract java.util.Iterator<E> iterator();
+            public abstract boolean add(E);
+            public abstract boolean remove(java.lang.Object);
+            public abstract void clear();
+        }
+
+        public class java.util.HashSet<E>
+            // extends java.util.AbstractSet<E>
+            implements java.util.Set<E> // , java.lang.Cloneable, java.io.Serializable
+        {
+            public java.util.HashSet();
+            // public java.util.HashSet(java.util.Collection<? extends E>);
+            // public java.util.HashSet(int, float);
+            // public java.util.HashSet(int);
+            public java.util.Iterator<E> iterator();
+            public int size();
+            public boolean isEmpty();
+            public boolean contains(java.lang.Object);
+            public boolean add(E);
+            public boolean remove(java.lang.Object);
+            public void clear();
+        }
+
+        public interface java.util.Comparator<T> {
+            public abstract int compare(T, T);
+            public default java.util.Comparator<T> reversed();
+            // public default java.util.Comparator<T> thenComparing(java.util.Comparator<? super T>);
+            // public static <T extends java.lang.Comparable<? super T>> java.util.Comparator<T> naturalOrder();
+            // public static <T extends java.lang.Comparable<? super T>> java.util.Comparator<T> reverseOrder();
+        }
+
         public interface java.util.Map<K, V> {
             public abstract int size();
             public abstract boolean isEmpty();
@@ -150,9 +289,9 @@ mod auto {
             public abstract V remove(java.lang.Object);
             public abstract void putAll(java.util.Map<? extends K, ? extends V>);
             public abstract void clear();
-            // public abstract java.util.Set<K> keySet();
+            public abstract java.util.Set<K> keySet();
             // public abstract java.util.Collection<V> values();
-            // public abstract java.util.Set<java.util.Map$Entry<K, V>> entrySet();
+            public abstract java.util.Set<java.util.Map$Entry<K, V>> entrySet();
             public abstract boolean equals(java.lang.Object);
             public abstract int hashCode();
             public default V getOrDefault(java.lang.Object, V);
@@ -182,6 +321,19 @@ mod auto {
             // public static <K, V> java.util.Map<K, V> copyOf(java.util.Map<? extends K, ? extends V>);
         }
 
+        public interface java.util.Map$Entry<K, V> {
+            public abstract K getKey();
+            public abstract V getValue();
+            public abstract V setValue(V);
+            public abstract boolean equals(java.lang.Object);
+            public abstract int hashCode();
+            // public static <K extends java.lang.Comparable<? super K>, V> java.util.Comparator<java.util.Map$Entry<K, V>> comparingByKey();
+            // public static <K, V extends java.lang.Comparable<? super V>> java.util.Comparator<java.util.Map$Entry<K, V>> comparingByValue();
+            // public static <K, V> java.util.Comparator<java.util.Map$Entry<K, V>> comparingByKey(java.util.Comparator<? super K>);
+            // public static <K, V> java.util.Comparator<java.util.Map$Entry<K, V>> comparingByValue(java.util.Comparator<? super V>);
+            // public static <K, V> java.util.Map$Entry<K, V> copyOf(java.util.Map$Entry<? extends K, ? extends V>);
+        }
+
         public class java.util.HashMap<K, V>
             // extends java.util.AbstractMap<K, V>
             implements java.util.Map<K, V> // , java.lang.Cloneable, java.io.Serializable
@@ -199,9 +351,9 @@ mod auto {
             public V remove(java.lang.Object);
             public void clear();
             public boolean containsValue(java.lang.Object);
-            // public java.util.Set<K> keySet();
+            public java.util.Set<K> keySet();
             // public java.util.Collection<V> values();
-            // public java.util.Set<java.util.Map$Entry<K, V>> entrySet();
+            public java.util.Set<java.util.Map$Entry<K, V>> entrySet();
             public V getOrDefault(java.lang.Object, V);
             public V putIfAbsent(K, V);
             // public boolean remove(java.lang.Object, java.lang.Object);
@@ -216,7 +368,37 @@ mod auto {
             public java.lang.Object clone();
         }
 
-        public class java.util.Date { // implements java.io.Serializable, java.lang.Cloneable, java.lang.Comparable<java.util.Date> {
+        public class java.util.Collections {
+            public static final <T> java.util.List<T> emptyList();
+            public static <T> java.util.List<T> singletonList(T);
+            public static final <T> java.util.Set<T> emptySet();
+            public static <T> java.util.Set<T> singleton(T);
+            public static final <K, V> java.util.Map<K, V> emptyMap();
+            public static <K, V> java.util.Map<K, V> singletonMap(K, V);
+        }
+
+        public class java.util.Properties
+            // extends java.util.Hashtable<java.lang.Object, java.lang.Object>
+        {
+            public java.util.Properties();
+            public synchronized java.lang.Object setProperty(java.lang.String, java.lang.String);
+            public java.lang.String getProperty(java.lang.String);
+            // public java.lang.String getProperty(java.lang.String, java.lang.String);
+            public java.util.Set<java.lang.String> stringPropertyNames();
+        }
+
+        #[cfg(feature = "uuid")]
+        public final class java.util.UUID implements java.lang.Comparable<java.util.UUID> {
+            public java.util.UUID(long, long);
+            public static java.util.UUID randomUUID();
+            public static java.util.UUID fromString(java.lang.String);
+            public long getMostSignificantBits();
+            public long getLeastSignificantBits();
+            public int version();
+        }
+
+        public class java.util.Date implements java.lang.Comparable<java.util.Date> { // java.io.Serializable, java.lang.Cloneable
+
             public java.util.Date();
             //   public java.util.Date(long);
             //   public java.util.Date(int, int, int);
@@ -256,12 +438,144 @@ mod auto {
             // public int compareTo(java.lang.Object);
             //   static {};
         }
+
+
+        package java.util.logging;
+
+        public abstract class java.util.logging.Handler {
+            public abstract void flush();
+            public abstract void close() throws java.lang.SecurityException;
+        }
+
+        // `Logger.getLogger` is caller sensitive, and fails when called from JNI without a Java caller
+        public class java.util.logging.LogManager {
+            public static java.util.logging.LogManager getLogManager();
+            public java.util.logging.Logger getLogger(java.lang.String);
+        }
+
+        public class java.util.logging.Logger {
+            public java.lang.String getName();
+            public void addHandler(java.util.logging.Handler) throws java.lang.SecurityException;
+            public void removeHandler(java.util.logging.Handler) throws java.lang.SecurityException;
+            public java.util.logging.Handler[] getHandlers();
+            public void severe(java.lang.String);
+            public void warning(java.lang.String);
+            public void info(java.lang.String);
+            public void config(java.lang.String);
+            public void fine(java.lang.String);
+            public void finer(java.lang.String);
+            public void finest(java.lang.String);
+        }
+
+
+        package java.io;
+
+        public class java.io.IOException extends java.lang.Exception {
+            public java.io.IOException(java.lang.String);
+        }
+
+        public interface java.io.Closeable extends java.lang.AutoCloseable {
+            public abstract void close() throws java.io.IOException;
+        }
+
+        public abstract class java.io.InputStream implements java.io.Closeable {
+            public int read(byte[], int, int) throws java.io.IOException;
+            public int available() throws java.io.IOException;
+            public void close() throws java.io.IOException;
+        }
+
+        public abstract class java.io.OutputStream implements java.io.Closeable { // java.io.Flushable
+            public void write(byte[], int, int) throws java.io.IOException;
+            public void flush() throws java.io.IOException;
+            public void close() throws java.io.IOException;
+        }
+
+        public class java.io.FilterOutputStream extends java.io.OutputStream {
+        }
+
+        public class java.io.PrintStream extends java.io.FilterOutputStream { // java.lang.Appendable, java.io.Closeable
+            public java.io.PrintStream(java.io.OutputStream, boolean, java.lang.String) throws java.io.UnsupportedEncodingException;
+            public void flush();
+            public void close();
+            public boolean checkError();
+            public void print(java.lang.String);
+            public void println(java.lang.String);
+        }
+
+        public class java.io.ByteArrayInputStream extends java.io.InputStream {
+            public java.io.ByteArrayInputStream(byte[]);
+            public synchronized int read(byte[], int, int);
+            public synchronized int available();
+        }
+
+        public class java.io.ByteArrayOutputStream extends java.io.OutputStream {
+            public java.io.ByteArrayOutputStream();
+            public synchronized void write(byte[], int, int);
+            public synchronized byte[] toByteArray();
+            public synchronized int size();
+        }
     }
 }
 
 pub use auto::java::*;
 
+/// Bindings for `java.time`, enabled by the `javatime` feature.
+#[cfg(feature = "javatime")]
+mod time_auto {
+    #[cfg(not(doctest))]
+    use crate as duchess;
+
+    duchess_macro::java_package! {
+        package java.time;
+
+        public final class java.time.Instant {
+            public static java.time.Instant ofEpochSecond(long, long);
+            public long getEpochSecond();
+            public int getNano();
+        }
+
+        public final class java.time.Duration {
+            public static java.time.Duration ofSeconds(long, long);
+            public boolean isNegative();
+            public long getSeconds();
+            public int getNano();
+        }
+    }
+}
+
+#[cfg(feature = "javatime")]
+pub use time_auto::java::time;
+
+/// Bindings for `java.math`, enabled by the `javamath` feature.
+#[cfg(feature = "javamath")]
+mod math_auto {
+    #[cfg(not(doctest))]
+    use crate as duchess;
+
+    duchess_macro::java_package! {
+        package java.math;
+
+        public class java.math.BigInteger extends java.lang.Number implements java.lang.Comparable<java.math.BigInteger> {
+            public java.math.BigInteger(byte[]);
+            public byte[] toByteArray();
+            public int signum();
+            public int bitLength();
+        }
+
+        public class java.math.BigDecimal extends java.lang.Number implements java.lang.Comparable<java.math.BigDecimal> {
+            public java.math.BigDecimal(java.math.BigInteger, int);
+            public java.math.BigInteger unscaledValue();
+            public int scale();
+        }
+    }
+}
+
+#[cfg(feature = "javamath")]
+pub use math_auto::java::math;
+
 // XX this isn't a real class in the JVM, since each array type (e.g. Foo[] and int[]) is just a subclass of Object.
 // Should it go somewhere outside of the JDK core classes?
+pub use crate::array::ArrayElement;
 pub use crate::array::JavaArray as Array;
 pub use crate::array::JavaArrayExt as ArrayExt;
+pub use crate::chars::CharArrayExt;
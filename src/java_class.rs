@@ -0,0 +1,51 @@
+use crate::{
+    java::lang::{Class, Object},
+    plumbing::JavaObjectExt,
+    raw::{HasEnvPtr, ObjectPtr},
+    AsJRef, Error, Jvm, JvmOp, Local,
+};
+
+/// [`JvmOp`][] that returns the runtime class of an object, see [`JvmOp::java_class`].
+#[derive(Clone)]
+pub struct JavaClass<This> {
+    this: This,
+}
+
+impl<This> JavaClass<This>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<Object>,
+{
+    pub(crate) fn new(this: This) -> Self {
+        Self { this }
+    }
+}
+
+impl<This> JvmOp for JavaClass<This>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<Object>,
+{
+    type Output<'jvm> = Local<'jvm, Class>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let this = self.this.execute_with(jvm)?;
+        let this = this.as_jref()?;
+
+        let env = jvm.env();
+        // SAFETY: `this` is a valid, non-null reference for the duration of the call
+        let class = unsafe {
+            env.invoke(
+                |env| env.GetObjectClass,
+                |env, f| f(env, this.as_raw().as_ptr()),
+            )
+        };
+        match ObjectPtr::new(class) {
+            // SAFETY: `GetObjectClass` returns a new local reference to a `java.lang.Class`
+            Some(class) => Ok(unsafe { Local::from_raw(env, class) }),
+            None => Err(Error::JvmInternal(
+                "JVM failed to get an object's class".into(),
+            )),
+        }
+    }
+}
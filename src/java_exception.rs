@@ -0,0 +1,161 @@
+//! Java exceptions as standard Rust errors, see [`JvmOp::to_rust_error`].
+
+use std::fmt;
+
+use crate::{
+    java::lang::{ClassExt, StackTraceElement, StackTraceElementExt, Throwable, ThrowableExt},
+    Error, Jvm, JvmOp, Local, ToRust,
+};
+
+/// A Java exception converted to a Rust error, for code that just wants to report it rather than match on its class
+/// with a bespoke enum. It holds no Java references, so it outlives the JVM operation (and thread) it came from.
+///
+/// It displays like Java's `Throwable.toString()`, e.g. `java.lang.IllegalStateException: closed`; the alternate
+/// form (`{:#}`) adds the stack trace, one `at` line per frame. [`source`](std::error::Error::source) is the
+/// exception's cause, so the whole cause chain is reported by error reporters that follow it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JavaException {
+    class_name: String,
+    message: Option<String>,
+    stack_trace: Vec<String>,
+    cause: Option<Box<JavaException>>,
+}
+
+impl JavaException {
+    /// The binary name of the exception's class, e.g. `java.lang.IllegalStateException`.
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// The exception's `getMessage()`, which is `None` if it has none.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// The frames of the exception's stack trace, innermost first, formatted like Java prints them, e.g.
+    /// `com.example.Foo.bar(Foo.java:12)`.
+    pub fn stack_trace(&self) -> &[String] {
+        &self.stack_trace
+    }
+
+    /// The exception's `getCause()`. A cause that was already seen further up the chain is left out, so the chain
+    /// always ends.
+    pub fn cause(&self) -> Option<&JavaException> {
+        self.cause.as_deref()
+    }
+}
+
+impl fmt::Display for JavaException {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{}: {message}", self.class_name)?,
+            None => write!(f, "{}", self.class_name)?,
+        }
+        if f.alternate() {
+            for frame in &self.stack_trace {
+                write!(f, "\n\tat {frame}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for JavaException {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.cause
+            .as_deref()
+            .map(|cause| cause as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl ToRust<JavaException> for Throwable {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, JavaException> {
+        // Java allows cycles in the cause chain, so stop at a cause that was already seen, like `printStackTrace`
+        let mut chain: Vec<Local<'jvm, Throwable>> = vec![jvm.local(self)];
+        loop {
+            let cause = chain.last().unwrap().get_cause().execute_with(jvm)?;
+            let Some(cause) = cause else {
+                break;
+            };
+            let mut seen = false;
+            for throwable in &chain {
+                seen |= throwable.ref_eq(&cause).execute_with(jvm)?;
+            }
+            if seen {
+                break;
+            }
+            chain.push(cause);
+        }
+
+        let mut exception = None;
+        for throwable in chain.iter().rev() {
+            exception = Some(JavaException {
+                class_name: throwable
+                    .java_class()
+                    .get_name()
+                    .assert_not_null()
+                    .to_rust()
+                    .execute_with(jvm)?,
+                message: throwable.get_message().to_rust().execute_with(jvm)?,
+                stack_trace: throwable
+                    .get_stack_trace()
+                    .assert_not_null()
+                    .to_rust()
+                    .execute_with(jvm)?,
+                cause: exception.map(Box::new),
+            });
+        }
+        Ok(exception.unwrap())
+    }
+}
+
+impl ToRust<Box<dyn std::error::Error + Send + Sync>> for Throwable {
+    fn to_rust<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Box<dyn std::error::Error + Send + Sync>> {
+        let exception: JavaException = ToRust::to_rust(self, jvm)?;
+        Ok(Box::new(exception))
+    }
+}
+
+/// A stack frame as Java prints it, e.g. `com.example.Foo.bar(Foo.java:12)`.
+impl ToRust<String> for StackTraceElement {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, String> {
+        StackTraceElementExt::to_string(self)
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)
+    }
+}
+
+/// [`JvmOp`][] that converts the Java exceptions thrown by an operation to Rust errors, see
+/// [`JvmOp::to_rust_error`].
+#[derive(Clone)]
+pub struct ToRustError<This> {
+    this: This,
+}
+
+impl<This> ToRustError<This>
+where
+    This: JvmOp,
+{
+    pub(crate) fn new(this: This) -> Self {
+        Self { this }
+    }
+}
+
+impl<This> JvmOp for ToRustError<This>
+where
+    This: JvmOp,
+{
+    type Output<'jvm> = Result<This::Output<'jvm>, Box<dyn std::error::Error + Send + Sync>>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        match self.this.execute_with(jvm) {
+            Ok(output) => Ok(Ok(output)),
+            Err(Error::Thrown(exception)) => Ok(Err(ToRust::to_rust(&exception, jvm)?)),
+            Err(e) => Err(e),
+        }
+    }
+}
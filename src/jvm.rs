@@ -1,16 +1,32 @@
 use crate::{
-    cast::{AsUpcast, TryDowncast, Upcast},
-    find::find_class,
-    global::{GlobalOp, IntoGlobal},
-    java::lang::{Class, ClassExt, Throwable},
+    call_super::CallSuper,
+    cast::{AsUpcast, DowncastTargets, Returning, TryDowncast, TryDowncastOneOf, Upcast},
+    compare::{CmpTo, SortNatural},
+    error::check_exception,
+    finally::Finally,
+    find::{self, find_class, find_constructor},
+    global::{GlobalOp, IntoGlobal, LocalResultExt},
+    global_iter::{JavaIterable, ToGlobalIter},
+    java::lang::{Class, ClassExt, ClassLoader, Comparable, Object, Throwable},
+    java_class::JavaClass,
+    java_exception::ToRustError,
+    map_java::{AndThenJava, MapJava},
     not_null::NotNull,
     raw::{self, EnvPtr, HasEnvPtr, JvmPtr, ObjectPtr},
-    to_rust::ToRustOp,
+    ref_eq::RefEq,
+    string_chunks::ToRustChunks,
+    thread,
+    to_rust::{ToRustInto, ToRustIntoOp, ToRustOp, ToRustStringOrDefault},
     try_catch::TryCatch,
-    thread, AsJRef, ToRust, TryJDeref, Error, Global, GlobalResult, Local,
+    AsJRef, Error, Global, GlobalResult, IntoJava, IntoLocal, Local, ToRust, TryJDeref,
 };
 
-use std::{ffi::CStr, fmt::Display, ptr::NonNull};
+use std::{
+    ffi::CStr,
+    fmt::Display,
+    ptr::NonNull,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use once_cell::sync::OnceCell;
 
@@ -51,6 +67,52 @@ pub trait JvmOp: Sized {
         TryDowncast::new(self)
     }
 
+    /// Tries to downcast the output of this operation to each class in the tuple `Targets` in order, like a chain of
+    /// `instanceof` checks in Java, and produces the first that matches, or the original output if none do:
+    ///
+    /// ```rust,ignore
+    /// match exception
+    ///     .try_downcast_one_of::<(auth::DeniedException, auth::ExpiredException)>()
+    ///     .execute_with(jvm)?
+    /// {
+    ///     OneOf2::First(denied) => ...,
+    ///     OneOf2::Second(expired) => ...,
+    ///     OneOf2::Other(exception) => ...,
+    /// }
+    /// ```
+    ///
+    /// The result is a [`OneOf2`](crate::OneOf2) for two classes, up to [`OneOf6`](crate::OneOf6) for six. List
+    /// subclasses before their superclasses, since the first match wins.
+    fn try_downcast_one_of<Targets>(self) -> TryDowncastOneOf<Self, Targets>
+    where
+        for<'jvm> Self::Output<'jvm>: TryJDeref,
+        Targets: for<'jvm> DowncastTargets<<Self::Output<'jvm> as TryJDeref>::Java>,
+    {
+        TryDowncastOneOf::new(self)
+    }
+
+    /// Downcasts the result of this operation, typically a method call whose declared return type is broader than what
+    /// it actually returns, to `To`, like a cast in Java:
+    ///
+    /// ```rust,ignore
+    /// let name: String = registry
+    ///     .lookup("name")
+    ///     .returning::<java::lang::String>()
+    ///     .assert_not_null()
+    ///     .to_rust()
+    ///     .execute_with(jvm)?;
+    /// ```
+    ///
+    /// `null` stays `None`. If the object isn't a `To`, the `ClassCastException` thrown by `Class.cast` is returned as
+    /// [`Error::Thrown`](crate::Error::Thrown). Use [`JvmOp::try_downcast`] instead to handle the mismatch.
+    fn returning<To>(self) -> Returning<Self, To>
+    where
+        for<'jvm> Self::Output<'jvm>: TryJDeref,
+        To: for<'jvm> Upcast<<Self::Output<'jvm> as TryJDeref>::Java>,
+    {
+        Returning::new(self)
+    }
+
     /// Most duchess-wrapped Java objects will automatically be able to call all
     /// methods defined on any of its super classes or interfaces it implements,
     /// but this can be used to "force" the output of the operation to be typed
@@ -63,6 +125,23 @@ pub trait JvmOp: Sized {
         AsUpcast::new(self)
     }
 
+    /// Invokes methods on the output of this operation as `super.method(...)` would from a subclass of `S`: the
+    /// implementation declared by (or inherited by) `S` is called, even if the object's class overrides it.
+    ///
+    /// `S` must be a supertype of the output, which is checked at compile time through [`Upcast`].
+    ///
+    /// ```rust,ignore
+    /// // Calls `Object.toString` rather than `Dog.toString`
+    /// let s = dog.call_super::<java::lang::Object>().to_string().execute_with(jvm)?;
+    /// ```
+    fn call_super<S>(self) -> CallSuper<Self, S>
+    where
+        for<'jvm> Self::Output<'jvm>: AsJRef<S>,
+        S: JavaObject,
+    {
+        CallSuper::new(self)
+    }
+
     /// Given a JVM op that creates a local reference, convert the local reference
     /// into a global one. Global JVM references can be held as long as you like
     /// within
@@ -73,6 +152,16 @@ pub trait JvmOp: Sized {
         GlobalOp::new(self)
     }
 
+    /// Catches Java exceptions of type `J` (or its subclasses) thrown while executing this operation,
+    /// producing `Ok(output)` on success and `Err(exception)` if a `J` was thrown. Other exceptions
+    /// continue to propagate as [`Error::Thrown`].
+    ///
+    /// The catch is scoped to `self`: it can be applied to any sub-operation, and exceptions thrown by
+    /// operations executed afterwards are unaffected. Duchess clears the JVM's pending exception as soon as
+    /// it is observed, so a caught exception never lingers into later JNI calls. An exception that doesn't
+    /// match `J` is propagated as an ordinary Rust error rather than re-thrown in the JVM, which means nested
+    /// catches compose: in `op.catch::<A>().catch::<B>()`, the outer catch sees any exception that the
+    /// inner one didn't match.
     fn catch<J>(self) -> TryCatch<Self, J>
     where
         J: Upcast<Throwable>,
@@ -80,6 +169,127 @@ pub trait JvmOp: Sized {
         TryCatch::new(self)
     }
 
+    /// Catches any Java exception thrown while executing this operation and converts it to a Rust error, producing
+    /// `Ok(output)` on success and `Err(error)` if an exception was thrown. The error is a [`JavaException`], which
+    /// captures the class name, message, stack trace and cause chain of the exception so that it can be reported
+    /// with `?` or `anyhow` once the JVM operation is over. Other errors still propagate as a [`crate::Error`].
+    ///
+    /// [`JavaException`]: crate::JavaException
+    fn to_rust_error(self) -> ToRustError<Self> {
+        ToRustError::new(self)
+    }
+
+    /// Passes the output of this operation to `f`, along with the [`Jvm`], so that a step that depends on it can be
+    /// chained into a single operation instead of executing each step separately:
+    ///
+    /// ```rust,ignore
+    /// let len = list.get(0).assert_not_null().map_java(|s, jvm| s.length().execute_with(jvm));
+    /// ```
+    ///
+    /// `f` can execute further operations and create locals, but its result can't refer to them. Use
+    /// [`and_then_java()`][`Self::and_then_java`] to produce a Java object instead, or return a [`Global`].
+    fn map_java<F, R>(self, f: F) -> MapJava<Self, F>
+    where
+        F: for<'jvm> FnOnce(Self::Output<'jvm>, &mut Jvm<'jvm>) -> crate::Result<'jvm, R>,
+    {
+        MapJava::new(self, f)
+    }
+
+    /// Like [`map_java()`][`Self::map_java`], but `f` produces a Java object, so that further operations can be
+    /// chained on its result:
+    ///
+    /// ```rust,ignore
+    /// let request = context
+    ///     .to_java::<java::util::Map<_, _>>()
+    ///     .assert_not_null()
+    ///     .and_then_java(|context, jvm| AuthorizeRequest::new(resource, action, &context).execute_with(jvm))
+    ///     .global()
+    ///     .execute()?;
+    /// ```
+    fn and_then_java<F, T>(self, f: F) -> AndThenJava<Self, F, T>
+    where
+        F: for<'jvm> FnOnce(
+            Self::Output<'jvm>,
+            &mut Jvm<'jvm>,
+        ) -> crate::Result<'jvm, Local<'jvm, T>>,
+        T: JavaObject,
+    {
+        AndThenJava::new(self, f)
+    }
+
+    /// Runs `cleanup` after this operation, whether it succeeded or failed, like a `finally` block in Java:
+    ///
+    /// ```rust,ignore
+    /// let text = (&stream)
+    ///     .map_java(|stream, jvm| read_to_string(stream, jvm))
+    ///     .finally_java(|jvm| stream.close().execute_with(jvm))
+    ///     .execute_with(jvm)?;
+    /// ```
+    ///
+    /// If only `cleanup` fails, its error is returned. If both fail, the error of this operation is returned: when
+    /// both threw an exception, the one thrown by `cleanup` is added to it as a suppressed exception, like Java's
+    /// try-with-resources does, and otherwise the error of `cleanup` is logged.
+    fn finally_java<F>(self, cleanup: F) -> Finally<Self, F>
+    where
+        F: for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::Result<'jvm, ()>,
+    {
+        Finally::new(self, cleanup)
+    }
+
+    /// Compares the output of this operation with `other` using Java's natural ordering, i.e. `compareTo`, mapping
+    /// the result to an [`Ordering`](std::cmp::Ordering). This allows sorting Java objects from Rust:
+    ///
+    /// ```rust,ignore
+    /// words.sort_by(|a, b| a.cmp_to(b).execute_with(jvm).expect("strings are comparable"));
+    /// ```
+    ///
+    /// If `compareTo` throws (e.g. a `ClassCastException` because the objects can't be compared to each other), the
+    /// exception is returned as an [`Error::Thrown`].
+    fn cmp_to<T, O>(self, other: O) -> CmpTo<Self, O, T>
+    where
+        for<'jvm> Self::Output<'jvm>: AsJRef<Comparable<T>>,
+        O: IntoJava<T>,
+        T: Upcast<T>,
+    {
+        CmpTo::new(self, other)
+    }
+
+    /// Sorts the `java.util.List` output by this operation in place, by the natural ordering of its elements. The
+    /// elements are read into Rust, sorted with [`cmp_to`](Self::cmp_to), and written back with `set`, so this works
+    /// for any list that supports `set`, like `Collections.sort` does.
+    ///
+    /// If a comparison throws, the exception is returned as an [`Error::Thrown`] and the list is left unchanged. A
+    /// null element fails with [`Error::NullDeref`].
+    fn sort_natural<E>(self) -> SortNatural<Self, E>
+    where
+        for<'jvm> Self::Output<'jvm>: AsJRef<crate::java::util::List<E>>,
+        E: Upcast<Comparable<E>> + Upcast<E>,
+    {
+        SortNatural::new(self)
+    }
+
+    /// Checks whether the output of this operation and the output of `other` are the same object, like `==` on
+    /// references in Java. This doesn't call any Java method (unlike `equals`), works for references of unrelated
+    /// types, and treats two null references as the same.
+    fn ref_eq<O>(self, other: O) -> RefEq<Self, O>
+    where
+        for<'jvm> Self::Output<'jvm>: TryJDeref,
+        O: JvmOp,
+        for<'jvm> O::Output<'jvm>: TryJDeref,
+    {
+        RefEq::new(self, other)
+    }
+
+    /// Returns the runtime class of the output of this operation, like `getClass()` in Java. Its monitor, held with
+    /// [`Jvm::synchronize`], is the one used by the class's `static synchronized` methods (if they are declared by
+    /// the runtime class itself rather than inherited).
+    fn java_class(self) -> JavaClass<Self>
+    where
+        for<'jvm> Self::Output<'jvm>: AsJRef<Object>,
+    {
+        JavaClass::new(self)
+    }
+
     /// Given a JVM op that returns some Java type, convert it to its Rust equivalent
     /// (e.g., from a Java String to a Rust string).
     fn to_rust<R>(self) -> ToRustOp<Self, R>
@@ -89,11 +299,121 @@ pub trait JvmOp: Sized {
         ToRustOp::new(self)
     }
 
+    /// Like [`to_rust()`][`Self::to_rust`], but writes the Rust equivalent into `target`, reusing its
+    /// allocation where possible (e.g., the buffer of a `String` or `Vec`).
+    fn to_rust_into<R>(self, target: &mut R) -> ToRustIntoOp<'_, Self, R>
+    where
+        R: ?Sized,
+        for<'jvm> Self::Output<'jvm>: ToRustInto<R>,
+    {
+        ToRustIntoOp::new(self, target)
+    }
+
+    /// Converts a `Map<String, String>` or `Properties` to a Rust `HashMap<String, String>`. This is
+    /// [`to_rust()`][`Self::to_rust`] with the result type spelled out, see the [`ToRust`] impls for how nulls and
+    /// non-string properties are handled.
+    fn to_rust_string_map(self) -> ToRustOp<Self, std::collections::HashMap<String, String>>
+    where
+        for<'jvm> Self::Output<'jvm>: ToRust<std::collections::HashMap<String, String>>,
+    {
+        ToRustOp::new(self)
+    }
+
+    /// Converts a `Map` to a `Vec` of its `(key, value)` entries, in the order the map iterates over them, converting
+    /// each key and value with [`ToRust`]:
+    ///
+    /// ```rust,ignore
+    /// let scores: Vec<(String, i32)> = map.to_rust_entries::<String, i32>().execute_with(jvm)?;
+    /// ```
+    ///
+    /// An entry that fails to convert, like one with a null key or value, fails with
+    /// [`Error::ElementConversion`](crate::Error::ElementConversion), see the [`ToRust`] impls.
+    fn to_rust_entries<K, V>(self) -> ToRustOp<Self, Vec<(K, V)>>
+    where
+        for<'jvm> Self::Output<'jvm>: ToRust<Vec<(K, V)>>,
+    {
+        ToRustOp::new(self)
+    }
+
+    /// Converts a `Map` to a Rust `HashMap`, converting each key and value with [`ToRust`]. This is
+    /// [`to_rust()`][`Self::to_rust`] with the result type spelled out, see the [`ToRust`] impls for how nulls are
+    /// handled.
+    fn to_rust_map<K, V>(self) -> ToRustOp<Self, std::collections::HashMap<K, V>>
+    where
+        for<'jvm> Self::Output<'jvm>: ToRust<std::collections::HashMap<K, V>>,
+    {
+        ToRustOp::new(self)
+    }
+
+    /// Converts a Java string that may be null, like the message of an exception, to a Rust `String`, which is empty
+    /// if the Java string is null. This saves the `.assert_not_null().to_rust()` that would fail on a null string:
+    ///
+    /// ```rust,ignore
+    /// let message = exception.get_message().to_rust_string_or_default().execute_with(jvm)?;
+    /// ```
+    ///
+    /// To tell a null string apart from an empty one, convert to an `Option` with `.to_rust::<Option<String>>()`.
+    fn to_rust_string_or_default(self) -> ToRustStringOrDefault<Self>
+    where
+        for<'jvm> Self::Output<'jvm>: ToRust<Option<String>>,
+    {
+        ToRustStringOrDefault::new(self)
+    }
+
+    /// Iterates over the elements of the Java collection (or `java.util.Iterator`) produced by this operation,
+    /// converting each of them to the Rust type `R`. Unlike converting the whole collection with
+    /// [`to_rust()`][`Self::to_rust`], the elements are converted one at a time as the iterator is advanced:
+    ///
+    /// ```rust,ignore
+    /// let names: GlobalIter<String> = list.to_global_iter().execute()?;
+    /// for name in names {
+    ///     println!("{}", name?);
+    /// }
+    /// ```
+    ///
+    /// The resulting [`GlobalIter`](crate::GlobalIter) holds the Java iterator as a global reference, so it isn't tied
+    /// to the [`Jvm`] and can be stored or returned. Each step attaches the current thread to the JVM if needed,
+    /// which is cheapest on threads attached with [`Jvm::attach_thread_permanently`].
+    fn to_global_iter<R>(self) -> ToGlobalIter<Self, R>
+    where
+        for<'jvm> Self::Output<'jvm>: TryJDeref,
+        for<'jvm> <Self::Output<'jvm> as TryJDeref>::Java: JavaIterable,
+        for<'jvm> <<Self::Output<'jvm> as TryJDeref>::Java as JavaIterable>::Item: ToRust<R>,
+        R: 'static,
+    {
+        ToGlobalIter::new(self)
+    }
+
+    /// Converts the Java string produced by this operation to Rust a piece at a time, so that a huge string can be
+    /// processed without allocating all of it in Rust memory. The pieces have at most `max_len` UTF-16 code units
+    /// (Java `char`s) each, which is bytes of UTF-8 for ASCII text:
+    ///
+    /// ```rust,ignore
+    /// let chunks: StringChunks = document.to_rust_chunks(64 * 1024).execute()?;
+    /// for chunk in chunks {
+    ///     hasher.update(chunk?.as_bytes());
+    /// }
+    /// ```
+    ///
+    /// Like [`to_global_iter()`](Self::to_global_iter), the resulting [`StringChunks`](crate::StringChunks) holds
+    /// the string as a global reference, so it isn't tied to the [`Jvm`]. Panics if `max_len` is 0.
+    fn to_rust_chunks(self, max_len: usize) -> ToRustChunks<Self>
+    where
+        for<'jvm> Self::Output<'jvm>: AsJRef<crate::java::lang::String>,
+    {
+        ToRustChunks::new(self, max_len)
+    }
+
     /// Execute the jvm op, starting a JVM instance if necessary.
     /// To use this method, the result type cannot be tied to the JVM.
     /// Typically this is achieved by a call to [`to_rust()`][`Self::to_rust`],
     /// but if you wish to hold on to a reference to a JVM object,
     /// you can use [`global()`][`Self::global`] to create a global reference.
+    ///
+    /// If this call attaches the current thread to the JVM, it also detaches it once the op completes, so one-shot
+    /// calls from short-lived threads don't keep occupying a JVM thread. Threads that were already attached, either
+    /// through [`Jvm::attach_thread_permanently`] or outside of duchess (e.g. a Java thread calling into Rust), are
+    /// left attached. See [`Jvm::with`] for more.
     fn execute<R>(self) -> crate::GlobalResult<R>
     where
         for<'jvm> Self: JvmOp<Output<'jvm> = R>,
@@ -101,6 +421,21 @@ pub trait JvmOp: Sized {
         Jvm::with(|jvm| self.execute_with(jvm))
     }
 
+    /// Execute the jvm op like [`execute()`][`Self::execute`], but give up after `timeout`.
+    ///
+    /// JNI calls can't be preempted, so the op runs on a dedicated thread. If it doesn't complete in time, that
+    /// thread is interrupted with `Thread.interrupt()` and [`Error::Timeout`] is returned right away. This only
+    /// stops operations that respond to interrupts, such as `Thread.sleep`, `Object.wait`, or interruptible IO and
+    /// locks; other operations keep running in the background until they complete, and their result is discarded.
+    fn execute_with_timeout<R>(self, timeout: std::time::Duration) -> crate::GlobalResult<R>
+    where
+        Self: Send + 'static,
+        R: Send + 'static,
+        for<'jvm> Self: JvmOp<Output<'jvm> = R>,
+    {
+        crate::timeout::execute_with_timeout(self, timeout)
+    }
+
     fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>>;
 }
 
@@ -111,7 +446,18 @@ impl IsVoid for () {}
 
 static GLOBAL_JVM: OnceCell<JvmPtr> = OnceCell::new();
 
+/// Set once [`run`] has destroyed the JVM. `GLOBAL_JVM` can't be unset, so it is checked before using that.
+static DESTROYED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the JVM was destroyed by [`run`], so that `GLOBAL_JVM` dangles.
+pub(crate) fn is_destroyed() -> bool {
+    DESTROYED.load(Ordering::SeqCst)
+}
+
 fn get_or_default_init_jvm() -> crate::GlobalResult<JvmPtr> {
+    if is_destroyed() {
+        return Err(Error::JvmDestroyed);
+    }
     match GLOBAL_JVM.get() {
         Some(jvm) => Ok(*jvm),
         None => {
@@ -133,7 +479,7 @@ pub(crate) fn unwrap_global_jvm() -> JvmPtr {
     *GLOBAL_JVM.get().expect("JVM can't be unset")
 }
 
-pub struct Jvm<'jvm>(EnvPtr<'jvm>);
+pub struct Jvm<'jvm>(pub(crate) EnvPtr<'jvm>);
 
 impl<'jvm> Jvm<'jvm> {
     pub fn builder() -> JvmBuilder {
@@ -145,6 +491,11 @@ impl<'jvm> Jvm<'jvm> {
         Ok(())
     }
 
+    /// Runs `op` with the JVM, launching it with the default settings if needed.
+    ///
+    /// If the current thread isn't attached to the JVM, it is attached for the duration of `op` and detached
+    /// afterwards. Threads attached with [`Jvm::attach_thread_permanently`], or attached outside of duchess, stay
+    /// attached.
     pub fn with<R>(
         op: impl for<'a> FnOnce(&mut Jvm<'a>) -> crate::Result<'a, R>,
     ) -> crate::GlobalResult<R> {
@@ -153,7 +504,80 @@ impl<'jvm> Jvm<'jvm> {
         let mut guard = unsafe { thread::attach(jvm)? };
 
         let mut jvm = Jvm(guard.env());
-        op(&mut jvm).map_err(|e| e.into_global(&mut jvm))
+        op(&mut jvm).into_global_result(&mut jvm)
+    }
+
+    /// Creates an instance of the class named `class_name`, like `com.example.MyPlugin` (or `com.example.Outer$Inner`
+    /// for a nested class), with its no-argument constructor, and returns it as a `T`. This is for classes that are
+    /// only known at runtime, like plugins implementing a bound interface:
+    ///
+    /// ```rust,ignore
+    /// let plugin = jvm.new_instance::<plugins::Plugin>(&plugin_class_name)?;
+    /// plugin.start().execute_with(jvm)?;
+    /// ```
+    ///
+    /// The class is loaded like those of bindings. If it can't be found or has no no-argument constructor, the
+    /// `NoClassDefFoundError` or `NoSuchMethodError` is returned as [`Error::Thrown`], and if it isn't a subtype of
+    /// `T`, an [`Error::Conversion`] is returned without creating an instance.
+    pub fn new_instance<T>(&mut self, class_name: &str) -> crate::Result<'jvm, Local<'jvm, T>>
+    where
+        T: JavaObject,
+    {
+        let jni_name = std::ffi::CString::new(class_name.replace('.', "/"))
+            .map_err(|_| Error::JvmInternal(format!("invalid class name `{class_name}`")))?;
+        let class = find_class(self, &jni_name)?;
+
+        let expected = T::class(self)?;
+        let env = self.env();
+        let is_subtype = unsafe {
+            env.invoke(
+                |env| env.IsAssignableFrom,
+                |env, f| f(env, class.as_raw().as_ptr(), expected.as_raw().as_ptr()),
+            ) != jni_sys::JNI_FALSE
+        };
+        if !is_subtype {
+            let expected: String = expected
+                .get_name()
+                .assert_not_null()
+                .to_rust()
+                .execute_with(self)?;
+            return Err(Error::Conversion(format!(
+                "`{class_name}` is not a subtype of `{expected}`"
+            )));
+        }
+
+        let constructor = find_constructor(self, &class, c"()V")?;
+
+        let obj = unsafe {
+            env.invoke(
+                |env| env.NewObjectA,
+                |env, f| {
+                    f(
+                        env,
+                        class.as_raw().as_ptr(),
+                        constructor.as_ptr(),
+                        std::ptr::null(),
+                    )
+                },
+            )
+        };
+        if let Some(obj) = ObjectPtr::new(obj) {
+            // SAFETY: the class of `obj` is a subtype of `T`, as checked above
+            Ok(unsafe { Local::from_raw(env, obj) })
+        } else {
+            check_exception(self)?;
+            Err(Error::JvmInternal(format!(
+                "failed to create a new `{class_name}`"
+            )))
+        }
+    }
+
+    /// The `JNIEnv` of the current thread, to call JNI functions that duchess doesn't cover directly. The pointer is
+    /// only valid while this `Jvm` is. Local references created through it aren't freed by duchess, and exceptions
+    /// thrown by the calls must be taken with [`Jvm::take_pending_exception`] before executing duchess operations
+    /// again.
+    pub fn raw_env(&self) -> *mut jni_sys::JNIEnv {
+        self.0.as_ptr()
     }
 
     pub fn local<R>(&mut self, r: &R) -> Local<'jvm, R>
@@ -169,6 +593,20 @@ impl<'jvm> Jvm<'jvm> {
     {
         Global::new(self.0, r)
     }
+
+    /// Converts `value` to the Rust type `R`, which can be named at the call site:
+    ///
+    /// ```rust,ignore
+    /// let error = jvm.to_rust::<AuthenticateError>(&exception)?;
+    /// ```
+    ///
+    /// This is [`ToRust::to_rust`] with the arguments swapped. Calling `value.to_rust(jvm)` directly is ambiguous on
+    /// a [`Local`] or [`Global`], which also have [`JvmOp::to_rust`], and needs a type annotation when the Java type
+    /// converts to more than one Rust type. For an operation, [`JvmOp::to_rust`] takes the type in the same way:
+    /// `op.to_rust::<AuthenticateError>()`.
+    pub fn to_rust<R>(&mut self, value: &impl ToRust<R>) -> crate::Result<'jvm, R> {
+        ToRust::to_rust(value, self)
+    }
 }
 
 impl<'jvm> HasEnvPtr<'jvm> for Jvm<'jvm> {
@@ -177,23 +615,63 @@ impl<'jvm> HasEnvPtr<'jvm> for Jvm<'jvm> {
     }
 }
 
+/// Launches the JVM with the default settings, runs `op` with it like [`Jvm::with`], and then destroys the JVM with
+/// `DestroyJavaVM`. This is an entry point for `main`, which shuts the JVM down at a known point, running its shutdown
+/// hooks and flushing its output, instead of leaving that to the exit of the process:
+///
+/// ```rust,ignore
+/// fn main() -> duchess::GlobalResult<()> {
+///     duchess::run(|jvm| {
+///         java::lang::System::get_property("java.version")
+///             .assert_not_null()
+///             .to_rust::<String>()
+///             .execute_with(jvm)
+///     })?;
+///     Ok(())
+/// }
+/// ```
+///
+/// `Jvm::builder().run(op)` does the same with a configured JVM. Returns [`Error::JvmAlreadyExists`] without running
+/// `op` if a JVM was already launched, since duchess only destroys a JVM it launched itself.
+///
+/// `DestroyJavaVM` waits for all other non-daemon Java threads to finish, including Rust threads attached with
+/// [`Jvm::attach_thread_permanently`], so those must have detached or exited. JNI only fully supports destroying the JVM
+/// from the thread that launched it, which is why this launches the JVM too; call it from `main`, or from a thread
+/// that will outlive every other use of the JVM. A JVM can't be launched again in the same process once it has been
+/// destroyed, so afterwards every use of duchess fails with [`Error::JvmDestroyed`]. Java references that outlive
+/// `op`, e.g. a [`Global`] in its result, can't be used, and dropping them does nothing. An exception thrown by `op` is
+/// returned as an [`Error::JvmInternal`] with its message.
+pub fn run<R>(op: impl for<'a> FnOnce(&mut Jvm<'a>) -> crate::Result<'a, R>) -> GlobalResult<R> {
+    Jvm::builder().run(op)
+}
+
+/// Creates the class loader configured with [`JvmBuilder::class_loader`].
+type ClassLoaderFn = Box<
+    dyn for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, ClassLoader>> + Send,
+>;
+
 pub struct JvmBuilder {
     options: Vec<String>,
+    check_jni: bool,
+    jni_version: jni_sys::jint,
     #[cfg(feature = "dylibjvm")]
     libjvm_path: Option<std::path::PathBuf>,
+    class_loader: Option<ClassLoaderFn>,
+    route_java_logging: bool,
 }
 
 impl JvmBuilder {
     fn new() -> Self {
         let mut this = Self {
             options: vec![],
+            check_jni: cfg!(debug_assertions),
+            jni_version: raw::DEFAULT_VERSION,
             #[cfg(feature = "dylibjvm")]
             libjvm_path: None,
+            class_loader: None,
+            route_java_logging: false,
         };
 
-        if cfg!(debug_assertions) {
-            this = this.custom("-Xcheck:jni");
-        }
         if let Ok(classpath) = std::env::var("CLASSPATH") {
             this = this.add_classpath(classpath);
         }
@@ -210,24 +688,146 @@ impl JvmBuilder {
         self
     }
 
+    /// Whether to launch the JVM with `-Xcheck:jni`, which validates the arguments of every JNI call and reports
+    /// misuse as a warning or a fatal error. This is on by default in debug builds.
+    ///
+    /// The checks slow down every JNI call significantly and are meant for debugging only. For even more detail,
+    /// `.custom("-verbose:jni")` logs the registration of native methods.
+    pub fn check_jni(mut self, enabled: bool) -> Self {
+        self.check_jni = enabled;
+        self
+    }
+
+    /// The JNI version to request when launching the JVM and attaching threads to it, one of the `JNI_VERSION_*`
+    /// constants in [`jni_sys`]. Newer versions are needed for some JNI functions, e.g. `GetModule` needs
+    /// `JNI_VERSION_9`. The default is `JNI_VERSION_1_8`, which every JVM since Java 8 supports.
+    ///
+    /// Launching fails with [`Error::UnsupportedJniVersion`] if the JVM doesn't support `version`. When using an
+    /// existing JVM that wasn't launched by duchess, the error is returned by the first [`Jvm::with`] instead. Has no
+    /// effect if the JVM has already been launched by duchess.
+    pub fn jni_version(mut self, version: jni_sys::jint) -> Self {
+        self.jni_version = version;
+        self
+    }
+
+    /// Load `libjvm` from `path` instead of locating it through `JAVA_HOME` or the `java` executable on the `PATH`.
+    /// Has no effect if `libjvm` has already been loaded.
     #[cfg(feature = "dylibjvm")]
     pub fn load_libjvm_at(mut self, path: impl AsRef<std::path::Path>) -> Self {
         self.libjvm_path = Some(path.as_ref().into());
         self
     }
 
+    /// Load the classes of bindings through the class loader returned by `class_loader` instead of with JNI's
+    /// `FindClass`, which uses the system class loader on threads started in Rust. Use this when the bound classes
+    /// are loaded by another class loader, as in an application server or a plugin system. JDK classes (in `java.*`
+    /// packages) are still loaded with `FindClass`.
+    ///
+    /// `class_loader` runs once the JVM has been launched, or found if it already exists. Classes that were looked up
+    /// before then keep the class they were found as. Returns an error if a class loader was already configured.
+    pub fn class_loader(
+        mut self,
+        class_loader: impl for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, ClassLoader>>
+            + Send
+            + 'static,
+    ) -> Self {
+        self.class_loader = Some(Box::new(class_loader));
+        self
+    }
+
+    /// Forward the records logged with `java.util.logging` to the [`tracing`] crate, so Java logs go through the
+    /// application's logging pipeline. The handlers of the root logger, e.g. the default one printing to `System.err`,
+    /// are replaced by one that emits a `tracing` event with the target `java` for each record, with `logger`,
+    /// `thread` and, if an exception was logged, `exception` (its stack trace) fields. `SEVERE` maps to
+    /// [`ERROR`](tracing::Level::ERROR), `WARNING` to `WARN`, `INFO` and `CONFIG` to `INFO`, `FINE` and `FINER` to
+    /// `DEBUG`, and `FINEST` to `TRACE`.
+    ///
+    /// The levels of the Java loggers still apply, so e.g. `FINE` records only reach Rust if the logger's level lets
+    /// them through. Like [`class_loader`](Self::class_loader), this takes effect once the JVM has been launched or
+    /// found, and only the first time.
+    pub fn route_java_logging(mut self) -> Self {
+        self.route_java_logging = true;
+        self
+    }
+
     /// Launch a new JVM, returning [`Error::JvmAlreadyExists`] if one already exists.
-    pub fn try_launch(self) -> GlobalResult<()> {
+    pub fn try_launch(mut self) -> GlobalResult<()> {
+        let class_loader = self.class_loader.take();
+        let route_java_logging = self.route_java_logging;
+        self.create()?;
+        install_class_loader(class_loader)?;
+        if route_java_logging {
+            crate::logging::route_java_logging()?;
+        }
+        Ok(())
+    }
+
+    pub fn launch_or_use_existing(mut self) -> GlobalResult<()> {
+        let class_loader = self.class_loader.take();
+        let route_java_logging = self.route_java_logging;
+        let jni_version = self.jni_version;
+        match self.create() {
+            Err(Error::JvmAlreadyExists) => {
+                // Two cases: (1) another thread successfully invoked try_launch() and we'll now get the pointer out of
+                // GLOBAL_JVM, or (2) the JVM was created by some non-duchess code and we'll now need to look it up with
+                // the existing_jvm() call.
+                GLOBAL_JVM.get_or_try_init(|| {
+                    // SAFETY: we're behind the GLOBAL_JVM lock and we won't race with other threads creating or finding
+                    // an existing JVM.
+                    let jvm = unsafe { raw::existing_jvm() }?.expect("JVM should already exist");
+                    raw::set_version(jni_version);
+                    GlobalResult::Ok(jvm)
+                })?;
+            }
+            result => result?,
+        }
+        install_class_loader(class_loader)?;
+        if route_java_logging {
+            crate::logging::route_java_logging()?;
+        }
+        Ok(())
+    }
+
+    /// Launches a new JVM, runs `op` with it like [`Jvm::with`], and then destroys the JVM, see [`run`].
+    pub fn run<R>(
+        self,
+        op: impl for<'a> FnOnce(&mut Jvm<'a>) -> crate::Result<'a, R>,
+    ) -> GlobalResult<R> {
+        self.try_launch()?;
+        // An exception is described while it can still be, as the reference to it won't outlive the JVM.
+        let result = Jvm::with(op).map_err(|error| match error {
+            Error::Thrown(_) => Error::JvmInternal(error.to_string()),
+            error => error,
+        });
+
+        let jvm = *GLOBAL_JVM.get().expect("try_launch must set GLOBAL_JVM");
+        DESTROYED.store(true, Ordering::SeqCst);
+        // SAFETY: the JVM was launched above, so it's ours to destroy, and nothing uses it once `DESTROYED` is set
+        unsafe { jvm.destroy()? };
+        result
+    }
+
+    fn create(self) -> GlobalResult<()> {
+        if is_destroyed() {
+            return Err(Error::JvmDestroyed);
+        }
+
         #[cfg(feature = "dylibjvm")]
         if let Some(path) = self.libjvm_path {
             crate::libjvm::libjvm_or_load_at(&path)?;
         }
 
+        let mut options = self.options;
+        if self.check_jni {
+            options.push("-Xcheck:jni".to_string());
+        }
+
         let mut already_exists = true;
         GLOBAL_JVM.get_or_try_init(|| {
             // SAFETY: we're behind the GLOBAL_JVM lock and we won't race with other threads creating or finding an
             // existing JVM.
-            let jvm = unsafe { raw::try_create_jvm(self.options.into_iter()) }?;
+            let jvm = unsafe { raw::try_create_jvm(options.into_iter(), self.jni_version) }?;
+            raw::set_version(self.jni_version);
             already_exists = false;
             GlobalResult::Ok(jvm)
         })?;
@@ -238,25 +838,16 @@ impl JvmBuilder {
             Ok(())
         }
     }
+}
 
-    pub fn launch_or_use_existing(self) -> GlobalResult<()> {
-        match self.try_launch() {
-            Err(Error::JvmAlreadyExists) => {
-                // Two cases: (1) another thread successfully invoked try_launch() and we'll now get the pointer out of
-                // GLOBAL_JVM, or (2) the JVM was created by some non-duchess code and we'll now need to look it up with
-                // the existing_jvm() call.
-                GLOBAL_JVM.get_or_try_init(|| {
-                    // SAFETY: we're behind the GLOBAL_JVM lock and we won't race with other threads creating or finding
-                    // an existing JVM.
-                    GlobalResult::Ok(
-                        unsafe { raw::existing_jvm() }?.expect("JVM should already exist"),
-                    )
-                })?;
-                Ok(())
-            }
-            result => result,
-        }
-    }
+fn install_class_loader(class_loader: Option<ClassLoaderFn>) -> GlobalResult<()> {
+    let Some(class_loader) = class_loader else {
+        return Ok(());
+    };
+    Jvm::with(|jvm| {
+        let loader = class_loader(jvm)?;
+        find::set_class_loader(jvm.global(&loader))
+    })
 }
 
 /// A trait for zero-sized dummy types that represent Java object types.
@@ -308,6 +899,19 @@ impl<T: JavaObject> JavaObjectExt for T {
     }
 }
 
+/// Java classes that can be instantiated with a public no-argument constructor. `java_package!` implements this for
+/// each bound class that has one, so generic code can create instances of any such class.
+///
+/// ```ignore
+/// fn fresh<T: JavaDefault>() -> duchess::GlobalResult<Global<T>> {
+///     T::java_default().global().execute()
+/// }
+/// ```
+pub trait JavaDefault: JavaObject {
+    /// Returns an operation that calls the no-argument constructor, the same as `T::new()`.
+    fn java_default() -> impl IntoLocal<Self>;
+}
+
 pub unsafe trait JavaType: 'static {
     /// Returns the Java Class object for a Java array containing elements of
     /// `Self`. All Java types, even scalars can be elements of an array object.
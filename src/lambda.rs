@@ -0,0 +1,336 @@
+//! Implementing Java functional interfaces with Rust closures, see [`Lambda`].
+
+use std::{ffi::c_void, marker::PhantomData};
+
+use once_cell::sync::OnceCell;
+
+use crate::{
+    error::check_exception,
+    find::find_method,
+    java::{self, lang::Object},
+    jvm::JavaObjectExt,
+    raw::{HasEnvPtr, MethodPtr, ObjectPtr},
+    Error, Global, JavaObject, Jvm, JvmOp, Local, NativeMethod,
+};
+
+/// The class file of `duchess.RustInvocationHandler`, compiled from `lambda/RustInvocationHandler.java`. It isn't on
+/// the classpath, so it is defined with `DefineClass` the first time a closure is converted.
+const CLASS_FILE: &[u8] = include_bytes!("lambda/RustInvocationHandler.class");
+
+/// The closure a `RustInvocationHandler` calls, with the arguments of the interface method boxed as objects. The
+/// handler holds a pointer to a `Box<Closure>` in its `closure` field.
+type Closure = dyn for<'jvm> Fn(
+        &mut Jvm<'jvm>,
+        Vec<Option<Local<'jvm, Object>>>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, Object>>>
+    + Send
+    + Sync;
+
+/// An operation that implements the functional (single-abstract-method) interface `I` with a Rust closure, producing
+/// a `java.lang.reflect.Proxy` that calls the closure when Java calls the interface's abstract method. `java_package!`
+/// generates a `from_fn` function creating one for each functional interface, which takes a closure with the method's
+/// argument and return types:
+///
+/// ```rust,ignore
+/// // interface Transformer { String apply(String input); }
+/// let upper = Transformer::from_fn(|jvm, input| {
+///     let input: String = jvm.to_rust(&*input.ok_or(duchess::Error::NullDeref)?)?;
+///     input.to_uppercase().as_str().execute_with(jvm).map(Some)
+/// });
+/// ```
+///
+/// Like other operations producing an `I`, it can be passed as an argument where Java expects an `I`. Each execution
+/// creates a new proxy, so it can only be executed once.
+///
+/// The closure may be called from any Java thread, and more than once at the same time, so it must be `Send + Sync`.
+/// Errors it returns are thrown to Java as by [`Jvm::native`]. It is dropped when the proxy is garbage collected.
+/// Calls to `equals`, `hashCode` and `toString` don't reach the closure: proxies are only equal to themselves. Default
+/// methods of the interface are called as implemented by the interface, which needs Java 16 or later.
+#[must_use]
+pub struct Lambda<I> {
+    closure: Box<Closure>,
+    _interface: PhantomData<fn() -> I>,
+}
+
+impl<I> Lambda<I>
+where
+    I: JavaObject,
+{
+    /// Implements `I` with `closure`, which receives the arguments of the abstract method boxed as objects, like
+    /// Java's `InvocationHandler`, and returns its result as an object, which Java casts or unboxes to the method's
+    /// return type. The generated `from_fn` functions are easier to use.
+    pub fn new<F>(closure: F) -> Self
+    where
+        F: for<'jvm> Fn(
+                &mut Jvm<'jvm>,
+                Vec<Option<Local<'jvm, Object>>>,
+            ) -> crate::Result<'jvm, Option<Local<'jvm, Object>>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Lambda {
+            closure: Box::new(closure),
+            _interface: PhantomData,
+        }
+    }
+}
+
+impl<I> JvmOp for Lambda<I>
+where
+    I: JavaObject,
+{
+    type Output<'jvm> = Local<'jvm, I>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, I>> {
+        static NEW_PROXY: OnceCell<MethodPtr> = OnceCell::new();
+
+        let class = handler_class(jvm)?;
+        let new_proxy = NEW_PROXY.get_or_try_init(|| {
+            find_method(
+                jvm,
+                class,
+                c"newProxy",
+                c"(Ljava/lang/Class;J)Ljava/lang/Object;",
+                true,
+            )
+        })?;
+        let interface = I::class(jvm)?;
+
+        let closure: *mut Box<Closure> = Box::into_raw(Box::new(self.closure));
+        let env = jvm.env();
+        let proxy = unsafe {
+            env.invoke(
+                |env| env.CallStaticObjectMethodA,
+                |env, f| {
+                    f(
+                        env,
+                        class.as_raw().as_ptr(),
+                        new_proxy.as_ptr(),
+                        [
+                            jni_sys::jvalue {
+                                l: interface.as_raw().as_ptr(),
+                            },
+                            jni_sys::jvalue {
+                                j: closure as jni_sys::jlong,
+                            },
+                        ]
+                        .as_ptr(),
+                    )
+                },
+            )
+        };
+        if let Err(error) = check_exception(jvm) {
+            // SAFETY: `newProxy` threw, so the handler gave up the closure
+            drop(unsafe { Box::from_raw(closure) });
+            return Err(error);
+        }
+        match ObjectPtr::new(proxy) {
+            // SAFETY: `newProxy` returns a new local reference to a proxy implementing `I`
+            Some(proxy) => Ok(unsafe { Local::from_raw(env, proxy) }),
+            None => Err(Error::JvmInternal(
+                "`duchess.RustInvocationHandler.newProxy` returned null".to_string(),
+            )),
+        }
+    }
+}
+
+/// Converts an argument of a functional interface method, as passed to a [`Lambda`], to the type the closure of the
+/// generated `from_fn` function takes for it: `Option<Local<T>>` for objects, and the scalar for boxed scalars.
+pub trait LambdaArg<'jvm>: Sized {
+    /// # Safety
+    ///
+    /// `arg` must be `null` or an instance of the Java type of `Self`.
+    unsafe fn from_lambda_arg(
+        jvm: &mut Jvm<'jvm>,
+        arg: Option<Local<'jvm, Object>>,
+    ) -> crate::Result<'jvm, Self>;
+}
+
+/// Converts the result of the closure of a generated `from_fn` function to the object returned to Java, see
+/// [`LambdaArg`].
+pub trait LambdaResult<'jvm> {
+    fn into_lambda_result(
+        self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, Object>>>;
+}
+
+impl<'jvm, T> LambdaArg<'jvm> for Option<Local<'jvm, T>>
+where
+    T: JavaObject,
+{
+    unsafe fn from_lambda_arg(
+        jvm: &mut Jvm<'jvm>,
+        arg: Option<Local<'jvm, Object>>,
+    ) -> crate::Result<'jvm, Self> {
+        Ok(arg.map(|arg| {
+            let obj = arg.as_raw();
+            // The new `Local` owns the local ref
+            std::mem::forget(arg);
+            // SAFETY: the caller vouches that `arg` is a `T`
+            unsafe { Local::from_raw(jvm.env(), obj) }
+        }))
+    }
+}
+
+impl<'jvm, T> LambdaResult<'jvm> for Option<Local<'jvm, T>>
+where
+    T: JavaObject,
+{
+    fn into_lambda_result(
+        self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, Object>>> {
+        Ok(self.map(|result| {
+            let obj = result.as_raw();
+            // The new `Local` owns the local ref
+            std::mem::forget(result);
+            // SAFETY: every object is an `Object`
+            unsafe { Local::from_raw(jvm.env(), obj) }
+        }))
+    }
+}
+
+/// Void methods return `null`, which Java ignores.
+impl<'jvm> LambdaResult<'jvm> for () {
+    fn into_lambda_result(
+        self,
+        _jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, Object>>> {
+        Ok(None)
+    }
+}
+
+macro_rules! boxed_lambda_scalar {
+    ($($rust:ty => $java:ident,)*) => {
+        $(
+            /// Java passes scalar arguments boxed, and never `null`.
+            impl<'jvm> LambdaArg<'jvm> for $rust {
+                unsafe fn from_lambda_arg(
+                    jvm: &mut Jvm<'jvm>,
+                    arg: Option<Local<'jvm, Object>>,
+                ) -> crate::Result<'jvm, Self> {
+                    let boxed = unsafe { Option::<Local<'jvm, java::lang::$java>>::from_lambda_arg(jvm, arg)? };
+                    jvm.to_rust(&*boxed.ok_or(Error::NullDeref)?)
+                }
+            }
+
+            /// Scalar results are boxed, and unboxed by Java.
+            impl<'jvm> LambdaResult<'jvm> for $rust {
+                fn into_lambda_result(
+                    self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::Result<'jvm, Option<Local<'jvm, Object>>> {
+                    let boxed = java::lang::$java::value_of(self).execute_with(jvm)?;
+                    Ok(boxed.map(|boxed| boxed.upcast()))
+                }
+            }
+        )*
+    };
+}
+
+boxed_lambda_scalar! {
+    i32 => Integer,
+    i64 => Long,
+    f64 => Double,
+    bool => Boolean,
+}
+
+/// Defines `duchess.RustInvocationHandler` and binds its native methods, the first time it is needed.
+fn handler_class<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, &'static Global<java::lang::Class>> {
+    static CLASS: OnceCell<Global<java::lang::Class>> = OnceCell::new();
+
+    CLASS.get_or_try_init(|| {
+        let env = jvm.env();
+        // Defined by the bootstrap class loader, so it is found no matter which class loader is configured. It only
+        // refers to JDK classes, and proxies are defined by the class loader of their interface.
+        let class = unsafe {
+            env.invoke(
+                |env| env.DefineClass,
+                |env, f| {
+                    f(
+                        env,
+                        c"duchess/RustInvocationHandler".as_ptr(),
+                        std::ptr::null_mut(),
+                        CLASS_FILE.as_ptr().cast(),
+                        CLASS_FILE.len() as jni_sys::jsize,
+                    )
+                },
+            )
+        };
+        let Some(class) = ObjectPtr::new(class) else {
+            check_exception(jvm)?;
+            return Err(Error::JvmInternal(
+                "failed to define `duchess.RustInvocationHandler`".to_string(),
+            ));
+        };
+        // SAFETY: DefineClass returns a new local reference to the class
+        let class: Local<'jvm, java::lang::Class> = unsafe { Local::from_raw(env, class) };
+
+        // SAFETY: the functions match the descriptors
+        let methods = unsafe {
+            [
+                NativeMethod::new(
+                    c"call",
+                    c"(J[Ljava/lang/Object;)Ljava/lang/Object;",
+                    call as *const c_void,
+                ),
+                NativeMethod::new(c"release", c"(J)V", release as *const c_void),
+            ]
+        };
+        jvm.register_natives_on(&class, &methods)?;
+        Ok(jvm.global(&*class))
+    })
+}
+
+extern "system" fn call(
+    env: *mut jni_sys::JNIEnv,
+    _this: jni_sys::jobject,
+    closure: jni_sys::jlong,
+    args: jni_sys::jobject,
+) -> jni_sys::jobject {
+    unsafe {
+        Jvm::native(env, |jvm| {
+            let args = jvm
+                .native_arg::<java::Array<Object>>(args)
+                .ok_or(Error::NullDeref)?;
+            let len = jvm.env().invoke(
+                |env| env.GetArrayLength,
+                |env, f| f(env, args.as_raw().as_ptr()),
+            );
+            let mut objects = Vec::with_capacity(len as usize);
+            for index in 0..len {
+                let env = jvm.env();
+                let element = env.invoke(
+                    |env| env.GetObjectArrayElement,
+                    |env, f| f(env, args.as_raw().as_ptr(), index),
+                );
+                check_exception(jvm)?;
+                // SAFETY: GetObjectArrayElement returns a new local ref
+                objects.push(ObjectPtr::new(element).map(|element| Local::from_raw(env, element)));
+            }
+
+            // SAFETY: the handler passes its closure, which it keeps alive during the call
+            let closure = &*(closure as *const Box<Closure>);
+            let result = closure(jvm, objects)?;
+            Ok(result.map(|result| jvm.global(&*result)))
+        })
+    }
+}
+
+extern "system" fn release(
+    env: *mut jni_sys::JNIEnv,
+    _class: jni_sys::jclass,
+    closure: jni_sys::jlong,
+) {
+    unsafe {
+        Jvm::native(env, |_jvm| {
+            // SAFETY: the handler is being finalized, so this is the last use
+            drop(Box::from_raw(closure as *mut Box<Closure>));
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,81 @@
+package duchess;
+
+import java.lang.reflect.InvocationHandler;
+import java.lang.reflect.InvocationTargetException;
+import java.lang.reflect.Method;
+import java.lang.reflect.Proxy;
+
+/**
+ * An {@code InvocationHandler} that implements the abstract method of a functional interface with a Rust closure, which
+ * duchess defines at runtime to create the proxies returned by the generated {@code from_fn} functions. {@code closure}
+ * points to the Rust closure, which is freed when the handler is finalized.
+ */
+public final class RustInvocationHandler implements InvocationHandler {
+    private long closure;
+
+    private RustInvocationHandler(long closure) {
+        this.closure = closure;
+    }
+
+    /**
+     * Creates a proxy implementing {@code iface} with the closure. If this throws, the caller still owns the closure.
+     */
+    static Object newProxy(Class<?> iface, long closure) {
+        RustInvocationHandler handler = new RustInvocationHandler(closure);
+        try {
+            return Proxy.newProxyInstance(iface.getClassLoader(), new Class<?>[] { iface }, handler);
+        } catch (RuntimeException | Error e) {
+            handler.closure = 0;
+            throw e;
+        }
+    }
+
+    @Override
+    public Object invoke(Object proxy, Method method, Object[] args) throws Throwable {
+        if (method.getDeclaringClass() == Object.class) {
+            switch (method.getName()) {
+                case "equals":
+                    return proxy == args[0];
+                case "hashCode":
+                    return System.identityHashCode(proxy);
+                default:
+                    return proxy.getClass().getName() + "@" + Integer.toHexString(System.identityHashCode(proxy));
+            }
+        }
+        if (method.isDefault()) {
+            return invokeDefault(proxy, method, args);
+        }
+        return call(closure, args == null ? new Object[0] : args);
+    }
+
+    /** Calls a default method with {@code InvocationHandler.invokeDefault}, which was added in Java 16. */
+    private static Object invokeDefault(Object proxy, Method method, Object[] args) throws Throwable {
+        Method invokeDefault;
+        try {
+            invokeDefault = InvocationHandler.class.getMethod("invokeDefault", Object.class, Method.class,
+                    Object[].class);
+        } catch (NoSuchMethodException e) {
+            throw new UnsupportedOperationException("default methods of closures need Java 16: " + method);
+        }
+        try {
+            return invokeDefault.invoke(null, proxy, method, args);
+        } catch (InvocationTargetException e) {
+            throw e.getCause();
+        }
+    }
+
+    @Override
+    protected void finalize() {
+        if (closure != 0) {
+            release(closure);
+            closure = 0;
+        }
+    }
+
+    /**
+     * Calls the closure. It is an instance method so that the handler, and so the closure, stays alive during the call.
+     */
+    private native Object call(long closure, Object[] args) throws Throwable;
+
+    private static native void release(long closure);
+}
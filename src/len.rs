@@ -0,0 +1,116 @@
+//! The number of elements of Java collections and arrays, and of characters of strings, see [`LenExt::len`].
+
+use crate::{
+    array::{JavaArray, JavaArrayExt},
+    java::{
+        self,
+        lang::{CharSequenceExt, StringBuilderExt, StringExt},
+        util::{ListExt, MapExt, SetExt},
+    },
+    Global, JavaObject, JavaType, Jvm, JvmOp, Local,
+};
+
+/// Java objects with a length: the `size()` of a collection or map, the `length()` of a string, or the length of an
+/// array. Implemented for references to them too, so that [`LenExt::len`] works on any operation producing one.
+pub trait JavaLen {
+    fn len<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, usize>;
+}
+
+/// Extension trait adding [`len`](LenExt::len) to operations that produce a collection, map, string or array.
+///
+/// It isn't part of the prelude: Rust `String`s and `Vec`s are operations that produce a Java string or array, so
+/// with this trait in scope, `.len()` on them would create the Java object instead of calling the Rust method.
+/// `is_empty()` comes with the class's own extension trait, e.g. [`ListExt`] or [`ArrayExt`](crate::java::ArrayExt).
+// `is_empty` would be ambiguous with the ext traits that already have it
+#[allow(clippy::len_without_is_empty)]
+pub trait LenExt: JvmOp {
+    /// Returns the number of elements or characters, like `size()` or `length()` in Java.
+    fn len(self) -> Len<Self>;
+}
+
+impl<This> LenExt for This
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: JavaLen,
+{
+    fn len(self) -> Len<Self> {
+        Len { this: self }
+    }
+}
+
+/// [`JvmOp`][] that returns the length of a collection, map, string or array, see [`LenExt::len`].
+#[derive(Clone)]
+pub struct Len<This> {
+    this: This,
+}
+
+impl<This> JvmOp for Len<This>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: JavaLen,
+{
+    type Output<'jvm> = usize;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let this = self.this.execute_with(jvm)?;
+        JavaLen::len(&this, jvm)
+    }
+}
+
+macro_rules! java_len {
+    ($(impl<$($param:ident),*> for $java:ty => $len:path,)*) => {
+        $(
+            impl<$($param: JavaObject),*> JavaLen for $java {
+                fn len<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, usize> {
+                    // Java lengths are never negative
+                    Ok($len(self).execute_with(jvm)? as usize)
+                }
+            }
+        )*
+    };
+}
+
+java_len! {
+    impl<E> for java::util::List<E> => ListExt::size,
+    impl<E> for java::util::ArrayList<E> => ListExt::size,
+    impl<E> for java::util::Set<E> => SetExt::size,
+    impl<E> for java::util::HashSet<E> => SetExt::size,
+    impl<K, V> for java::util::Map<K, V> => MapExt::size,
+    impl<K, V> for java::util::HashMap<K, V> => MapExt::size,
+    impl<> for java::lang::CharSequence => CharSequenceExt::length,
+    impl<> for java::lang::String => StringExt::length,
+    impl<> for java::lang::StringBuilder => StringBuilderExt::length,
+}
+
+impl<T: JavaType> JavaLen for JavaArray<T> {
+    fn len<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, usize> {
+        Ok(self.length().execute_with(jvm)? as usize)
+    }
+}
+
+impl<J> JavaLen for Local<'_, J>
+where
+    J: JavaObject + JavaLen,
+{
+    fn len<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, usize> {
+        J::len(self, jvm)
+    }
+}
+
+impl<J> JavaLen for Global<J>
+where
+    J: JavaObject + JavaLen,
+{
+    fn len<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, usize> {
+        J::len(&**self, jvm)
+    }
+}
+
+impl<J> JavaLen for &J
+where
+    J: JavaLen,
+{
+    fn len<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, usize> {
+        J::len(self, jvm)
+    }
+}
@@ -1,38 +1,94 @@
 //! Experiments with Java-Rust interop.
 
 mod array;
+mod boxed;
+mod cache;
+mod call_super;
+mod capture;
 mod cast;
+mod chars;
+mod checked;
+mod closeable;
+mod collect_java;
+mod collections;
+mod compare;
+mod descriptor;
 mod error;
+mod finally;
 mod find;
 mod global;
+mod global_iter;
+mod io;
+mod java_class;
+mod java_exception;
 mod jvm;
+mod lambda;
+mod len;
 mod libjvm;
+mod logging;
+mod map_java;
+#[cfg(feature = "javamath")]
+mod math;
+mod monitor;
+mod native;
 mod not_null;
 mod ops;
 mod raw;
 mod ref_;
+mod ref_eq;
 mod refs;
 mod str;
+mod string_chunks;
 mod thread;
+#[cfg(feature = "javatime")]
+mod time;
+mod timeout;
 mod to_java;
 mod to_rust;
 mod try_catch;
+#[cfg(feature = "uuid")]
+mod uuid;
 
 /// Contains reusable declarations for classes distributed by the JDK under the `java.*` packages.
 pub mod java;
 
+pub mod test;
+
+pub use cache::JavaObjectCache;
+pub use call_super::SuperRef;
+pub use capture::OutputCapture;
+pub use cast::{DowncastTargets, OneOf2, OneOf3, OneOf4, OneOf5, OneOf6};
+pub use checked::{CatchChecked, CheckedException};
+pub use closeable::CloseableGuard;
+pub use collect_java::FromRustIterator;
+pub use descriptor::MethodDescriptor;
 pub use duchess_macro::{java_package, ToJava, ToRust};
 pub use error::{Error, GlobalResult, Result};
+pub use global_iter::{GlobalIter, JavaIterable};
+pub use io::{JavaReader, JavaWriter};
+pub use java_exception::{JavaException, ToRustError};
+pub use jvm::run;
+pub use jvm::JavaDefault;
 pub use jvm::JavaObject;
 pub use jvm::JavaType;
 pub use jvm::Jvm;
-pub use ref_::{Global, Local};
+pub use lambda::Lambda;
+pub use len::{JavaLen, Len, LenExt};
+#[cfg(feature = "javamath")]
+pub use math::{BigDecimalParts, BigIntegerBytes};
+pub use monitor::MonitorGuard;
+pub use native::{NativeMethod, NativeReturn};
+pub use ref_::{Global, Local, WeakGlobal};
 pub use refs::{AsJRef, JDeref, NullJRef, Nullable, TryJDeref};
-pub use to_rust::ToRust;
+pub use string_chunks::{StringChunks, ToRustChunks};
+pub use to_rust::{ToRust, ToRustInto};
 pub use try_catch::TryCatch;
 
 pub use prelude::*;
 
+/// The raw JNI types, which appear in the signatures of native methods implemented in Rust, see [`NativeMethod`].
+pub use jni_sys;
+
 /// Re-export the dependencies that are used by the generated code.
 pub mod codegen_deps {
     pub use once_cell;
@@ -41,6 +97,8 @@ pub mod codegen_deps {
 /// Contains traits with methods expected to be invoked by end-users.
 pub mod prelude {
     pub use crate::cast::by_type;
+    pub use crate::collect_java::CollectJava;
+    pub use crate::global::LocalResultExt;
     pub use crate::jvm::JvmOp;
     pub use crate::ops::{
         IntoJava, IntoLocal, IntoOptLocal, IntoScalar, IntoVoid, JavaField, JavaMethod,
@@ -53,10 +111,12 @@ pub mod prelude {
 /// names used by generated code.
 pub mod plumbing {
     pub use crate::cast::Upcast;
-    pub use crate::error::check_exception;
+    pub use crate::checked::global_if_instance;
+    pub use crate::error::{check_exception, try_extract_message};
     pub use crate::find::{find_class, find_constructor, find_field, find_method};
     pub use crate::global::GlobalOp;
     pub use crate::jvm::JavaObjectExt;
+    pub use crate::lambda::{LambdaArg, LambdaResult};
     pub use crate::raw::{FieldPtr, FromJniValue, HasEnvPtr, IntoJniValue, MethodPtr, ObjectPtr};
     pub use crate::refs::NullJRef;
     pub use crate::to_java::ToJavaImpl;
@@ -27,6 +27,24 @@ mod dynlib {
 
     static LIBJVM: OnceCell<Libjvm> = OnceCell::new();
 
+    /// Reasons why `libjvm` could not be loaded, reported through [`Error::UnableToLoadLibjvm`].
+    #[derive(Debug, thiserror::Error)]
+    enum LoadError {
+        #[error(
+            "unable to find the JVM library `{}` ({source}); set `JAVA_HOME` to a JDK installation or pass its path to `JvmBuilder::load_libjvm_at`",
+            java_locator::get_jvm_dyn_lib_file_name()
+        )]
+        NotFound {
+            source: java_locator::errors::JavaLocatorError,
+        },
+
+        #[error("failed to load the JVM library at `{}`: {source}", .path.display())]
+        Load {
+            path: PathBuf,
+            source: libloading::Error,
+        },
+    }
+
     #[allow(non_snake_case)]
     fn load_libjvm_at(path: &Path) -> GlobalResult<Libjvm> {
         (|| {
@@ -39,14 +57,20 @@ mod dynlib {
                 JNI_GetCreatedJavaVMs,
             })
         })()
-        .map_err(|e: libloading::Error| Error::UnableToLoadLibjvm(Box::new(e)))
+        .map_err(|source: libloading::Error| {
+            Error::UnableToLoadLibjvm(Box::new(LoadError::Load {
+                path: path.to_owned(),
+                source,
+            }))
+        })
     }
 
     pub(crate) fn libjvm_or_load() -> GlobalResult<&'static Libjvm> {
         LIBJVM.get_or_try_init(|| {
             let path: PathBuf = [
-                &java_locator::locate_jvm_dyn_library()
-                    .map_err(|e| Error::UnableToLoadLibjvm(Box::new(e)))?,
+                &java_locator::locate_jvm_dyn_library().map_err(|source| {
+                    Error::UnableToLoadLibjvm(Box::new(LoadError::NotFound { source }))
+                })?,
                 java_locator::get_jvm_dyn_lib_file_name(),
             ]
             .into_iter()
@@ -0,0 +1,155 @@
+//! Forwarding `java.util.logging` records to Rust's `tracing`, see `Jvm::builder().route_java_logging()`.
+
+use std::ffi::c_void;
+
+use once_cell::sync::OnceCell;
+
+use crate::{
+    error::check_exception,
+    find::find_method,
+    java,
+    jvm::JavaObjectExt,
+    raw::{HasEnvPtr, MethodPtr, ObjectPtr},
+    Error, Global, GlobalResult, Jvm, JvmOp, Local, NativeMethod,
+};
+
+/// The class file of `duchess.RustLogHandler`, compiled from `logging/RustLogHandler.java`. Like
+/// `duchess.RustOutputStream`, it isn't on the classpath, so it is defined with `DefineClass`.
+const CLASS_FILE: &[u8] = include_bytes!("logging/RustLogHandler.class");
+
+/// Installs a `RustLogHandler` on the root logger, replacing its handlers. Does nothing if that was already done, so
+/// that calling `launch_or_use_existing()` again doesn't log records twice.
+pub(crate) fn route_java_logging() -> GlobalResult<()> {
+    static INSTALLED: OnceCell<()> = OnceCell::new();
+    static INSTALL: OnceCell<MethodPtr> = OnceCell::new();
+
+    INSTALLED.get_or_try_init(|| {
+        Jvm::with(|jvm| {
+            let class = handler_class(jvm)?;
+            let install =
+                INSTALL.get_or_try_init(|| find_method(jvm, class, c"install", c"()V", true))?;
+            unsafe {
+                jvm.env().invoke(
+                    |env| env.CallStaticVoidMethodA,
+                    |env, f| {
+                        f(
+                            env,
+                            class.as_raw().as_ptr(),
+                            install.as_ptr(),
+                            std::ptr::null(),
+                        )
+                    },
+                );
+            }
+            check_exception(jvm)
+        })
+    })?;
+    Ok(())
+}
+
+/// Defines `duchess.RustLogHandler` and binds its native method, the first time it is needed.
+fn handler_class<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+) -> crate::Result<'jvm, &'static Global<java::lang::Class>> {
+    static CLASS: OnceCell<Global<java::lang::Class>> = OnceCell::new();
+
+    CLASS.get_or_try_init(|| {
+        let env = jvm.env();
+        // Defined by the bootstrap class loader, like `duchess.RustOutputStream`. It only refers to JDK classes.
+        let class = unsafe {
+            env.invoke(
+                |env| env.DefineClass,
+                |env, f| {
+                    f(
+                        env,
+                        c"duchess/RustLogHandler".as_ptr(),
+                        std::ptr::null_mut(),
+                        CLASS_FILE.as_ptr().cast(),
+                        CLASS_FILE.len() as jni_sys::jsize,
+                    )
+                },
+            )
+        };
+        let Some(class) = ObjectPtr::new(class) else {
+            check_exception(jvm)?;
+            return Err(Error::JvmInternal(
+                "failed to define `duchess.RustLogHandler`".to_string(),
+            ));
+        };
+        // SAFETY: DefineClass returns a new local reference to the class
+        let class: Local<'jvm, java::lang::Class> = unsafe { Local::from_raw(env, class) };
+
+        // SAFETY: the function matches the descriptor
+        let methods = unsafe {
+            [NativeMethod::new(
+                c"log",
+                c"(ILjava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;)V",
+                log as *const c_void,
+            )]
+        };
+        jvm.register_natives_on(&class, &methods)?;
+        Ok(jvm.global(&*class))
+    })
+}
+
+/// Maps the `intValue()` of a `java.util.logging.Level` to a `tracing` level. Custom levels go to the closest
+/// standard level below them.
+fn level(value: jni_sys::jint) -> tracing::Level {
+    match value {
+        // SEVERE
+        1000.. => tracing::Level::ERROR,
+        // WARNING
+        900.. => tracing::Level::WARN,
+        // INFO and CONFIG
+        700.. => tracing::Level::INFO,
+        // FINE and FINER
+        400.. => tracing::Level::DEBUG,
+        // FINEST
+        _ => tracing::Level::TRACE,
+    }
+}
+
+extern "system" fn log(
+    env: *mut jni_sys::JNIEnv,
+    _class: jni_sys::jclass,
+    level_value: jni_sys::jint,
+    logger: jni_sys::jobject,
+    thread: jni_sys::jobject,
+    message: jni_sys::jobject,
+    thrown: jni_sys::jobject,
+) {
+    unsafe {
+        Jvm::native(env, |jvm| {
+            let mut string = |object| -> crate::Result<'_, Option<String>> {
+                jvm.native_arg::<java::lang::String>(object)
+                    .map(|string| string.to_rust().execute_with(jvm))
+                    .transpose()
+            };
+            let logger = string(logger)?;
+            let thread = string(thread)?;
+            let message = string(message)?.unwrap_or_default();
+            let thrown = string(thrown)?;
+
+            macro_rules! event {
+                ($level:expr) => {
+                    tracing::event!(
+                        target: "java",
+                        $level,
+                        logger = logger.as_deref(),
+                        thread = thread.as_deref(),
+                        exception = thrown.as_deref(),
+                        "{message}"
+                    )
+                };
+            }
+            match level(level_value) {
+                tracing::Level::ERROR => event!(tracing::Level::ERROR),
+                tracing::Level::WARN => event!(tracing::Level::WARN),
+                tracing::Level::INFO => event!(tracing::Level::INFO),
+                tracing::Level::DEBUG => event!(tracing::Level::DEBUG),
+                tracing::Level::TRACE => event!(tracing::Level::TRACE),
+            }
+            Ok(())
+        })
+    }
+}
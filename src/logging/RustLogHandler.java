@@ -0,0 +1,61 @@
+package duchess;
+
+import java.io.PrintWriter;
+import java.io.StringWriter;
+import java.util.logging.Formatter;
+import java.util.logging.Handler;
+import java.util.logging.LogRecord;
+import java.util.logging.Logger;
+import java.util.logging.SimpleFormatter;
+
+/**
+ * A {@code java.util.logging} handler that forwards log records to Rust's {@code tracing}, which duchess defines at
+ * runtime and installs on the root logger when the JVM is built with {@code route_java_logging()}.
+ */
+public final class RustLogHandler extends Handler {
+    private static final Formatter MESSAGES = new SimpleFormatter();
+
+    private RustLogHandler() {
+    }
+
+    /**
+     * Replaces the handlers of the root logger with a {@code RustLogHandler}. The default {@code ConsoleHandler} would
+     * print the records to {@code System.err} as well.
+     */
+    static void install() {
+        Logger root = Logger.getLogger("");
+        for (Handler handler : root.getHandlers()) {
+            root.removeHandler(handler);
+        }
+        root.addHandler(new RustLogHandler());
+    }
+
+    @Override
+    public void publish(LogRecord record) {
+        if (!isLoggable(record)) {
+            return;
+        }
+        String thrown = null;
+        if (record.getThrown() != null) {
+            StringWriter stackTrace = new StringWriter();
+            record.getThrown().printStackTrace(new PrintWriter(stackTrace));
+            thrown = stackTrace.toString();
+        }
+        log(
+            record.getLevel().intValue(),
+            record.getLoggerName(),
+            Thread.currentThread().getName(),
+            MESSAGES.formatMessage(record),
+            thrown);
+    }
+
+    @Override
+    public void flush() {
+    }
+
+    @Override
+    public void close() {
+    }
+
+    private static native void log(int level, String logger, String thread, String message, String thrown);
+}
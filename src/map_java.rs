@@ -0,0 +1,71 @@
+use std::marker::PhantomData;
+
+use crate::{JavaObject, Jvm, JvmOp, Local};
+
+/// [`JvmOp`][] that passes the output of an operation to a closure, see [`JvmOp::map_java`].
+#[derive(Clone)]
+pub struct MapJava<This, F> {
+    this: This,
+    f: F,
+}
+
+impl<This, F, R> MapJava<This, F>
+where
+    This: JvmOp,
+    F: for<'jvm> FnOnce(This::Output<'jvm>, &mut Jvm<'jvm>) -> crate::Result<'jvm, R>,
+{
+    pub(crate) fn new(this: This, f: F) -> Self {
+        Self { this, f }
+    }
+}
+
+impl<This, F, R> JvmOp for MapJava<This, F>
+where
+    This: JvmOp,
+    F: for<'jvm> FnOnce(This::Output<'jvm>, &mut Jvm<'jvm>) -> crate::Result<'jvm, R>,
+{
+    type Output<'jvm> = R;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let this = self.this.execute_with(jvm)?;
+        (self.f)(this, jvm)
+    }
+}
+
+/// [`JvmOp`][] that passes the output of an operation to a closure producing a new Java object, see
+/// [`JvmOp::and_then_java`].
+#[derive(Clone)]
+pub struct AndThenJava<This, F, T> {
+    this: This,
+    f: F,
+    phantom: PhantomData<T>,
+}
+
+impl<This, F, T> AndThenJava<This, F, T>
+where
+    This: JvmOp,
+    F: for<'jvm> FnOnce(This::Output<'jvm>, &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, T>>,
+    T: JavaObject,
+{
+    pub(crate) fn new(this: This, f: F) -> Self {
+        Self {
+            this,
+            f,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<This, F, T> JvmOp for AndThenJava<This, F, T>
+where
+    This: JvmOp,
+    F: for<'jvm> FnOnce(This::Output<'jvm>, &mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, T>>,
+    T: JavaObject,
+{
+    type Output<'jvm> = Local<'jvm, T>;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let this = self.this.execute_with(jvm)?;
+        (self.f)(this, jvm)
+    }
+}
@@ -0,0 +1,105 @@
+//! Conversions for `java.math` big numbers, enabled by the `javamath` feature.
+//!
+//! Rather than picking a Rust big-number crate, the numbers are converted to and from their lossless byte forms,
+//! which every big-number crate can read and write.
+
+use crate::{
+    java::{
+        self,
+        math::{BigDecimalExt, BigIntegerExt},
+    },
+    to_java::ToJavaImpl,
+    Error, Jvm, JvmOp, Local, ToRust,
+};
+
+/// The value of a `java.math.BigInteger` as big-endian two's-complement bytes, the format of
+/// `BigInteger.toByteArray()`. This is the format read by e.g. `num_bigint::BigInt::from_signed_bytes_be`.
+///
+/// Values converted from Java use the minimal number of bytes, which is at least one. When converting to Java, an
+/// empty vector is zero.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigIntegerBytes(pub Vec<u8>);
+
+/// The value of a `java.math.BigDecimal`, which is `unscaled × 10^-scale`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigDecimalParts {
+    pub unscaled: BigIntegerBytes,
+    pub scale: i32,
+}
+
+impl ToRust<BigIntegerBytes> for java::math::BigInteger {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, BigIntegerBytes> {
+        let bytes = self.to_byte_array().assert_not_null().execute_with(jvm)?;
+        let bytes: Vec<i8> = ToRust::to_rust(&bytes, jvm)?;
+        Ok(BigIntegerBytes(
+            bytes.into_iter().map(|b| b as u8).collect(),
+        ))
+    }
+}
+
+impl ToJavaImpl<java::math::BigInteger> for BigIntegerBytes {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::math::BigInteger>>> {
+        // `new BigInteger(byte[])` rejects an empty array
+        let bytes: Vec<i8> = match &rust.0[..] {
+            [] => vec![0],
+            bytes => bytes.iter().map(|&b| b as i8).collect(),
+        };
+        let array = bytes.as_slice().execute_with(jvm)?;
+        let integer = java::math::BigInteger::new(&array).execute_with(jvm)?;
+        Ok(Some(integer))
+    }
+}
+
+/// Converts a `BigInteger` that fits in an `i128`, failing otherwise.
+impl ToRust<i128> for java::math::BigInteger {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, i128> {
+        let BigIntegerBytes(bytes) = ToRust::to_rust(self, jvm)?;
+        if bytes.len() > 16 {
+            return Err(Error::Conversion(format!(
+                "java.math.BigInteger of {} bytes is out of range for i128",
+                bytes.len()
+            )));
+        }
+
+        // sign-extend to 16 bytes
+        let fill = if bytes[0] & 0x80 != 0 { 0xff } else { 0 };
+        let mut be = [fill; 16];
+        be[16 - bytes.len()..].copy_from_slice(&bytes);
+        Ok(i128::from_be_bytes(be))
+    }
+}
+
+impl ToJavaImpl<java::math::BigInteger> for i128 {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::math::BigInteger>>> {
+        let bytes = BigIntegerBytes(rust.to_be_bytes().to_vec());
+        ToJavaImpl::to_java_impl(&bytes, jvm)
+    }
+}
+
+impl ToRust<BigDecimalParts> for java::math::BigDecimal {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, BigDecimalParts> {
+        let unscaled = self.unscaled_value().assert_not_null().execute_with(jvm)?;
+        let unscaled = ToRust::to_rust(&unscaled, jvm)?;
+        let scale = self.scale().execute_with(jvm)?;
+        Ok(BigDecimalParts { unscaled, scale })
+    }
+}
+
+impl ToJavaImpl<java::math::BigDecimal> for BigDecimalParts {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::math::BigDecimal>>> {
+        let unscaled: Option<Local<'jvm, java::math::BigInteger>> =
+            ToJavaImpl::to_java_impl(&rust.unscaled, jvm)?;
+        let decimal = java::math::BigDecimal::new(unscaled.as_ref().unwrap(), rust.scale)
+            .execute_with(jvm)?;
+        Ok(Some(decimal))
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    error::check_exception,
+    java::lang::Object,
+    plumbing::JavaObjectExt,
+    raw::{EnvPtr, HasEnvPtr},
+    AsJRef, Error, Jvm, Local,
+};
+
+/// Holds the monitor of a Java object, like being inside a `synchronized (object) { ... }` block in Java. Created by
+/// [`Jvm::synchronize`], and the monitor is released when the guard is dropped.
+///
+/// Monitors are reentrant and owned by the current thread, so Java code called while the guard is live can still
+/// synchronize on the same object, while other threads block until the guard is dropped.
+#[must_use = "the monitor is released as soon as the guard is dropped"]
+pub struct MonitorGuard<'jvm> {
+    env: EnvPtr<'jvm>,
+    object: Local<'jvm, Object>,
+}
+
+impl<'jvm> Jvm<'jvm> {
+    /// Enters the monitor of `object`, blocking until no other thread holds it. Any object can be synchronized on,
+    /// including a `java.lang.Class` (e.g. from [`JvmOp::java_class`](crate::JvmOp::java_class) or
+    /// [`JavaObject::class`](crate::JavaObject::class)), whose monitor is the one held by Java's `static
+    /// synchronized` methods.
+    pub fn synchronize(
+        &mut self,
+        object: &impl AsJRef<Object>,
+    ) -> crate::Result<'jvm, MonitorGuard<'jvm>> {
+        let object = self.local(object.as_jref()?);
+
+        let env = self.env();
+        // SAFETY: `object` is a valid, non-null reference for the duration of the call
+        let result = unsafe {
+            env.invoke(
+                |env| env.MonitorEnter,
+                |env, f| f(env, object.as_raw().as_ptr()),
+            )
+        };
+        if result != jni_sys::JNI_OK {
+            check_exception(self)?;
+            return Err(Error::JvmInternal(format!(
+                "failed to enter monitor: error code {result}"
+            )));
+        }
+
+        Ok(MonitorGuard { env, object })
+    }
+}
+
+impl Drop for MonitorGuard<'_> {
+    fn drop(&mut self) {
+        // SAFETY: the current thread entered the monitor in `Jvm::synchronize`, and `object` is still a valid
+        // reference. Exiting can only fail if the thread doesn't own the monitor.
+        unsafe {
+            self.env.invoke_unchecked(
+                |env| env.MonitorExit,
+                |env, f| f(env, self.object.as_raw().as_ptr()),
+            );
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Implementing the `native` methods of Java classes in Rust, see [`Jvm::register_natives`].
+
+use std::{
+    ffi::{c_void, CStr},
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use crate::{
+    error::check_exception,
+    java::{self, lang::Throwable},
+    jvm::JavaObjectExt,
+    raw::{EnvPtr, HasEnvPtr, ObjectPtr},
+    Error, Global, JavaObject, Jvm, JvmOp, Local,
+};
+
+/// A Rust function implementing a Java `native` method, to be registered with [`Jvm::register_natives`].
+#[derive(Clone, Copy, Debug)]
+pub struct NativeMethod {
+    name: &'static CStr,
+    descriptor: &'static CStr,
+    fn_ptr: *mut c_void,
+}
+
+impl NativeMethod {
+    /// Describes the native method `name` with the JNI `descriptor`, e.g. `c"(ILjava/lang/String;)V"`, implemented by
+    /// `fn_ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `fn_ptr` must point to an `extern "system" fn` whose parameters are a `*mut jni_sys::JNIEnv`, the receiver (a
+    /// `jni_sys::jobject`, or a `jni_sys::jclass` for static methods), and then the JNI types of the arguments in
+    /// `descriptor`, and which returns the JNI type of its return type, e.g.
+    ///
+    /// ```ignore
+    /// extern "system" fn add(env: *mut jni_sys::JNIEnv, class: jni_sys::jclass, a: jni_sys::jint, b: jni_sys::jint) -> jni_sys::jint
+    /// ```
+    pub unsafe fn new(
+        name: &'static CStr,
+        descriptor: &'static CStr,
+        fn_ptr: *const c_void,
+    ) -> Self {
+        NativeMethod {
+            name,
+            descriptor,
+            fn_ptr: fn_ptr.cast_mut(),
+        }
+    }
+}
+
+// SAFETY: the pointers are to static strings and a function
+unsafe impl Send for NativeMethod {}
+unsafe impl Sync for NativeMethod {}
+
+impl<'jvm> Jvm<'jvm> {
+    /// Binds the `native` methods of the Java class `C` to Rust functions with JNI's `RegisterNatives`, so Java code
+    /// calling them calls into Rust. Registering a method again replaces the earlier function.
+    ///
+    /// The functions receive raw JNI values; [`Jvm::native`] converts their result back and turns errors into Java
+    /// exceptions. If a method isn't a `native` method of `C`, the `NoSuchMethodError` is returned as
+    /// [`Error::Thrown`] and none of the methods are registered.
+    pub fn register_natives<C>(&mut self, methods: &[NativeMethod]) -> crate::Result<'jvm, ()>
+    where
+        C: JavaObject,
+    {
+        let class = C::class(self)?;
+        self.register_natives_on(&class, methods)
+    }
+
+    /// Like [`Jvm::register_natives`], for a class that has no Rust type.
+    pub(crate) fn register_natives_on(
+        &mut self,
+        class: &java::lang::Class,
+        methods: &[NativeMethod],
+    ) -> crate::Result<'jvm, ()> {
+        let jni_methods: Vec<jni_sys::JNINativeMethod> = methods
+            .iter()
+            .map(|m| jni_sys::JNINativeMethod {
+                name: m.name.as_ptr().cast_mut(),
+                signature: m.descriptor.as_ptr().cast_mut(),
+                fnPtr: m.fn_ptr,
+            })
+            .collect();
+        let Ok(count) = i32::try_from(jni_methods.len()) else {
+            return Err(Error::SliceTooLong(jni_methods.len()));
+        };
+
+        let env = self.env();
+        // SAFETY: the names and descriptors are nul-terminated, and `NativeMethod::new` vouches for the functions
+        let status = unsafe {
+            env.invoke(
+                |env| env.RegisterNatives,
+                |env, f| f(env, class.as_raw().as_ptr(), jni_methods.as_ptr(), count),
+            )
+        };
+        if status != jni_sys::JNI_OK {
+            check_exception(self)?;
+            return Err(Error::JvmInternal(format!(
+                "failed to register native methods (error code {status})"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Runs `op` in the body of a native method registered with [`Jvm::register_natives`], where `env` is the first
+    /// argument of the function, and converts its result to the value returned to Java:
+    ///
+    /// ```ignore
+    /// extern "system" fn greet(env: *mut jni_sys::JNIEnv, _this: jni_sys::jobject, name: jni_sys::jobject) -> jni_sys::jobject {
+    ///     unsafe {
+    ///         Jvm::native(env, |jvm| {
+    ///             let name = jvm.native_arg::<java::lang::String>(name).ok_or(Error::NullDeref)?;
+    ///             let name: String = name.to_rust().execute_with(jvm)?;
+    ///             Ok(format!("Hello, {name}"))
+    ///         })
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// An [`Error::Thrown`] is rethrown to Java, and other errors and panics are thrown as a
+    /// `java.lang.RuntimeException` with the error message. Java ignores the return value in that case.
+    ///
+    /// # Safety
+    ///
+    /// `env` must be the `JNIEnv` pointer the JVM passed to the native method, and this must be called on the thread
+    /// that it was passed on, before the native method returns.
+    pub unsafe fn native<R>(
+        env: *mut jni_sys::JNIEnv,
+        op: impl for<'a> FnOnce(&mut Jvm<'a>) -> crate::Result<'a, R>,
+    ) -> R::Jni
+    where
+        R: NativeReturn,
+    {
+        let env = unsafe { EnvPtr::new(env) }.expect("native method called with a null `JNIEnv`");
+        let mut jvm = Jvm(env);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let value = op(&mut jvm)?;
+            value.into_jni(&mut jvm)
+        }));
+        match result {
+            Ok(Ok(value)) => value,
+            Ok(Err(Error::Thrown(exception))) => {
+                throw(&mut jvm, exception);
+                R::null()
+            }
+            Ok(Err(error)) => {
+                throw_runtime_exception(&mut jvm, &error.to_string());
+                R::null()
+            }
+            Err(panic) => {
+                let message = panic
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| panic.downcast_ref::<String>().map(|s| s.as_str()))
+                    .unwrap_or("Rust native method panicked");
+                throw_runtime_exception(&mut jvm, message);
+                R::null()
+            }
+        }
+    }
+
+    /// Converts an object argument of a native method to a reference, or `None` if Java passed `null`.
+    ///
+    /// # Safety
+    ///
+    /// `object` must be an argument of the native method running in [`Jvm::native`] that is an instance of `T`.
+    pub unsafe fn native_arg<T>(&self, object: jni_sys::jobject) -> Option<&'jvm T>
+    where
+        T: JavaObject,
+    {
+        // SAFETY: the JVM keeps the arguments of a native method alive until it returns
+        ObjectPtr::new(object).map(|object| unsafe { T::from_raw(object) })
+    }
+}
+
+fn throw(jvm: &mut Jvm<'_>, exception: Local<'_, Throwable>) {
+    let env = jvm.env();
+    // SAFETY: exception is a live reference to a Throwable
+    unsafe {
+        env.invoke_unchecked(
+            |env| env.Throw,
+            |env, f| f(env, exception.as_raw().as_ptr()),
+        )
+    };
+}
+
+fn throw_runtime_exception(jvm: &mut Jvm<'_>, message: &str) {
+    // An exception may be pending if the error happened while handling another one
+    drop(jvm.take_pending_exception());
+    let env = jvm.env();
+
+    let message = std::ffi::CString::new(message.replace('\0', "")).unwrap_or_default();
+    let class = unsafe {
+        env.invoke(
+            |env| env.FindClass,
+            |env, f| f(env, c"java/lang/RuntimeException".as_ptr()),
+        )
+    };
+    if !class.is_null() {
+        unsafe { env.invoke(|env| env.ThrowNew, |env, f| f(env, class, message.as_ptr())) };
+    }
+}
+
+/// Values that a native method implemented with [`Jvm::native`] can return to Java.
+pub trait NativeReturn {
+    /// The JNI type returned by the native function, e.g. `jni_sys::jint` for an `int`.
+    type Jni;
+
+    /// Converts the value to return it to Java.
+    fn into_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Jni>;
+
+    /// The value returned when an exception is thrown instead.
+    fn null() -> Self::Jni;
+}
+
+macro_rules! scalar_native_return {
+    ($($rust:ty: $jni:ty,)*) => {
+        $(
+            impl NativeReturn for $rust {
+                type Jni = $jni;
+
+                fn into_jni<'jvm>(self, _jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, $jni> {
+                    Ok(self.into())
+                }
+
+                fn null() -> $jni {
+                    Default::default()
+                }
+            }
+        )*
+    };
+}
+
+scalar_native_return! {
+    (): (),
+    i8: jni_sys::jbyte,
+    u16: jni_sys::jchar,
+    i16: jni_sys::jshort,
+    i32: jni_sys::jint,
+    i64: jni_sys::jlong,
+    f32: jni_sys::jfloat,
+    f64: jni_sys::jdouble,
+}
+
+impl NativeReturn for bool {
+    type Jni = jni_sys::jboolean;
+
+    fn into_jni<'jvm>(self, _jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, jni_sys::jboolean> {
+        Ok(if self {
+            jni_sys::JNI_TRUE
+        } else {
+            jni_sys::JNI_FALSE
+        })
+    }
+
+    fn null() -> jni_sys::jboolean {
+        jni_sys::JNI_FALSE
+    }
+}
+
+/// Objects are returned as a new local reference, which the JVM frees when the native method returns.
+impl<T: JavaObject> NativeReturn for Global<T> {
+    type Jni = jni_sys::jobject;
+
+    fn into_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, jni_sys::jobject> {
+        Ok(into_local_ptr(jvm.local(&*self)))
+    }
+
+    fn null() -> jni_sys::jobject {
+        std::ptr::null_mut()
+    }
+}
+
+impl<T: JavaObject> NativeReturn for Option<Global<T>> {
+    type Jni = jni_sys::jobject;
+
+    fn into_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, jni_sys::jobject> {
+        match self {
+            Some(object) => object.into_jni(jvm),
+            None => Ok(std::ptr::null_mut()),
+        }
+    }
+
+    fn null() -> jni_sys::jobject {
+        std::ptr::null_mut()
+    }
+}
+
+/// Returns a new Java `String`.
+impl NativeReturn for String {
+    type Jni = jni_sys::jobject;
+
+    fn into_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, jni_sys::jobject> {
+        let string = self.as_str().execute_with(jvm)?;
+        Ok(into_local_ptr(string))
+    }
+
+    fn null() -> jni_sys::jobject {
+        std::ptr::null_mut()
+    }
+}
+
+/// Releases `local` without deleting the local reference, so that it can be returned to Java.
+fn into_local_ptr<T: JavaObject>(local: Local<'_, T>) -> jni_sys::jobject {
+    let ptr = local.as_raw().as_ptr();
+    std::mem::forget(local);
+    ptr
+}
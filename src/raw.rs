@@ -8,13 +8,27 @@ use std::{
     ffi,
     marker::PhantomData,
     ptr::{self, NonNull},
+    sync::atomic::{AtomicI32, Ordering},
 };
 
 use jni_sys::jvalue;
 
 use crate::{jvm::JavaObjectExt, Error, GlobalResult, JavaObject, Jvm, Local};
 
-const VERSION: jni_sys::jint = jni_sys::JNI_VERSION_1_8;
+/// The JNI version requested when the JVM is launched and threads are attached, see
+/// [`JvmBuilder::jni_version`](crate::jvm::JvmBuilder::jni_version). Supported by every JVM since Java 8.
+pub(crate) const DEFAULT_VERSION: jni_sys::jint = jni_sys::JNI_VERSION_1_8;
+
+static VERSION: AtomicI32 = AtomicI32::new(DEFAULT_VERSION);
+
+/// Sets the JNI version used by [`JvmPtr::env`] and [`JvmPtr::attach_thread`] from now on.
+pub(crate) fn set_version(version: jni_sys::jint) {
+    VERSION.store(version, Ordering::Relaxed);
+}
+
+fn version() -> jni_sys::jint {
+    VERSION.load(Ordering::Relaxed)
+}
 
 /// Get a [`JvmPtr`] to an already initialized JVM (if one exists).
 ///
@@ -53,8 +67,8 @@ pub(crate) unsafe fn existing_jvm() -> GlobalResult<Option<JvmPtr>> {
     }
 }
 
-/// Try to initialize a new JVM with the provided `options`, returning a [`JvmPtr`] on success or an
-/// [`Error::JvmAlreadyExists`] if one already exists.
+/// Try to initialize a new JVM with the provided `options` and JNI `version`, returning a [`JvmPtr`] on success or an
+/// [`Error::JvmAlreadyExists`] if one already exists, and [`Error::UnsupportedJniVersion`] if it doesn't support `version`.
 ///
 /// If the `dynlibjvm` feature is enabled and `libjvm` isn't already loaded, it will first force it to be loaded.
 ///
@@ -63,6 +77,7 @@ pub(crate) unsafe fn existing_jvm() -> GlobalResult<Option<JvmPtr>> {
 /// Caller must ensure that no two threads race to call this fn or [`jvm()`].
 pub(crate) unsafe fn try_create_jvm<'a>(
     options: impl IntoIterator<Item = String>,
+    version: jni_sys::jint,
 ) -> GlobalResult<JvmPtr> {
     let libjvm = crate::libjvm::libjvm_or_load()?;
 
@@ -80,7 +95,7 @@ pub(crate) unsafe fn try_create_jvm<'a>(
         .collect::<Vec<_>>();
 
     let mut args = jni_sys::JavaVMInitArgs {
-        version: VERSION,
+        version,
         nOptions: options.len().try_into().unwrap(),
         options: option_ptrs.as_mut_ptr(),
         ignoreUnrecognized: jni_sys::JNI_FALSE,
@@ -102,13 +117,16 @@ pub(crate) unsafe fn try_create_jvm<'a>(
     match code {
         jni_sys::JNI_OK => {
             let Some(jvm) = JvmPtr::new(jvm) else {
-                return Err(Error::JvmInternal("JNI_CreateJavaVM returned null pointer".into()));
+                return Err(Error::JvmInternal(
+                    "JNI_CreateJavaVM returned null pointer".into(),
+                ));
             };
             // Undo default attaching of current thread like the jni crate does
             unsafe { jvm.detach_thread() }?;
             Ok(jvm)
         }
         jni_sys::JNI_EEXIST => Err(Error::JvmAlreadyExists),
+        jni_sys::JNI_EVERSION => Err(Error::UnsupportedJniVersion(version)),
         _ => Err(Error::JvmInternal(format!(
             "CreateJavaVM failed with code `{code}`"
         ))),
@@ -136,10 +154,11 @@ impl JvmPtr {
         match fn_table_call(
             self.0,
             |jvm| jvm.GetEnv,
-            |jvm, f| f(jvm, &mut env_ptr as *mut _, VERSION),
+            |jvm, f| f(jvm, &mut env_ptr as *mut _, version()),
         ) {
             jni_sys::JNI_OK => Ok(Some(EnvPtr::new(env_ptr.cast()).unwrap())),
             jni_sys::JNI_EDETACHED => Ok(None),
+            jni_sys::JNI_EVERSION => Err(Error::UnsupportedJniVersion(version())),
             code => Err(Error::JvmInternal(format!(
                 "GetEnv failed with code `{code}`"
             ))),
@@ -155,6 +174,11 @@ impl JvmPtr {
     /// JVM.
     pub(crate) unsafe fn attach_thread<'jvm>(self) -> GlobalResult<EnvPtr<'jvm>> {
         let mut env_ptr = std::ptr::null_mut::<ffi::c_void>();
+        let mut args = jni_sys::JavaVMAttachArgs {
+            version: version(),
+            name: std::ptr::null_mut(),
+            group: std::ptr::null_mut(),
+        };
         match fn_table_call(
             self.0,
             |jvm| jvm.AttachCurrentThread,
@@ -162,11 +186,12 @@ impl JvmPtr {
                 f(
                     jvm,
                     &mut env_ptr as *mut _,
-                    std::ptr::null_mut(), /* args */
+                    &mut args as *mut _ as *mut ffi::c_void,
                 )
             },
         ) {
             jni_sys::JNI_OK => Ok(EnvPtr::new(env_ptr.cast()).unwrap()),
+            jni_sys::JNI_EVERSION => Err(Error::UnsupportedJniVersion(args.version)),
             code => Err(Error::JvmInternal(format!(
                 "AttachCurrentThread failed with code `{code}`"
             ))),
@@ -186,6 +211,20 @@ impl JvmPtr {
             ))),
         }
     }
+
+    /// Destroys the JVM, after waiting for all non-daemon Java threads other than the current one to finish.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the JVM isn't used again, from any thread.
+    pub(crate) unsafe fn destroy(self) -> GlobalResult<()> {
+        match fn_table_call(self.0, |jvm| jvm.DestroyJavaVM, |jvm, f| f(jvm)) {
+            jni_sys::JNI_OK => Ok(()),
+            code => Err(Error::JvmInternal(format!(
+                "DestroyJavaVM failed with code `{code}`"
+            ))),
+        }
+    }
 }
 
 /// Invokes a JNI function through a virtual table interface
@@ -195,8 +234,8 @@ unsafe fn fn_table_call<T, F, R>(
     call: impl FnOnce(*mut *const T, F) -> R,
 ) -> R {
     let fn_field = fn_field(&**table_ptr.as_ptr());
-    // SAFETY: We specify VERSION when accessing the JNI interfaces and libjvm promises these fn pointers will be
-    // non-null
+    // SAFETY: We specify the JNI version when accessing the JNI interfaces and libjvm promises these fn pointers will
+    // be non-null
     let fn_field = fn_field.unwrap_unchecked();
     call(table_ptr.as_ptr(), fn_field)
 }
@@ -225,8 +264,16 @@ impl<'jvm> EnvPtr<'jvm> {
         })
     }
 
+    /// The raw `JNIEnv` pointer, see [`crate::Jvm::raw_env`].
+    pub(crate) fn as_ptr(self) -> *mut jni_sys::JNIEnv {
+        self.ptr.as_ptr()
+    }
+
     /// Invoke a JNI method dispatched through a virtual table lookup. Used by codegen to make most JNI calls.
     ///
+    /// Most JNI functions must not be called while an exception is pending, so in debug builds this panics if an
+    /// earlier call threw an exception that was never checked (see [`crate::error::check_exception`]).
+    ///
     /// # Safety
     ///
     /// The caller must ensure that the [`jni_sys::JNIEnv`] raw pointer is only used for this invocation.
@@ -235,9 +282,41 @@ impl<'jvm> EnvPtr<'jvm> {
         self,
         fn_field: impl FnOnce(&jni_sys::JNINativeInterface_) -> Option<F>,
         call: impl FnOnce(*mut jni_sys::JNIEnv, F) -> T,
+    ) -> T {
+        #[cfg(debug_assertions)]
+        self.assert_no_pending_exception();
+
+        fn_table_call(self.ptr, fn_field, call)
+    }
+
+    /// Like [`EnvPtr::invoke`], but for the JNI functions that may be called while an exception is pending, such as
+    /// `ExceptionOccurred` or `DeleteLocalRef`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the [`jni_sys::JNIEnv`] raw pointer is only used for this invocation.
+    #[doc(hidden)]
+    pub unsafe fn invoke_unchecked<F, T>(
+        self,
+        fn_field: impl FnOnce(&jni_sys::JNINativeInterface_) -> Option<F>,
+        call: impl FnOnce(*mut jni_sys::JNIEnv, F) -> T,
     ) -> T {
         fn_table_call(self.ptr, fn_field, call)
     }
+
+    #[cfg(debug_assertions)]
+    fn assert_no_pending_exception(self) {
+        // SAFETY: ExceptionCheck may be called while an exception is pending
+        let pending = unsafe { self.invoke_unchecked(|env| env.ExceptionCheck, |env, f| f(env)) };
+        if pending != jni_sys::JNI_FALSE {
+            // SAFETY: ExceptionDescribe may be called while an exception is pending; it prints and clears it
+            unsafe { self.invoke_unchecked(|env| env.ExceptionDescribe, |env, f| f(env)) };
+            panic!(
+                "JNI call made while a Java exception was pending (described above); \
+                 an earlier JNI call threw and its exception was never checked or cleared"
+            );
+        }
+    }
 }
 
 // Note: EnvPtr isn't Send/Sync!
@@ -421,6 +500,7 @@ impl<'jvm> FromJniValue<'jvm> for bool {
     type JniValue = jni_sys::jboolean;
 
     unsafe fn from_jni_value(_jvm: &mut Jvm<'jvm>, value: Self::JniValue) -> Self {
-        value == jni_sys::JNI_TRUE
+        // JNI only guarantees that false is zero, e.g. fields written through `Unsafe` can hold other values
+        value != jni_sys::JNI_FALSE
     }
 }
@@ -1,4 +1,4 @@
-use std::{marker::PhantomData, ops::Deref, ptr::NonNull};
+use std::{borrow::Borrow, marker::PhantomData, ops::Deref, ptr::NonNull};
 
 use crate::jvm::JavaObjectExt;
 use crate::thread;
@@ -6,6 +6,22 @@ use crate::{cast::Upcast, jvm::CloneIn, plumbing::ObjectPtr, raw::EnvPtr, JavaOb
 
 /// An owned local reference to a non-null Java object of type `T`. The reference will be freed when
 /// dropped. Cannot be shared across threads or [`Jvm::with`] invocations.
+///
+/// `Local` deliberately does not implement [`Clone`]: every local reference occupies a slot in the current frame, so
+/// creating one should be explicit. Use [`Jvm::local`] to create a new local reference to the same object, or
+/// [`Jvm::global`] to create a [`Global`] that can be cloned freely.
+///
+/// ```compile_fail
+/// fn assert_clone<T: Clone>() {}
+/// assert_clone::<duchess::Local<'static, duchess::java::lang::Object>>();
+/// ```
+///
+/// Local references are only valid on the thread that created them, so `Local` is neither [`Send`] nor [`Sync`]:
+///
+/// ```compile_fail
+/// fn assert_send<T: Send>() {}
+/// assert_send::<duchess::Local<'static, duchess::java::lang::Object>>();
+/// ```
 pub struct Local<'jvm, T: JavaObject> {
     env: EnvPtr<'jvm>,
     obj: ObjectPtr,
@@ -48,7 +64,7 @@ impl<T: JavaObject> Drop for Local<'_, T> {
         // SAFETY: Local owns the local ref and it's no longer possible to dereference the object pointer.
         unsafe {
             self.env
-                .invoke(|jni| jni.DeleteLocalRef, |jni, f| f(jni, self.obj.as_ptr()));
+                .invoke_unchecked(|jni| jni.DeleteLocalRef, |jni, f| f(jni, self.obj.as_ptr()));
         }
     }
 }
@@ -63,6 +79,11 @@ impl<T: JavaObject> Deref for Local<'_, T> {
 }
 
 /// An owned global reference to a non-null Java object of type `T`. The reference will be freed when dropped.
+///
+/// Global references are valid on every thread, so `Global<T>` is [`Send`] and [`Sync`] for every `T`, and can be
+/// stored in shared application state. Using one still requires a [`Jvm`] for the current thread, which duchess
+/// attaches on demand. Note that this only makes the *reference* thread-safe: the Java object itself is subject to
+/// Java's rules, so e.g. an `ArrayList` shared between threads needs the same synchronization it would need in Java.
 pub struct Global<T: JavaObject> {
     obj: ObjectPtr,
     _marker: PhantomData<T>,
@@ -93,34 +114,84 @@ impl<T: JavaObject> Global<T> {
     }
 }
 
+impl<T: JavaObject> Global<T> {
+    /// Runs `op` with a JNI environment for the current thread, attaching the thread for the duration of the call
+    /// if needed. Globals can be cloned and dropped outside of [`Jvm::with`], so they cannot assume an environment.
+    pub(crate) fn with_env<R>(&self, what: &str, op: impl FnOnce(EnvPtr<'_>) -> R) -> Option<R> {
+        with_env(what, op)
+    }
+
+    /// Creates a [`WeakGlobal`] reference to the same object, which doesn't keep it from being garbage collected.
+    ///
+    /// This may be called outside of [`Jvm::with`]; the current thread is attached to the JVM for the duration of the
+    /// call if needed.
+    ///
+    /// Once the JVM has been destroyed by [`duchess::run`](crate::run), the returned reference is already cleared.
+    pub fn downgrade(&self) -> WeakGlobal<T> {
+        self.with_env("create weak global ref", |env| {
+            WeakGlobal::new(env, &**self)
+        })
+        .unwrap_or_else(WeakGlobal::cleared)
+    }
+}
+
+fn with_env<R>(what: &str, op: impl FnOnce(EnvPtr<'_>) -> R) -> Option<R> {
+    // References that outlive `duchess::run` are leaked, the JVM is gone
+    if crate::jvm::is_destroyed() {
+        return None;
+    }
+    let jvm = crate::jvm::unwrap_global_jvm();
+
+    match unsafe { jvm.env() } {
+        Ok(Some(env)) => Some(op(env)),
+        Ok(None) => {
+            // SAFETY: jvm is a valid pointer since duchess will not deinitialize a JVM once created
+            match unsafe { thread::attach(jvm) } {
+                Ok(mut attached) => Some(op(attached.env())),
+                Err(err) => {
+                    tracing::warn!(?err, "unable to attach current thread to {what}");
+                    None
+                }
+            }
+        }
+        Err(err) => {
+            tracing::warn!(
+                ?err,
+                "unable to get JNI interface for local thread to {what}"
+            );
+            None
+        }
+    }
+}
+
 impl<T: JavaObject> Drop for Global<T> {
     fn drop(&mut self) {
-        let jvm = crate::jvm::unwrap_global_jvm();
-
         // SAFETY: Global owns the global ref and it's no longer possible to dereference the object pointer.
-        let delete = |env: EnvPtr<'_>| unsafe {
-            env.invoke(
+        self.with_env("delete global ref", |env| unsafe {
+            env.invoke_unchecked(
                 |jni| jni.DeleteGlobalRef,
                 |jni, f| f(jni, self.obj.as_ptr()),
             )
-        };
+        });
+    }
+}
 
-        match unsafe { jvm.env() } {
-            Ok(Some(env)) => delete(env),
-            Ok(None) => {
-                // SAFETY: jvm is a valid pointer since duchess will not deinitialize a JVM once created
-                match unsafe { thread::attach(jvm) } {
-                    Ok(mut attached) => delete(attached.env()),
-                    Err(err) => {
-                        tracing::warn!(?err, "unable to attach current thread to delete global ref")
-                    }
-                }
-            }
-            Err(err) => tracing::warn!(
-                ?err,
-                "unable to get JNI interface for local thread to delete global ref"
-            ),
+/// Cloning a `Global` creates a *new* global reference to the same object via a `NewGlobalRef` JNI call. Each clone
+/// owns its reference and can be dropped independently of the others.
+///
+/// This may be called outside of [`Jvm::with`]; the current thread is attached to the JVM for the duration of the call
+/// if needed.
+///
+/// Once the JVM has been destroyed by [`duchess::run`](crate::run), the clone is as inert as the original: it shares
+/// the leaked reference, which can't be used, and dropping it does nothing.
+impl<T: JavaObject> Clone for Global<T> {
+    fn clone(&self) -> Self {
+        if crate::jvm::is_destroyed() {
+            // SAFETY: the reference is never deleted or dereferenced once the JVM is gone, so it can be shared
+            return unsafe { Global::from_raw(self.obj) };
         }
+        self.with_env("create global ref", |env| Global::new(env, &**self))
+            .expect("unable to create global ref")
     }
 }
 
@@ -137,6 +208,94 @@ impl<T: JavaObject> Deref for Global<T> {
     }
 }
 
+/// A weak global reference to a Java object of type `T`, created by [`Global::downgrade`]. Unlike a [`Global`], it
+/// doesn't keep the object alive: once no other reference to it remains, the garbage collector may reclaim it, after
+/// which [`WeakGlobal::upgrade`] returns `None`.
+///
+/// Like a [`Global`], it is valid on every thread, and can be cloned and dropped outside of [`Jvm::with`].
+pub struct WeakGlobal<T: JavaObject> {
+    /// `None` if the reference is known to be cleared, e.g. a clone made after the object was collected.
+    obj: Option<ObjectPtr>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: JavaObject> WeakGlobal<T> {
+    /// Creates a weak global reference to `obj` via a `NewWeakGlobalRef` JNI call.
+    fn new(env: EnvPtr<'_>, obj: &T) -> Self {
+        // SAFETY: The JavaObject trait contract ensures that &T points to a Java object that is an instance of T.
+        unsafe { Self::new_from_ptr(env, obj.as_raw()) }
+    }
+
+    /// Creates a weak global reference to the object referred to by `ptr`, which is cleared if `NewWeakGlobalRef`
+    /// returns null because the object was already collected.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a live local, global or weak global reference to an instance of `T`.
+    unsafe fn new_from_ptr(env: EnvPtr<'_>, ptr: ObjectPtr) -> Self {
+        let new_ref = env.invoke(|e| e.NewWeakGlobalRef, |e, f| f(e, ptr.as_ptr()));
+        Self {
+            obj: NonNull::new(new_ref).map(ObjectPtr::from),
+            _marker: PhantomData,
+        }
+    }
+
+    /// A reference that is already cleared.
+    fn cleared() -> Self {
+        Self {
+            obj: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a [`Global`] reference to the object, which keeps it alive from then on, or `None` if it has already
+    /// been garbage collected.
+    ///
+    /// This may be called outside of [`Jvm::with`]; the current thread is attached to the JVM for the duration of the
+    /// call if needed. Once the JVM has been destroyed by [`duchess::run`](crate::run), this returns `None`.
+    pub fn upgrade(&self) -> Option<Global<T>> {
+        let obj = self.obj?;
+        with_env("upgrade weak global ref", |env| {
+            // SAFETY: the weak ref is live until dropped; `NewGlobalRef` returns null if the object was collected, and
+            // otherwise a global ref to an instance of T.
+            unsafe {
+                let new_ref = env.invoke(|e| e.NewGlobalRef, |e, f| f(e, obj.as_ptr()));
+                NonNull::new(new_ref).map(|obj| Global::from_raw(obj.into()))
+            }
+        })
+        .flatten()
+    }
+}
+
+impl<T: JavaObject> Drop for WeakGlobal<T> {
+    fn drop(&mut self) {
+        let Some(obj) = self.obj else { return };
+        // SAFETY: WeakGlobal owns the weak global ref, which is never dereferenced directly.
+        with_env("delete weak global ref", |env| unsafe {
+            env.invoke_unchecked(|jni| jni.DeleteWeakGlobalRef, |jni, f| f(jni, obj.as_ptr()))
+        });
+    }
+}
+
+/// Cloning a `WeakGlobal` creates a *new* weak global reference to the same object, which can be dropped
+/// independently of the others. The clone of a reference whose object has been collected is cleared too.
+impl<T: JavaObject> Clone for WeakGlobal<T> {
+    fn clone(&self) -> Self {
+        let Some(obj) = self.obj else {
+            return Self::cleared();
+        };
+        // SAFETY: the weak ref is live until dropped and refers to an instance of T, or to nothing if it was collected.
+        with_env("create weak global ref", |env| unsafe {
+            Self::new_from_ptr(env, obj)
+        })
+        .unwrap_or_else(Self::cleared)
+    }
+}
+
+// SAFETY: Weak global refs are shareable across threads, like global refs
+unsafe impl<T: JavaObject> Send for WeakGlobal<T> {}
+unsafe impl<T: JavaObject> Sync for WeakGlobal<T> {}
+
 impl<'a, R, S> AsRef<S> for Local<'a, R>
 where
     R: Upcast<S>,
@@ -148,6 +307,13 @@ where
     }
 }
 
+/// Lets a `Local<T>` be passed to functions generic over `impl Borrow<T>`, which also accept a plain `&T`.
+impl<T: JavaObject> Borrow<T> for Local<'_, T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
 impl<'a, R: JavaObject> Local<'a, R> {
     pub fn upcast<S>(self) -> Local<'a, S>
     where
@@ -156,6 +322,8 @@ impl<'a, R: JavaObject> Local<'a, R> {
     {
         // SAFETY: From the Upcast trait contract, we know R is also an instance of S
         let upcast = unsafe { Local::<S>::from_raw(self.env, self.obj) };
+        // The upcast value now owns the local ref
+        std::mem::forget(self);
         upcast
     }
 }
@@ -171,6 +339,13 @@ where
     }
 }
 
+/// Lets a `Global<T>` be passed to functions generic over `impl Borrow<T>`, which also accept a plain `&T`.
+impl<T: JavaObject> Borrow<T> for Global<T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
 impl<R: JavaObject> Global<R> {
     pub fn upcast<S>(self) -> Global<S>
     where
@@ -179,6 +354,8 @@ impl<R: JavaObject> Global<R> {
     {
         // SAFETY: From the Upcast trait contract, we know R is also an instance of S
         let upcast = unsafe { Global::<S>::from_raw(self.obj) };
+        // The upcast value now owns the global ref
+        std::mem::forget(self);
         upcast
     }
 }
@@ -191,12 +368,3 @@ where
         jvm.local(self)
     }
 }
-
-impl<'jvm, T> CloneIn<'jvm> for Global<T>
-where
-    T: JavaObject,
-{
-    fn clone_in(&self, jvm: &mut Jvm<'jvm>) -> Self {
-        jvm.global(self)
-    }
-}
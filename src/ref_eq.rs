@@ -0,0 +1,54 @@
+use std::ptr;
+
+use crate::{plumbing::JavaObjectExt, raw::HasEnvPtr, Jvm, JvmOp, TryJDeref};
+
+/// [`JvmOp`][] that checks whether two references point to the same object, see [`JvmOp::ref_eq`].
+#[derive(Clone)]
+pub struct RefEq<This, Other> {
+    this: This,
+    other: Other,
+}
+
+impl<This, Other> RefEq<This, Other>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: TryJDeref,
+    Other: JvmOp,
+    for<'jvm> Other::Output<'jvm>: TryJDeref,
+{
+    pub(crate) fn new(this: This, other: Other) -> Self {
+        Self { this, other }
+    }
+}
+
+impl<This, Other> JvmOp for RefEq<This, Other>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: TryJDeref,
+    Other: JvmOp,
+    for<'jvm> Other::Output<'jvm>: TryJDeref,
+{
+    type Output<'jvm> = bool;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let this = self.this.execute_with(jvm)?;
+        let other = self.other.execute_with(jvm)?;
+
+        // Null references are passed as null pointers, which `IsSameObject` considers equal to each other only.
+        let this = match this.try_jderef() {
+            Ok(this) => this.as_raw().as_ptr(),
+            Err(_) => ptr::null_mut(),
+        };
+        let other = match other.try_jderef() {
+            Ok(other) => other.as_raw().as_ptr(),
+            Err(_) => ptr::null_mut(),
+        };
+
+        // SAFETY: both pointers are valid references (or null) for the duration of the call
+        let same = unsafe {
+            jvm.env()
+                .invoke(|env| env.IsSameObject, |env, f| f(env, this, other))
+        };
+        Ok(same != jni_sys::JNI_FALSE)
+    }
+}
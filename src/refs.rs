@@ -1,6 +1,27 @@
-use crate::{cast::Upcast, java::lang::Throwable, Error, Global, JavaObject, Local};
-
-/// Possibly null reference to a Java object.
+use crate::{
+    cast::Upcast,
+    java::lang::{Class, Throwable},
+    Error, Global, JavaObject, Jvm, Local,
+};
+
+/// Looks up the class that a [`crate::SuperRef`] calls the implementations of, see [`TryJDeref::super_class`].
+#[doc(hidden)]
+pub type SuperClass = for<'jvm> fn(&mut Jvm<'jvm>) -> crate::Result<'jvm, Local<'jvm, Class>>;
+
+/// Possibly null reference to a Java object that can be viewed as a `U`, i.e., whose Java type is `U` or a subtype
+/// of it.
+///
+/// This is the trait that method arguments are converted through, so every kind of handle is accepted the same way:
+/// `&U`, `Local<U>`, `Global<U>`, references to either of those, and their `Option` wrappers (where `None` is null).
+/// Write functions that take `impl AsJRef<U>` to accept all of them as well:
+///
+/// ```
+/// use duchess::{java, AsJRef};
+///
+/// fn is_null(object: &impl AsJRef<java::lang::Object>) -> bool {
+///     object.as_jref().is_err()
+/// }
+/// ```
 pub trait AsJRef<U>: TryJDeref {
     fn as_jref(&self) -> Nullable<&U>;
 }
@@ -32,6 +53,13 @@ pub trait TryJDeref {
 
     /// Dereference to a plain reference to the java object, or `Err` if it is null.
     fn try_jderef(&self) -> Nullable<&Self::Java>;
+
+    /// Used by codegen to make a nonvirtual call for references produced by [`crate::JvmOp::call_super`]: returns the
+    /// class whose implementation of a method is called.
+    #[doc(hidden)]
+    fn super_class(&self) -> Option<SuperClass> {
+        None
+    }
 }
 
 /// Reference to a Java object that cannot be null (e.g., `Global<java::lang::Object>`).
@@ -49,6 +77,10 @@ where
     fn try_jderef(&self) -> Nullable<&T::Java> {
         T::try_jderef(self)
     }
+
+    fn super_class(&self) -> Option<SuperClass> {
+        T::super_class(self)
+    }
 }
 
 impl<T> JDeref for &T
@@ -112,6 +144,10 @@ where
             None => Err(NullJRef),
         }
     }
+
+    fn super_class(&self) -> Option<SuperClass> {
+        self.as_ref().and_then(T::super_class)
+    }
 }
 
 impl<T> From<NullJRef> for Error<T>
@@ -1,8 +1,6 @@
-use std::ffi::CString;
-
 use crate::{
     error::check_exception, java::lang::String as JavaString, jvm::JavaObjectExt,
-    plumbing::HasEnvPtr, raw::ObjectPtr, to_rust::ToRust, Error, Jvm, JvmOp, Local,
+    plumbing::HasEnvPtr, raw::ObjectPtr, to_rust::ToRust, Error, Jvm, JvmOp, Local, ToRustInto,
 };
 
 impl JvmOp for &str {
@@ -12,14 +10,17 @@ impl JvmOp for &str {
         self,
         jvm: &mut Jvm<'jvm>,
     ) -> crate::Result<'jvm, Local<'jvm, JavaString>> {
-        let encoded = cesu8::to_java_cesu8(self);
-        // SAFETY: cesu8 encodes interior nul bytes as 0xC080
-        let c_string = unsafe { CString::from_vec_unchecked(encoded.into_owned()) };
+        // Java strings are UTF-16, so characters outside the Basic Multilingual Plane become surrogate pairs. Going
+        // through UTF-16 (rather than `NewStringUTF`, which expects Modified UTF-8) also keeps interior nul chars.
+        let utf16: Vec<u16> = self.encode_utf16().collect();
+        let Ok(len) = i32::try_from(utf16.len()) else {
+            return Err(Error::SliceTooLong(utf16.len()));
+        };
 
         let env = jvm.env();
-        // SAFETY: c_string is non-null pointer to cesu8-encoded encoded string ending in a trailing nul byte
+        // SAFETY: utf16 is a non-null pointer to `len` UTF-16 code units
         let string =
-            unsafe { env.invoke(|env| env.NewStringUTF, |env, f| f(env, c_string.as_ptr())) };
+            unsafe { env.invoke(|env| env.NewString, |env, f| f(env, utf16.as_ptr(), len)) };
         if let Some(string) = ObjectPtr::new(string) {
             Ok(unsafe { Local::from_raw(env, string) })
         } else {
@@ -42,63 +43,131 @@ impl JvmOp for String {
 
 impl ToRust<String> for JavaString {
     fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, String> {
-        let str_raw = self.as_raw();
+        let mut string = String::new();
+        ToRustInto::<String>::to_rust_into(self, jvm, &mut string)?;
+        Ok(string)
+    }
+}
 
-        let env = jvm.env();
+/// The number of UTF-16 code units that [`ToRustInto<String>`] reads from a Java `String` at a time.
+const CHUNK_LEN: usize = 1024;
 
-        // Note: we need to pull both the Modified UTF-8 length and the UTF-16 length of the string. The indexes to
-        // GetStringUTFRegion are UTF-16, while the space we need to allocate for the output pointer is the Modified
-        // UTF-8 length!
+/// Reuses the buffer of the target `String`. The UTF-16 code units of the Java string are read into a buffer on the
+/// stack a chunk at a time, so that the conversion doesn't allocate besides growing `target`. Unlike the Modified
+/// UTF-8 that `GetStringUTFChars` and friends return, UTF-16 can be decoded to standard UTF-8 without a separate pass
+/// for surrogate pairs and nul chars.
+impl ToRustInto<String> for JavaString {
+    fn to_rust_into<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        target: &mut String,
+    ) -> crate::Result<'jvm, ()> {
+        let len = string_len(self, jvm);
+        target.clear();
+        // UTF-8 takes at least one byte per UTF-16 code unit
+        target.reserve(len as usize);
 
-        // SAFETY: J::Output impls AsRef<JavaString>, so we know str_raw points to a non-null Java String
-        let cesu8_len = unsafe {
-            env.invoke(
-                |env| env.GetStringUTFLength,
-                |env, f| f(env, str_raw.as_ptr()),
-            )
-        };
-        assert!(cesu8_len >= 0);
-
-        // SAFETY: same as for cesu8_len
-        let utf16_len =
-            unsafe { env.invoke(|env| env.GetStringLength, |env, f| f(env, str_raw.as_ptr())) };
-        assert!(utf16_len >= 0);
-
-        let mut cesu_bytes = Vec::<u8>::with_capacity(cesu8_len as usize);
-        // SAFETY: cesu_bytes is a non-null pointer with enough capacity for the entire string when encoded in Modified
-        // UTF-8 (with no trailing nul byte).
-        unsafe {
-            env.invoke(
-                |env| env.GetStringUTFRegion,
-                |env, f| {
-                    f(
-                        env,
-                        str_raw.as_ptr(),
-                        0,
-                        utf16_len,
-                        cesu_bytes.as_mut_ptr().cast::<i8>(),
-                    )
-                },
-            );
-            cesu_bytes.set_len(cesu8_len as usize);
-        };
-        check_exception(jvm)?;
-
-        // In the common case where there are no surrogate bytes, we can do a (checked) conversion of the Vec into a
-        // Rust String. Otherwise, we'll need to use the cesu8 crate to convert properly. Note that this is the same
-        // first check done by cesu8, but because the interface takes an &[u8], it would force a copy.
-        let decoded = match String::from_utf8(cesu_bytes) {
-            Ok(s) => s,
-            Err(err) => cesu8::from_java_cesu8(err.as_bytes())
-                .map_err(|e| {
-                    Error::JvmInternal(format!(
-                        "Java String contained invalid Modified UTF-8: {}",
-                        e
-                    ))
-                })?
-                .into_owned(),
-        };
+        let mut buffer = [0_u16; CHUNK_LEN];
+        // A high surrogate that ended the previous chunk, decoded with the low surrogate that starts this one
+        let mut high_surrogate = None;
+        let mut start = 0;
+        while start < len {
+            let chunk = &mut buffer[..(len - start).min(CHUNK_LEN as i32) as usize];
+            if let Err(e) = read_utf16_region(self, jvm, start, chunk) {
+                target.clear();
+                return Err(e);
+            }
+            start += chunk.len() as i32;
+
+            let mut units = &chunk[..];
+            let carried = high_surrogate.take();
+            if let Some((&last, rest)) = units.split_last() {
+                if start < len && is_high(last) {
+                    high_surrogate = Some(last);
+                    units = rest;
+                }
+            }
+            push_utf16(
+                "String",
+                carried.into_iter().chain(units.iter().copied()),
+                target,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the UTF-16 code units of a Java `what` (a `String` or `char[]`) into `target`, which is left empty if they
+/// contain an unpaired surrogate.
+pub(crate) fn decode_utf16_into<'jvm>(
+    what: &str,
+    utf16: &[u16],
+    target: &mut String,
+) -> crate::Result<'jvm, ()> {
+    target.clear();
+    // UTF-8 takes at least one byte per UTF-16 code unit
+    target.reserve(utf16.len());
+    push_utf16(what, utf16.iter().copied(), target)
+}
+
+/// Decodes the UTF-16 code units of a Java `what` and appends them to `target`, which is cleared if they contain an
+/// unpaired surrogate.
+fn push_utf16<'jvm>(
+    what: &str,
+    utf16: impl IntoIterator<Item = u16>,
+    target: &mut String,
+) -> crate::Result<'jvm, ()> {
+    for c in char::decode_utf16(utf16) {
+        match c {
+            Ok(c) => target.push(c),
+            Err(e) => {
+                target.clear();
+                return Err(Error::Conversion(format!(
+                    "Java {what} contained an unpaired surrogate: {:#06x}",
+                    e.unpaired_surrogate()
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// True if `unit` is the first half of a surrogate pair.
+pub(crate) fn is_high(unit: u16) -> bool {
+    (0xD800..0xDC00).contains(&unit)
+}
+
+/// The number of UTF-16 code units in `string`, like `String.length()`.
+pub(crate) fn string_len(string: &JavaString, jvm: &mut Jvm<'_>) -> i32 {
+    let str_raw = string.as_raw();
+
+    // SAFETY: str_raw points to a non-null Java String
+    let len = unsafe {
+        jvm.env()
+            .invoke(|env| env.GetStringLength, |env, f| f(env, str_raw.as_ptr()))
+    };
+    assert!(len >= 0);
+    len
+}
+
+/// Copies the UTF-16 code units of `string` starting at `start` into `target`, filling it.
+pub(crate) fn read_utf16_region<'jvm>(
+    string: &JavaString,
+    jvm: &mut Jvm<'jvm>,
+    start: i32,
+    target: &mut [u16],
+) -> crate::Result<'jvm, ()> {
+    let str_raw = string.as_raw();
+    let Ok(len) = i32::try_from(target.len()) else {
+        return Err(Error::SliceTooLong(target.len()));
+    };
 
-        Ok(decoded)
+    // SAFETY: `target` has room for the `len` UTF-16 code units
+    unsafe {
+        jvm.env().invoke(
+            |env| env.GetStringRegion,
+            |env, f| f(env, str_raw.as_ptr(), start, len, target.as_mut_ptr()),
+        );
     }
+    check_exception(jvm)
 }
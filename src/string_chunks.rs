@@ -0,0 +1,122 @@
+//! Converting large Java strings a piece at a time, see [`JvmOp::to_rust_chunks`].
+
+use crate::{
+    java::lang::String as JavaString,
+    str::{decode_utf16_into, is_high, read_utf16_region, string_len},
+    AsJRef, Global, GlobalResult, Jvm, JvmOp,
+};
+
+/// [`JvmOp`][] that starts a [`StringChunks`], see [`JvmOp::to_rust_chunks`].
+#[derive(Clone)]
+pub struct ToRustChunks<This> {
+    this: This,
+    max_len: usize,
+}
+
+impl<This> ToRustChunks<This>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaString>,
+{
+    pub(crate) fn new(this: This, max_len: usize) -> Self {
+        assert!(max_len > 0, "chunks must be at least one character long");
+        Self { this, max_len }
+    }
+}
+
+impl<This> JvmOp for ToRustChunks<This>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaString>,
+{
+    type Output<'jvm> = StringChunks;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let this = self.this.execute_with(jvm)?;
+        let string = this.as_jref()?;
+        let len = string_len(string, jvm);
+        Ok(StringChunks {
+            string: jvm.global(string),
+            len,
+            offset: 0,
+            max_len: i32::try_from(self.max_len).unwrap_or(i32::MAX),
+            utf16: Vec::new(),
+        })
+    }
+}
+
+/// A Rust iterator over the pieces of a Java string, each converted to a Rust `String`, so that a huge string can be
+/// processed without holding all of it in Rust memory. Created by [`JvmOp::to_rust_chunks`].
+///
+/// Like a [`GlobalIter`](crate::GlobalIter), it holds the string as a global reference and isn't tied to a [`Jvm`]:
+/// each call to `next` runs in [`Jvm::with`], attaching the current thread if needed, and copies the next piece of
+/// the string with a single JNI call. The buffer the piece is copied into is reused, so memory usage is bounded by
+/// the size of the pieces.
+///
+/// Each piece has at most the requested number of UTF-16 code units (Java `char`s), except that a surrogate pair is
+/// never split, so a piece may end one code unit early (or, for pieces of a single code unit, late). A piece
+/// containing an unpaired surrogate fails to convert, and iteration can continue past it.
+pub struct StringChunks {
+    string: Global<JavaString>,
+    len: i32,
+    offset: i32,
+    max_len: i32,
+    utf16: Vec<u16>,
+}
+
+impl StringChunks {
+    /// The number of UTF-16 code units that haven't been converted yet.
+    pub fn remaining_len(&self) -> usize {
+        (self.len - self.offset) as usize
+    }
+
+    /// Copies the next piece of the string into `utf16` and advances past it.
+    fn next_utf16<'jvm>(&mut self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, ()> {
+        let start = self.offset;
+        let len = self.max_len.min(self.len - start);
+        self.utf16.clear();
+        self.utf16.resize(len as usize, 0);
+        read_utf16_region(&self.string, jvm, start, &mut self.utf16)?;
+
+        // Keep surrogate pairs together
+        let mut end = start + len;
+        let last = *self.utf16.last().unwrap();
+        if end < self.len && is_high(last) {
+            if self.utf16.len() > 1 {
+                self.utf16.pop();
+                end -= 1;
+            } else {
+                self.utf16.push(0);
+                read_utf16_region(&self.string, jvm, end, &mut self.utf16[1..])?;
+                end += 1;
+            }
+        }
+        self.offset = end;
+        Ok(())
+    }
+}
+
+impl Iterator for StringChunks {
+    type Item = GlobalResult<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.len {
+            return None;
+        }
+
+        let result = Jvm::with(|jvm| {
+            let start = self.offset;
+            let result = self.next_utf16(jvm).and_then(|()| {
+                let mut chunk = String::new();
+                decode_utf16_into("String", &self.utf16, &mut chunk)?;
+                Ok(chunk)
+            });
+            // Skip a piece that can't be converted, so that iteration can continue past it
+            if result.is_err() && self.offset == start {
+                self.offset = self.len.min(start + self.max_len);
+            }
+            result
+        });
+        Some(result)
+    }
+}
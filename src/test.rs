@@ -0,0 +1,40 @@
+//! Helpers for testing code that uses duchess, see [`with_jvm`].
+
+use crate::{java::lang::ThrowableExt, Error, Jvm, JvmOp};
+
+/// Runs `op` on the JVM shared by the whole test binary, launching it on first use, and panics if `op` fails:
+///
+/// ```rust,ignore
+/// #[test]
+/// fn greets() {
+///     let greeting: String = duchess::test::with_jvm(|jvm| {
+///         Greeter::new().greet("world").assert_not_null().to_rust().execute_with(jvm)
+///     });
+///     assert_eq!(greeting, "Hello, world");
+/// }
+/// ```
+///
+/// There can only be one JVM per process, and `cargo test` runs the tests of a binary on parallel threads. Each call
+/// attaches the calling thread to the same JVM for the duration of `op`, which JNI allows for any number of threads,
+/// so the tests keep running in parallel. As with [`Jvm::with`], `op` can't call `with_jvm` itself.
+///
+/// The JVM is launched with the default settings, whose classpath comes from the `CLASSPATH` environment variable when
+/// the test runs. If a test launches the JVM itself with [`Jvm::builder`], the tests that run after it use that JVM.
+///
+/// If `op` fails with a Java exception, its stack trace is printed to the JVM's stderr (which the test harness
+/// doesn't capture) before panicking.
+#[track_caller]
+pub fn with_jvm<R>(op: impl for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::Result<'jvm, R>) -> R {
+    let result = Jvm::with(|jvm| {
+        op(jvm).inspect_err(|e| {
+            if let Error::Thrown(exception) = e {
+                // Best effort: the exception is reported by the panic below either way.
+                let _ = exception.print_stack_trace().execute_with(jvm);
+            }
+        })
+    });
+    match result {
+        Ok(r) => r,
+        Err(e) => panic!("{e}"),
+    }
+}
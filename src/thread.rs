@@ -28,7 +28,7 @@ fn attached_or(
         State::AttachedPermanently(env) => Ok(AttachGuard {
             jvm,
             env,
-            permanent: true,
+            on_drop: OnDrop::KeepPermanently,
         }),
         State::InUse => Err(Error::NestedUsage),
         State::Detached => {
@@ -47,41 +47,60 @@ pub fn attach_permanently(jvm: JvmPtr) -> GlobalResult<AttachGuard> {
             jvm,
             // no-op if already attached outside of duchess
             env: unsafe { jvm.attach_thread()? },
-            permanent: true,
+            on_drop: OnDrop::KeepPermanently,
         })
     })
 }
 
 pub unsafe fn attach<'jvm>(jvm: JvmPtr) -> GlobalResult<AttachGuard> {
     attached_or(jvm, || {
+        // If the thread was attached outside of duchess (e.g. it's a Java thread calling into Rust, or the thread that
+        // created the JVM), it's not ours to detach.
+        if let Some(env) = unsafe { jvm.env()? } {
+            return Ok(AttachGuard {
+                jvm,
+                env,
+                on_drop: OnDrop::Release,
+            });
+        }
+
         Ok(AttachGuard {
             jvm,
-            // no-op if already attached outside of duchess
             env: unsafe { jvm.attach_thread()? },
-            permanent: false,
+            on_drop: OnDrop::Detach,
         })
     })
 }
 
-/// When dropped, will detach the current thread from the JVM unless it was permanently attached.
+/// What an [`AttachGuard`] does with the current thread's attachment when dropped.
+enum OnDrop {
+    /// The thread was permanently attached by duchess, so it stays attached.
+    KeepPermanently,
+    /// The thread was attached outside of duchess, so it stays attached but duchess forgets about it.
+    Release,
+    /// The thread was attached by this guard, so it is detached.
+    Detach,
+}
+
+/// When dropped, will detach the current thread from the JVM if it was attached by this guard.
 pub struct AttachGuard {
     jvm: JvmPtr,
     env: EnvPtr<'static>, // not send!
-    permanent: bool,
+    on_drop: OnDrop,
 }
 
 impl Drop for AttachGuard {
     fn drop(&mut self) {
-        if self.permanent {
-            STATE.with(|state| {
+        match self.on_drop {
+            OnDrop::KeepPermanently => STATE.with(|state| {
                 let old_state = state.replace(State::AttachedPermanently(self.env));
                 debug_assert!(matches!(old_state, State::InUse))
-            });
-        } else {
-            match unsafe { self.jvm.detach_thread() } {
+            }),
+            OnDrop::Release => STATE.with(|state| state.set(State::Detached)),
+            OnDrop::Detach => match unsafe { self.jvm.detach_thread() } {
                 Ok(()) => STATE.with(|state| state.set(State::Detached)),
                 Err(err) => tracing::warn!(?err, "couldn't detach thread from JVM"),
-            }
+            },
         }
     }
 }
@@ -0,0 +1,93 @@
+//! Conversions between `java.time` and `std::time`, enabled by the `javatime` feature.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{
+    java::{
+        self,
+        time::{DurationExt, InstantExt},
+    },
+    to_java::ToJavaImpl,
+    Error, Jvm, JvmOp, Local, ToRust,
+};
+
+/// Converts an `Instant` using its offset from the epoch. Java normalizes instants so that the nanosecond
+/// adjustment is always positive, even for instants before the epoch.
+impl ToRust<SystemTime> for java::time::Instant {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, SystemTime> {
+        let seconds = self.get_epoch_second().execute_with(jvm)?;
+        let nanos = self.get_nano().execute_with(jvm)?;
+
+        let time = if seconds >= 0 {
+            UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64))
+        } else {
+            UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs()))
+        };
+        time.and_then(|t| t.checked_add(Duration::from_nanos(nanos as u64)))
+            .ok_or_else(|| {
+                Error::Conversion(format!(
+                    "java.time.Instant ({seconds}s, {nanos}ns from the epoch) is out of range for SystemTime"
+                ))
+            })
+    }
+}
+
+impl ToJavaImpl<java::time::Instant> for SystemTime {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::time::Instant>>> {
+        // `ofEpochSecond` normalizes a negative nanosecond adjustment, so times before the epoch can be passed
+        // as a negated offset.
+        let (seconds, nanos) = match rust.duration_since(UNIX_EPOCH) {
+            Ok(after) => (
+                i64::try_from(after.as_secs()),
+                i64::from(after.subsec_nanos()),
+            ),
+            Err(before) => {
+                let before = before.duration();
+                (
+                    i64::try_from(before.as_secs()).map(|s| -s),
+                    -i64::from(before.subsec_nanos()),
+                )
+            }
+        };
+        let seconds = seconds.map_err(|_| {
+            Error::Conversion(format!("{rust:?} is out of range for java.time.Instant"))
+        })?;
+
+        let instant = java::time::Instant::of_epoch_second(seconds, nanos).execute_with(jvm)?;
+        Ok(instant)
+    }
+}
+
+/// Converts a `Duration`. Java durations may be negative, which fails to convert since [`std::time::Duration`]
+/// cannot represent them.
+impl ToRust<Duration> for java::time::Duration {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Duration> {
+        let seconds = self.get_seconds().execute_with(jvm)?;
+        let nanos = self.get_nano().execute_with(jvm)?;
+
+        let seconds = u64::try_from(seconds).map_err(|_| {
+            Error::Conversion(format!(
+                "negative java.time.Duration ({seconds}s, {nanos}ns) cannot be converted to Duration"
+            ))
+        })?;
+        Ok(Duration::new(seconds, nanos as u32))
+    }
+}
+
+impl ToJavaImpl<java::time::Duration> for Duration {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::time::Duration>>> {
+        let seconds = i64::try_from(rust.as_secs()).map_err(|_| {
+            Error::Conversion(format!("{rust:?} is out of range for java.time.Duration"))
+        })?;
+
+        let duration = java::time::Duration::of_seconds(seconds, i64::from(rust.subsec_nanos()))
+            .execute_with(jvm)?;
+        Ok(duration)
+    }
+}
@@ -0,0 +1,63 @@
+use std::{sync::mpsc, time::Duration};
+
+use crate::{
+    java::lang::{Thread, ThreadExt},
+    Error, Global, GlobalResult, Jvm, JvmOp,
+};
+
+/// What the worker thread of [`execute_with_timeout`] reports back.
+enum Message<R> {
+    /// The operation is about to run on the given Java thread, which is interrupted on timeout.
+    Started(Global<Thread>),
+
+    /// The operation completed, or failed before it could start (e.g. because the thread couldn't be attached).
+    Done(GlobalResult<R>),
+}
+
+/// Implementation of [`JvmOp::execute_with_timeout`].
+pub(crate) fn execute_with_timeout<J, R>(op: J, timeout: Duration) -> GlobalResult<R>
+where
+    J: Send + 'static,
+    R: Send + 'static,
+    for<'jvm> J: JvmOp<Output<'jvm> = R>,
+{
+    let (tx, rx) = mpsc::channel::<Message<R>>();
+
+    let worker = std::thread::spawn(move || {
+        let result = Jvm::with(|jvm| {
+            let thread = Thread::current_thread()
+                .assert_not_null()
+                .global()
+                .execute_with(jvm)?;
+            let _ = tx.send(Message::Started(thread));
+            op.execute_with(jvm)
+        });
+        let _ = tx.send(Message::Done(result));
+    });
+
+    // The channel disconnects without a `Done` message only if the worker panicked, which is propagated
+    let resume_panic = |worker: std::thread::JoinHandle<()>| -> ! {
+        match worker.join() {
+            Err(panic) => std::panic::resume_unwind(panic),
+            Ok(()) => unreachable!("worker exited without sending a result"),
+        }
+    };
+
+    let thread = match rx.recv() {
+        Ok(Message::Started(thread)) => thread,
+        Ok(Message::Done(result)) => return result,
+        Err(mpsc::RecvError) => resume_panic(worker),
+    };
+
+    match rx.recv_timeout(timeout) {
+        Ok(Message::Done(result)) => result,
+        Ok(Message::Started(_)) => unreachable!("worker started twice"),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            // The worker keeps running until the operation notices the interrupt (or completes anyway); its result
+            // is discarded.
+            thread.interrupt().execute()?;
+            Err(Error::Timeout(timeout))
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => resume_panic(worker),
+    }
+}
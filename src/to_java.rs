@@ -1,6 +1,10 @@
 use std::{collections::HashMap, marker::PhantomData};
 
-use crate::{cast::Upcast, java, Jvm, JvmOp, Local};
+use crate::{
+    cast::Upcast,
+    collect_java::{array_list_with_capacity, hash_map_with_capacity},
+    java, Jvm, JvmOp, Local,
+};
 
 pub trait ToJava: Sized {
     type JvmOp<'a, J>: for<'jvm> JvmOp<Output<'jvm> = Option<Local<'jvm, J>>>
@@ -26,7 +30,8 @@ where
 }
 
 impl<R> ToJava for R {
-    type JvmOp<'a, J> = ToJavaOp<'a, R, J>
+    type JvmOp<'a, J>
+        = ToJavaOp<'a, R, J>
     where
         Self: 'a,
         Self: ToJavaImpl<J>,
@@ -61,6 +66,20 @@ where
     }
 }
 
+/// References convert like the value they refer to, e.g. for the items of `vec.iter()`.
+impl<R, J> ToJavaImpl<J> for &R
+where
+    R: ToJavaImpl<J> + ?Sized,
+    J: Upcast<java::lang::Object>,
+{
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, J>>> {
+        R::to_java_impl(rust, jvm)
+    }
+}
+
 impl<K, V, JK, JV, S> ToJavaImpl<java::util::HashMap<JK, JV>> for HashMap<K, V, S>
 where
     K: ToJavaImpl<JK>,
@@ -75,7 +94,7 @@ where
         use java::util::MapExt;
 
         let jmap: Local<'jvm, java::util::HashMap<JK, JV>> =
-            java::util::HashMap::new().execute_with(jvm)?;
+            hash_map_with_capacity(rust.len(), jvm)?;
         for (key, value) in rust {
             jmap.put(key.to_java(), value.to_java()).execute_with(jvm)?;
         }
@@ -103,6 +122,29 @@ where
     }
 }
 
+/// Builds a `Properties` object with `setProperty`, which needs no conversion of the keys and values beyond creating
+/// the Java strings.
+impl<K, V, S> ToJavaImpl<java::util::Properties> for HashMap<K, V, S>
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::util::Properties>>> {
+        use java::util::PropertiesExt;
+
+        let properties = java::util::Properties::new().execute_with(jvm)?;
+        for (key, value) in rust {
+            properties
+                .set_property(key.as_ref(), value.as_ref())
+                .execute_with(jvm)?;
+        }
+        Ok(Some(properties))
+    }
+}
+
 impl<E, JE> ToJavaImpl<java::util::ArrayList<JE>> for Vec<E>
 where
     E: ToJavaImpl<JE>,
@@ -114,7 +156,7 @@ where
     ) -> crate::Result<'jvm, Option<Local<'jvm, java::util::ArrayList<JE>>>> {
         use java::util::ListExt;
         let jvec: Local<'jvm, java::util::ArrayList<JE>> =
-            java::util::ArrayList::new().execute_with(jvm)?;
+            array_list_with_capacity(rust.len(), jvm)?;
         for element in rust {
             jvec.add(element.to_java()).execute_with(jvm)?;
         }
@@ -159,6 +201,26 @@ impl ToJavaImpl<java::lang::String> for str {
     }
 }
 
+impl ToJavaImpl<java::lang::CharSequence> for String {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::lang::CharSequence>>> {
+        str::to_java_impl(rust, jvm)
+    }
+}
+
+/// Strings are converted to `CharSequence` by creating a Java `String`.
+impl ToJavaImpl<java::lang::CharSequence> for str {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::lang::CharSequence>>> {
+        let jstr = rust.execute_with(jvm)?;
+        Ok(Some(jstr.upcast()))
+    }
+}
+
 impl ToJavaImpl<java::Array<i8>> for Vec<u8> {
     fn to_java_impl<'jvm>(
         rust: &Self,
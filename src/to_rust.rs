@@ -10,6 +10,15 @@ pub trait ToRust<R> {
     fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, R>;
 }
 
+/// Types that can be converted into an existing Rust `R`, reusing its allocation where possible. This is the
+/// equivalent of [`ToRust`] for hot paths that read many values into the same buffer, in the same way that
+/// [`std::io::Read::read_to_string`] reuses a `String`.
+///
+/// On success, `target` holds the converted value and its previous contents are discarded.
+pub trait ToRustInto<R: ?Sized> {
+    fn to_rust_into<'jvm>(&self, jvm: &mut Jvm<'jvm>, target: &mut R) -> crate::Result<'jvm, ()>;
+}
+
 macro_rules! identity_rust_op {
     ($($t:ty,)*) => {
         $(
@@ -84,6 +93,36 @@ where
     }
 }
 
+impl<R, J> ToRustInto<R> for Local<'_, J>
+where
+    R: ?Sized,
+    J: JavaObject + ToRustInto<R>,
+{
+    fn to_rust_into<'jvm>(&self, jvm: &mut Jvm<'jvm>, target: &mut R) -> crate::Result<'jvm, ()> {
+        J::to_rust_into(self, jvm, target)
+    }
+}
+
+impl<R, J> ToRustInto<R> for Global<J>
+where
+    R: ?Sized,
+    J: JavaObject + ToRustInto<R>,
+{
+    fn to_rust_into<'jvm>(&self, jvm: &mut Jvm<'jvm>, target: &mut R) -> crate::Result<'jvm, ()> {
+        J::to_rust_into(&**self, jvm, target)
+    }
+}
+
+impl<R, J> ToRustInto<R> for &J
+where
+    R: ?Sized,
+    J: ToRustInto<R>,
+{
+    fn to_rust_into<'jvm>(&self, jvm: &mut Jvm<'jvm>, target: &mut R) -> crate::Result<'jvm, ()> {
+        J::to_rust_into(self, jvm, target)
+    }
+}
+
 pub struct ToRustOp<This, R>
 where
     This: JvmOp,
@@ -118,3 +157,67 @@ where
         Ok(rust)
     }
 }
+
+/// [`JvmOp`][] that converts a possibly-null Java string to a Rust string, see
+/// [`JvmOp::to_rust_string_or_default`].
+pub struct ToRustStringOrDefault<This> {
+    this: This,
+}
+
+impl<This> ToRustStringOrDefault<This>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: ToRust<Option<String>>,
+{
+    pub(crate) fn new(this: This) -> Self {
+        ToRustStringOrDefault { this }
+    }
+}
+
+impl<This> JvmOp for ToRustStringOrDefault<This>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: ToRust<Option<String>>,
+{
+    type Output<'jvm> = String;
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let java = self.this.execute_with(jvm)?;
+        let rust: Option<String> = ToRust::to_rust(&java, jvm)?;
+        Ok(rust.unwrap_or_default())
+    }
+}
+
+pub struct ToRustIntoOp<'a, This, R>
+where
+    This: JvmOp,
+    R: ?Sized,
+{
+    this: This,
+    target: &'a mut R,
+}
+
+impl<'a, This, R> ToRustIntoOp<'a, This, R>
+where
+    This: JvmOp,
+    R: ?Sized,
+    for<'jvm> This::Output<'jvm>: ToRustInto<R>,
+{
+    pub(crate) fn new(this: This, target: &'a mut R) -> Self {
+        ToRustIntoOp { this, target }
+    }
+}
+
+impl<This, R> JvmOp for ToRustIntoOp<'_, This, R>
+where
+    This: JvmOp,
+    R: ?Sized,
+    for<'jvm> This::Output<'jvm>: ToRustInto<R>,
+{
+    type Output<'jvm> = ();
+
+    fn execute_with<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Self::Output<'jvm>> {
+        let java = self.this.execute_with(jvm)?;
+        ToRustInto::to_rust_into(&java, jvm, self.target)
+    }
+}
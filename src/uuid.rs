@@ -0,0 +1,29 @@
+//! Conversions between `java.util.UUID` and `uuid::Uuid`, enabled by the `uuid` feature.
+
+use ::uuid::Uuid;
+
+use crate::{
+    java::{self, util::UUIDExt},
+    to_java::ToJavaImpl,
+    Jvm, JvmOp, Local, ToRust,
+};
+
+/// Converts a `UUID` from its two 64-bit halves, which hold its 128 bits in the same big-endian order as `Uuid`.
+impl ToRust<Uuid> for java::util::UUID {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::Result<'jvm, Uuid> {
+        let most = self.get_most_significant_bits().execute_with(jvm)?;
+        let least = self.get_least_significant_bits().execute_with(jvm)?;
+        Ok(Uuid::from_u64_pair(most as u64, least as u64))
+    }
+}
+
+impl ToJavaImpl<java::util::UUID> for Uuid {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::Result<'jvm, Option<Local<'jvm, java::util::UUID>>> {
+        let (most, least) = rust.as_u64_pair();
+        let uuid = java::util::UUID::new(most as i64, least as i64).execute_with(jvm)?;
+        Ok(Some(uuid))
+    }
+}
@@ -0,0 +1,28 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package shapes;
+
+    class Shape { * }
+    class Square { * }
+}
+
+use shapes::ShapeExt;
+
+#[test]
+fn abstract_methods_callable_through_subclass() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let square = shapes::Square::new(3.0).execute_with(jvm)?;
+        let area = square.area().execute_with(jvm)?;
+        assert_eq!(area, 9.0);
+
+        let shape = square.upcast::<shapes::Shape>();
+        let description: String = shape
+            .describe()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(description, "Square with area 9.0");
+        Ok(())
+    })
+}
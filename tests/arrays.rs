@@ -0,0 +1,122 @@
+use duchess::java::lang::StringExt;
+use duchess::{java, prelude::*, Jvm};
+
+duchess::java_package! {
+    package arrays;
+
+    class Grid { * }
+}
+
+#[test]
+fn split_returns_string_array() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let parts: Vec<String> = "a,b,,c"
+            .execute_with(jvm)?
+            .split(",")
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(parts, vec!["a", "b", "", "c"]);
+        Ok(())
+    })
+}
+
+#[test]
+fn get_bytes_returns_byte_array() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let bytes: Vec<i8> = "hi"
+            .execute_with(jvm)?
+            .get_bytes()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(bytes, vec![b'h' as i8, b'i' as i8]);
+        Ok(())
+    })
+}
+
+#[test]
+fn multidimensional_arrays() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let grid = arrays::Grid::identity(3)
+            .assert_not_null()
+            .execute_with(jvm)?;
+        let rows: Vec<Vec<i32>> = (&grid).to_rust().execute_with(jvm)?;
+        assert_eq!(rows, vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]]);
+
+        let sum = arrays::Grid::sum(&grid).execute_with(jvm)?;
+        assert_eq!(sum, 3);
+        Ok(())
+    })
+}
+
+#[test]
+fn null_array_element_fails_to_convert() {
+    let result: duchess::GlobalResult<Vec<String>> = arrays::Grid::with_null()
+        .assert_not_null()
+        .to_rust()
+        .execute();
+    match result {
+        Err(duchess::Error::ElementConversion { index, error }) => {
+            assert_eq!(index, 1);
+            assert!(matches!(*error, duchess::Error::NullDeref));
+        }
+        other => panic!("expected an element conversion error, got {other:?}"),
+    }
+}
+
+#[test]
+fn slices_as_array_arguments() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let total = arrays::Grid::total(&[1_i64, 2, 3][..]).execute_with(jvm)?;
+        assert_eq!(total, 6);
+        let total = arrays::Grid::total(vec![4_i64, 5]).execute_with(jvm)?;
+        assert_eq!(total, 9);
+
+        let checksum = arrays::Grid::checksum(&[0xff_u8, 1][..]).execute_with(jvm)?;
+        assert_eq!(checksum, 256);
+        let bytes: Vec<u8> = vec![2, 3];
+        let checksum = arrays::Grid::checksum(&bytes).execute_with(jvm)?;
+        assert_eq!(checksum, 5);
+
+        let rows: Vec<Vec<i32>> = vec![vec![1, 2], vec![3]];
+        let sum = arrays::Grid::sum(&rows).execute_with(jvm)?;
+        assert_eq!(sum, 6);
+        Ok(())
+    })
+}
+
+#[test]
+fn object_slices_as_array_arguments() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let joined: String = arrays::Grid::join(&["a", "b"][..])
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(joined, "a,b");
+
+        let owned = vec!["c".to_string(), "d".to_string()];
+        let joined: String = arrays::Grid::join(&owned)
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(joined, "c,d");
+
+        let local = "e".execute_with(jvm)?;
+        let global = jvm.global(&*local);
+        let handles = [Some(jvm.global(&*local)), None, Some(global)];
+        let joined: String = arrays::Grid::join(&handles[..])
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(joined, "e,null,e");
+
+        let refs: Vec<&java::lang::String> = vec![&local, &local];
+        let joined: String = arrays::Grid::join(refs)
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(joined, "e,e");
+        Ok(())
+    })
+}
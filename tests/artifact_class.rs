@@ -0,0 +1,34 @@
+//! `pinned.Version` is only in `java/pinned.jar`, which isn't on the `CLASSPATH`, so its bindings can only be
+//! generated by reflecting it from the jar. This test launches its own JVM with the jar on its class path, so it must
+//! be the only test in this file.
+
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package pinned;
+
+    class Version from "java/pinned.jar" { * }
+}
+
+use pinned::VersionExt;
+
+const JAVA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/java");
+
+#[test]
+fn class_reflected_from_jar() -> duchess::GlobalResult<()> {
+    Jvm::builder()
+        .add_classpath(format!("{JAVA_DIR}/pinned.jar"))
+        .launch_or_use_existing()?;
+
+    Jvm::with(|jvm| {
+        let version = pinned::Version::new().execute_with(jvm)?;
+        let description: String = version
+            .describe()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(description, "pinned 1.2");
+        assert_eq!(pinned::Version::major().execute_with(jvm)?, 1);
+        Ok(())
+    })
+}
@@ -0,0 +1,58 @@
+use duchess::{java, prelude::*, Jvm};
+
+duchess::java_package! {
+    package booleans;
+
+    class Flags { * }
+}
+
+#[test]
+fn boolean_returns_and_arguments() -> duchess::GlobalResult<()> {
+    assert!(booleans::Flags::is_even(4).execute()?);
+    assert!(!booleans::Flags::is_even(3).execute()?);
+    assert!(booleans::Flags::not(false).execute()?);
+    assert!(!booleans::Flags::not(true).execute()?);
+    Ok(())
+}
+
+#[test]
+fn boolean_arrays_round_trip() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let values = [true, false, true].as_slice().execute_with(jvm)?;
+        assert_eq!(booleans::Flags::count(&values).execute_with(jvm)?, 2);
+
+        let back: Vec<bool> = values.to_rust().execute_with(jvm)?;
+        assert_eq!(back, [true, false, true]);
+        Ok(())
+    })
+}
+
+// JNI only guarantees that a `jboolean` is zero for false. Java code can't produce other values, so the fixture writes
+// the raw bytes with `Unsafe`.
+#[test]
+fn nonzero_boolean_bytes_are_true() -> duchess::GlobalResult<()> {
+    booleans::Flags::set_flag_byte(2_i8).execute()?;
+    assert!(booleans::Flags::get_flag().execute()?);
+
+    booleans::Flags::set_flag_byte(0_i8).execute()?;
+    assert!(!booleans::Flags::get_flag().execute()?);
+
+    let values: Vec<bool> = booleans::Flags::with_byte(-1_i8)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(values, [false, true]);
+    Ok(())
+}
+
+#[test]
+fn boolean_to_java() -> duchess::GlobalResult<()> {
+    let values = vec![false, true];
+    let count = values
+        .to_java::<java::Array<bool>>()
+        .assert_not_null()
+        .map_java(|values, jvm| booleans::Flags::count(&values).execute_with(jvm))
+        .execute()?;
+    assert_eq!(count, 1);
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use duchess::java::util::ComparatorExt;
+use duchess::{java, prelude::*, Jvm, Local};
+
+duchess::java_package! {
+    package bridges;
+
+    class LengthScorer { * }
+    interface Scorer { * }
+    class ByLength { * }
+
+    // Specified by hand, so the descriptors are computed from the erasure of `T` and `U` to `Number`
+    public class bridges.Holder<T extends java.lang.Number> {
+        public bridges.Holder(T);
+        public T get();
+        public <U extends T> boolean holds(U);
+    }
+}
+
+use bridges::{HolderExt, ScorerExt};
+
+#[test]
+fn interface_method_with_bounded_type_parameter() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let scorer: Local<bridges::Scorer<java::lang::String>> =
+            bridges::LengthScorer::new().upcast().execute_with(jvm)?;
+        assert_eq!(scorer.score("four").execute_with(jvm)?, 4);
+        Ok(())
+    })
+}
+
+#[test]
+fn comparator_implemented_for_a_concrete_type() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let comparator: Local<java::util::Comparator<java::lang::String>> =
+            bridges::ByLength::new().upcast().execute_with(jvm)?;
+        assert!(comparator.compare("fig", "apple").execute_with(jvm)? < 0);
+
+        let reversed = comparator.reversed().assert_not_null().execute_with(jvm)?;
+        assert!(reversed.compare("fig", "apple").execute_with(jvm)? > 0);
+        Ok(())
+    })
+}
+
+#[test]
+fn class_with_bounded_type_parameter() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let five = java::lang::Integer::value_of(5).execute_with(jvm)?;
+        let holder = bridges::Holder::<java::lang::Integer>::new(&five).execute_with(jvm)?;
+        let value: i32 = holder.get().assert_not_null().to_rust().execute_with(jvm)?;
+        assert_eq!(value, 5);
+        assert!(holder
+            .holds::<_, java::lang::Integer>(&five)
+            .execute_with(jvm)?);
+        Ok(())
+    })
+}
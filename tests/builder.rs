@@ -0,0 +1,49 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package params;
+
+    #[builder]
+    class Contact { * }
+
+    package closeables;
+
+    #[builder]
+    class Resource { * }
+}
+
+use closeables::ResourceExt;
+use params::ContactExt;
+
+#[test]
+fn builder_methods_named_after_parameters() -> duchess::GlobalResult<()> {
+    let description: String = params::Contact::builder()
+        .last_name("Hopper")
+        .age(85)
+        .first_name("Grace")
+        .build()
+        .describe()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(description, "Grace Hopper (85)");
+    Ok(())
+}
+
+#[test]
+fn builder_methods_without_parameter_names_are_positional() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let resource = closeables::Resource::builder()
+            .arg1(false)
+            .arg0("db")
+            .build()
+            .execute_with(jvm)?;
+        let name: String = resource
+            .name()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(name, "db");
+        Ok(())
+    })
+}
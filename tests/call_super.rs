@@ -0,0 +1,96 @@
+use duchess::java::lang::ObjectExt;
+use duchess::{java, prelude::*, Jvm};
+
+duchess::java_package! {
+    package supercall;
+
+    class Animal { * }
+    class Bird { * }
+    class Sparrow { * }
+}
+
+use supercall::AnimalExt;
+
+#[test]
+fn call_super_skips_overrides() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let bird = supercall::Bird::new().execute_with(jvm)?;
+
+        let speech: String = bird.speak().assert_not_null().to_rust().execute_with(jvm)?;
+        assert_eq!(speech, "Tweet");
+        assert_eq!(bird.legs().execute_with(jvm)?, 2);
+
+        let speech: String = bird
+            .call_super::<supercall::Animal>()
+            .speak()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(speech, "...");
+        assert_eq!(
+            bird.call_super::<supercall::Animal>()
+                .legs()
+                .execute_with(jvm)?,
+            4
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn call_super_reaches_inherited_implementations() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let bird = supercall::Bird::new().execute_with(jvm)?;
+
+        let description: String = bird
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(description, "a bird");
+
+        // `Animal` doesn't override `toString`, so this is `Object.toString`
+        let description: String = bird
+            .call_super::<supercall::Animal>()
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert!(description.starts_with("supercall.Bird@"), "{description}");
+
+        let description: String = bird
+            .call_super::<java::lang::Object>()
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert!(description.starts_with("supercall.Bird@"), "{description}");
+        Ok(())
+    })
+}
+
+#[test]
+fn call_super_calls_the_override_in_the_given_class() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        // `toString` is declared by `Object`, but `Bird` overrides it
+        let bird = supercall::Bird::new().execute_with(jvm)?;
+        let description: String = bird
+            .call_super::<supercall::Bird>()
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(description, "a bird");
+
+        // `speak` is declared by `Animal` and overridden by both `Bird` and `Sparrow`
+        let sparrow = supercall::Sparrow::new().execute_with(jvm)?;
+        let speech: String = sparrow
+            .call_super::<supercall::Bird>()
+            .speak()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(speech, "Tweet");
+        Ok(())
+    })
+}
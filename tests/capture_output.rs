@@ -0,0 +1,95 @@
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+use duchess::{java, prelude::*, Jvm};
+
+duchess::java_package! {
+    package printing;
+
+    class Printer { * }
+}
+
+/// Capturing replaces `System.out` for the whole JVM, so the tests take turns.
+static STDOUT: Mutex<()> = Mutex::new(());
+
+/// A writer whose output the test can still read after the capture drops it.
+#[derive(Clone, Default)]
+struct Shared(Arc<Mutex<Vec<u8>>>);
+
+impl Shared {
+    fn text(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for Shared {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+struct Failing;
+
+impl Write for Failing {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::BrokenPipe, "sink is gone"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn capture_and_restore_stdout() -> duchess::GlobalResult<()> {
+    let _lock = STDOUT.lock().unwrap();
+    let output = Shared::default();
+    Jvm::with(|jvm| {
+        let original = java::lang::System::get_out().execute_with(jvm)?;
+        let capture = jvm.capture_stdout(output.clone())?;
+        printing::Printer::greet("duchess").execute_with(jvm)?;
+        printing::Printer::greet("again").execute_with(jvm)?;
+        assert_eq!(output.text(), "Hello, duchess! ✓\nHello, again! ✓\n");
+
+        capture.restore(jvm)?;
+        printing::Printer::greet("nobody").execute_with(jvm)?;
+        assert_eq!(output.text(), "Hello, duchess! ✓\nHello, again! ✓\n");
+
+        let restored = java::lang::System::get_out().execute_with(jvm)?;
+        assert!(original.ref_eq(&restored).execute_with(jvm)?);
+        Ok(())
+    })?;
+
+    // The writer was dropped when the capture ended
+    assert_eq!(Arc::strong_count(&output.0), 1);
+    Ok(())
+}
+
+#[test]
+fn capture_stderr_until_dropped() -> duchess::GlobalResult<()> {
+    let output = Shared::default();
+    Jvm::with(|jvm| {
+        let _capture = jvm.capture_stderr(output.clone())?;
+        printing::Printer::warn("low disk").execute_with(jvm)?;
+        Ok(())
+    })?;
+
+    // `print` doesn't flush, but ending the capture does
+    assert_eq!(output.text(), "warning: low disk");
+    assert_eq!(Arc::strong_count(&output.0), 1);
+    Ok(())
+}
+
+#[test]
+fn writer_errors_are_reported_to_java() -> duchess::GlobalResult<()> {
+    let _lock = STDOUT.lock().unwrap();
+    Jvm::with(|jvm| {
+        let _capture = jvm.capture_stdout(Failing)?;
+        assert!(printing::Printer::print_and_check("lost").execute_with(jvm)?);
+        Ok(())
+    })
+}
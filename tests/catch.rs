@@ -0,0 +1,62 @@
+use duchess::java::lang::ThrowableExt;
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package exceptions;
+
+    class Thrower { * }
+    class CheckedFailure { * }
+    class UncheckedFailure { * }
+}
+
+#[test]
+fn catch_is_scoped_to_its_sub_op() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let caught = exceptions::Thrower::throw_checked()
+            .catch::<exceptions::CheckedFailure>()
+            .execute_with(jvm)?;
+        let Err(exception) = caught else {
+            panic!("expected `CheckedFailure` to be caught")
+        };
+        let message: String = exception
+            .get_message()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(message, "checked");
+
+        // The caught exception is no longer pending, so later ops in the same session run normally.
+        let ok: String = exceptions::Thrower::succeed()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(ok, "ok");
+
+        // Ops outside the scope of the catch still propagate their exceptions.
+        let uncaught = exceptions::Thrower::throw_unchecked().execute_with(jvm);
+        assert!(matches!(uncaught, Err(duchess::Error::Thrown(_))));
+
+        Ok(())
+    })
+}
+
+#[test]
+fn nested_catch_rethrows_unmatched_exceptions() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        // The inner catch doesn't match, so the exception propagates to the outer catch.
+        let caught = exceptions::Thrower::throw_unchecked()
+            .catch::<exceptions::CheckedFailure>()
+            .catch::<exceptions::UncheckedFailure>()
+            .execute_with(jvm)?;
+        assert!(caught.is_err());
+
+        // The inner catch matches, so the outer catch never sees the exception.
+        let caught = exceptions::Thrower::throw_checked()
+            .catch::<exceptions::CheckedFailure>()
+            .catch::<exceptions::UncheckedFailure>()
+            .execute_with(jvm)?;
+        assert!(matches!(caught, Ok(Err(_))));
+
+        Ok(())
+    })
+}
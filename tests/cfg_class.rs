@@ -0,0 +1,26 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package gated;
+
+    #[cfg(any())]
+    class Widget { * }
+
+    #[cfg(all())]
+    class Gadget { * }
+}
+
+use gated::GadgetExt;
+
+#[test]
+fn members_mentioning_gated_classes_are_gated() -> duchess::GlobalResult<()> {
+    // `Gadget.widget()` returns a `Widget`, which is compiled out
+    let names: Vec<&str> = gated::Gadget::METHODS.iter().map(|m| m.name).collect();
+    assert_eq!(names, ["size"]);
+
+    Jvm::with(|jvm| {
+        let gadget = gated::Gadget::new().execute_with(jvm)?;
+        assert_eq!(gadget.size().execute_with(jvm)?, 3);
+        Ok(())
+    })
+}
@@ -0,0 +1,95 @@
+use duchess::java::lang::StringExt;
+use duchess::java::CharArrayExt;
+use duchess::{java, prelude::*, Jvm};
+use secrets::VaultExt;
+
+duchess::java_package! {
+    package secrets;
+
+    class Vault { * }
+}
+
+#[test]
+fn chars_to_char_array_and_back() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let password: Vec<char> = "hunter2".chars().collect();
+        let vault = secrets::Vault::new(&password).execute_with(jvm)?;
+        assert!(vault.unlock(&password).execute_with(jvm)?);
+        assert!(!vault.unlock(vec!['n', 'o', 'p', 'e']).execute_with(jvm)?);
+
+        let chars: Vec<char> = vault
+            .password()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(chars, password);
+        let string: String = vault
+            .password()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(string, "hunter2");
+        Ok(())
+    })
+}
+
+#[test]
+fn astral_chars_take_two_java_chars() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let crab = ['a', '\u{1F980}'];
+        let array = crab.as_slice().execute_with(jvm)?;
+        let units: Vec<u16> = (&array).to_rust().execute_with(jvm)?;
+        assert_eq!(units, vec![u16::from(b'a'), 0xD83E, 0xDD80]);
+
+        let chars: Vec<char> = (&array).to_rust().execute_with(jvm)?;
+        assert_eq!(chars, crab);
+        Ok(())
+    })
+}
+
+#[test]
+fn unpaired_surrogate_fails_to_decode() {
+    let result: duchess::GlobalResult<String> = [0xD83E_u16].as_slice().to_rust().execute();
+    assert!(matches!(result, Err(duchess::Error::Conversion(_))));
+}
+
+#[test]
+fn string_to_char_array() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let array = "hello"
+            .execute_with(jvm)?
+            .to_char_array()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        let units: Vec<u16> = (&array).to_rust().execute_with(jvm)?;
+        assert_eq!(units, "hello".encode_utf16().collect::<Vec<_>>());
+
+        let string: String = java::lang::String::value_of(&array)
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(string, "hello");
+        Ok(())
+    })
+}
+
+#[test]
+fn zero_overwrites_the_password() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let password = vec!['s', 'e', 'c', 'r', 'e', 't'];
+        let array = password
+            .to_java::<java::Array<u16>>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        let vault = secrets::Vault::new(&array).execute_with(jvm)?;
+
+        array.zero().execute_with(jvm)?;
+        let units: Vec<u16> = (&array).to_rust().execute_with(jvm)?;
+        assert_eq!(units, vec![0; 6]);
+
+        // The vault kept its own copy
+        assert!(vault.unlock(&password).execute_with(jvm)?);
+        assert!(!vault.unlock(&array).execute_with(jvm)?);
+        Ok(())
+    })
+}
@@ -0,0 +1,68 @@
+//! Runs a variety of operations under `-Xcheck:jni`, which reports misuse of JNI as warnings on the JVM's output.
+//! The operations run in a child process so that the output can be inspected.
+
+use std::process::Command;
+
+use duchess::{java, prelude::*, Jvm};
+
+duchess::java_package! {
+    package exceptions;
+
+    class Thrower { * }
+    class CheckedFailure { * }
+}
+
+const CHILD_VAR: &str = "DUCHESS_CHECK_JNI_CHILD";
+
+#[test]
+fn duchess_passes_check_jni() {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["exercise_jni", "--exact", "--nocapture", "--test-threads=1"])
+        .env(CHILD_VAR, "1")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "{stdout}\n{stderr}");
+    assert!(stdout.contains("1 passed"), "{stdout}");
+    for text in [&stdout, &stderr] {
+        assert!(!text.contains("WARNING in native method"), "{text}");
+    }
+}
+
+#[test]
+fn exercise_jni() -> duchess::GlobalResult<()> {
+    if std::env::var_os(CHILD_VAR).is_none() {
+        return Ok(());
+    }
+
+    Jvm::builder().check_jni(true).try_launch()?;
+    Jvm::with(|jvm| {
+        let words = vec!["one".to_string(), "two".to_string()];
+        let list: Vec<String> = words
+            .to_java::<java::util::ArrayList<java::lang::String>>()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(list, words);
+
+        let bytes: Vec<i8> = [1_i8, 2, 3].as_slice().to_rust().execute_with(jvm)?;
+        assert_eq!(bytes, [1, 2, 3]);
+
+        let global = "global"
+            .to_string()
+            .to_java::<java::lang::String>()
+            .assert_not_null()
+            .global()
+            .execute_with(jvm)?;
+        let text: String = global.to_rust().execute_with(jvm)?;
+        assert_eq!(text, "global");
+
+        let caught = exceptions::Thrower::throw_checked()
+            .catch::<exceptions::CheckedFailure>()
+            .execute_with(jvm)?;
+        assert!(caught.is_err());
+        Ok(())
+    })
+}
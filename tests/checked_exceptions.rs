@@ -0,0 +1,156 @@
+use duchess::java::lang::ThrowableExt;
+use duchess::{prelude::*, Error, Jvm};
+
+duchess::java_package! {
+    package vaults;
+
+    public class Vault {
+        public vaults.Vault(java.lang.String);
+        #[checked]
+        public java.lang.String open(java.lang.String) throws vaults.WrongCode, vaults.Locked;
+        #[checked]
+        public void resetPIN(java.lang.String) throws vaults.Locked, vaults.alarm.Locked;
+        #[checked]
+        public void resetPin(java.lang.String) throws vaults.alarm.Locked;
+        #[checked]
+        public static vaults.Vault named(java.lang.String) throws vaults.Missing;
+    }
+
+    class WrongCode { * }
+    class Locked { * }
+    class Missing { * }
+
+    package vaults.alarm;
+
+    class Locked { * }
+}
+
+use vaults::{
+    Vault, VaultExt, VaultNamedError, VaultOpenError, VaultResetPin2Error, VaultResetPinError,
+    WrongCodeExt,
+};
+
+#[test]
+fn checked_method_returns_output() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let vault = Vault::new("1234").execute_with(jvm)?;
+        let Ok(contents) = vault.open_checked("1234").execute_with(jvm)? else {
+            panic!("expected the vault to open");
+        };
+        let contents: String = contents.unwrap().to_rust().execute_with(jvm)?;
+        assert_eq!(contents, "treasure");
+        Ok(())
+    })
+}
+
+#[test]
+fn declared_exceptions_are_typed() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let vault = Vault::new("1234").execute_with(jvm)?;
+
+        let Err(VaultOpenError::WrongCode(wrong)) = vault.open_checked("0000").execute_with(jvm)?
+        else {
+            panic!("expected a `WrongCode`");
+        };
+        assert_eq!(wrong.attempts().execute_with(jvm)?, 1);
+
+        vault.open_checked("0000").execute_with(jvm)?.err().unwrap();
+        let Err(VaultOpenError::Locked(_)) = vault.open_checked("1234").execute_with(jvm)? else {
+            panic!("expected `Locked`");
+        };
+        Ok(())
+    })
+}
+
+#[test]
+fn checked_static_method() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let Ok(vault) = Vault::named_checked("main").execute_with(jvm)? else {
+            panic!("expected the main vault");
+        };
+        assert!(vault.is_some());
+
+        let Err(VaultNamedError::Missing(missing)) =
+            Vault::named_checked("side").execute_with(jvm)?
+        else {
+            panic!("expected `Missing`");
+        };
+        let message: String = missing
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(message, "vaults.Missing: no vault named side");
+        Ok(())
+    })
+}
+
+#[test]
+fn undeclared_exceptions_are_thrown() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let vault = Vault::new("1234").execute_with(jvm)?;
+        let Err(Error::Thrown(exception)) = vault.open_checked("").execute_with(jvm) else {
+            panic!("expected an `IllegalArgumentException`");
+        };
+        let message: String = exception
+            .get_message()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(message, "empty code");
+        Ok(())
+    })
+}
+
+#[test]
+fn unchecked_binding_is_unchanged() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let vault = Vault::new("1234").execute_with(jvm)?;
+        let Err(Error::Thrown(_)) = vault.open("0000").execute_with(jvm) else {
+            panic!("expected the `WrongCode` to be thrown");
+        };
+        Ok(())
+    })
+}
+
+#[test]
+fn methods_named_alike_get_their_own_enums() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let vault = Vault::new("1234").execute_with(jvm)?;
+
+        // `vaults.Locked` and `vaults.alarm.Locked` are told apart by their packages
+        let Err(VaultResetPinError::VaultsAlarmLocked(_)) =
+            vault.reset_pin_checked("0000").execute_with(jvm)?
+        else {
+            panic!("expected `vaults.alarm.Locked`");
+        };
+        let Err(VaultResetPin2Error::Locked(_)) =
+            vault.reset_pin_2_checked("0000").execute_with(jvm)?
+        else {
+            panic!("expected `vaults.alarm.Locked`");
+        };
+        Ok(())
+    })
+}
+
+#[test]
+fn checked_errors_propagate_with_question_mark() -> Result<(), Box<dyn std::error::Error>> {
+    fn open(code: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let contents = Jvm::with(|jvm| {
+            let vault = Vault::new("1234").execute_with(jvm)?;
+            match vault.open_checked(code).execute_with(jvm)? {
+                Ok(contents) => Ok(Ok(contents.unwrap().to_rust().execute_with(jvm)?)),
+                Err(error) => Ok(Err(error)),
+            }
+        })??;
+        Ok(contents)
+    }
+
+    assert_eq!(open("1234")?, "treasure");
+
+    let error = open("0000").unwrap_err();
+    assert_eq!(error.to_string(), "vaults.WrongCode: wrong code");
+    let error = error.downcast::<VaultOpenError>().unwrap();
+    assert_eq!(format!("{error:?}"), "WrongCode(vaults.WrongCode)");
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use duchess::java::lang::ThrowableExt;
+use duchess::{prelude::*, Error, Global, Jvm};
+
+duchess::java_package! {
+    package closeables;
+
+    class Resource { * }
+}
+
+use closeables::{Resource, ResourceExt};
+
+#[test]
+fn dropping_the_guard_closes() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let resource = Resource::new("db", false).execute_with(jvm)?;
+        {
+            let guard = jvm.closeable(&*resource);
+            let name: String = guard.name().assert_not_null().to_rust().execute_with(jvm)?;
+            assert_eq!(name, "db");
+            assert!(!guard.is_closed().execute_with(jvm)?);
+        }
+        assert!(resource.is_closed().execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn explicit_close_returns_exception() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let resource = Resource::new("socket", true).execute_with(jvm)?;
+        let guard = jvm.closeable(&*resource);
+        let Err(Error::Thrown(exception)) = guard.close(jvm) else {
+            panic!("expected `close` to throw");
+        };
+        let message: String = exception
+            .get_message()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(message, "socket failed to close");
+        assert!(resource.is_closed().execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn guard_dropped_outside_jvm_with() -> duchess::GlobalResult<()> {
+    let (guard, resource) = Jvm::with(|jvm| {
+        let resource = Resource::new("file", false).execute_with(jvm)?;
+        let guard = jvm.closeable(&*resource);
+        Ok((guard, jvm.global(&*resource)))
+    })?;
+
+    // Dropped on another thread, which isn't attached to the JVM
+    std::thread::spawn(move || drop(guard)).join().unwrap();
+
+    assert!(resource.is_closed().execute()?);
+    Ok(())
+}
+
+#[test]
+fn failing_close_on_drop_is_discarded() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let resource = Resource::new("pipe", true).execute_with(jvm)?;
+        drop(jvm.closeable(&*resource));
+        assert!(resource.is_closed().execute_with(jvm)?);
+
+        // The exception doesn't linger into later calls
+        assert!(!Resource::new("next", false).is_closed().execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn into_inner_keeps_open() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let resource = Resource::new("cache", false).execute_with(jvm)?;
+        let inner: Global<Resource> = jvm.closeable(&*resource).into_inner();
+        assert!(!inner.is_closed().execute_with(jvm)?);
+        Ok(())
+    })
+}
@@ -0,0 +1,137 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use duchess::{java, prelude::*, Error, Jvm};
+
+use functional::{CombinerExt, ShouterExt, TransformerExt};
+
+duchess::java_package! {
+    package functional;
+
+    class Transformer { * }
+    class Shouter { * }
+    class Combiner { * }
+    class Concat { * }
+    class IntOperator { * }
+    class Callers { * }
+}
+
+fn exclaim<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    input: Option<duchess::Local<'jvm, java::lang::String>>,
+) -> duchess::Result<'jvm, Option<duchess::Local<'jvm, java::lang::String>>> {
+    let input: String = jvm.to_rust(&*input.ok_or(Error::NullDeref)?)?;
+    format!("{input}!").as_str().execute_with(jvm).map(Some)
+}
+
+fn concat<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    left: Option<duchess::Local<'jvm, java::lang::String>>,
+    right: Option<duchess::Local<'jvm, java::lang::String>>,
+) -> duchess::Result<'jvm, Option<duchess::Local<'jvm, java::lang::String>>> {
+    let left: String = jvm.to_rust(&*left.ok_or(Error::NullDeref)?)?;
+    let right: String = jvm.to_rust(&*right.ok_or(Error::NullDeref)?)?;
+    format!("{left}-{right}")
+        .as_str()
+        .execute_with(jvm)
+        .map(Some)
+}
+
+#[test]
+fn closure_passed_as_functional_interface() -> duchess::GlobalResult<()> {
+    let greeting: String =
+        functional::Callers::greet(functional::Transformer::from_fn(exclaim), "duchess")
+            .assert_not_null()
+            .to_rust()
+            .execute()?;
+    assert_eq!(greeting, "Hello, duchess!");
+    Ok(())
+}
+
+#[test]
+fn closure_called_from_rust_and_default_methods() -> duchess::GlobalResult<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counted = calls.clone();
+    Jvm::with(|jvm| {
+        let transformer = functional::Transformer::from_fn(move |jvm, input| {
+            counted.fetch_add(1, Ordering::SeqCst);
+            exclaim(jvm, input)
+        })
+        .execute_with(jvm)?;
+
+        let once: String = transformer
+            .apply("a")
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(once, "a!");
+
+        let twice: String = transformer
+            .twice()
+            .apply("b")
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(twice, "b!!");
+        Ok(())
+    })?;
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    Ok(())
+}
+
+#[test]
+fn closure_for_inherited_abstract_method() -> duchess::GlobalResult<()> {
+    let shouted: String = functional::Shouter::from_fn(exclaim)
+        .shout("quiet")
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(shouted, "QUIET!");
+    Ok(())
+}
+
+#[test]
+fn closure_for_generic_interface() -> duchess::GlobalResult<()> {
+    let joined: String =
+        functional::Callers::join(functional::Concat::from_fn(concat), "a", "b", "c")
+            .assert_not_null()
+            .to_rust()
+            .execute()?;
+    assert_eq!(joined, "a-b-c");
+
+    let combined: String = functional::Combiner::<java::lang::String>::from_fn(concat)
+        .combine("x", "y")
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(combined, "x-y");
+    Ok(())
+}
+
+#[test]
+fn closure_with_scalar_arguments() -> duchess::GlobalResult<()> {
+    let product = functional::Callers::reduce(
+        functional::IntOperator::from_fn(|_jvm, left, right| Ok(left * right)),
+        2,
+        3,
+        7,
+    )
+    .execute()?;
+    assert_eq!(product, 42);
+    Ok(())
+}
+
+#[test]
+fn closure_error_is_thrown_to_java() -> duchess::GlobalResult<()> {
+    let result: duchess::GlobalResult<String> = functional::Callers::greet(
+        functional::Transformer::from_fn(|_jvm, _input| Err(Error::NullDeref)),
+        "duchess",
+    )
+    .assert_not_null()
+    .to_rust()
+    .execute();
+    assert!(matches!(result, Err(Error::Thrown(_))));
+    Ok(())
+}
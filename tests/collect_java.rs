@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use duchess::java::util::{MapExt, SetExt};
+use duchess::plumbing::ToJavaImpl;
+use duchess::{java, prelude::*, Error, Jvm, Local};
+
+#[test]
+fn collect_into_array_list() -> duchess::GlobalResult<()> {
+    let words = ["alpha", "beta", "gamma"];
+    let list: Vec<String> = words
+        .iter()
+        .collect_java::<java::util::ArrayList<java::lang::String>>()
+        .to_rust()
+        .execute()?;
+    assert_eq!(list, words);
+    Ok(())
+}
+
+#[test]
+fn collect_into_hash_set() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let set = [1, 2, 2, 3]
+            .into_iter()
+            .collect_java::<java::util::HashSet<java::lang::Integer>>()
+            .execute_with(jvm)?;
+        assert_eq!(set.size().execute_with(jvm)?, 3);
+        assert!(set
+            .contains(java::lang::Integer::value_of(2))
+            .execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn collect_pairs_into_hash_map() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let map = (1..=3)
+            .map(|i| (format!("key{i}"), i * 10))
+            .collect_java::<java::util::HashMap<java::lang::String, java::lang::Integer>>()
+            .execute_with(jvm)?;
+        assert_eq!(map.size().execute_with(jvm)?, 3);
+
+        let map: HashMap<String, i32> = map.to_rust().execute_with(jvm)?;
+        assert_eq!(map["key2"], 20);
+        Ok(())
+    })
+}
+
+#[test]
+fn collect_iterator_of_unknown_length() -> duchess::GlobalResult<()> {
+    let evens: Vec<i32> = (0..10)
+        .filter(|i| i % 2 == 0)
+        .collect_java::<java::util::ArrayList<java::lang::Integer>>()
+        .to_rust()
+        .execute()?;
+    assert_eq!(evens, [0, 2, 4, 6, 8]);
+    Ok(())
+}
+
+#[test]
+fn convert_large_collections() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let numbers: Vec<i32> = (0..10_000).collect();
+        let list = numbers
+            .to_java::<java::util::ArrayList<java::lang::Integer>>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        let list: Vec<i32> = list.to_rust().execute_with(jvm)?;
+        assert_eq!(list, numbers);
+
+        let squares: HashMap<i32, i32> = numbers.iter().map(|&i| (i, i * i)).collect();
+        let map = squares
+            .to_java::<java::util::HashMap<java::lang::Integer, java::lang::Integer>>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        assert_eq!(map.size().execute_with(jvm)?, 10_000);
+        let map: HashMap<i32, i32> = map.to_rust().execute_with(jvm)?;
+        assert_eq!(map, squares);
+        Ok(())
+    })
+}
+
+/// Fails to convert negative values.
+struct Positive(i32);
+
+impl ToJavaImpl<java::lang::Integer> for Positive {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> duchess::Result<'jvm, Option<Local<'jvm, java::lang::Integer>>> {
+        if rust.0 < 0 {
+            return Err(Error::Conversion(format!("{} is negative", rust.0)));
+        }
+        ToJavaImpl::to_java_impl(&rust.0, jvm)
+    }
+}
+
+#[test]
+fn conversion_failure_reports_index() {
+    let result = [1, 2, -3, 4]
+        .into_iter()
+        .map(Positive)
+        .collect_java::<java::util::ArrayList<java::lang::Integer>>()
+        .global()
+        .execute();
+    match result {
+        Err(Error::ElementConversion { index, error }) => {
+            assert_eq!(index, 2);
+            assert!(matches!(*error, Error::Conversion(_)));
+        }
+        Err(other) => panic!("expected an element conversion error, got {other:?}"),
+        Ok(_) => panic!("expected an element conversion error"),
+    }
+}
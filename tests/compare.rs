@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+
+use duchess::java::lang::ThrowableExt;
+use duchess::{java, prelude::*, Error, Global, Jvm};
+
+duchess::java_package! {
+    package compare;
+
+    class Erased { * }
+}
+
+#[test]
+fn cmp_to_strings() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        assert_eq!("apple".cmp_to("banana").execute_with(jvm)?, Ordering::Less);
+        assert_eq!("pear".cmp_to("pear").execute_with(jvm)?, Ordering::Equal);
+        assert_eq!("pear".cmp_to("apple").execute_with(jvm)?, Ordering::Greater);
+        Ok(())
+    })
+}
+
+#[test]
+fn sort_by_natural_ordering() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let mut words: Vec<Global<java::lang::String>> = ["pear", "apple", "fig"]
+            .into_iter()
+            .map(|word| word.global().execute_with(jvm))
+            .collect::<Result<_, _>>()?;
+
+        words.sort_by(|a, b| a.cmp_to(b).execute_with(jvm).unwrap());
+
+        let words: Vec<String> = words
+            .iter()
+            .map(|word| word.to_rust().execute_with(jvm))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(words, ["apple", "fig", "pear"]);
+        Ok(())
+    })
+}
+
+#[test]
+fn sort_natural_sorts_a_list_in_place() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list = vec![
+            String::from("pear"),
+            String::from("apple"),
+            String::from("fig"),
+        ]
+        .to_java::<java::util::List<java::lang::String>>()
+        .assert_not_null()
+        .execute_with(jvm)?;
+        // `to_java` creates an `ArrayList`, which supports `set`
+        list.sort_natural().execute_with(jvm)?;
+
+        let words: Vec<String> = list.to_rust().execute_with(jvm)?;
+        assert_eq!(words, ["apple", "fig", "pear"]);
+        Ok(())
+    })
+}
+
+#[test]
+fn sort_natural_leaves_the_list_unchanged_when_a_comparison_throws() -> duchess::GlobalResult<()> {
+    use duchess::java::util::ListExt;
+
+    Jvm::with(|jvm| {
+        // `String.compareTo` throws a `ClassCastException` for the element that isn't a string
+        let list = compare::Erased::polluted()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        let result = list.sort_natural().execute_with(jvm);
+        assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+
+        let first: String = list.get(0).assert_not_null().to_rust().execute_with(jvm)?;
+        assert_eq!(first, "pear");
+        Ok(())
+    })
+}
+
+#[test]
+fn incomparable_objects_throw() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let date = java::util::Date::new().execute_with(jvm)?;
+        let result = compare::Erased::erase("apple")
+            .assert_not_null()
+            .cmp_to(&date)
+            .execute_with(jvm);
+
+        let Err(Error::Thrown(exception)) = result else {
+            panic!("expected a ClassCastException, got {result:?}");
+        };
+        let message: String = (&exception)
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert!(
+            message.starts_with("java.lang.ClassCastException"),
+            "{message}"
+        );
+        Ok(())
+    })
+}
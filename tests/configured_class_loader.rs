@@ -0,0 +1,51 @@
+//! Bound classes are loaded through the class loader configured with `JvmBuilder::class_loader`. This test launches
+//! its own JVM whose class path only has `loaders.jar`, so it must be the only test in this file.
+
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package hidden;
+
+    class Secret { * }
+}
+
+duchess::java_package! {
+    package loaders;
+
+    class Isolated { * }
+}
+
+const JAVA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/java");
+
+#[test]
+fn classes_loaded_through_configured_class_loader() -> duchess::GlobalResult<()> {
+    Jvm::builder()
+        .add_classpath(format!("{JAVA_DIR}/loaders.jar"))
+        .class_loader(|jvm| {
+            loaders::Isolated::over(JAVA_DIR)
+                .assert_not_null()
+                .execute_with(jvm)
+        })
+        .launch_or_use_existing()?;
+
+    // Threads started in Rust find the class too, without setting their context class loader.
+    let secret = std::thread::spawn(|| {
+        hidden::Secret::reveal()
+            .assert_not_null()
+            .to_rust::<String>()
+            .execute()
+    })
+    .join()
+    .unwrap()?;
+    assert_eq!(secret, "found");
+
+    let again = Jvm::builder()
+        .class_loader(|jvm| {
+            loaders::Isolated::over(JAVA_DIR)
+                .assert_not_null()
+                .execute_with(jvm)
+        })
+        .launch_or_use_existing();
+    assert!(matches!(again, Err(duchess::Error::JvmInternal(_))));
+    Ok(())
+}
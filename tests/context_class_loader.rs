@@ -0,0 +1,44 @@
+//! Classes that the system class loader can't see are found through the thread's context class loader. This test
+//! launches its own JVM whose class path only has `loaders.jar`, so it must be the only test in this file.
+
+use duchess::java::lang::ThreadExt;
+use duchess::{java, prelude::*, Jvm};
+
+duchess::java_package! {
+    package hidden;
+
+    class Secret { * }
+}
+
+duchess::java_package! {
+    package loaders;
+
+    class Isolated { * }
+}
+
+#[test]
+fn class_found_through_context_class_loader() -> duchess::GlobalResult<()> {
+    let java_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/java");
+    Jvm::builder()
+        .add_classpath(format!("{java_dir}/loaders.jar"))
+        .launch_or_use_existing()?;
+
+    Jvm::with(|jvm| {
+        // Not found through `FindClass`, which uses the system class loader on a thread started in Rust.
+        let hidden = hidden::Secret::reveal().execute_with(jvm);
+        assert!(matches!(hidden, Err(duchess::Error::Thrown(_))));
+
+        let loader = loaders::Isolated::over(java_dir).execute_with(jvm)?;
+        let thread = java::lang::Thread::current_thread()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        thread.set_context_class_loader(&loader).execute_with(jvm)?;
+
+        let secret: String = hidden::Secret::reveal()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(secret, "found");
+        Ok(())
+    })
+}
@@ -0,0 +1,56 @@
+use duchess::{java, prelude::*, Jvm, Local};
+
+use covariant::{AnimalExt, DogExt};
+
+duchess::java_package! {
+    package covariant;
+
+    class Animal { * }
+    class Dog { * }
+}
+
+#[test]
+fn overrides_return_the_covariant_type() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let dog = covariant::Dog::new(3).execute_with(jvm)?;
+        let itself: Local<covariant::Dog> =
+            DogExt::itself(&dog).assert_not_null().execute_with(jvm)?;
+        assert_eq!(itself.age().execute_with(jvm)?, 3);
+
+        #[allow(deprecated)]
+        let name: String = DogExt::name(&dog)
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(name, "dog");
+        Ok(())
+    })
+}
+
+#[test]
+fn overridden_methods_dispatch_virtually() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let dog = covariant::Dog::new(3).execute_with(jvm)?;
+        let animal: Local<covariant::Animal> = dog.upcast();
+        let name: Local<java::lang::Object> = animal.name().assert_not_null().execute_with(jvm)?;
+        let name: String = name
+            .try_downcast::<java::lang::String>()
+            .execute_with(jvm)?
+            .ok()
+            .unwrap()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(name, "dog");
+        Ok(())
+    })
+}
+
+#[test]
+fn generic_bridge_is_not_an_overload() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let young = covariant::Dog::new(1).execute_with(jvm)?;
+        let old = covariant::Dog::new(7).execute_with(jvm)?;
+        assert!(young.compare_to(&old).execute_with(jvm)? < 0);
+        Ok(())
+    })
+}
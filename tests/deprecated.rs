@@ -0,0 +1,23 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package deprecations;
+
+    #[annotations]
+    class Legacy { * }
+}
+
+use deprecations::LegacyExt;
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_methods_are_still_callable() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let legacy = deprecations::Legacy::create()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        assert_eq!(legacy.old_value().execute_with(jvm)?, 1);
+        assert_eq!(legacy.new_value().execute_with(jvm)?, 2);
+        Ok(())
+    })
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use duchess::{java, prelude::*, Jvm, Local};
+
+duchess::java_package! {
+    package auth;
+
+    class Authenticated { * }
+    class HttpRequest { * }
+}
+
+use auth::HttpRequestExt;
+
+#[derive(duchess::ToJava)]
+#[java(class = "HttpRequest", package = "auth")]
+struct HttpRequest {
+    verb: String,
+    path: String,
+    hashed_payload: Vec<u8>,
+    parameters: HashMap<String, Vec<String>>,
+    headers: HashMap<String, Vec<String>>,
+}
+
+#[derive(duchess::ToRust)]
+#[java(class = "auth.Authenticated")]
+struct Authenticated {
+    account_id: String,
+    user: String,
+}
+
+#[test]
+fn class_and_package_given_separately() -> duchess::GlobalResult<()> {
+    let request = HttpRequest {
+        verb: "GET".to_string(),
+        path: "/index.html".to_string(),
+        hashed_payload: vec![],
+        parameters: HashMap::new(),
+        headers: HashMap::new(),
+    };
+
+    Jvm::with(|jvm| {
+        let java_request: Local<auth::HttpRequest> = request.execute_with(jvm)?;
+        let verb: String = java_request
+            .verb()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        let path: String = java_request
+            .path()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(verb, "GET");
+        assert_eq!(path, "/index.html");
+        Ok(())
+    })
+}
+
+#[test]
+fn fully_qualified_class() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let authenticated: Authenticated =
+            auth::Authenticated::new().to_rust().execute_with(jvm)?;
+        assert_eq!(authenticated.account_id, "some-account-id");
+        assert_eq!(authenticated.user, "some-user");
+        Ok(())
+    })
+}
@@ -0,0 +1,85 @@
+use duchess::java::lang::ThrowableExt;
+use duchess::java::util::ListExt;
+use duchess::{java, prelude::*, Error, Jvm, Local};
+
+duchess::java_package! {
+    package factories;
+
+    public class Temperature {
+        #[factory]
+        public static factories.Temperature ofCelsius(double);
+        #[factory]
+        public static factories.Temperature parse(java.lang.String);
+        public static factories.Temperature find(java.lang.String);
+        public double celsius();
+    }
+}
+
+use factories::{Temperature, TemperatureExt};
+
+#[test]
+fn list_of_returns_the_list() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list: Local<java::util::List<java::lang::String>> =
+            java::util::List::of(&["a", "b", "c"][..]).execute_with(jvm)?;
+        assert_eq!(list.size().execute_with(jvm)?, 3);
+
+        let items: Vec<String> = list.to_rust().execute_with(jvm)?;
+        assert_eq!(items, ["a", "b", "c"]);
+        Ok(())
+    })
+}
+
+#[test]
+fn list_of_composes_without_assert_not_null() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let second: String = java::util::List::of::<java::lang::String>(&["a", "b"][..])
+            .get(1)
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(second, "b");
+        Ok(())
+    })
+}
+
+#[test]
+fn factory_returns_an_instance() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let celsius = Temperature::of_celsius(21.5).celsius().execute_with(jvm)?;
+        assert_eq!(celsius, 21.5);
+
+        let parsed: Local<Temperature> = Temperature::parse("-4C").execute_with(jvm)?;
+        assert_eq!(parsed.celsius().execute_with(jvm)?, -4.0);
+        Ok(())
+    })
+}
+
+#[test]
+fn factory_exceptions_are_thrown() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let Err(Error::Thrown(exception)) = Temperature::parse("warm").execute_with(jvm) else {
+            panic!("expected an `IllegalArgumentException`");
+        };
+        let message: String = exception
+            .get_message()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(message, "not a temperature: warm");
+        Ok(())
+    })
+}
+
+#[test]
+fn other_static_methods_stay_nullable() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        assert!(Temperature::find("boiling").execute_with(jvm)?.is_none());
+        let freezing = Temperature::find("freezing")
+            .assert_not_null()
+            .celsius()
+            .execute_with(jvm)?;
+        assert_eq!(freezing, 0.0);
+        Ok(())
+    })
+}
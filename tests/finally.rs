@@ -0,0 +1,106 @@
+use std::io::Read;
+
+use duchess::java::io::InputStreamExt;
+use duchess::java::lang::{ClassLoaderExt, ThrowableExt};
+use duchess::java::ArrayExt;
+use duchess::{java, prelude::*, Error, JavaReader, Jvm, Local};
+
+duchess::java_package! {
+    package streams;
+
+    class FailingStream { * }
+}
+
+fn message<'jvm>(
+    exception: &Local<'jvm, java::lang::Throwable>,
+    jvm: &mut Jvm<'jvm>,
+) -> duchess::Result<'jvm, String> {
+    exception
+        .get_message()
+        .to_rust_string_or_default()
+        .execute_with(jvm)
+}
+
+#[test]
+fn resource_stream_is_closed() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let stream = java::lang::ClassLoader::get_system_class_loader()
+            .assert_not_null()
+            .get_resource_as_stream("resources/greeting.txt")
+            .assert_not_null()
+            .execute_with(jvm)?;
+
+        let text = (&stream)
+            .map_java(|stream, jvm| {
+                let mut text = String::new();
+                JavaReader::new(jvm, stream)?
+                    .read_to_string(&mut text)
+                    .map_err(|e| Error::JvmInternal(e.to_string()))?;
+                Ok(text)
+            })
+            .finally_java(|jvm| stream.close().execute_with(jvm))
+            .execute_with(jvm)?;
+        assert_eq!(text, "Hello from a resource\n");
+
+        // Reading a closed stream throws
+        let closed = stream.available().execute_with(jvm);
+        assert!(matches!(closed, Err(Error::Thrown(_))));
+        Ok(())
+    })
+}
+
+#[test]
+fn cleanup_error_after_success_is_returned() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let stream = streams::FailingStream::new().execute_with(jvm)?;
+        let result = stream
+            .available()
+            .finally_java(|jvm| stream.close().execute_with(jvm))
+            .execute_with(jvm);
+        let Err(Error::Thrown(exception)) = result else {
+            panic!("expected the exception thrown by `close`");
+        };
+        assert_eq!(message(&exception, jvm)?, "stuck open");
+        Ok(())
+    })
+}
+
+#[test]
+fn cleanup_exception_is_suppressed() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let stream = streams::FailingStream::new().execute_with(jvm)?;
+        let buffer = [0_i8; 16];
+        let result = stream
+            .read(&buffer[..], 0, 16)
+            .finally_java(|jvm| stream.close().execute_with(jvm))
+            .execute_with(jvm);
+        let Err(Error::Thrown(exception)) = result else {
+            panic!("expected the exception thrown by `read`");
+        };
+        assert_eq!(message(&exception, jvm)?, "disk on fire");
+
+        let suppressed = exception
+            .get_suppressed()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        assert_eq!((&suppressed).length().execute_with(jvm)?, 1);
+        Ok(())
+    })
+}
+
+#[test]
+fn cleanup_runs_after_rust_error() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let mut cleaned_up = false;
+        let result = ()
+            .map_java(|(), _jvm| Err::<(), _>(Error::JvmInternal("broken".into())))
+            .finally_java(|_jvm| {
+                cleaned_up = true;
+                Ok(())
+            })
+            .execute_with(jvm);
+        assert!(matches!(result, Err(Error::JvmInternal(_))));
+        assert!(cleaned_up);
+        Ok(())
+    })
+}
@@ -0,0 +1,87 @@
+use duchess::{java, prelude::*, Jvm, Local};
+
+duchess::java_package! {
+    package generics;
+
+    class Holder { * }
+}
+
+use duchess::java::lang::CharSequenceExt;
+use duchess::java::util::ListExt;
+use generics::HolderExt;
+
+#[test]
+fn element_returns_use_the_class_parameter() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let holder: Local<generics::Holder<java::lang::String>> =
+            generics::Holder::new("hello").execute_with(jvm)?;
+
+        // `get` returns a `T`, which is `String` here
+        let value: Local<java::lang::String> = holder.get().assert_not_null().execute_with(jvm)?;
+        let value: String = value.to_rust().execute_with(jvm)?;
+        assert_eq!(value, "hello");
+
+        holder.set("world").execute_with(jvm)?;
+        let value: String = holder.get().assert_not_null().to_rust().execute_with(jvm)?;
+        assert_eq!(value, "world");
+        Ok(())
+    })
+}
+
+#[test]
+fn distinct_instantiations() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list: Local<java::util::ArrayList<java::lang::String>> =
+            java::util::ArrayList::new().execute_with(jvm)?;
+        let holder: Local<generics::Holder<java::util::List<java::lang::String>>> =
+            generics::Holder::new(&list).execute_with(jvm)?;
+        let inner: Local<java::util::List<java::lang::String>> =
+            holder.get().assert_not_null().execute_with(jvm)?;
+        let len = inner.size().execute_with(jvm)?;
+        assert_eq!(len, 0);
+        Ok(())
+    })
+}
+
+#[test]
+fn raw_types_default_to_object() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        // `raw` returns the raw type `Holder`, which is `Holder<Object>` in Rust
+        let holder: Local<generics::Holder<java::lang::Object>> = generics::Holder::raw("raw")
+            .assert_not_null()
+            .execute_with(jvm)?;
+        let value: Local<java::lang::Object> = holder.get().assert_not_null().execute_with(jvm)?;
+        let value: String = value
+            .try_downcast::<java::lang::String>()
+            .execute_with(jvm)?
+            .ok()
+            .unwrap()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(value, "raw");
+        Ok(())
+    })
+}
+
+#[test]
+fn generic_static_methods() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        // `<U> Holder<U> of(U)` and `<U> List<U> twice(U)` take their type argument with a turbofish
+        let holder: Local<generics::Holder<java::lang::String>> =
+            generics::Holder::of::<java::lang::String>("hello")
+                .assert_not_null()
+                .execute_with(jvm)?;
+        let value: String = holder.get().assert_not_null().to_rust().execute_with(jvm)?;
+        assert_eq!(value, "hello");
+
+        let twice: Local<java::util::List<java::lang::CharSequence>> =
+            generics::Holder::twice::<java::lang::CharSequence>("ab")
+                .assert_not_null()
+                .execute_with(jvm)?;
+        assert_eq!(twice.size().execute_with(jvm)?, 2);
+        let first: Local<java::lang::CharSequence> =
+            twice.get(0).assert_not_null().execute_with(jvm)?;
+        assert_eq!(CharSequenceExt::length(&first).execute_with(jvm)?, 2);
+        Ok(())
+    })
+}
@@ -0,0 +1,72 @@
+use duchess::java::lang::StringExt;
+use duchess::java::util::{Collections, ListExt, MapExt, SetExt};
+use duchess::{java, prelude::*, Jvm};
+
+#[test]
+fn type_arguments_from_turbofish() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        // Nothing to infer `T` from, so it is given explicitly
+        let empty: Vec<String> = Collections::empty_list::<java::lang::String>()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert!(empty.is_empty());
+
+        let set = Collections::empty_set::<java::lang::Integer>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        assert_eq!(set.size().execute_with(jvm)?, 0);
+
+        let map = Collections::empty_map::<java::lang::String, java::lang::Integer>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        assert!(map.get("missing").execute_with(jvm)?.is_none());
+        Ok(())
+    })
+}
+
+#[test]
+fn elements_are_typed() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list = Collections::singleton_list::<java::lang::String>("hello")
+            .assert_not_null()
+            .execute_with(jvm)?;
+        // `get` returns a `String`, so its methods can be called directly
+        let first = list.get(0).assert_not_null().execute_with(jvm)?;
+        assert_eq!(first.length().execute_with(jvm)?, 5);
+        let first: String = first.to_rust().execute_with(jvm)?;
+        assert_eq!(first, "hello");
+
+        // Chained without executing in between
+        let second: Vec<String> = list
+            .get(0)
+            .assert_not_null()
+            .split("l")
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(second, ["he", "", "o"]);
+        Ok(())
+    })
+}
+
+#[test]
+fn type_arguments_choose_upcasts() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let answer = java::lang::Integer::value_of(42).execute_with(jvm)?;
+        // Like in Java, the inputs don't determine the type arguments on their own, since they can be upcast
+        let set = Collections::singleton::<java::lang::Number>(&answer)
+            .assert_not_null()
+            .execute_with(jvm)?;
+        assert!(set.contains(&answer).execute_with(jvm)?);
+
+        let map = Collections::singleton_map::<java::lang::String, java::lang::Integer>(
+            "answer", &answer,
+        )
+        .assert_not_null()
+        .execute_with(jvm)?;
+        let value: Option<i32> = map.get("answer").to_rust().execute_with(jvm)?;
+        assert_eq!(value, Some(42));
+        Ok(())
+    })
+}
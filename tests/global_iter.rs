@@ -0,0 +1,73 @@
+use duchess::java::util::ListExt;
+use duchess::{java, prelude::*, Error, GlobalIter, GlobalResult};
+
+duchess::java_package! {
+    package lists;
+
+    class Lists { * }
+}
+
+fn names(names: &[&str]) -> GlobalResult<GlobalIter<String>> {
+    names
+        .iter()
+        .collect_java::<java::util::ArrayList<java::lang::String>>()
+        .to_global_iter()
+        .execute()
+}
+
+#[test]
+fn iterator_outlives_the_jvm_call() -> GlobalResult<()> {
+    let iter = names(&["alpha", "beta", "gamma"])?;
+    let names: Vec<String> = iter.collect::<GlobalResult<_>>()?;
+    assert_eq!(names, ["alpha", "beta", "gamma"]);
+    Ok(())
+}
+
+#[test]
+fn iterate_on_another_thread() -> GlobalResult<()> {
+    let iter = names(&["one", "two"])?;
+    let names = std::thread::spawn(move || iter.collect::<GlobalResult<Vec<String>>>())
+        .join()
+        .unwrap()?;
+    assert_eq!(names, ["one", "two"]);
+    Ok(())
+}
+
+#[test]
+fn null_element_reports_index() -> GlobalResult<()> {
+    let mut iter: GlobalIter<String> = lists::Lists::with_null()
+        .assert_not_null()
+        .to_global_iter()
+        .execute()?;
+    assert_eq!(iter.next().unwrap()?, "alpha");
+    match iter.next() {
+        Some(Err(Error::ElementConversion { index, error })) => {
+            assert_eq!(index, 1);
+            assert!(matches!(*error, Error::NullDeref));
+        }
+        other => panic!(
+            "expected an element conversion error, got {:?}",
+            other.map(|r| r.is_ok())
+        ),
+    }
+    assert_eq!(iter.next().unwrap()?, "gamma");
+    assert!(iter.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn failing_iterator_is_fused() -> GlobalResult<()> {
+    let list = ["one", "two"]
+        .iter()
+        .collect_java::<java::util::ArrayList<java::lang::String>>()
+        .global()
+        .execute()?;
+    let mut iter: GlobalIter<String> = list.to_global_iter().execute()?;
+    assert_eq!(iter.next().unwrap()?, "one");
+
+    // Modifying the list makes its iterator throw a `ConcurrentModificationException`
+    list.add("three").execute()?;
+    assert!(matches!(iter.next(), Some(Err(Error::Thrown(_)))));
+    assert!(iter.next().is_none());
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use duchess::java::lang::ObjectExt;
+use duchess::java::util::ListExt;
+use duchess::{java, prelude::*, Error, Global, Jvm};
+
+#[test]
+fn clone_global_outlives_original() -> duchess::GlobalResult<()> {
+    let original: Global<java::lang::String> = "hello".global().execute()?;
+    let clone = original.clone();
+    drop(original);
+
+    let s: String = (&clone).to_rust().execute()?;
+    assert_eq!(s, "hello");
+    Ok(())
+}
+
+#[test]
+fn clone_global_on_detached_thread() -> duchess::GlobalResult<()> {
+    let original: Global<java::lang::String> = "hello".global().execute()?;
+
+    let clone = std::thread::spawn(move || {
+        let clone = original.clone();
+        drop(original);
+        clone
+    })
+    .join()
+    .unwrap();
+
+    let s: String = (&clone).to_rust().execute()?;
+    assert_eq!(s, "hello");
+    Ok(())
+}
+
+#[test]
+fn upcast_global_keeps_reference() -> duchess::GlobalResult<()> {
+    let string: Global<java::lang::String> = "hello".global().execute()?;
+    let object: Global<java::lang::Object> = string.upcast();
+
+    let s: String = object.to_string().assert_not_null().to_rust().execute()?;
+    assert_eq!(s, "hello");
+    Ok(())
+}
+
+#[test]
+fn globalize_result() -> duchess::GlobalResult<()> {
+    let string: duchess::GlobalResult<Global<java::lang::String>> =
+        Jvm::with(|jvm| Ok("hello".execute_with(jvm).globalize(jvm)))?;
+    let s: String = (&string?).to_rust().execute()?;
+    assert_eq!(s, "hello");
+
+    let thrown: duchess::GlobalResult<Option<Global<java::lang::String>>> = Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<java::lang::String>::new().execute_with(jvm)?;
+        // throws an `IndexOutOfBoundsException`
+        let result = list.get(0).execute_with(jvm);
+        Ok(result.globalize(jvm))
+    })?;
+    assert!(matches!(thrown, Err(Error::Thrown(_))));
+    Ok(())
+}
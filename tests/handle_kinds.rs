@@ -0,0 +1,114 @@
+//! Every kind of handle to a Java object can be used as a receiver or an argument.
+
+use duchess::java::util::ListExt;
+use std::borrow::Borrow;
+
+use duchess::java::lang::ObjectExt;
+use duchess::{java, prelude::*, AsJRef, Global, Jvm, Local};
+
+type JString = java::lang::String;
+
+#[test]
+fn handles_as_arguments() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<JString>::new().execute_with(jvm)?;
+        let local: Local<'_, JString> = "local".to_java().assert_not_null().execute_with(jvm)?;
+        let global: Global<JString> = "global"
+            .to_java()
+            .assert_not_null()
+            .global()
+            .execute_with(jvm)?;
+        let some_local: Option<Local<'_, JString>> = Some(jvm.local(&*local));
+        let some_global: Option<Global<JString>> = Some(global.clone());
+
+        list.add(&local).execute_with(jvm)?;
+        list.add(&global).execute_with(jvm)?;
+        list.add(&*local).execute_with(jvm)?;
+        list.add(&*global).execute_with(jvm)?;
+        list.add(&some_local).execute_with(jvm)?;
+        list.add(&some_global).execute_with(jvm)?;
+
+        let elements: Vec<String> = list.to_rust().execute_with(jvm)?;
+        assert_eq!(
+            elements,
+            ["local", "global", "local", "global", "local", "global"]
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn handles_as_receivers() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let local = java::util::ArrayList::<JString>::new().execute_with(jvm)?;
+        local.add("element").execute_with(jvm)?;
+        let global: Global<java::util::ArrayList<JString>> = jvm.global(&local);
+        let some_global = Some(global.clone());
+
+        assert_eq!(local.size().execute_with(jvm)?, 1);
+        assert_eq!((&local).size().execute_with(jvm)?, 1);
+        assert_eq!(global.size().execute_with(jvm)?, 1);
+        assert_eq!((*global).size().execute_with(jvm)?, 1);
+        assert_eq!(some_global.size().execute_with(jvm)?, 1);
+
+        let none: Option<Global<java::util::ArrayList<JString>>> = None;
+        assert!(matches!(
+            none.size().execute_with(jvm),
+            Err(duchess::Error::NullDeref)
+        ));
+        Ok(())
+    })
+}
+
+#[test]
+fn handles_upcast_as_arguments() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<java::lang::Object>::new().execute_with(jvm)?;
+        let local: Local<'_, JString> = "local".to_java().assert_not_null().execute_with(jvm)?;
+        let global: Global<JString> = "global"
+            .to_java()
+            .assert_not_null()
+            .global()
+            .execute_with(jvm)?;
+
+        list.add(&local).execute_with(jvm)?;
+        list.add(&global).execute_with(jvm)?;
+        assert!(list.contains(&global).execute_with(jvm)?);
+        assert_eq!(list.size().execute_with(jvm)?, 2);
+        Ok(())
+    })
+}
+
+/// A Rust function generic over the handle kind, like a generated method argument.
+fn hash_code<'jvm>(
+    object: &impl AsJRef<java::lang::Object>,
+    jvm: &mut Jvm<'jvm>,
+) -> duchess::Result<'jvm, i32> {
+    let object: &java::lang::Object = object.as_jref()?;
+    object.hash_code().execute_with(jvm)
+}
+
+#[test]
+fn handles_as_generic_references() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let global: Global<JString> = "hello"
+            .to_java()
+            .assert_not_null()
+            .global()
+            .execute_with(jvm)?;
+        let local = jvm.local(&*global);
+        let expected = hash_code(&global, jvm)?;
+        assert_eq!(hash_code(&local, jvm)?, expected);
+        assert_eq!(hash_code(&&*global, jvm)?, expected);
+        assert_eq!(hash_code(&Some(global.clone()), jvm)?, expected);
+        assert!(matches!(
+            hash_code(&None::<Global<JString>>, jvm),
+            Err(duchess::Error::NullDeref)
+        ));
+
+        // `Borrow` gives the plain Java reference behind a handle.
+        let object: &JString = global.borrow();
+        assert_eq!(hash_code(&object, jvm)?, expected);
+        Ok(())
+    })
+}
@@ -0,0 +1,40 @@
+//! Classes whose members are specified by hand can list indirect supertypes in `extends` and `implements`.
+
+use duchess::java::lang::ThrowableExt;
+use duchess::{java, prelude::*, Jvm, Local};
+
+duchess::java_package! {
+    package exceptions;
+
+    class Thrower { * }
+
+    public class exceptions.CheckedFailure extends java.lang.Exception, java.lang.Throwable {
+        public exceptions.CheckedFailure(java.lang.String);
+    }
+
+    public class exceptions.UncheckedFailure
+        extends java.lang.RuntimeException, java.lang.Exception, java.lang.Throwable
+    {
+        public exceptions.UncheckedFailure(java.lang.String);
+    }
+}
+
+#[test]
+fn indirect_supertypes_are_upcasts() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let failure = exceptions::UncheckedFailure::new("unchecked").execute_with(jvm)?;
+        let exception: Local<java::lang::Exception> = failure.upcast();
+        let message: String = exception
+            .get_message()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(message, "unchecked");
+
+        let caught = exceptions::Thrower::throw_checked()
+            .catch::<exceptions::CheckedFailure>()
+            .execute_with(jvm)?;
+        assert!(caught.is_err());
+        Ok(())
+    })
+}
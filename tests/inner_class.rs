@@ -0,0 +1,58 @@
+use duchess::{java, prelude::*, Jvm};
+
+duchess::java_package! {
+    package inner;
+
+    class Outer { * }
+    class Outer$Counter { * }
+    class Outer$Tally { * }
+    class Outer$Nested { * }
+}
+
+use inner::{Outer__CounterExt, Outer__NestedExt, Outer__TallyExt};
+
+#[test]
+fn inner_class_constructor_takes_enclosing_instance() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let outer = inner::Outer::new("outer").execute_with(jvm)?;
+        let counter = inner::Outer__Counter::new(&outer, 3).execute_with(jvm)?;
+        let description: String = counter
+            .describe()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(description, "outer:3");
+        Ok(())
+    })
+}
+
+#[test]
+fn generic_inner_class_constructor_takes_enclosing_instance() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let outer = inner::Outer::new("outer").execute_with(jvm)?;
+        let items = vec!["a".to_string(), "b".to_string()];
+        let items = items
+            .to_java::<java::util::List<java::lang::String>>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        let tally = inner::Outer__Tally::new(&outer, &items).execute_with(jvm)?;
+        let description: String = tally
+            .describe()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(description, "outer:2");
+        Ok(())
+    })
+}
+
+#[test]
+fn static_nested_class_constructor() -> duchess::GlobalResult<()> {
+    let description: String = inner::Outer__Nested::new()
+        .describe()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(description, "nested");
+    Ok(())
+}
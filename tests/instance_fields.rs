@@ -0,0 +1,54 @@
+use duchess::{java, prelude::*, Jvm};
+
+use fields::{HolderExt, SampleExt};
+
+duchess::java_package! {
+    package fields;
+
+    class Sample { * }
+    class Holder { * }
+}
+
+#[test]
+fn read_instance_fields() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let sample = fields::Sample::new(3, "three").execute_with(jvm)?;
+        assert_eq!(sample.get_count_2().execute_with(jvm)?, 3);
+        assert_eq!(sample.get_ratio().execute_with(jvm)?, 0.5);
+        let label: String = sample
+            .get_label()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(label, "three");
+        assert_eq!(fields::Sample::get_limit().execute_with(jvm)?, 10);
+        Ok(())
+    })
+}
+
+#[test]
+fn field_reads_see_updates() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let sample = fields::Sample::new(0, "counter").execute_with(jvm)?;
+        for expected in 1..=100 {
+            sample.increment().execute_with(jvm)?;
+            assert_eq!(sample.get_count_2().execute_with(jvm)?, expected);
+        }
+        Ok(())
+    })
+}
+
+#[test]
+fn generic_field() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let holder: duchess::Local<fields::Holder<java::lang::String>> =
+            fields::Holder::new("held").execute_with(jvm)?;
+        let value: String = holder
+            .get_value()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(value, "held");
+        Ok(())
+    })
+}
@@ -0,0 +1,78 @@
+use std::io::{BufRead, BufReader, Read, Write};
+
+use duchess::java::io::ByteArrayOutputStreamExt;
+use duchess::{java, prelude::*, JavaReader, JavaWriter, Jvm};
+
+duchess::java_package! {
+    package streams;
+
+    class FailingStream { * }
+}
+
+#[test]
+fn read_input_stream_to_end() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        // Larger than the internal buffer, so it takes several reads
+        let data: Vec<u8> = (0..20_000).map(|i| (i % 251) as u8).collect();
+        let java_data: Vec<i8> = data.iter().map(|&b| b as i8).collect();
+        let stream = java::io::ByteArrayInputStream::new(&java_data[..]).execute_with(jvm)?;
+
+        let mut reader = JavaReader::new(jvm, &stream)?;
+        let mut read = Vec::new();
+        reader.read_to_end(&mut read).unwrap();
+        assert_eq!(read, data);
+
+        // Reads after EOF keep returning 0
+        assert_eq!(reader.read(&mut [0; 16]).unwrap(), 0);
+        Ok(())
+    })
+}
+
+#[test]
+fn buffered_lines() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let java_data: Vec<i8> = b"first\nsecond\n".iter().map(|&b| b as i8).collect();
+        let stream = java::io::ByteArrayInputStream::new(&java_data[..]).execute_with(jvm)?;
+
+        let reader = BufReader::new(JavaReader::new(jvm, &stream)?);
+        let lines: Vec<String> = reader.lines().map(|l| l.unwrap()).collect();
+        assert_eq!(lines, ["first", "second"]);
+        Ok(())
+    })
+}
+
+#[test]
+fn write_output_stream() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let stream = java::io::ByteArrayOutputStream::new().execute_with(jvm)?;
+
+        let data: Vec<u8> = (0..20_000).map(|i| (i % 251) as u8).collect();
+        let mut writer = JavaWriter::new(jvm, &stream)?;
+        writer.write_all(&data).unwrap();
+        writer.flush().unwrap();
+
+        let mut written: Vec<u8> = vec![];
+        stream
+            .to_byte_array()
+            .assert_not_null()
+            .to_rust_into(&mut written)
+            .execute_with(jvm)?;
+        assert_eq!(written, data);
+        Ok(())
+    })
+}
+
+#[test]
+fn io_exception_becomes_io_error() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let stream = streams::FailingStream::new().execute_with(jvm)?;
+
+        let mut reader = JavaReader::new(jvm, &stream)?;
+        let error = reader.read(&mut [0; 16]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Java invocation threw: java.io.IOException: disk on fire"
+        );
+        Ok(())
+    })
+}
@@ -0,0 +1,45 @@
+use duchess::java::util::{ListExt, MapExt};
+use duchess::{java, prelude::*, Global, JavaDefault, Jvm};
+
+use plugins::PluginExt;
+
+duchess::java_package! {
+    package plugins;
+
+    interface Plugin { * }
+    class Greeter { * }
+}
+
+/// Creates an instance of any class with a no-argument constructor.
+fn fresh<T: JavaDefault>() -> duchess::GlobalResult<Global<T>> {
+    T::java_default().global().execute()
+}
+
+#[test]
+fn default_collections() -> duchess::GlobalResult<()> {
+    let list: Global<java::util::ArrayList<java::lang::String>> = fresh()?;
+    let map: Global<java::util::HashMap<java::lang::String, java::lang::Integer>> = fresh()?;
+    Jvm::with(|jvm| {
+        assert_eq!(list.size().execute_with(jvm)?, 0);
+        assert_eq!(map.size().execute_with(jvm)?, 0);
+        Ok(())
+    })
+}
+
+#[test]
+fn default_reflected_class() -> duchess::GlobalResult<()> {
+    let greeter: Global<plugins::Greeter> = fresh()?;
+    let name: String = greeter.name().assert_not_null().to_rust().execute()?;
+    assert_eq!(name, "greeter");
+    Ok(())
+}
+
+#[test]
+fn default_is_new() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let greeter = plugins::Greeter::java_default().execute_with(jvm)?;
+        let other = plugins::Greeter::new().execute_with(jvm)?;
+        assert!(!greeter.ref_eq(&other).execute_with(jvm)?);
+        Ok(())
+    })
+}
@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use duchess::java::util::logging::{LogManager, LogManagerExt, LoggerExt};
+use duchess::{prelude::*, Jvm, LenExt};
+use tracing::field::{Field, Visit};
+use tracing::{span, Event, Level, Metadata, Subscriber};
+
+duchess::java_package! {
+    package logging;
+
+    class Chatty { * }
+}
+
+/// A `tracing` event, with its fields formatted.
+#[derive(Debug)]
+struct Record {
+    level: Level,
+    target: String,
+    fields: BTreeMap<String, String>,
+}
+
+impl Record {
+    fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+}
+
+/// Records the events emitted while it is the default subscriber.
+#[derive(Clone, Default)]
+struct Recorder {
+    records: Arc<Mutex<Vec<Record>>>,
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        struct Fields<'a>(&'a mut BTreeMap<String, String>);
+
+        impl Visit for Fields<'_> {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.insert(field.name().to_string(), value.to_string());
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0
+                    .insert(field.name().to_string(), format!("{value:?}"));
+            }
+        }
+
+        let mut fields = BTreeMap::new();
+        event.record(&mut Fields(&mut fields));
+        self.records.lock().unwrap().push(Record {
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            fields,
+        });
+    }
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+/// Runs `op` with a fresh [`Recorder`] as the default subscriber, and returns the events emitted from the `logger`.
+/// Java calls the handler on the thread that logs, so the thread's subscriber receives them.
+fn record(
+    logger: &str,
+    op: impl FnOnce() -> duchess::GlobalResult<()>,
+) -> duchess::GlobalResult<Vec<Record>> {
+    Jvm::builder()
+        .route_java_logging()
+        .launch_or_use_existing()?;
+
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), op)?;
+    let records = std::mem::take(&mut *recorder.records.lock().unwrap());
+    Ok(records
+        .into_iter()
+        .filter(|record| record.field("logger") == Some(logger))
+        .collect())
+}
+
+#[test]
+fn levels_are_mapped() -> duchess::GlobalResult<()> {
+    let records = record("logging.Chatty", || {
+        logging::Chatty::every_level().execute()
+    })?;
+    let levels: Vec<(Level, &str)> = records
+        .iter()
+        .map(|record| (record.level, record.field("message").unwrap()))
+        .collect();
+    assert_eq!(
+        levels,
+        [
+            (Level::ERROR, "severe"),
+            (Level::WARN, "warning"),
+            (Level::INFO, "info"),
+            (Level::INFO, "config"),
+            (Level::DEBUG, "fine"),
+            (Level::DEBUG, "finer"),
+            (Level::TRACE, "finest"),
+        ]
+    );
+    assert!(records.iter().all(|record| record.target == "java"));
+    Ok(())
+}
+
+#[test]
+fn records_have_structured_fields() -> duchess::GlobalResult<()> {
+    let records = record("logging.Chatty", || {
+        logging::Chatty::greet("ferris").execute()
+    })?;
+    let [record] = &records[..] else {
+        panic!("expected one record: {records:?}");
+    };
+    // Parameters are formatted into the message
+    assert_eq!(record.field("message"), Some("hello ferris"));
+    assert!(record.field("thread").is_some(), "{record:?}");
+    assert_eq!(record.field("exception"), None);
+    Ok(())
+}
+
+#[test]
+fn exceptions_are_included() -> duchess::GlobalResult<()> {
+    let records = record("logging.Chatty", || logging::Chatty::fail().execute())?;
+    let [record] = &records[..] else {
+        panic!("expected one record: {records:?}");
+    };
+    assert_eq!(record.level, Level::WARN);
+    assert_eq!(record.field("message"), Some("failed"));
+    let exception = record.field("exception").unwrap();
+    assert!(
+        exception.starts_with("java.lang.IllegalStateException: boom"),
+        "{exception}"
+    );
+    assert!(exception.contains("at logging.Chatty.fail"), "{exception}");
+    Ok(())
+}
+
+#[test]
+fn handler_is_installed_once() -> duchess::GlobalResult<()> {
+    Jvm::builder()
+        .route_java_logging()
+        .launch_or_use_existing()?;
+    Jvm::builder()
+        .route_java_logging()
+        .launch_or_use_existing()?;
+
+    // The default console handler was replaced
+    let root = LogManager::get_log_manager()
+        .assert_not_null()
+        .get_logger("")
+        .assert_not_null();
+    assert_eq!(root.get_handlers().assert_not_null().len().execute()?, 1);
+    Ok(())
+}
+
+#[test]
+fn logging_from_rust() -> duchess::GlobalResult<()> {
+    // The root logger, which is named ""
+    let records = record("", || {
+        LogManager::get_log_manager()
+            .assert_not_null()
+            .get_logger("")
+            .assert_not_null()
+            .warning("from rust")
+            .execute()
+    })?;
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].level, Level::WARN);
+    assert_eq!(records[0].field("message"), Some("from rust"));
+    Ok(())
+}
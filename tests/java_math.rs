@@ -0,0 +1,83 @@
+use duchess::{java, java::lang::ObjectExt, prelude::*, BigDecimalParts, BigIntegerBytes, Jvm};
+
+fn round_trip_integer(value: i128) -> duchess::GlobalResult<i128> {
+    value
+        .to_java::<java::math::BigInteger>()
+        .assert_not_null()
+        .to_rust()
+        .execute()
+}
+
+#[test]
+fn big_integer_i128_round_trip() -> duchess::GlobalResult<()> {
+    for value in [
+        0,
+        1,
+        -1,
+        255,
+        -256,
+        i64::MAX as i128 + 1,
+        i128::MAX,
+        i128::MIN,
+    ] {
+        assert_eq!(round_trip_integer(value)?, value);
+    }
+    Ok(())
+}
+
+#[test]
+fn big_integer_bytes_are_minimal() -> duchess::GlobalResult<()> {
+    let bytes: BigIntegerBytes = 255_i128
+        .to_java::<java::math::BigInteger>()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(bytes, BigIntegerBytes(vec![0x00, 0xff]));
+
+    let zero: BigIntegerBytes = BigIntegerBytes(vec![])
+        .to_java::<java::math::BigInteger>()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(zero, BigIntegerBytes(vec![0]));
+    Ok(())
+}
+
+#[test]
+fn big_integer_out_of_range_for_i128() -> duchess::GlobalResult<()> {
+    let mut bytes = vec![0x01];
+    bytes.extend([0; 16]);
+    let result: duchess::GlobalResult<i128> = BigIntegerBytes(bytes)
+        .to_java::<java::math::BigInteger>()
+        .assert_not_null()
+        .to_rust()
+        .execute();
+    assert!(matches!(result, Err(duchess::Error::Conversion(_))));
+    Ok(())
+}
+
+#[test]
+fn big_decimal_round_trip() -> duchess::GlobalResult<()> {
+    // -123.45
+    let parts = BigDecimalParts {
+        unscaled: BigIntegerBytes((-12345_i16).to_be_bytes().to_vec()),
+        scale: 2,
+    };
+    Jvm::with(|jvm| {
+        let decimal = parts
+            .to_java::<java::math::BigDecimal>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+
+        let description: String = decimal
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(description, "-123.45");
+
+        let back: BigDecimalParts = decimal.to_rust().execute_with(jvm)?;
+        assert_eq!(back, parts);
+        Ok(())
+    })
+}
@@ -0,0 +1,72 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use duchess::{java, java::lang::ObjectExt, prelude::*};
+
+fn round_trip_instant(time: SystemTime) -> duchess::GlobalResult<SystemTime> {
+    time.to_java::<java::time::Instant>()
+        .assert_not_null()
+        .to_rust()
+        .execute()
+}
+
+#[test]
+fn instant_round_trip() -> duchess::GlobalResult<()> {
+    let now = SystemTime::now();
+    assert_eq!(round_trip_instant(now)?, now);
+
+    let precise = UNIX_EPOCH + Duration::new(1_686_000_000, 123_456_789);
+    assert_eq!(round_trip_instant(precise)?, precise);
+    Ok(())
+}
+
+#[test]
+fn instant_epoch_boundary() -> duchess::GlobalResult<()> {
+    for time in [
+        UNIX_EPOCH,
+        UNIX_EPOCH + Duration::from_nanos(1),
+        UNIX_EPOCH - Duration::from_nanos(1),
+        UNIX_EPOCH - Duration::new(1, 500_000_000),
+    ] {
+        assert_eq!(round_trip_instant(time)?, time);
+    }
+    Ok(())
+}
+
+#[test]
+fn instant_before_epoch_is_normalized() -> duchess::GlobalResult<()> {
+    let java_string: String = (UNIX_EPOCH - Duration::from_nanos(1))
+        .to_java::<java::time::Instant>()
+        .assert_not_null()
+        .to_string()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(java_string, "1969-12-31T23:59:59.999999999Z");
+    Ok(())
+}
+
+#[test]
+fn duration_round_trip() -> duchess::GlobalResult<()> {
+    for duration in [
+        Duration::ZERO,
+        Duration::from_nanos(1),
+        Duration::new(86_400, 999_999_999),
+    ] {
+        let converted: Duration = duration
+            .to_java::<java::time::Duration>()
+            .assert_not_null()
+            .to_rust()
+            .execute()?;
+        assert_eq!(converted, duration);
+    }
+    Ok(())
+}
+
+#[test]
+fn negative_duration_fails() {
+    let result: duchess::GlobalResult<Duration> = java::time::Duration::of_seconds(-1_i64, 0_i64)
+        .assert_not_null()
+        .to_rust()
+        .execute();
+    assert!(matches!(result, Err(duchess::Error::Conversion(_))));
+}
@@ -0,0 +1,45 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package versions;
+
+    public class versions.Release {
+        // Members of newer JDKs than the one building the crate are left out (with a warning) rather than reported
+        // as missing.
+        #[since(999)]
+        public static final int LATEST;
+
+        public versions.Release();
+
+        #[since(999)]
+        public versions.Release(java.lang.String);
+
+        public java.lang.String name();
+
+        #[since(999)]
+        public java.lang.String codename();
+
+        #[since(8)]
+        public static int count();
+    }
+}
+
+use versions::ReleaseExt;
+
+#[test]
+fn members_of_newer_jdks_are_left_out() -> duchess::GlobalResult<()> {
+    let names: Vec<&str> = versions::Release::METHODS.iter().map(|m| m.name).collect();
+    assert_eq!(names, ["name", "count"]);
+
+    Jvm::with(|jvm| {
+        let release = versions::Release::new().execute_with(jvm)?;
+        let name: String = release
+            .name()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(name, "stable");
+        assert_eq!(versions::Release::count().execute_with(jvm)?, 1);
+        Ok(())
+    })
+}
@@ -0,0 +1,29 @@
+use duchess::{java, jni_sys, prelude::*, Error, Jvm};
+
+#[test]
+fn jni_version() -> duchess::GlobalResult<()> {
+    // Launching fails without creating the JVM, so it can be launched again afterwards
+    let result = Jvm::builder().jni_version(0x00630000).try_launch();
+    let Err(error @ Error::UnsupportedJniVersion(_)) = result else {
+        panic!("expected an unsupported JNI version to be rejected");
+    };
+    assert_eq!(error.to_string(), "the JVM doesn't support JNI version 99");
+
+    Jvm::builder()
+        .jni_version(jni_sys::JNI_VERSION_10)
+        .try_launch()?;
+    let version: Option<String> = java::lang::System::get_property("java.version")
+        .to_rust()
+        .execute()?;
+    assert!(version.is_some());
+
+    // Threads are attached with the same version
+    std::thread::spawn(|| {
+        java::lang::System::get_property("java.version")
+            .to_rust::<Option<String>>()
+            .execute()
+    })
+    .join()
+    .unwrap()?;
+    Ok(())
+}
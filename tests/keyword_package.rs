@@ -0,0 +1,40 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package keywords.move;
+
+    class Dispatcher { * }
+
+    // `mod` and `except` are only keywords at the start of the declaration and after a wildcard package
+    package keywords.mod.except;
+
+    class Filter { * }
+}
+
+use keywords::r#move::{Dispatcher, DispatcherExt};
+
+#[test]
+fn keyword_segments_are_escaped() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let dispatcher = Dispatcher::new().execute_with(jvm)?;
+        let matched: String = dispatcher
+            .r#match("input")
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(matched, "matched input");
+
+        assert_eq!(Dispatcher::r#type().execute_with(jvm)?, 42);
+        Ok(())
+    })
+}
+
+#[test]
+fn contextual_keyword_segments() -> duchess::GlobalResult<()> {
+    let described: String = keywords::r#mod::except::Filter::describe()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(described, "filter");
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use duchess::java::lang::StringExt;
+use duchess::java::util::{ListExt, MapExt};
+use duchess::java::ArrayExt;
+use duchess::{java, prelude::*, Jvm, LenExt};
+
+#[test]
+fn len_of_collections() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list = [1, 2, 3]
+            .into_iter()
+            .collect_java::<java::util::ArrayList<java::lang::Integer>>()
+            .execute_with(jvm)?;
+        assert_eq!(list.len().execute_with(jvm)?, 3);
+        assert!(!list.is_empty().execute_with(jvm)?);
+
+        let set = [1, 1, 2]
+            .into_iter()
+            .collect_java::<java::util::HashSet<java::lang::Integer>>()
+            .execute_with(jvm)?;
+        assert_eq!(set.len().execute_with(jvm)?, 2);
+
+        let map = java::util::HashMap::<java::lang::String, java::lang::Integer>::new()
+            .execute_with(jvm)?;
+        assert_eq!(map.len().execute_with(jvm)?, 0);
+        assert!(map.is_empty().execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn len_of_strings() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let string = "héllo".execute_with(jvm)?;
+        // `length()` counts UTF-16 code units, not bytes
+        assert_eq!(string.len().execute_with(jvm)?, 5);
+
+        let builder = java::lang::StringBuilder::new().execute_with(jvm)?;
+        assert_eq!(builder.len().execute_with(jvm)?, 0);
+        Ok(())
+    })
+}
+
+#[test]
+fn len_of_arrays() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let parts = "a,b,c"
+            .execute_with(jvm)?
+            .split(",")
+            .assert_not_null()
+            .execute_with(jvm)?;
+        assert_eq!(parts.len().execute_with(jvm)?, 3);
+        assert!(!parts.is_empty().execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn empty_array() -> duchess::GlobalResult<()> {
+    let bytes = "".get_bytes().assert_not_null();
+    assert_eq!(bytes.clone().len().execute()?, 0);
+    assert!(bytes.is_empty().execute()?);
+    Ok(())
+}
+
+#[test]
+fn rust_len_with_prelude() {
+    // `String` is a JvmOp, but without `LenExt` in the prelude, `len()` still resolves to the inherent method
+    mod scope {
+        #[allow(unused_imports)]
+        use duchess::prelude::*;
+
+        pub fn len(string: String) -> usize {
+            string.len()
+        }
+    }
+    assert_eq!(scope::len("abc".to_string()), 3);
+}
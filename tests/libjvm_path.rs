@@ -0,0 +1,16 @@
+#![cfg(feature = "dylibjvm")]
+
+use duchess::{Error, Jvm};
+
+#[test]
+fn missing_libjvm_is_reported() {
+    let path = "/does/not/exist/libjvm.so";
+    match Jvm::builder().load_libjvm_at(path).try_launch() {
+        Err(e @ Error::UnableToLoadLibjvm(_)) => {
+            let message = e.to_string();
+            assert!(message.contains(path), "{message}");
+        }
+        Err(other) => panic!("expected an error loading libjvm, got {other:?}"),
+        Ok(()) => panic!("expected an error loading libjvm"),
+    }
+}
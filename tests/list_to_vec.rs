@@ -0,0 +1,128 @@
+use duchess::java::lang::ThrowableExt;
+use duchess::java::util::ListExt;
+use duchess::{java, prelude::*, Error, Jvm};
+
+duchess::java_package! {
+    package lists;
+
+    class Lists { * }
+}
+
+#[test]
+fn list_to_vec() -> duchess::GlobalResult<()> {
+    let words: Vec<String> = lists::Lists::words()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(words, vec!["alpha", "beta", "gamma"]);
+    Ok(())
+}
+
+#[test]
+fn round_trip_through_array_list() -> duchess::GlobalResult<()> {
+    let rust = vec!["one".to_string(), "two".to_string()];
+
+    let list: Vec<String> = rust
+        .to_java::<java::util::ArrayList<java::lang::String>>()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(list, rust);
+
+    let empty: Vec<String> = Vec::<String>::new()
+        .to_java::<java::util::List<java::lang::String>>()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert!(empty.is_empty());
+    Ok(())
+}
+
+#[test]
+fn nested_lists() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let outer = java::util::ArrayList::<java::util::List<java::lang::String>>::new()
+            .execute_with(jvm)?;
+        for words in [
+            vec!["a".to_string()],
+            vec![],
+            vec!["b".to_string(), "c".to_string()],
+        ] {
+            outer
+                .add(
+                    words
+                        .to_java::<java::util::List<java::lang::String>>()
+                        .assert_not_null(),
+                )
+                .execute_with(jvm)?;
+        }
+
+        let nested: Vec<Vec<String>> = outer.to_rust().execute_with(jvm)?;
+        assert_eq!(nested, vec![vec!["a"], vec![], vec!["b", "c"]]);
+        Ok(())
+    })
+}
+
+#[test]
+fn null_element_reports_index() {
+    let result: duchess::GlobalResult<Vec<String>> = lists::Lists::with_null()
+        .assert_not_null()
+        .to_rust()
+        .execute();
+    match result {
+        Err(Error::ElementConversion { index, error }) => {
+            assert_eq!(index, 1);
+            assert!(matches!(*error, Error::NullDeref));
+        }
+        other => panic!("expected an element conversion error, got {other:?}"),
+    }
+}
+
+#[test]
+fn unconvertible_element_reports_index() -> duchess::GlobalResult<()> {
+    use duchess::java::lang::{CharSequenceExt, ObjectExt};
+
+    Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<java::lang::String>::new().execute_with(jvm)?;
+        list.add("whole").execute_with(jvm)?;
+        // Half of a surrogate pair is a valid Java string but not a valid Rust string
+        let half = "\u{1F980}"
+            .sub_sequence(0, 1)
+            .assert_not_null()
+            .to_string()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        list.add(&half).execute_with(jvm)?;
+
+        let result: Result<Vec<String>, _> = list.to_rust().execute_with(jvm);
+        match result {
+            Err(Error::ElementConversion { index, error }) => {
+                assert_eq!(index, 1);
+                assert!(matches!(*error, Error::Conversion(_)));
+            }
+            other => panic!("expected an element conversion error, got {other:?}"),
+        }
+        Ok(())
+    })
+}
+
+#[test]
+fn exception_from_element_can_be_caught() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let result = lists::Lists::failing()
+            .assert_not_null()
+            .to_rust::<Vec<String>>()
+            .catch::<java::lang::RuntimeException>()
+            .execute_with(jvm)?;
+        let Err(exception) = result else {
+            panic!("expected the exception thrown by `get` to be caught");
+        };
+        let message: String = exception
+            .get_message()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(message, "element 1 is unavailable");
+        Ok(())
+    })
+}
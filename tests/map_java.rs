@@ -0,0 +1,47 @@
+use duchess::java::lang::StringExt;
+use duchess::java::util::ListExt;
+use duchess::{java, prelude::*, Error};
+
+fn words(
+) -> impl for<'jvm> JvmOp<Output<'jvm> = duchess::Local<'jvm, java::util::ArrayList<java::lang::String>>>
+{
+    java::util::ArrayList::<java::lang::String>::new().and_then_java(|list, jvm| {
+        list.add("hello").execute_with(jvm)?;
+        list.add("world!").execute_with(jvm)?;
+        Ok(list)
+    })
+}
+
+#[test]
+fn and_then_java_chains_further_operations() -> duchess::GlobalResult<()> {
+    let list: Vec<String> = words().to_rust().execute()?;
+    assert_eq!(list, ["hello", "world!"]);
+
+    // Methods can be called on the result directly
+    assert_eq!(words().size().execute()?, 2);
+    Ok(())
+}
+
+#[test]
+fn map_java_receives_intermediate_value() -> duchess::GlobalResult<()> {
+    let lengths = words()
+        .map_java(|list, jvm| {
+            let mut lengths = vec![];
+            for i in 0..list.size().execute_with(jvm)? {
+                let word = list.get(i).assert_not_null().execute_with(jvm)?;
+                lengths.push(StringExt::length(&word).execute_with(jvm)?);
+            }
+            Ok(lengths)
+        })
+        .execute()?;
+    assert_eq!(lengths, [5, 6]);
+    Ok(())
+}
+
+#[test]
+fn exceptions_in_closures_are_returned() {
+    let result = words()
+        .map_java(|list, jvm| list.get(5).execute_with(jvm).map(|_| ()))
+        .execute();
+    assert!(matches!(result, Err(Error::Thrown(_))));
+}
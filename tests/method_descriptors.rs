@@ -0,0 +1,84 @@
+use duchess::MethodDescriptor;
+
+duchess::java_package! {
+    package shapes;
+
+    class Shape { * }
+    class Square { * }
+}
+
+#[test]
+fn methods_lists_bound_methods() {
+    assert_eq!(
+        shapes::Shape::METHODS,
+        &[
+            MethodDescriptor {
+                name: "area",
+                rust_name: "area",
+                descriptor: "()D",
+                is_static: false,
+            },
+            MethodDescriptor {
+                name: "describe",
+                rust_name: "describe",
+                descriptor: "()Ljava/lang/String;",
+                is_static: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn methods_includes_static_methods() {
+    let current_thread = duchess::java::lang::Thread::METHODS
+        .iter()
+        .find(|m| m.name == "currentThread")
+        .expect("Thread binds currentThread");
+    assert_eq!(current_thread.rust_name, "current_thread");
+    assert!(current_thread.is_static);
+
+    let get_bytes = duchess::java::lang::String::METHODS
+        .iter()
+        .find(|m| m.name == "getBytes")
+        .expect("String binds getBytes");
+    assert_eq!(get_bytes.rust_name, "get_bytes");
+    assert_eq!(get_bytes.descriptor, "()[B");
+    assert!(!get_bytes.is_static);
+}
+
+duchess::java_package! {
+    package functional;
+
+    class Transformer { * }
+    class Listener { * }
+    class Shouter { * }
+    class Echo { * }
+}
+
+#[test]
+fn functional_method_of_sam_interfaces() {
+    assert_eq!(
+        functional::Transformer::FUNCTIONAL_METHOD,
+        Some(MethodDescriptor {
+            name: "apply",
+            rust_name: "apply",
+            descriptor: "(Ljava/lang/String;)Ljava/lang/String;",
+            is_static: false,
+        })
+    );
+}
+
+#[test]
+fn functional_method_inherited_from_superinterface() {
+    assert_eq!(
+        functional::Shouter::FUNCTIONAL_METHOD,
+        functional::Transformer::FUNCTIONAL_METHOD
+    );
+}
+
+#[test]
+fn functional_method_of_other_types_is_none() {
+    assert_eq!(functional::Listener::FUNCTIONAL_METHOD, None);
+    assert_eq!(functional::Echo::FUNCTIONAL_METHOD, None);
+    assert_eq!(shapes::Shape::FUNCTIONAL_METHOD, None);
+}
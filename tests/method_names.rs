@@ -0,0 +1,44 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package names;
+
+    class Links { * }
+}
+
+use names::LinksExt;
+
+#[test]
+fn acronyms_and_collisions() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let links = names::Links::new().execute_with(jvm)?;
+
+        let http: String = links
+            .get_http_url()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(http, "http");
+
+        // `getURL` is declared first, so it keeps `get_url`
+        let upper: String = links
+            .get_url()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(upper, "upper");
+        let camel: String = links
+            .get_url_2()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(camel, "camel");
+
+        let field: String = names::Links::get_url_3()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(field, "static");
+        Ok(())
+    })
+}
@@ -0,0 +1,116 @@
+use duchess::jni_sys::{jclass, jint, jobject, JNIEnv};
+use duchess::{prelude::*, Jvm, NativeMethod};
+
+use modifiers::CounterExt;
+
+duchess::java_package! {
+    package modifiers;
+
+    class Counter { * }
+}
+
+// The same methods, specified with their modifiers in another order than `javap` prints them
+mod specified {
+    duchess::java_package! {
+        package modifiers;
+
+        public class Counter {
+            public modifiers.Counter();
+            synchronized public int increment();
+            synchronized final public int get();
+            synchronized static public modifiers.Counter create();
+            final synchronized static public int limit();
+            strictfp public double half();
+            static strictfp public double scale(double);
+            strictfp synchronized final public double average(int);
+            native public int doubled();
+            native static public int tripled(int);
+            native synchronized final public int negated();
+        }
+    }
+}
+
+extern "system" fn doubled(_env: *mut JNIEnv, _this: jobject) -> jint {
+    42
+}
+
+extern "system" fn tripled(_env: *mut JNIEnv, _class: jclass, value: jint) -> jint {
+    value * 3
+}
+
+extern "system" fn negated(_env: *mut JNIEnv, _this: jobject) -> jint {
+    -1
+}
+
+fn register<'jvm>(jvm: &mut Jvm<'jvm>) -> duchess::Result<'jvm, ()> {
+    let methods = unsafe {
+        [
+            NativeMethod::new(c"doubled", c"()I", doubled as *const _),
+            NativeMethod::new(c"tripled", c"(I)I", tripled as *const _),
+            NativeMethod::new(c"negated", c"()I", negated as *const _),
+        ]
+    };
+    jvm.register_natives::<modifiers::Counter>(&methods)
+}
+
+#[test]
+fn synchronized_methods() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let counter = modifiers::Counter::create()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        assert_eq!(counter.increment().execute_with(jvm)?, 1);
+        assert_eq!(counter.increment().execute_with(jvm)?, 2);
+        assert_eq!(counter.get().execute_with(jvm)?, 2);
+        assert_eq!(modifiers::Counter::limit().execute_with(jvm)?, 10);
+        Ok(())
+    })
+}
+
+#[test]
+fn strictfp_methods() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let counter = modifiers::Counter::new().execute_with(jvm)?;
+        counter.increment().execute_with(jvm)?;
+        assert_eq!(counter.half().execute_with(jvm)?, 0.5);
+        assert_eq!(counter.average(3).execute_with(jvm)?, 2.0);
+        assert_eq!(modifiers::Counter::scale(2.0).execute_with(jvm)?, 3.0);
+        Ok(())
+    })
+}
+
+#[test]
+fn native_methods() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        register(jvm)?;
+        let counter = modifiers::Counter::new().execute_with(jvm)?;
+        assert_eq!(counter.doubled().execute_with(jvm)?, 42);
+        assert_eq!(counter.negated().execute_with(jvm)?, -1);
+        assert_eq!(modifiers::Counter::tripled(5).execute_with(jvm)?, 15);
+        Ok(())
+    })
+}
+
+#[test]
+fn reordered_modifiers() -> duchess::GlobalResult<()> {
+    use specified::modifiers::{Counter, CounterExt};
+
+    // Only the modifiers that affect the bindings are kept
+    assert_eq!(Counter::METHODS, modifiers::Counter::METHODS);
+    assert_eq!(Counter::FUNCTIONAL_METHOD, None);
+
+    Jvm::with(|jvm| {
+        register(jvm)?;
+        let counter = Counter::create().assert_not_null().execute_with(jvm)?;
+        assert_eq!(counter.increment().execute_with(jvm)?, 1);
+        assert_eq!(counter.get().execute_with(jvm)?, 1);
+        assert_eq!(counter.average(1).execute_with(jvm)?, 1.0);
+        assert_eq!(Counter::limit().execute_with(jvm)?, 10);
+        assert_eq!(Counter::scale(4.0).execute_with(jvm)?, 6.0);
+        assert_eq!(counter.half().execute_with(jvm)?, 0.5);
+        assert_eq!(counter.doubled().execute_with(jvm)?, 42);
+        assert_eq!(counter.negated().execute_with(jvm)?, -1);
+        assert_eq!(Counter::tripled(2).execute_with(jvm)?, 6);
+        Ok(())
+    })
+}
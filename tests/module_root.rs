@@ -0,0 +1,39 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    mod bindings;
+
+    package shapes;
+
+    class Shape { * }
+    class Square { * }
+
+    package keywords.move;
+
+    class Dispatcher { * }
+}
+
+pub use bindings::shapes::{ShapeExt, Square};
+
+#[test]
+fn classes_are_generated_in_the_module() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        // `Square extends Shape` resolves to the `Shape` in the same module
+        let square = Square::new(2.0).execute_with(jvm)?;
+        let shape = square.upcast::<bindings::shapes::Shape>();
+        let description: String = shape
+            .describe()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(description, "Square with area 4.0");
+        Ok(())
+    })
+}
+
+#[test]
+fn nested_packages_are_generated_in_the_module() -> duchess::GlobalResult<()> {
+    let kind = bindings::keywords::r#move::Dispatcher::r#type().execute()?;
+    assert_eq!(kind, 42);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use duchess::{java, prelude::*, JavaObject, Jvm};
+
+duchess::java_package! {
+    package monitors;
+
+    class Locks { * }
+}
+
+#[test]
+fn object_monitor_is_held_until_guard_is_dropped() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let object = java::lang::Object::new().execute_with(jvm)?;
+        assert!(!monitors::Locks::holds(&object).execute_with(jvm)?);
+
+        let guard = jvm.synchronize(&object)?;
+        assert!(monitors::Locks::holds(&object).execute_with(jvm)?);
+
+        drop(guard);
+        assert!(!monitors::Locks::holds(&object).execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn class_monitor_from_java_class() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let locks = monitors::Locks::new().execute_with(jvm)?;
+        let class = (&locks).java_class().execute_with(jvm)?;
+
+        let guard = jvm.synchronize(&class)?;
+        assert!(monitors::Locks::holds_class_lock().execute_with(jvm)?);
+
+        // Monitors are reentrant, so static synchronized methods can still be called while holding the class lock
+        let before = monitors::Locks::increment().execute_with(jvm)?;
+        let after = monitors::Locks::increment().execute_with(jvm)?;
+        assert_eq!(after, before + 1);
+
+        drop(guard);
+        assert!(!monitors::Locks::holds_class_lock().execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn class_monitor_from_java_object_class() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let class = monitors::Locks::class(jvm)?;
+        let _guard = jvm.synchronize(&class)?;
+        assert!(monitors::Locks::holds_class_lock().execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn class_monitor_blocks_other_threads() -> duchess::GlobalResult<()> {
+    let class = Jvm::with(|jvm| {
+        let class = monitors::Locks::class(jvm)?;
+        Ok(jvm.global(&*class))
+    })?;
+
+    let (entered_tx, entered_rx) = std::sync::mpsc::channel();
+    let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+    let holder = std::thread::spawn(move || {
+        Jvm::with(|jvm| {
+            let _guard = jvm.synchronize(&class)?;
+            entered_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+            Ok(())
+        })
+    });
+    entered_rx.recv().unwrap();
+
+    let (done_tx, done_rx) = std::sync::mpsc::channel();
+    let incrementer = std::thread::spawn(move || {
+        let count = monitors::Locks::increment().execute();
+        done_tx.send(()).unwrap();
+        count
+    });
+
+    // `increment` is `static synchronized`, so it waits for the other thread to release the class monitor
+    let waited = done_rx.recv_timeout(std::time::Duration::from_millis(200));
+    assert!(waited.is_err());
+
+    release_tx.send(()).unwrap();
+    holder.join().unwrap()?;
+    incrementer.join().unwrap()?;
+    done_rx.recv().unwrap();
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use duchess::jni_sys::{jboolean, jclass, jint, jobject, JNIEnv};
+use duchess::{java, prelude::*, Error, Jvm, NativeMethod};
+
+use natives::CalculatorExt;
+
+duchess::java_package! {
+    package natives;
+
+    class Calculator { * }
+}
+
+extern "system" fn add(env: *mut JNIEnv, _class: jclass, a: jint, b: jint) -> jint {
+    unsafe { Jvm::native(env, |_jvm| Ok(a + b)) }
+}
+
+extern "system" fn greet(env: *mut JNIEnv, this: jobject, name: jobject) -> jobject {
+    unsafe {
+        Jvm::native(env, |jvm| {
+            let this = jvm
+                .native_arg::<natives::Calculator>(this)
+                .ok_or(Error::NullDeref)?;
+            let owner: String = this.owner().assert_not_null().to_rust().execute_with(jvm)?;
+            let name: Option<String> = jvm
+                .native_arg::<java::lang::String>(name)
+                .map(|name| name.to_rust().execute_with(jvm))
+                .transpose()?;
+            Ok(format!(
+                "{owner} greets {}",
+                name.as_deref().unwrap_or("nobody")
+            ))
+        })
+    }
+}
+
+extern "system" fn check(env: *mut JNIEnv, _class: jclass, input: jobject) -> jboolean {
+    unsafe {
+        Jvm::native(env, |jvm| {
+            let input = jvm
+                .native_arg::<java::lang::String>(input)
+                .ok_or(Error::NullDeref)?;
+            let input: String = input.to_rust().execute_with(jvm)?;
+            match input.as_str() {
+                "panic" => panic!("asked to panic"),
+                // `Integer.parseInt` rethrows its `NumberFormatException` to Java
+                _ => Ok(natives::Calculator::parse(&input[..]).execute_with(jvm)? > 0),
+            }
+        })
+    }
+}
+
+fn register<'jvm>(jvm: &mut Jvm<'jvm>) -> duchess::Result<'jvm, ()> {
+    let methods = unsafe {
+        [
+            NativeMethod::new(c"add", c"(II)I", add as *const _),
+            NativeMethod::new(
+                c"greet",
+                c"(Ljava/lang/String;)Ljava/lang/String;",
+                greet as *const _,
+            ),
+            NativeMethod::new(c"check", c"(Ljava/lang/String;)Z", check as *const _),
+        ]
+    };
+    jvm.register_natives::<natives::Calculator>(&methods)
+}
+
+#[test]
+fn call_static_native_method() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        register(jvm)?;
+        assert_eq!(natives::Calculator::add(2, 3).execute_with(jvm)?, 5);
+        // Java code calling the native method
+        assert_eq!(natives::Calculator::add_twice(2, 3).execute_with(jvm)?, 8);
+        Ok(())
+    })
+}
+
+#[test]
+fn call_instance_native_method() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        register(jvm)?;
+        let calculator = natives::Calculator::new("calc").execute_with(jvm)?;
+        let greeting: String = calculator
+            .greet("ferris")
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(greeting, "calc greets ferris");
+
+        let greeting: String = calculator
+            .greet_nobody()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(greeting, "calc greets nobody");
+        Ok(())
+    })
+}
+
+#[test]
+fn errors_are_thrown_to_java() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        register(jvm)?;
+        assert!(natives::Calculator::check("5").execute_with(jvm)?);
+
+        Ok(())
+    })?;
+
+    let message = |input: &'static str| -> duchess::GlobalResult<String> {
+        natives::Calculator::check_or_message(input)
+            .assert_not_null()
+            .to_rust()
+            .execute()
+    };
+    assert_eq!(message("-1")?, "false");
+    assert_eq!(message("x")?, "For input string: \"x\"");
+    assert_eq!(message("panic")?, "asked to panic");
+
+    let thrown = natives::Calculator::check("x").execute();
+    assert!(matches!(thrown, Err(Error::Thrown(_))));
+    Ok(())
+}
+
+#[test]
+fn unknown_native_method() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let methods = unsafe { [NativeMethod::new(c"missing", c"()V", add as *const _)] };
+        let result = jvm.register_natives::<natives::Calculator>(&methods);
+        assert!(matches!(result, Err(Error::Thrown(_))));
+        Ok(())
+    })
+}
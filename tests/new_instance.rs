@@ -0,0 +1,56 @@
+use duchess::{prelude::*, Error, Jvm};
+
+use plugins::PluginExt;
+
+duchess::java_package! {
+    package plugins;
+
+    interface Plugin { * }
+}
+
+#[test]
+fn instantiate_by_name() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let plugin = jvm.new_instance::<plugins::Plugin>("plugins.Greeter")?;
+        let name: String = plugin
+            .name()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(name, "greeter");
+        Ok(())
+    })
+}
+
+#[test]
+fn class_not_found() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let result = jvm.new_instance::<plugins::Plugin>("plugins.Missing");
+        assert!(matches!(result, Err(Error::Thrown(_))));
+        Ok(())
+    })
+}
+
+#[test]
+fn no_no_argument_constructor() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let result = jvm.new_instance::<plugins::Plugin>("plugins.Configured");
+        assert!(matches!(result, Err(Error::Thrown(_))));
+        Ok(())
+    })
+}
+
+#[test]
+fn not_a_subtype() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        match jvm.new_instance::<plugins::Plugin>("java.util.ArrayList") {
+            Err(Error::Conversion(message)) => assert_eq!(
+                message,
+                "`java.util.ArrayList` is not a subtype of `plugins.Plugin`"
+            ),
+            Err(other) => panic!("expected a type mismatch, got {other:?}"),
+            Ok(_) => panic!("expected a type mismatch"),
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,104 @@
+use duchess::{java, prelude::*, Global, JavaObjectCache, Jvm};
+
+/// Collects garbage until `weak` is cleared, since a single `System.gc()` is only a hint.
+fn collect(weak: &duchess::WeakGlobal<java::lang::String>) -> duchess::GlobalResult<()> {
+    for _ in 0..100 {
+        java::lang::System::gc().execute()?;
+        if weak.upgrade().is_none() {
+            return Ok(());
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    panic!("object was never collected");
+}
+
+#[test]
+fn upgrade_while_alive() -> duchess::GlobalResult<()> {
+    let string: Global<java::lang::String> =
+        "weak".to_java().assert_not_null().global().execute()?;
+    let weak = string.downgrade();
+    let upgraded = weak.upgrade().expect("object is still referenced");
+    assert!((&*upgraded).ref_eq(&*string).execute()?);
+
+    let clone = weak.clone();
+    drop(weak);
+    assert!(clone.upgrade().is_some());
+    Ok(())
+}
+
+#[test]
+fn upgrade_after_collection() -> duchess::GlobalResult<()> {
+    let string: Global<java::lang::String> =
+        "gone".to_java().assert_not_null().global().execute()?;
+    let weak = string.downgrade();
+    drop(string);
+    collect(&weak)?;
+    assert!(weak.upgrade().is_none());
+
+    // A clone of a cleared reference is cleared too
+    let clone = weak.clone();
+    assert!(clone.upgrade().is_none());
+    Ok(())
+}
+
+#[test]
+fn cache_returns_live_object() -> duchess::GlobalResult<()> {
+    let cache = JavaObjectCache::<&str, java::lang::String>::new();
+    Jvm::with(|jvm| {
+        let first =
+            cache.get_or_insert_with(jvm, "greeting", "hello".to_java().assert_not_null())?;
+        let second =
+            cache.get_or_insert_with(jvm, "greeting", "ignored".to_java().assert_not_null())?;
+        assert!((&*first).ref_eq(&*second).execute_with(jvm)?);
+
+        let value: String = second.to_rust().execute_with(jvm)?;
+        assert_eq!(value, "hello");
+        assert_eq!(cache.len(), 1);
+        Ok(())
+    })
+}
+
+#[test]
+fn cache_rebuilds_collected_object() -> duchess::GlobalResult<()> {
+    let cache = JavaObjectCache::<String, java::lang::String>::new();
+    let weak = Jvm::with(|jvm| {
+        let first = cache.get_or_insert_with(
+            jvm,
+            "key".to_string(),
+            "first".to_java().assert_not_null(),
+        )?;
+        Ok(first.downgrade())
+    })?;
+    collect(&weak)?;
+    assert!(cache.get("key").is_none());
+    assert!(cache.is_empty());
+
+    Jvm::with(|jvm| {
+        let second = cache.get_or_insert_with(
+            jvm,
+            "key".to_string(),
+            "second".to_java().assert_not_null(),
+        )?;
+        let value: String = second.to_rust().execute_with(jvm)?;
+        assert_eq!(value, "second");
+        Ok(())
+    })
+}
+
+#[test]
+fn purge_and_remove() -> duchess::GlobalResult<()> {
+    let cache = JavaObjectCache::<u32, java::lang::String>::new();
+    let (kept, weak) = Jvm::with(|jvm| {
+        let kept = cache.get_or_insert_with(jvm, 1, "kept".to_java().assert_not_null())?;
+        let dropped = cache.get_or_insert_with(jvm, 2, "dropped".to_java().assert_not_null())?;
+        Ok((kept, dropped.downgrade()))
+    })?;
+    collect(&weak)?;
+
+    cache.purge();
+    assert_eq!(cache.len(), 1);
+    let removed = cache.remove(&1).expect("object is still referenced");
+    assert!((&*removed).ref_eq(&*kept).execute()?);
+    assert!(cache.is_empty());
+    Ok(())
+}
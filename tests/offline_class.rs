@@ -0,0 +1,46 @@
+//! Specified classes don't have to be on the classpath when the crate is built: `offline.Gadget` and
+//! `offline.Widget` are only packaged in `offline.jar`, which is added to the class path at runtime. This test
+//! launches its own JVM, so it must be the only test in this file.
+
+use duchess::{prelude::*, Global, Jvm};
+use offline::{GadgetExt, WidgetExt};
+
+duchess::java_package! {
+    package offline;
+
+    public class offline.Gadget {
+        public offline.Gadget(java.lang.String);
+        public java.lang.String name();
+        public static int version();
+    }
+
+    public class offline.Widget extends offline.Gadget {
+        public offline.Widget(java.lang.String, int);
+        public int size();
+        public java.lang.String describe();
+    }
+}
+
+#[test]
+fn classes_missing_at_build_time() -> duchess::GlobalResult<()> {
+    Jvm::builder()
+        .add_classpath(concat!(env!("CARGO_MANIFEST_DIR"), "/java/offline.jar"))
+        .launch_or_use_existing()?;
+
+    let widget = offline::Widget::new("knob", 3).global().execute()?;
+    assert_eq!(widget.size().execute()?, 3);
+    assert_eq!(
+        widget.describe().assert_not_null().to_rust().execute()?,
+        "knob of size 3"
+    );
+
+    // Inherited from the specified superclass, which isn't on the classpath at build time either
+    let name: String = widget.name().assert_not_null().to_rust().execute()?;
+    assert_eq!(name, "knob");
+    assert_eq!(offline::Gadget::version().execute()?, 3);
+
+    let gadget: Global<offline::Gadget> = widget.upcast();
+    let name: String = gadget.name().assert_not_null().to_rust().execute()?;
+    assert_eq!(name, "knob");
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package shapes;
+
+    class Shape { * }
+    optional class Square { * }
+
+    // Not on the classpath: skipped rather than reported as an error.
+    optional class Circle { * }
+}
+
+use shapes::ShapeExt;
+
+#[test]
+fn optional_classes_are_bound_when_found() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let square = shapes::Square::new(3.0).execute_with(jvm)?;
+        let description: String = square
+            .describe()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(description, "Square with area 9.0");
+        Ok(())
+    })
+}
@@ -0,0 +1,37 @@
+use duchess::{java, prelude::*, Local, ToJava};
+
+duchess::java_package! {
+    package params;
+
+    class Contact { * }
+}
+
+use params::ContactExt;
+
+/// The fields are declared in a different order than the arguments of the constructor, which is compiled with
+/// `javac -parameters`, so they are matched by name.
+#[derive(ToJava)]
+#[java(params.Contact)]
+struct Contact {
+    age: i32,
+    last_name: String,
+    first_name: String,
+}
+
+#[test]
+fn derive_matches_fields_to_parameter_names() -> duchess::GlobalResult<()> {
+    let contact = Contact {
+        age: 36,
+        last_name: "Lovelace".to_string(),
+        first_name: "Ada".to_string(),
+    };
+    let description: String = contact
+        .to_java::<params::Contact>()
+        .assert_not_null()
+        .describe()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(description, "Ada Lovelace (36)");
+    Ok(())
+}
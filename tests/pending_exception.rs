@@ -0,0 +1,70 @@
+use duchess::java::lang::ThrowableExt;
+use duchess::java::util::ListExt;
+use duchess::{java, prelude::*, Jvm};
+
+/// Throws an `IllegalStateException` with raw JNI, leaving it pending.
+fn throw_with_raw_jni(jvm: &Jvm<'_>, message: &std::ffi::CStr) {
+    let env = jvm.raw_env();
+    unsafe {
+        let class = ((**env).FindClass.unwrap())(env, c"java/lang/IllegalStateException".as_ptr());
+        assert!(!class.is_null());
+        ((**env).ThrowNew.unwrap())(env, class, message.as_ptr());
+        ((**env).DeleteLocalRef.unwrap())(env, class);
+    }
+}
+
+#[test]
+fn take_pending_exception() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        assert!(!jvm.has_pending_exception());
+        assert!(jvm.take_pending_exception().is_none());
+
+        throw_with_raw_jni(jvm, c"raw failure");
+        assert!(jvm.has_pending_exception());
+
+        let exception = jvm.take_pending_exception().expect("exception is pending");
+        assert!(!jvm.has_pending_exception());
+        assert!(jvm.take_pending_exception().is_none());
+
+        // Duchess operations work again once the exception is taken
+        let message: String = exception
+            .get_message()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(message, "raw failure");
+        Ok(())
+    })
+}
+
+#[test]
+fn operations_leave_no_pending_exception() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let list = java::util::ArrayList::<java::lang::Object>::new().execute_with(jvm)?;
+        let result = list.get(3).execute_with(jvm);
+        assert!(result.is_err());
+        assert!(!jvm.has_pending_exception());
+        Ok(())
+    })
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "JNI call made while a Java exception was pending")]
+fn unchecked_exception_panics_on_next_call() {
+    use duchess::plumbing::HasEnvPtr;
+
+    let _ = Jvm::with(|jvm| {
+        // Leaves a `NoClassDefFoundError` pending without checking for it
+        let env = jvm.env();
+        unsafe {
+            env.invoke(
+                |env| env.FindClass,
+                |env, f| f(env, c"does/not/Exist".as_ptr()),
+            )
+        };
+
+        "hello".execute_with(jvm)?;
+        Ok(())
+    });
+}
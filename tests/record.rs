@@ -0,0 +1,66 @@
+use duchess::{prelude::*, Jvm, Local, ToJava, ToRust};
+
+duchess::java_package! {
+    package records;
+
+    record Point { * }
+
+    // The one-argument constructor is left out, since overloads can't be bound
+    public record records.Interval {
+        public records.Interval(int, int);
+        public int start();
+        public int end();
+    }
+}
+
+use records::PointExt;
+
+#[test]
+fn records_bind_canonical_constructor_and_accessors() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let point = records::Point::new(2, 3).execute_with(jvm)?;
+        assert_eq!(point.x().execute_with(jvm)?, 2);
+        let scaled = point.scale(10).assert_not_null().execute_with(jvm)?;
+        assert_eq!(scaled.y().execute_with(jvm)?, 30);
+        Ok(())
+    })
+}
+
+#[derive(Debug, PartialEq, ToJava, ToRust)]
+#[java(records.Point)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn records_derive_conversions() -> duchess::GlobalResult<()> {
+    let point = Point { x: 4, y: -1 };
+    let back: Point = point
+        .to_java::<records::Point>()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(back, point);
+    Ok(())
+}
+
+/// `Interval` also has a one-argument constructor; the number of fields selects the canonical one.
+#[derive(Debug, PartialEq, ToJava, ToRust)]
+#[java(records.Interval)]
+struct Interval {
+    start: i32,
+    end: i32,
+}
+
+#[test]
+fn derive_selects_constructor_by_arity() -> duchess::GlobalResult<()> {
+    let interval = Interval { start: 1, end: 5 };
+    let back: Interval = interval
+        .to_java::<records::Interval>()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(back, interval);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use duchess::java::lang::ObjectExt;
+use duchess::{java, prelude::*, Global, Jvm, Local};
+
+#[test]
+fn ref_eq_distinguishes_identity_from_equality() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let a = "hello".execute_with(jvm)?;
+        let b = "hello".execute_with(jvm)?;
+
+        assert!(a.equals(&b).execute_with(jvm)?);
+        assert!(!(&a).ref_eq(&b).execute_with(jvm)?);
+        assert!((&a).ref_eq(&a).execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn ref_eq_across_handle_kinds() -> duchess::GlobalResult<()> {
+    let global: Global<java::lang::String> = "hello".global().execute()?;
+    let clone = global.clone();
+
+    Jvm::with(|jvm| {
+        let local = jvm.local(&*global);
+        assert!((&global).ref_eq(&clone).execute_with(jvm)?);
+        assert!((&local).ref_eq(&global).execute_with(jvm)?);
+
+        // The types don't need to be related
+        let date = java::util::Date::new().execute_with(jvm)?;
+        assert!(!(&date).ref_eq(&global).execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn ref_eq_with_null() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let object = java::lang::Object::new().execute_with(jvm)?;
+        let null_object: Option<Local<java::lang::Object>> = None;
+        let null_string: Option<Global<java::lang::String>> = None;
+
+        assert!((&null_object).ref_eq(&null_string).execute_with(jvm)?);
+        assert!(!(&null_object).ref_eq(&object).execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn identity_hash_code_follows_identity() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let a = "hello".global().execute_with(jvm)?;
+        let b = a.clone();
+
+        let hash_a = java::lang::System::identity_hash_code(&a).execute_with(jvm)?;
+        let hash_b = java::lang::System::identity_hash_code(&b).execute_with(jvm)?;
+        assert_eq!(hash_a, hash_b);
+        Ok(())
+    })
+}
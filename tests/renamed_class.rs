@@ -0,0 +1,91 @@
+use duchess::java::lang::ObjectExt;
+use duchess::{prelude::*, Jvm, Local};
+
+duchess::java_package! {
+    package renames;
+
+    class Result as JResult { * }
+
+    public class Box as JBox extends renames.Result {
+        public renames.Box(int);
+        public renames.Result unbox();
+    }
+
+    class Holder { * }
+}
+
+// The glob import brings in `JResult` and `JBox`, leaving Rust's `Result` and `Box` alone.
+use renames::*;
+
+#[derive(duchess::ToJava, duchess::ToRust)]
+#[java(renames.Result as JResult)]
+struct Value {
+    value: i32,
+}
+
+// The rename is picked up without repeating it.
+#[derive(duchess::ToJava, duchess::ToRust)]
+#[java(renames.Result)]
+struct JavaNamedValue {
+    value: i32,
+}
+
+#[test]
+fn renamed_struct_binds_the_java_class() -> Result<(), Box<dyn std::error::Error>> {
+    Jvm::with(|jvm| {
+        let two = JResult::new(2).execute_with(jvm)?;
+        let sum = two.plus(JResult::zero()).plus(&two);
+        assert_eq!(sum.assert_not_null().value().execute_with(jvm)?, 4);
+
+        let string: String = two
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert!(string.starts_with("renames.Result@"));
+        Ok(())
+    })
+    .map_err(|e| e.to_string().into())
+}
+
+#[test]
+fn references_use_the_rust_name() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let boxed = JBox::new(7).execute_with(jvm)?;
+
+        // `JBox` upcasts to `JResult`, and `Holder` takes and returns a `JResult`.
+        let holder = Holder::new(&boxed).execute_with(jvm)?;
+        let held: Option<Local<JResult>> = holder.get().execute_with(jvm)?;
+        assert_eq!(held.unwrap().value().execute_with(jvm)?, 7);
+
+        let result: Local<JResult> = boxed.unbox().assert_not_null().execute_with(jvm)?;
+        assert_eq!(result.value().execute_with(jvm)?, 7);
+        Ok(())
+    })
+}
+
+#[test]
+fn derive_selects_renamed_class() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let java_value: Local<JResult> = Value { value: 5 }.execute_with(jvm)?;
+        assert_eq!(java_value.value().execute_with(jvm)?, 5);
+
+        let value: Value = java_value
+            .plus(&java_value)
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(value.value, 10);
+        Ok(())
+    })
+}
+
+#[test]
+fn derive_finds_renamed_class_by_java_name() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let java_value: Local<JResult> = JavaNamedValue { value: 3 }.execute_with(jvm)?;
+        let value: JavaNamedValue = java_value.to_rust().execute_with(jvm)?;
+        assert_eq!(value.value, 3);
+        Ok(())
+    })
+}
@@ -0,0 +1,70 @@
+use duchess::java::lang::ThrowableExt;
+use duchess::{java, prelude::*, Error, Jvm};
+
+duchess::java_package! {
+    package registry;
+
+    class Registry { * }
+}
+
+use registry::{Registry, RegistryExt};
+
+#[test]
+fn returning_composes_with_to_rust() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let registry = Registry::new().execute_with(jvm)?;
+        let name: String = registry
+            .lookup("name")
+            .returning::<java::lang::String>()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(name, "duchess");
+
+        let count: i32 = registry
+            .lookup("count")
+            .returning::<java::lang::Integer>()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(count, 3);
+        Ok(())
+    })
+}
+
+#[test]
+fn returning_keeps_null() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let registry = Registry::new().execute_with(jvm)?;
+        let missing = registry
+            .lookup("missing")
+            .returning::<java::lang::String>()
+            .execute_with(jvm)?;
+        assert!(missing.is_none());
+        Ok(())
+    })
+}
+
+#[test]
+fn returning_wrong_type_throws_class_cast_exception() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let registry = Registry::new().execute_with(jvm)?;
+        let result = registry
+            .lookup("count")
+            .returning::<java::lang::String>()
+            .execute_with(jvm);
+        let Err(Error::Thrown(exception)) = result else {
+            panic!("expected a `ClassCastException`");
+        };
+        let description: String = exception
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(
+            description,
+            "java.lang.ClassCastException: Cannot cast java.lang.Integer to java.lang.String"
+        );
+        Ok(())
+    })
+}
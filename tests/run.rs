@@ -0,0 +1,57 @@
+//! `duchess::run` destroys the JVM once it's done, so this must be the only test in this file.
+
+use std::time::Duration;
+
+use duchess::{java, prelude::*, Error, Global, Jvm};
+
+duchess::java_package! {
+    package shutdown;
+
+    class Hooks { * }
+}
+
+#[test]
+fn run_destroys_the_jvm() -> duchess::GlobalResult<()> {
+    let marker = std::env::temp_dir().join(format!("duchess-run-{}", std::process::id()));
+    let _ = std::fs::remove_file(&marker);
+
+    let (version, global): (String, Global<java::lang::String>) = duchess::run(|jvm| {
+        shutdown::Hooks::touch_on_shutdown(marker.to_str().unwrap()).execute_with(jvm)?;
+        let version = java::lang::System::get_property("java.version")
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        let global = "outlives the JVM".global().execute_with(jvm)?;
+        Ok((version, global))
+    })?;
+    assert!(!version.is_empty());
+
+    // The shutdown hooks ran before `run` returned
+    assert_eq!(std::fs::read_to_string(&marker).unwrap(), "done");
+    std::fs::remove_file(&marker).unwrap();
+
+    assert!(matches!(Jvm::with(|_| Ok(())), Err(Error::JvmDestroyed)));
+    assert!(matches!(duchess::run(|_| Ok(())), Err(Error::JvmDestroyed)));
+    assert!(matches!(
+        Jvm::builder().launch_or_use_existing(),
+        Err(Error::JvmDestroyed)
+    ));
+    // Fails before the operation starts, rather than panicking
+    let touched =
+        shutdown::Hooks::touch_on_shutdown("unused").execute_with_timeout(Duration::from_secs(1));
+    assert!(matches!(touched, Err(Error::JvmDestroyed)));
+
+    // Weak references behave as if the object was collected
+    let weak = global.downgrade();
+    assert!(weak.upgrade().is_none());
+    assert!(weak.clone().upgrade().is_none());
+
+    // Clones are as inert as the original
+    let clone = global.clone();
+
+    // Leaked rather than deleted
+    drop(weak);
+    drop(clone);
+    drop(global);
+    Ok(())
+}
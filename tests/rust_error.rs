@@ -0,0 +1,66 @@
+use duchess::{prelude::*, JavaException, Jvm};
+
+duchess::java_package! {
+    package exceptions;
+
+    class Thrower { * }
+}
+
+fn thrown<'jvm>(
+    op: impl JvmOp,
+    jvm: &mut Jvm<'jvm>,
+) -> duchess::Result<'jvm, Box<dyn std::error::Error + Send + Sync>> {
+    match op.to_rust_error().execute_with(jvm)? {
+        Ok(_) => panic!("expected an exception"),
+        Err(error) => Ok(error),
+    }
+}
+
+#[test]
+fn to_rust_error_keeps_the_cause_chain() -> duchess::GlobalResult<()> {
+    let error = Jvm::with(|jvm| thrown(exceptions::Thrower::throw_wrapped(), jvm))?;
+    assert_eq!(
+        error.to_string(),
+        "java.lang.IllegalStateException: wrapped"
+    );
+
+    let exception = error.downcast_ref::<JavaException>().unwrap();
+    assert_eq!(exception.class_name(), "java.lang.IllegalStateException");
+    assert_eq!(exception.message(), Some("wrapped"));
+    assert!(exception.stack_trace()[0].starts_with("exceptions.Thrower.throwWrapped("));
+    assert!(format!("{exception:#}").contains("\n\tat exceptions.Thrower.throwWrapped("));
+
+    let cause = error.source().unwrap();
+    assert_eq!(cause.to_string(), "exceptions.UncheckedFailure: root cause");
+    assert!(cause.source().is_none());
+    Ok(())
+}
+
+#[test]
+fn to_rust_error_stops_at_a_cause_cycle() -> duchess::GlobalResult<()> {
+    let error = Jvm::with(|jvm| thrown(exceptions::Thrower::throw_cyclic(), jvm))?;
+    let exception = error.downcast_ref::<JavaException>().unwrap();
+    assert_eq!(exception.message(), Some("outer"));
+    let cause = exception.cause().unwrap();
+    assert_eq!(cause.message(), Some("inner"));
+    assert!(cause.cause().is_none());
+    Ok(())
+}
+
+#[test]
+fn to_rust_error_without_message() -> duchess::GlobalResult<()> {
+    let error = Jvm::with(|jvm| thrown(exceptions::Thrower::throw_without_message(), jvm))?;
+    assert_eq!(error.to_string(), "java.lang.UnsupportedOperationException");
+    Ok(())
+}
+
+#[test]
+fn to_rust_error_passes_through_success() -> duchess::GlobalResult<()> {
+    let ok = exceptions::Thrower::succeed()
+        .assert_not_null()
+        .to_rust()
+        .to_rust_error()
+        .execute()?;
+    assert_eq!(ok.unwrap(), "ok");
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use duchess::java::util::ListExt;
+use duchess::{java, prelude::*, Global};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+fn assert_send<T: Send>() {}
+
+#[test]
+fn globals_are_send_and_sync() {
+    assert_send_sync::<Global<java::lang::Object>>();
+    assert_send_sync::<Global<java::util::ArrayList<java::lang::String>>>();
+    assert_send_sync::<Option<Global<java::lang::Throwable>>>();
+
+    // Results can be sent back from a thread (the error for failing to load libjvm isn't `Sync`)
+    assert_send::<duchess::GlobalResult<Global<java::lang::String>>>();
+}
+
+#[test]
+fn globals_can_be_shared_between_threads() -> duchess::GlobalResult<()> {
+    let list: Global<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().global().execute()?;
+    list.add("main").execute()?;
+
+    let shared = Arc::new(list);
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let shared = Arc::clone(&shared);
+            std::thread::spawn(move || -> duchess::GlobalResult<String> {
+                shared.get(0).assert_not_null().to_rust().execute()
+            })
+        })
+        .collect();
+
+    for thread in threads {
+        assert_eq!(thread.join().unwrap()?, "main");
+    }
+    Ok(())
+}
+
+#[test]
+fn globals_can_be_moved_to_another_thread() -> duchess::GlobalResult<()> {
+    let string: Global<java::lang::String> = "moved".global().execute()?;
+    let rust: String = std::thread::spawn(move || string.to_rust().execute())
+        .join()
+        .unwrap()?;
+    assert_eq!(rust, "moved");
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use duchess::java::lang::{CharSequenceExt, StringExt};
+use duchess::{java, prelude::*, Jvm};
+
+#[test]
+fn str_as_char_sequence_argument() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let haystack = "hello, world".execute_with(jvm)?;
+        assert!(haystack.contains("world").execute_with(jvm)?);
+        assert!(!haystack.contains("mars").execute_with(jvm)?);
+
+        let needle = String::from("hello");
+        assert!(haystack.contains(needle.as_str()).execute_with(jvm)?);
+        assert!(haystack.contains(needle).execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn string_as_char_sequence_receiver() -> duchess::GlobalResult<()> {
+    let c = "hello"
+        .upcast::<java::lang::CharSequence>()
+        .char_at(1)
+        .execute()?;
+    assert_eq!(c, u16::from(b'e'));
+    Ok(())
+}
+
+#[test]
+fn vec_of_strings_to_list_of_char_sequences() -> duchess::GlobalResult<()> {
+    use duchess::java::util::ListExt;
+
+    let strings = vec!["a".to_string(), "bc".to_string()];
+    let len = strings
+        .to_java::<java::util::List<java::lang::CharSequence>>()
+        .assert_not_null()
+        .get(1)
+        .assert_not_null()
+        .length()
+        .execute()?;
+    assert_eq!(len, 2);
+    Ok(())
+}
+
+#[test]
+fn astral_chars_become_surrogate_pairs() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let crab = "\u{1F980}".execute_with(jvm)?;
+        assert_eq!(StringExt::length(&crab).execute_with(jvm)?, 2);
+        assert_eq!(crab.char_at(0).execute_with(jvm)?, 0xD83E);
+        assert_eq!(crab.char_at(1).execute_with(jvm)?, 0xDD80);
+        Ok(())
+    })
+}
+
+#[test]
+fn round_trip_special_chars() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        for s in [
+            "",
+            "nul\0in\0the\0middle",
+            "\0",
+            "\u{1F980}\u{1F980} crabs",
+            "wörld \u{10FFFF}",
+        ] {
+            let java = s.execute_with(jvm)?;
+            let back: String = java.to_rust().execute_with(jvm)?;
+            assert_eq!(back, s);
+        }
+
+        let nul = "a\0b".execute_with(jvm)?;
+        assert_eq!(StringExt::length(&nul).execute_with(jvm)?, 3);
+        assert_eq!(nul.char_at(1).execute_with(jvm)?, 0);
+        Ok(())
+    })
+}
+
+#[test]
+fn round_trip_long_strings() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        // Long strings are converted in pieces; the crabs' surrogate pairs straddle the boundaries between them
+        for prefix in [1023, 1024, 2047] {
+            let s = format!(
+                "{}\u{1F980}{}",
+                "a".repeat(prefix),
+                "\u{1F980}b".repeat(1000)
+            );
+            let java = s.as_str().execute_with(jvm)?;
+            let back: String = java.to_rust().execute_with(jvm)?;
+            assert_eq!(back, s);
+        }
+        Ok(())
+    })
+}
+
+#[test]
+fn unpaired_surrogate_fails_to_convert() -> duchess::GlobalResult<()> {
+    use duchess::java::lang::ObjectExt;
+
+    Jvm::with(|jvm| {
+        // Half of a surrogate pair is a valid Java string but not a valid Rust string
+        let half: Result<String, _> = "\u{1F980}"
+            .sub_sequence(0, 1)
+            .assert_not_null()
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm);
+        let Err(duchess::Error::Conversion(message)) = half else {
+            panic!("expected an error, got {half:?}");
+        };
+        assert!(str::contains(&message, "unpaired surrogate"), "{message}");
+        Ok(())
+    })
+}
+
+#[test]
+fn null_string_to_rust_string_or_default() -> duchess::GlobalResult<()> {
+    use duchess::java::lang::ThrowableExt;
+
+    Jvm::with(|jvm| {
+        let exception = java::lang::Throwable::new().execute_with(jvm)?;
+        let message = exception
+            .get_message()
+            .to_rust_string_or_default()
+            .execute_with(jvm)?;
+        assert_eq!(message, "");
+        let message: Option<String> = exception.get_message().to_rust().execute_with(jvm)?;
+        assert_eq!(message, None);
+
+        let text = "text"
+            .to_java::<java::lang::String>()
+            .to_rust_string_or_default()
+            .execute_with(jvm)?;
+        assert_eq!(text, "text");
+        Ok(())
+    })
+}
@@ -0,0 +1,60 @@
+use duchess::java::lang::StringBuilderExt;
+use duchess::{java, prelude::*, Jvm};
+
+#[test]
+fn fluent_builder_is_one_op() -> duchess::GlobalResult<()> {
+    let built: String = java::lang::StringBuilder::new()
+        .append("a")
+        .append(1)
+        .append(true)
+        .append(2.5)
+        .append(i64::MAX)
+        .to_string()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(built, format!("a1true2.5{}", i64::MAX));
+    Ok(())
+}
+
+#[test]
+fn builder_methods_chain_on_the_same_object() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let builder = java::lang::StringBuilder::new().execute_with(jvm)?;
+        builder.append("abc").append("def").execute_with(jvm)?;
+        let reversed: String = builder
+            .reverse()
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(reversed, "fedcba");
+        assert_eq!(builder.length().execute_with(jvm)?, 6);
+        Ok(())
+    })
+}
+
+#[test]
+fn boxing_round_trips() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let boxed = 42_i32
+            .to_java::<java::lang::Integer>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        let unboxed: i32 = boxed.to_rust().execute_with(jvm)?;
+        assert_eq!(unboxed, 42);
+
+        let boxed = true.to_java::<java::lang::Boolean>().execute_with(jvm)?;
+        let unboxed: bool = boxed.to_rust().execute_with(jvm)?.unwrap();
+        assert!(unboxed);
+
+        let boxed = (-7_i64).to_java::<java::lang::Long>().execute_with(jvm)?;
+        let unboxed: Option<i64> = boxed.to_rust().execute_with(jvm)?;
+        assert_eq!(unboxed, Some(-7));
+
+        let boxed = 0.5_f64.to_java::<java::lang::Double>().execute_with(jvm)?;
+        let unboxed: Option<f64> = boxed.to_rust().execute_with(jvm)?;
+        assert_eq!(unboxed, Some(0.5));
+        Ok(())
+    })
+}
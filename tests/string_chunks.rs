@@ -0,0 +1,71 @@
+use duchess::{prelude::*, GlobalIter, StringChunks};
+
+duchess::java_package! {
+    package payloads;
+
+    class Payloads { * }
+}
+
+#[test]
+fn chunks_concatenate_to_the_whole_string() -> duchess::GlobalResult<()> {
+    let whole: String = payloads::Payloads::text(100)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+
+    for max_len in [1, 2, 3, 7, 64, 100_000] {
+        let chunks: StringChunks = payloads::Payloads::text(100)
+            .to_rust_chunks(max_len)
+            .execute()?;
+        assert_eq!(chunks.remaining_len(), whole.encode_utf16().count());
+
+        let mut joined = String::new();
+        for chunk in chunks {
+            let chunk = chunk?;
+            // Surrogate pairs are never split, which may take one code unit more than a piece of 1
+            assert!(chunk.encode_utf16().count() <= max_len.max(2), "{chunk:?}");
+            joined.push_str(&chunk);
+        }
+        assert_eq!(joined, whole);
+    }
+    Ok(())
+}
+
+#[test]
+fn chunks_of_a_null_string() {
+    let chunks = payloads::Payloads::missing().to_rust_chunks(8).execute();
+    assert!(matches!(chunks, Err(duchess::Error::NullDeref)));
+}
+
+#[test]
+fn iteration_continues_past_an_unpaired_surrogate() -> duchess::GlobalResult<()> {
+    let chunks: Vec<_> = payloads::Payloads::broken()
+        .to_rust_chunks(2)
+        .execute()?
+        .collect();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].as_ref().unwrap(), "ok");
+    assert!(chunks[1].is_err());
+    Ok(())
+}
+
+#[test]
+fn records_are_converted_one_at_a_time() -> duchess::GlobalResult<()> {
+    // 20 million characters in all, of which only one record is converted at a time
+    let records: GlobalIter<String> = payloads::Payloads::records(2_000_i64, 10_000)
+        .assert_not_null()
+        .to_global_iter()
+        .execute()?;
+
+    let mut count = 0;
+    let mut total = 0;
+    for record in records {
+        let record = record?;
+        assert_eq!(record.len(), 10_000);
+        assert!(record.bytes().all(|b| b == b'a' + (count % 26) as u8));
+        count += 1;
+        total += record.len();
+    }
+    assert_eq!((count, total), (2_000, 20_000_000));
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use duchess::{java, prelude::*, Error, Jvm, ToRust};
+
+duchess::java_package! {
+    package maps;
+
+    class Maps { * }
+}
+
+#[test]
+fn map_to_rust() -> duchess::GlobalResult<()> {
+    let colors = maps::Maps::colors()
+        .assert_not_null()
+        .to_rust_string_map()
+        .execute()?;
+    assert_eq!(
+        colors,
+        HashMap::from([
+            ("red".to_string(), "#ff0000".to_string()),
+            ("green".to_string(), "#00ff00".to_string()),
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+fn map_with_null_value() {
+    let result = maps::Maps::with_null_value()
+        .assert_not_null()
+        .to_rust_string_map()
+        .execute();
+    let Err(Error::ElementConversion { error, .. }) = result else {
+        panic!("expected the null value to fail to convert");
+    };
+    assert!(matches!(*error, Error::NullDeref));
+}
+
+#[test]
+fn map_entries_in_iteration_order() -> duchess::GlobalResult<()> {
+    let scores = maps::Maps::scores()
+        .assert_not_null()
+        .to_rust_entries::<String, i32>()
+        .execute()?;
+    assert_eq!(
+        scores,
+        [
+            ("carol".to_string(), 7),
+            ("alice".to_string(), 12),
+            ("bob".to_string(), 3),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn map_entries_with_null_value() {
+    let result = maps::Maps::with_null_value()
+        .assert_not_null()
+        .to_rust_entries::<String, String>()
+        .execute();
+    let Err(Error::ElementConversion { error, .. }) = result else {
+        panic!("expected the null value to fail to convert");
+    };
+    assert!(matches!(*error, Error::NullDeref));
+}
+
+#[test]
+fn large_map_to_rust() -> duchess::GlobalResult<()> {
+    let numbered = maps::Maps::numbered(10_000)
+        .assert_not_null()
+        .to_rust_map::<i32, String>()
+        .execute()?;
+    assert_eq!(numbered.len(), 10_000);
+    assert_eq!(numbered[&1234], "1234");
+    Ok(())
+}
+
+#[test]
+fn properties_to_rust() -> duchess::GlobalResult<()> {
+    // Defaults are included and the non-string `port` is skipped
+    let properties = maps::Maps::with_defaults()
+        .assert_not_null()
+        .to_rust_string_map()
+        .execute()?;
+    assert_eq!(
+        properties,
+        HashMap::from([
+            ("host".to_string(), "localhost".to_string()),
+            ("timeout".to_string(), "30".to_string()),
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+fn system_properties() -> duchess::GlobalResult<()> {
+    let properties = java::lang::System::get_properties()
+        .assert_not_null()
+        .to_rust_string_map()
+        .execute()?;
+    let version: Option<String> = java::lang::System::get_property("java.version")
+        .to_rust()
+        .execute()?;
+    assert_eq!(properties.get("java.version"), version.as_ref());
+    Ok(())
+}
+
+#[test]
+fn properties_round_trip() -> duchess::GlobalResult<()> {
+    let config = HashMap::from([
+        ("name".to_string(), "duchess".to_string()),
+        ("empty".to_string(), String::new()),
+    ]);
+
+    Jvm::with(|jvm| {
+        let properties = config
+            .to_java::<java::util::Properties>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        let back: HashMap<String, String> = ToRust::to_rust(&properties, jvm)?;
+        assert_eq!(back, config);
+        Ok(())
+    })
+}
@@ -0,0 +1,40 @@
+use duchess::java::lang::NumberExt;
+use duchess::java::util::ListExt;
+use duchess::{java, prelude::*, test::with_jvm};
+
+#[test]
+fn runs_on_the_shared_jvm() {
+    let len = with_jvm(|jvm| {
+        let list = java::util::ArrayList::<java::lang::Object>::new().execute_with(jvm)?;
+        list.add(&list).execute_with(jvm)?;
+        list.size().execute_with(jvm)
+    });
+    assert_eq!(len, 1);
+}
+
+#[test]
+fn parallel_threads_share_the_jvm() {
+    let threads: Vec<_> = (0..4)
+        .map(|i| {
+            std::thread::spawn(move || {
+                with_jvm(|jvm| {
+                    java::lang::Integer::value_of(i)
+                        .int_value()
+                        .execute_with(jvm)
+                })
+            })
+        })
+        .collect();
+    let values: Vec<i32> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+    assert_eq!(values, [0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "java.lang.IndexOutOfBoundsException")]
+fn exceptions_panic() {
+    with_jvm(|jvm| {
+        let list = java::util::ArrayList::<java::lang::Object>::new().execute_with(jvm)?;
+        list.get(0).execute_with(jvm)?;
+        Ok(())
+    });
+}
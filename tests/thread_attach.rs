@@ -0,0 +1,35 @@
+use duchess::java::lang::ThreadExt;
+use duchess::{java, prelude::*, Jvm};
+
+/// Each time a thread is attached to the JVM, it gets a fresh `java.lang.Thread` object.
+fn current_thread_id() -> duchess::GlobalResult<i64> {
+    java::lang::Thread::current_thread()
+        .assert_not_null()
+        .get_id()
+        .execute()
+}
+
+#[test]
+fn execute_detaches_threads_it_attached() -> duchess::GlobalResult<()> {
+    std::thread::spawn(|| {
+        let first = current_thread_id()?;
+        let second = current_thread_id()?;
+        assert_ne!(first, second);
+        Ok(())
+    })
+    .join()
+    .unwrap()
+}
+
+#[test]
+fn execute_keeps_permanently_attached_threads() -> duchess::GlobalResult<()> {
+    std::thread::spawn(|| {
+        Jvm::attach_thread_permanently()?;
+        let first = current_thread_id()?;
+        let second = current_thread_id()?;
+        assert_eq!(first, second);
+        Ok(())
+    })
+    .join()
+    .unwrap()
+}
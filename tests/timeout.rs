@@ -0,0 +1,41 @@
+use std::time::{Duration, Instant};
+
+use duchess::{prelude::*, Error};
+
+duchess::java_package! {
+    package blocking;
+
+    class Sleeper { * }
+}
+
+#[test]
+fn timeout_interrupts_sleeping_thread() {
+    let started = Instant::now();
+    let result =
+        blocking::Sleeper::sleep(60_000_i64).execute_with_timeout(Duration::from_millis(200));
+    assert!(matches!(result, Err(Error::Timeout(d)) if d == Duration::from_millis(200)));
+    assert!(started.elapsed() < Duration::from_secs(30));
+
+    // The interrupt is delivered asynchronously to the worker thread
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while !blocking::Sleeper::was_interrupted().execute().unwrap() {
+        assert!(
+            Instant::now() < deadline,
+            "sleeping thread was not interrupted"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn fast_operations_complete() -> duchess::GlobalResult<()> {
+    let answer = blocking::Sleeper::answer().execute_with_timeout(Duration::from_secs(30))?;
+    assert_eq!(answer, 42);
+    Ok(())
+}
+
+#[test]
+fn exceptions_are_returned() {
+    let result = blocking::Sleeper::sleep(-1_i64).execute_with_timeout(Duration::from_secs(30));
+    assert!(matches!(result, Err(Error::Thrown(_))));
+}
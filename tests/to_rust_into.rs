@@ -0,0 +1,49 @@
+use duchess::java::lang::StringExt;
+use duchess::{prelude::*, Jvm};
+
+#[test]
+fn string_reuses_buffer() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let mut buffer = String::with_capacity(64);
+        let ptr = buffer.as_ptr();
+
+        for word in ["hello", "", "wörld", "\u{1F980} crab"] {
+            word.to_rust_into(&mut buffer).execute_with(jvm)?;
+            assert_eq!(buffer, word);
+            assert_eq!(buffer.as_ptr(), ptr);
+        }
+        Ok(())
+    })
+}
+
+#[test]
+fn byte_array_reuses_buffer() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let mut buffer: Vec<u8> = Vec::with_capacity(64);
+        let ptr = buffer.as_ptr();
+
+        for word in ["abc", "defgh", ""] {
+            word.execute_with(jvm)?
+                .get_bytes()
+                .assert_not_null()
+                .to_rust_into(&mut buffer)
+                .execute_with(jvm)?;
+            assert_eq!(buffer, word.as_bytes());
+            assert_eq!(buffer.as_ptr(), ptr);
+        }
+        Ok(())
+    })
+}
+
+#[test]
+fn primitive_array_overwrites_previous_contents() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let mut buffer: Vec<i32> = vec![7, 8, 9, 10];
+        [1_i32, 2]
+            .as_slice()
+            .to_rust_into(&mut buffer)
+            .execute_with(jvm)?;
+        assert_eq!(buffer, vec![1, 2]);
+        Ok(())
+    })
+}
@@ -0,0 +1,30 @@
+use duchess::{java, prelude::*, Jvm};
+
+/// A second conversion for `java.lang.String`, next to the one to `String`.
+#[derive(Debug, PartialEq)]
+struct Length(usize);
+
+impl duchess::ToRust<Length> for java::lang::String {
+    fn to_rust<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> duchess::Result<'jvm, Length> {
+        let string: String = jvm.to_rust(self)?;
+        Ok(Length(string.len()))
+    }
+}
+
+#[test]
+fn target_type_named_at_call_site() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let string = "typed"
+            .to_string()
+            .to_java::<java::lang::String>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+
+        assert_eq!(string.to_rust::<Length>().execute_with(jvm)?, Length(5));
+        assert_eq!(string.to_rust::<String>().execute_with(jvm)?, "typed");
+
+        assert_eq!(jvm.to_rust::<Length>(&string)?, Length(5));
+        assert_eq!(jvm.to_rust::<String>(&string)?, "typed");
+        Ok(())
+    })
+}
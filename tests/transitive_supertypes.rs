@@ -0,0 +1,90 @@
+//! Classes can be upcast to all of their supertypes with Rust bindings, so inherited methods are callable on them even
+//! though `javap` only lists the direct supertypes.
+
+use duchess::java::lang::ThrowableExt;
+use duchess::{java, prelude::*, Jvm, Local};
+use hierarchy::{LabeledExt, NamedExt, SourceExt};
+
+duchess::java_package! {
+    package hierarchy;
+
+    class Named { * }
+    class Labeled { * }
+    class Base { * }
+    class Leaf { * }
+    class Source { * }
+    class Cell { * }
+    class Greeting { * }
+
+    // Doesn't list any supertypes
+    class Failure {
+        hierarchy.Failure(java.lang.String);
+    }
+}
+
+#[test]
+fn interface_methods_through_superclass() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let leaf = hierarchy::Leaf::new().execute_with(jvm)?;
+
+        // `Leaf` implements `Labeled` through `Base`, and `Named` through `Labeled`
+        let label: String = leaf.label().assert_not_null().to_rust().execute_with(jvm)?;
+        assert_eq!(label, "a leaf");
+        let named: Local<hierarchy::Named> = leaf.upcast();
+        let name: String = named.name().assert_not_null().to_rust().execute_with(jvm)?;
+        assert_eq!(name, "leaf");
+        Ok(())
+    })
+}
+
+#[test]
+fn redeclared_methods_are_not_duplicated() -> duchess::GlobalResult<()> {
+    use hierarchy::LeafExt;
+
+    Jvm::with(|jvm| {
+        let leaf = hierarchy::Leaf::new().execute_with(jvm)?;
+
+        // Both dispatch to the override, `Leaf` just declares it again
+        let own: String = LeafExt::name(&leaf)
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        let inherited: String = NamedExt::name(&leaf)
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!((own.as_str(), inherited.as_str()), ("leaf", "leaf"));
+        Ok(())
+    })
+}
+
+#[test]
+fn generic_arguments_of_indirect_supertypes() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let greeting = hierarchy::Greeting::new().execute_with(jvm)?;
+
+        // `Greeting extends Cell<String>`, which implements `Source<T>` with `T = String`
+        let source: Local<hierarchy::Source<java::lang::String>> = greeting.upcast();
+        let value: String = source.get().assert_not_null().to_rust().execute_with(jvm)?;
+        assert_eq!(value, "hello");
+        Ok(())
+    })
+}
+
+#[test]
+fn supertypes_bound_by_duchess() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let failure = hierarchy::Failure::new("broken").execute_with(jvm)?;
+
+        // `Failure extends IllegalStateException`, which isn't bound, and reaches `Throwable` through
+        // `RuntimeException`, which is bound in `duchess::java`
+        let throwable: Local<java::lang::Throwable> = failure.upcast();
+        let message: String = throwable
+            .get_message()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(message, "broken");
+        Ok(())
+    })
+}
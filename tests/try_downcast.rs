@@ -0,0 +1,75 @@
+use duchess::java::lang::NumberExt;
+use duchess::{java, prelude::*, Jvm, OneOf2, OneOf3, ToRust};
+
+#[test]
+fn map_java_runs_only_on_successful_downcast() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let object = "downcast"
+            .to_string()
+            .to_java::<java::lang::String>()
+            .assert_not_null()
+            .upcast::<java::lang::Object>()
+            .execute_with(jvm)?;
+
+        let string = object
+            .try_downcast::<java::lang::String>()
+            .map_java(|s, jvm| ToRust::<String>::to_rust(&s, jvm))
+            .execute_with(jvm)?;
+        assert_eq!(string.ok().as_deref(), Some("downcast"));
+
+        let throwable = object
+            .try_downcast::<java::lang::Throwable>()
+            .map_java(|_, _| -> duchess::Result<'_, ()> { panic!("not a Throwable") })
+            .execute_with(jvm)?;
+        let Err(original) = throwable else {
+            panic!("expected the downcast to fail")
+        };
+        assert!(original.ref_eq(&object).execute_with(jvm)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn downcast_to_first_matching_class() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let string = "text"
+            .to_java::<java::lang::String>()
+            .assert_not_null()
+            .upcast::<java::lang::Object>()
+            .execute_with(jvm)?;
+        let number = java::lang::Integer::value_of(7)
+            .upcast::<java::lang::Object>()
+            .execute_with(jvm)?;
+        let list = java::util::ArrayList::<java::lang::Object>::new()
+            .upcast::<java::lang::Object>()
+            .execute_with(jvm)?;
+
+        type Targets = (
+            java::lang::Throwable,
+            java::lang::Number,
+            java::lang::String,
+        );
+        let OneOf3::Third(s) = string.try_downcast_one_of::<Targets>().execute_with(jvm)? else {
+            panic!("expected a String")
+        };
+        assert_eq!(ToRust::<String>::to_rust(&s, jvm)?, "text");
+
+        let OneOf3::Second(n) = number.try_downcast_one_of::<Targets>().execute_with(jvm)? else {
+            panic!("expected a Number")
+        };
+        assert_eq!(n.int_value().execute_with(jvm)?, 7);
+
+        let OneOf3::Other(original) = list.try_downcast_one_of::<Targets>().execute_with(jvm)?
+        else {
+            panic!("expected no match")
+        };
+        assert!(original.ref_eq(&list).execute_with(jvm)?);
+
+        // The first match wins, even if a later class is more specific
+        let first = string
+            .try_downcast_one_of::<(java::lang::CharSequence, java::lang::String)>()
+            .execute_with(jvm)?;
+        assert!(matches!(first, OneOf2::First(_)));
+        Ok(())
+    })
+}
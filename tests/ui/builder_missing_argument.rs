@@ -0,0 +1,17 @@
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package params;
+
+    #[builder]
+    class Contact { * }
+}
+
+fn main() {
+    // `age` isn't set, so there is no `build()` yet
+    let _contact = params::Contact::builder()
+        .first_name("Grace")
+        .last_name("Hopper")
+        .build()
+        .execute();
+}
@@ -0,0 +1,10 @@
+duchess::java_package! {
+    package records;
+
+    #[builder]
+    class Point { * }
+
+    class Interval as PointBuilder { * }
+}
+
+fn main() {}
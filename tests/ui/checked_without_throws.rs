@@ -0,0 +1,10 @@
+duchess::java_package! {
+    package vaults;
+
+    public class WrongCode {
+        #[checked]
+        public int attempts();
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+duchess::java_package! {
+    package java.lang;
+
+    public class java.util.Date {
+        public java.util.Date();
+    }
+}
+
+fn main() {}
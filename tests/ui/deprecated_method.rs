@@ -0,0 +1,20 @@
+#![deny(deprecated)]
+
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package deprecations;
+
+    #[annotations]
+    class Legacy { * }
+}
+
+use deprecations::LegacyExt;
+
+fn main() -> duchess::GlobalResult<()> {
+    let legacy = deprecations::Legacy::new().global().execute()?;
+    legacy.new_value().execute()?;
+    legacy.old_value().execute()?;
+    deprecations::Legacy::create().global().execute()?;
+    Ok(())
+}
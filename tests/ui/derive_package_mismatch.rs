@@ -0,0 +1,14 @@
+duchess::java_package! {
+    package auth;
+
+    class Authenticated { * }
+}
+
+#[derive(duchess::ToRust)]
+#[java(class = "java.lang.Authenticated", package = "auth")]
+struct Authenticated {
+    account_id: String,
+    user: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+duchess::java_package! {
+    package wildcard.*;
+    except Counter, Countr;
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+duchess::java_package! {
+    package factories;
+
+    public class Temperature {
+        #[factory]
+        public static factories.Temperature ofCelsius(double);
+        #[factory]
+        public double celsius();
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use duchess::{java, prelude::*, Jvm, Local};
+
+duchess::java_package! {
+    package generics;
+
+    class Holder { * }
+}
+
+fn main() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let holder: Local<generics::Holder<java::lang::String>> =
+            generics::Holder::new("hello").execute_with(jvm)?;
+        let _: Local<generics::Holder<java::lang::Object>> = holder;
+        Ok(())
+    })
+}
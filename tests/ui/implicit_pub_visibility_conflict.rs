@@ -0,0 +1,11 @@
+duchess::java_package! {
+    pub(crate) package keywords.move;
+
+    class Dispatcher { * }
+
+    package keywords.mod.except;
+
+    class Filter { * }
+}
+
+fn main() {}
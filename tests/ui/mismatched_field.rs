@@ -0,0 +1,9 @@
+duchess::java_package! {
+    package java.lang;
+
+    public final class java.lang.Integer {
+        public static final long MAX_VALUE;
+    }
+}
+
+fn main() {}
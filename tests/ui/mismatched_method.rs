@@ -0,0 +1,10 @@
+duchess::java_package! {
+    package java.lang;
+
+    public class java.lang.Object {
+        public java.lang.Object();
+        public boolean equals(int);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+mod internal {
+    duchess::java_package! {
+        pub(self) package wildcard;
+
+        class Greeter { * }
+    }
+}
+
+fn main() {
+    let _ = internal::wildcard::Greeter::new();
+}
@@ -0,0 +1,8 @@
+duchess::java_package! {
+    package renames;
+
+    class Result as Holder { * }
+    class Holder { * }
+}
+
+fn main() {}
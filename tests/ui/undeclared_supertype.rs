@@ -0,0 +1,9 @@
+duchess::java_package! {
+    package exceptions;
+
+    public class exceptions.CheckedFailure extends java.lang.Exception, java.lang.RuntimeException {
+        public exceptions.CheckedFailure(java.lang.String);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![deny(unused_must_use)]
+
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package factories;
+
+    class Temperature { * }
+}
+
+use factories::TemperatureExt;
+
+fn main() -> duchess::GlobalResult<()> {
+    let temperature = factories::Temperature::of_celsius(21.5)
+        .assert_not_null()
+        .global()
+        .execute()?;
+    temperature.celsius();
+    factories::Temperature::of_celsius(0.0);
+    Ok(())
+}
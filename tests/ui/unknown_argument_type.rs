@@ -0,0 +1,14 @@
+duchess::java_package! {
+    package java.lang;
+
+    public class java.lang.Object {
+        public java.lang.Object();
+    }
+
+    public final class java.lang.String {
+        public java.lang.String();
+        public boolean contentEquals(java.lang.CharSequence);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,54 @@
+use duchess::{java, java::lang::ObjectExt, java::util::UUIDExt, prelude::*, Jvm};
+use uuid::Uuid;
+
+fn round_trip(uuid: Uuid) -> duchess::GlobalResult<Uuid> {
+    uuid.to_java::<java::util::UUID>()
+        .assert_not_null()
+        .to_rust()
+        .execute()
+}
+
+#[test]
+fn uuid_round_trip() -> duchess::GlobalResult<()> {
+    for uuid in [Uuid::nil(), Uuid::max(), Uuid::new_v4()] {
+        assert_eq!(round_trip(uuid)?, uuid);
+    }
+    Ok(())
+}
+
+#[test]
+fn uuid_bits_match_java() -> duchess::GlobalResult<()> {
+    let uuid = Uuid::new_v4();
+    Jvm::with(|jvm| {
+        let java_uuid = uuid
+            .to_java::<java::util::UUID>()
+            .assert_not_null()
+            .execute_with(jvm)?;
+        assert_eq!(java_uuid.version().execute_with(jvm)?, 4);
+
+        let text: String = java_uuid
+            .to_string()
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(text, uuid.hyphenated().to_string());
+        Ok(())
+    })
+}
+
+#[test]
+fn uuid_from_java() -> duchess::GlobalResult<()> {
+    let text = "123e4567-e89b-12d3-a456-426614174000";
+    let uuid: Uuid = java::util::UUID::from_string(text)
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(uuid, Uuid::parse_str(text).unwrap());
+
+    let random: Uuid = java::util::UUID::random_uuid()
+        .assert_not_null()
+        .to_rust()
+        .execute()?;
+    assert_eq!(random.get_version_num(), 4);
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use duchess::{prelude::*, Jvm};
+
+mod internal {
+    duchess::java_package! {
+        pub(crate) mod bindings;
+
+        package wildcard;
+
+        class Greeter { * }
+    }
+
+    // Only the parent module can use these bindings
+    duchess::java_package! {
+        pub(super) package covariant;
+
+        class Animal { * }
+    }
+
+    /// Wraps the Java class without exposing it.
+    pub fn greet(name: &str) -> duchess::GlobalResult<String> {
+        use bindings::wildcard::{Greeter, GreeterExt};
+        use duchess::prelude::*;
+
+        Greeter::new()
+            .greet(name)
+            .assert_not_null()
+            .to_rust()
+            .execute()
+    }
+}
+
+#[test]
+fn restricted_bindings_are_usable_where_visible() -> duchess::GlobalResult<()> {
+    assert_eq!(internal::greet("duchess")?, "Hello, duchess");
+
+    Jvm::with(|jvm| {
+        let greeter = internal::bindings::wildcard::Greeter::new().execute_with(jvm)?;
+        let animal = internal::covariant::Animal::new().execute_with(jvm)?;
+        assert!(!greeter.ref_eq(&animal).execute_with(jvm)?);
+        Ok(())
+    })
+}
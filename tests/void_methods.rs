@@ -0,0 +1,52 @@
+use duchess::{prelude::*, Error, Jvm};
+
+duchess::java_package! {
+    package voids;
+
+    class Tally { * }
+}
+
+use voids::TallyExt;
+
+#[test]
+fn void_methods_return_unit() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let tally = voids::Tally::new().execute_with(jvm)?;
+
+        let () = tally.add(2).execute_with(jvm)?;
+        tally.add(3).execute_with(jvm)?;
+        assert_eq!(tally.count().execute_with(jvm)?, 5);
+        Ok(())
+    })
+}
+
+#[test]
+fn static_void_methods_return_unit() -> duchess::GlobalResult<()> {
+    // `execute` on a void op gives a `GlobalResult<()>`
+    let result: duchess::GlobalResult<()> = voids::Tally::reset_created().execute();
+    result?;
+
+    voids::Tally::new().global().execute()?;
+    assert!(voids::Tally::created().execute()? >= 1);
+    Ok(())
+}
+
+#[test]
+fn void_methods_propagate_exceptions() -> duchess::GlobalResult<()> {
+    let tally = voids::Tally::new().global().execute()?;
+
+    let result = tally.add(-1).execute();
+    assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+
+    // The failed call didn't change anything, and later calls work as usual
+    tally.add(1).execute()?;
+    assert_eq!(tally.count().execute()?, 1);
+    Ok(())
+}
+
+#[test]
+fn void_ops_are_void_methods() {
+    fn assert_void(_: impl VoidMethod) {}
+
+    assert_void(voids::Tally::reset_created());
+}
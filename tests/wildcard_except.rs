@@ -0,0 +1,22 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    import wildcard.*;
+    except Counter;
+}
+
+use wildcard::GreeterExt;
+
+#[test]
+fn binds_classes_that_are_not_excluded() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let greeter = wildcard::Greeter::new().execute_with(jvm)?;
+        let greeting: String = greeter
+            .greet("duchess")
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(greeting, "Hello, duchess");
+        Ok(())
+    })
+}
@@ -0,0 +1,25 @@
+use duchess::{prelude::*, Jvm};
+
+duchess::java_package! {
+    package wildcard.*;
+}
+
+use wildcard::{CounterExt, GreeterExt};
+
+#[test]
+fn binds_every_public_class() -> duchess::GlobalResult<()> {
+    Jvm::with(|jvm| {
+        let greeter = wildcard::Greeter::new().execute_with(jvm)?;
+        let greeting: String = greeter
+            .greet("duchess")
+            .assert_not_null()
+            .to_rust()
+            .execute_with(jvm)?;
+        assert_eq!(greeting, "Hello, duchess");
+
+        let counter = wildcard::Counter::new().execute_with(jvm)?;
+        counter.increment().execute_with(jvm)?;
+        assert_eq!(counter.increment().execute_with(jvm)?, 2);
+        Ok(())
+    })
+}